@@ -38,7 +38,7 @@ use move_vm_runtime::{
 use sui_cost_tables::bytecode_tables::GasStatus;
 use sui_framework::natives::object_runtime::{self, ObjectRuntime};
 use sui_json::primitive_type;
-use sui_protocol_constants::*;
+use sui_protocol_constants::ProtocolConfig;
 use sui_types::storage::SingleTxContext;
 use sui_types::{
     base_types::*,
@@ -57,21 +57,26 @@ use tracing::instrument;
 
 use crate::execution_mode::{self, ExecutionMode};
 
-pub fn new_move_vm(natives: NativeFunctionTable) -> Result<MoveVM, SuiError> {
+pub fn new_move_vm(
+    natives: NativeFunctionTable,
+    protocol_config: &ProtocolConfig,
+) -> Result<MoveVM, SuiError> {
     MoveVM::new_with_config(
         natives,
         VMConfig {
             verifier: VerifierConfig {
-                max_loop_depth: Some(MAX_LOOP_DEPTH),
-                max_generic_instantiation_length: Some(MAX_GENERIC_INSTANTIATION_LENGTH),
-                max_function_parameters: Some(MAX_FUNCTION_PARAMETERS),
-                max_basic_blocks: Some(MAX_BASIC_BLOCKS),
-                max_value_stack_size: MAX_VALUE_STACK_SIZE,
-                max_type_nodes: Some(MAX_TYPE_NODES),
-                max_push_size: Some(MAX_PUSH_SIZE),
-                max_dependency_depth: MAX_DEPENDENCY_DEPTH,
+                max_loop_depth: Some(protocol_config.max_loop_depth()),
+                max_generic_instantiation_length: Some(
+                    protocol_config.max_generic_instantiation_length(),
+                ),
+                max_function_parameters: Some(protocol_config.max_function_parameters()),
+                max_basic_blocks: Some(protocol_config.max_basic_blocks()),
+                max_value_stack_size: protocol_config.max_value_stack_size(),
+                max_type_nodes: Some(protocol_config.max_type_nodes()),
+                max_push_size: Some(protocol_config.max_push_size()),
+                max_dependency_depth: protocol_config.max_dependency_depth(),
             },
-            max_binary_format_version: MOVE_BINARY_FORMAT_VERSION,
+            max_binary_format_version: protocol_config.move_binary_format_version(),
             paranoid_type_checks: false,
         },
     )
@@ -121,6 +126,7 @@ pub fn execute<
     args: Vec<CallArg>,
     gas_status: &mut GasStatus,
     ctx: &mut TxContext,
+    protocol_config: &ProtocolConfig,
 ) -> Result<Mode::ExecutionResult, ExecutionError> {
     let mut objects: BTreeMap<ObjectID, &Object> = BTreeMap::new();
     for arg in &args {
@@ -171,6 +177,7 @@ pub fn execute<
         mutable_ref_objects,
         gas_status,
         ctx,
+        protocol_config,
     )
 }
 
@@ -198,6 +205,7 @@ fn execute_internal<
     mut mutable_ref_objects: BTreeMap<LocalIndex, ObjectID>,
     gas_status: &mut GasStatus, // gas status for the current call operation
     ctx: &mut TxContext,
+    protocol_config: &ProtocolConfig,
 ) -> Result<Mode::ExecutionResult, ExecutionError> {
     let input_objects = object_data
         .iter()
@@ -309,6 +317,7 @@ fn execute_internal<
         deletions,
         user_events,
         ctx,
+        protocol_config,
     )?;
     Ok(mode_result)
 }
@@ -327,6 +336,7 @@ pub fn publish<
     module_bytes: Vec<Vec<u8>>,
     ctx: &mut TxContext,
     gas_status: &mut GasStatus,
+    protocol_config: &ProtocolConfig,
 ) -> Result<(), ExecutionError> {
     let mut modules = module_bytes
         .iter()
@@ -342,7 +352,7 @@ pub fn publish<
 
     let package_id = generate_package_id(&mut modules, ctx)?;
     let vm = verify_and_link(state_view, &modules, package_id, natives, gas_status)?;
-    store_package_and_init_modules(state_view, &vm, modules, ctx, gas_status)
+    store_package_and_init_modules(state_view, &vm, modules, ctx, gas_status, protocol_config)
 }
 
 /// Store package in state_view and call module initializers
@@ -359,6 +369,7 @@ pub fn store_package_and_init_modules<
     modules: Vec<CompiledModule>,
     ctx: &mut TxContext,
     gas_status: &mut GasStatus,
+    protocol_config: &ProtocolConfig,
 ) -> Result<(), ExecutionError> {
     let modules_to_init = modules
         .iter()
@@ -379,7 +390,11 @@ pub fn store_package_and_init_modules<
 
     // wrap the modules in an object, write it to the store
     // The call to unwrap() will go away once we remove address owner from Immutable objects.
-    let package_object = Object::new_package(modules, ctx.digest())?;
+    let package_object = Object::new_package(
+        modules,
+        ctx.digest(),
+        protocol_config.max_move_package_size(),
+    )?;
     let id = package_object.id();
     let changes = BTreeMap::from([(
         id,
@@ -391,7 +406,14 @@ pub fn store_package_and_init_modules<
     )]);
     state_view.apply_object_changes(changes);
 
-    init_modules(state_view, vm, modules_to_init, ctx, gas_status)
+    init_modules(
+        state_view,
+        vm,
+        modules_to_init,
+        ctx,
+        gas_status,
+        protocol_config,
+    )
 }
 
 /// Modules in module_ids_to_init must have the init method defined
@@ -408,6 +430,7 @@ fn init_modules<
     module_ids_to_init: Vec<(ModuleId, usize)>,
     ctx: &mut TxContext,
     gas_status: &mut GasStatus,
+    protocol_config: &ProtocolConfig,
 ) -> Result<(), ExecutionError> {
     let init_ident = Identifier::new(INIT_FN_NAME.as_str()).unwrap();
     for (module_id, num_args) in module_ids_to_init {
@@ -436,6 +459,7 @@ fn init_modules<
             BTreeMap::new(),
             gas_status,
             ctx,
+            protocol_config,
         )?;
     }
     Ok(())
@@ -540,6 +564,7 @@ fn process_successful_execution<S: Storage + ParentSync>(
     deletions: LinkedHashMap<ObjectID, DeleteKind>,
     user_events: Vec<(StructTag, Vec<u8>)>,
     ctx: &TxContext,
+    protocol_config: &ProtocolConfig,
 ) -> Result<(), ExecutionError> {
     let sender = ctx.sender();
     let tx_ctx = SingleTxContext {
@@ -557,7 +582,7 @@ fn process_successful_execution<S: Storage + ParentSync>(
         obj.data
             .try_as_move_mut()
             .expect("We previously checked that mutable ref inputs are Move objects")
-            .update_contents(new_contents)?;
+            .update_contents(new_contents, protocol_config)?;
 
         changes.insert(
             obj_id,
@@ -598,6 +623,7 @@ fn process_successful_execution<S: Storage + ParentSync>(
                 has_public_transfer,
                 old_obj_ver.unwrap_or_else(SequenceNumber::new),
                 contents,
+                protocol_config,
             )?
         };
 
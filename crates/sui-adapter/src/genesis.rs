@@ -3,6 +3,7 @@
 
 use move_binary_format::CompiledModule;
 use once_cell::sync::Lazy;
+use sui_protocol_constants::ProtocolConfig;
 use sui_types::base_types::{ObjectRef, SuiAddress, TxContext};
 use sui_types::SUI_FRAMEWORK_ADDRESS;
 use sui_types::{base_types::TransactionDigest, object::Object};
@@ -39,10 +40,22 @@ pub fn get_genesis_context() -> TxContext {
 fn create_genesis_module_objects() -> Genesis {
     let sui_modules = sui_framework::get_sui_framework();
     let std_modules = sui_framework::get_move_stdlib();
+    // A new network starts at the oldest supported protocol version.
+    let max_move_package_size = ProtocolConfig::get_for_min_version().max_move_package_size();
     // unwraps safe because genesis packages should never exceed max size
     let objects = vec![
-        Object::new_package(std_modules.clone(), TransactionDigest::genesis()).unwrap(),
-        Object::new_package(sui_modules.clone(), TransactionDigest::genesis()).unwrap(),
+        Object::new_package(
+            std_modules.clone(),
+            TransactionDigest::genesis(),
+            max_move_package_size,
+        )
+        .unwrap(),
+        Object::new_package(
+            sui_modules.clone(),
+            TransactionDigest::genesis(),
+            max_move_package_size,
+        )
+        .unwrap(),
     ];
     let modules = vec![std_modules, sui_modules];
     Genesis { objects, modules }
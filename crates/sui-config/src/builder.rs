@@ -321,6 +321,9 @@ impl<R: rand::RngCore + rand::CryptoRng> ConfigBuilder<R> {
                     grpc_concurrency_limit: initial_accounts_config.grpc_concurrency_limit,
                     p2p_config,
                     authority_store_pruning_config: AuthorityStorePruningConfig::validator_config(),
+                    authority_store_cache_config: Default::default(),
+                    checkpoint_executor_config: Default::default(),
+                    supported_protocol_versions: None,
                 }
             })
             .collect();
@@ -17,6 +17,7 @@ use std::convert::TryInto;
 use std::{fs, path::Path};
 use sui_adapter::adapter::MoveVM;
 use sui_adapter::{adapter, execution_mode};
+use sui_protocol_constants::{ProtocolConfig, ProtocolVersion};
 use sui_types::base_types::ObjectID;
 use sui_types::base_types::TransactionDigest;
 use sui_types::chain_id::ChainId;
@@ -88,6 +89,10 @@ impl Genesis {
         self.sui_system_object().chain_id
     }
 
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.sui_system_object().protocol_version()
+    }
+
     pub fn get_default_genesis() -> Self {
         Builder::new().build()
     }
@@ -193,12 +198,28 @@ pub struct GenesisValidatorInfo {
 }
 
 /// Initial set of parameters for a chain.
-#[derive(Default, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct GenesisChainParameters {
     chain_id: ChainId,
+    /// Protocol version the chain starts at.
+    #[serde(default = "default_protocol_version")]
+    protocol_version: ProtocolVersion,
     // In the future we can add the initial gas schedule or other parameters here
 }
 
+impl Default for GenesisChainParameters {
+    fn default() -> Self {
+        Self {
+            chain_id: Default::default(),
+            protocol_version: default_protocol_version(),
+        }
+    }
+}
+
+fn default_protocol_version() -> ProtocolVersion {
+    ProtocolVersion::MIN
+}
+
 pub struct Builder {
     parameters: GenesisChainParameters,
     objects: BTreeMap<ObjectID, Object>,
@@ -225,6 +246,11 @@ impl Builder {
         self
     }
 
+    pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
+        self.parameters.protocol_version = protocol_version;
+        self
+    }
+
     pub fn with_parameters(mut self, parameters: GenesisChainParameters) -> Self {
         self.parameters = parameters;
         self
@@ -275,6 +301,7 @@ impl Builder {
         let objects = create_genesis_objects(
             &mut genesis_ctx,
             self.parameters.chain_id,
+            self.parameters.protocol_version,
             &modules,
             &objects,
             &validators,
@@ -291,6 +318,10 @@ impl Builder {
         // Verify that all the validators were properly created onchain
         let system_object = genesis.sui_system_object();
         assert_eq!(system_object.epoch, 0);
+        assert_eq!(
+            system_object.protocol_version(),
+            self.parameters.protocol_version
+        );
 
         for (validator, onchain_validator) in genesis
             .validator_set()
@@ -402,6 +433,7 @@ impl Builder {
 fn create_genesis_objects(
     genesis_ctx: &mut TxContext,
     chain_id: ChainId,
+    protocol_version: ProtocolVersion,
     modules: &[Vec<CompiledModule>],
     input_objects: &[Object],
     validators: &[GenesisValidatorInfo],
@@ -410,7 +442,8 @@ fn create_genesis_objects(
 
     let native_functions =
         sui_framework::natives::all_natives(MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS);
-    let move_vm = adapter::new_move_vm(native_functions.clone())
+    let protocol_config = ProtocolConfig::get_for_version(protocol_version);
+    let move_vm = adapter::new_move_vm(native_functions.clone(), &protocol_config)
         .expect("We defined natives to not fail here");
 
     for modules in modules {
@@ -419,6 +452,7 @@ fn create_genesis_objects(
             &native_functions,
             genesis_ctx,
             modules.to_owned(),
+            &protocol_config,
         )
         .unwrap();
     }
@@ -427,8 +461,15 @@ fn create_genesis_objects(
         store.insert_object(object.to_owned());
     }
 
-    generate_genesis_system_object(&mut store, &move_vm, chain_id, validators, genesis_ctx)
-        .unwrap();
+    generate_genesis_system_object(
+        &mut store,
+        &move_vm,
+        chain_id,
+        protocol_version,
+        validators,
+        genesis_ctx,
+    )
+    .unwrap();
 
     store
         .into_inner()
@@ -443,6 +484,7 @@ fn process_package(
     native_functions: &NativeFunctionTable,
     ctx: &mut TxContext,
     modules: Vec<CompiledModule>,
+    protocol_config: &ProtocolConfig,
 ) -> Result<()> {
     let inputs = Transaction::input_objects_in_compiled_modules(&modules);
     let ids: Vec<_> = inputs.iter().map(|kind| kind.object_id()).collect();
@@ -492,6 +534,7 @@ fn process_package(
         modules,
         ctx,
         gas_status.create_move_gas_status(),
+        protocol_config,
     )?;
 
     let (
@@ -510,6 +553,7 @@ pub fn generate_genesis_system_object(
     store: &mut InMemoryStorage,
     move_vm: &MoveVM,
     chain_id: ChainId,
+    protocol_version: ProtocolVersion,
     committee: &[GenesisValidatorInfo],
     genesis_ctx: &mut TxContext,
 ) -> Result<()> {
@@ -569,9 +613,11 @@ pub fn generate_genesis_system_object(
             CallArg::Pure(bcs::to_bytes(&stakes).unwrap()),
             CallArg::Pure(bcs::to_bytes(&gas_prices).unwrap()),
             CallArg::Pure(bcs::to_bytes(&commission_rates).unwrap()),
+            CallArg::Pure(bcs::to_bytes(&protocol_version.as_u64()).unwrap()),
         ],
         SuiGasStatus::new_unmetered().create_move_gas_status(),
         genesis_ctx,
+        &ProtocolConfig::get_for_version(protocol_version),
     )?;
 
     let (
@@ -13,6 +13,7 @@ use std::collections::BTreeMap;
 use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use sui_protocol_constants::SupportedProtocolVersions;
 use sui_types::base_types::SuiAddress;
 use sui_types::committee::StakeUnit;
 use sui_types::crypto::AccountKeyPair;
@@ -87,6 +88,18 @@ pub struct NodeConfig {
 
     #[serde(default = "default_authority_store_pruning_config")]
     pub authority_store_pruning_config: AuthorityStorePruningConfig,
+
+    #[serde(default)]
+    pub authority_store_cache_config: AuthorityStoreCacheConfig,
+
+    #[serde(default)]
+    pub checkpoint_executor_config: CheckpointExecutorConfig,
+
+    /// Range of protocol versions this node advertises to the committee.
+    /// Only intended for testing protocol upgrades - production nodes should
+    /// leave this unset and use the versions supported by the binary.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supported_protocol_versions: Option<SupportedProtocolVersions>,
 }
 
 fn default_key_pair() -> Arc<AuthorityKeyPair> {
@@ -212,6 +225,38 @@ impl ConsensusConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CheckpointExecutorConfig {
+    /// Maximum number of checkpoints whose transactions are handed to the TransactionManager at
+    /// the same time. Transactions of all these checkpoints are executed concurrently according
+    /// to their object dependencies, so a higher value speeds up catching up with the network.
+    #[serde(default = "default_checkpoint_execution_max_concurrency")]
+    pub checkpoint_execution_max_concurrency: usize,
+
+    /// Number of seconds to wait for the transactions of a checkpoint to be executed before
+    /// logging a warning.
+    #[serde(default = "default_local_execution_timeout_sec")]
+    pub local_execution_timeout_sec: u64,
+}
+
+fn default_checkpoint_execution_max_concurrency() -> usize {
+    200
+}
+
+fn default_local_execution_timeout_sec() -> u64 {
+    10
+}
+
+impl Default for CheckpointExecutorConfig {
+    fn default() -> Self {
+        Self {
+            checkpoint_execution_max_concurrency: default_checkpoint_execution_max_concurrency(),
+            local_execution_timeout_sec: default_local_execution_timeout_sec(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct AuthorityStorePruningConfig {
@@ -247,6 +292,27 @@ impl AuthorityStorePruningConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AuthorityStoreCacheConfig {
+    /// Upper bound in bytes on the memory held by the cache of latest object versions in front
+    /// of the objects table. Setting it to 0 disables the cache.
+    #[serde(default = "default_object_cache_size_bytes")]
+    pub object_cache_size_bytes: usize,
+}
+
+fn default_object_cache_size_bytes() -> usize {
+    256 * 1024 * 1024
+}
+
+impl Default for AuthorityStoreCacheConfig {
+    fn default() -> Self {
+        Self {
+            object_cache_size_bytes: default_object_cache_size_bytes(),
+        }
+    }
+}
+
 /// Publicly known information about a validator
 /// TODO read most of this from on-chain
 #[serde_as]
@@ -205,6 +205,9 @@ impl<'a> FullnodeConfigBuilder<'a> {
             grpc_concurrency_limit: None,
             p2p_config,
             authority_store_pruning_config: AuthorityStorePruningConfig::fullnode_config(),
+            authority_store_cache_config: Default::default(),
+            checkpoint_executor_config: Default::default(),
+            supported_protocol_versions: None,
         })
     }
 }
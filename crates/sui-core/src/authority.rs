@@ -11,7 +11,7 @@ use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
 use move_core_types::parser::parse_struct_tag;
 use move_core_types::{language_storage::ModuleId, resolver::ModuleResolver};
-use move_vm_runtime::{move_vm::MoveVM, native_functions::NativeFunctionTable};
+use move_vm_runtime::native_functions::NativeFunctionTable;
 use mysten_metrics::spawn_monitored_task;
 use prometheus::{
     register_histogram_with_registry, register_int_counter_with_registry,
@@ -21,8 +21,7 @@ use std::path::PathBuf;
 use std::str::FromStr;
 use std::time::Duration;
 use std::{collections::HashMap, pin::Pin, sync::Arc};
-use sui_config::node::AuthorityStorePruningConfig;
-use sui_protocol_constants::MAX_TX_GAS;
+use sui_config::node::{AuthorityStoreCacheConfig, AuthorityStorePruningConfig};
 use tap::TapFallible;
 use tokio::sync::mpsc::unbounded_channel;
 use tracing::{debug, error, instrument, warn, Instrument};
@@ -34,7 +33,7 @@ use narwhal_config::{
     Committee as ConsensusCommittee, WorkerCache as ConsensusWorkerCache,
     WorkerId as ConsensusWorkerId,
 };
-use sui_adapter::{adapter, execution_mode};
+use sui_adapter::execution_mode;
 use sui_config::genesis::Genesis;
 use sui_json_rpc_types::{
     type_and_fields_from_move_struct, DevInspectResults, SuiEvent, SuiEventEnvelope,
@@ -52,10 +51,10 @@ use sui_types::committee::EpochId;
 use sui_types::crypto::{AuthorityKeyPair, NetworkKeyPair};
 use sui_types::dynamic_field::{DynamicFieldInfo, DynamicFieldType};
 use sui_types::event::{Event, EventID};
-use sui_types::gas::{GasCostSummary, SuiGasStatus};
+use sui_types::gas::{GasCostSummary, SuiCostTable, SuiGasStatus};
 use sui_types::messages_checkpoint::{CheckpointRequest, CheckpointResponse};
 use sui_types::object::{Owner, PastObjectRead};
-use sui_types::query::{EventQuery, TransactionQuery};
+use sui_types::query::{EventQuery, ObjectTypeFilter, TransactionQuery};
 use sui_types::storage::{ObjectKey, WriteKind};
 use sui_types::sui_system_state::SuiSystemState;
 use sui_types::temporary_store::InnerTemporaryStore;
@@ -106,6 +105,7 @@ mod tbls_tests;
 
 pub mod authority_per_epoch_store;
 
+pub(crate) mod authority_store_cache;
 pub mod authority_store_pruner;
 pub mod authority_store_tables;
 
@@ -164,6 +164,8 @@ pub struct AuthorityMetrics {
     /// Consensus handler metrics
     pub consensus_handler_processed_batches: IntCounter,
     pub consensus_handler_processed_bytes: IntCounter,
+    pub consensus_handler_deferred_transactions: IntCounter,
+    pub consensus_handler_deferred_queue_depth: IntGauge,
 }
 
 // Override default Prom buckets for positive numbers in 0-50k range
@@ -361,6 +363,16 @@ impl AuthorityMetrics {
                 "Number of bytes processed by consensus_handler",
                 registry
             ).unwrap(),
+            consensus_handler_deferred_transactions: register_int_counter_with_registry!(
+                "consensus_handler_deferred_transactions",
+                "Number of times a certificate was deferred to a later commit because of shared object congestion",
+                registry
+            ).unwrap(),
+            consensus_handler_deferred_queue_depth: register_int_gauge_with_registry!(
+                "consensus_handler_deferred_queue_depth",
+                "Number of certificates waiting to be scheduled because of shared object congestion",
+                registry
+            ).unwrap(),
         }
     }
 }
@@ -383,7 +395,6 @@ pub struct AuthorityState {
 
     /// Move native functions that are available to invoke
     pub(crate) _native_functions: NativeFunctionTable,
-    pub(crate) move_vm: Arc<MoveVM>,
 
     /// The database
     pub database: Arc<AuthorityStore>, // TODO: remove pub
@@ -426,6 +437,10 @@ impl AuthorityState {
         &self.committee_store
     }
 
+    pub fn indexes(&self) -> Option<&Arc<IndexStore>> {
+        self.indexes.as_ref()
+    }
+
     /// This is a private method and should be kept that way. It doesn't check whether
     /// the provided transaction is a system transaction, and hence can only be called internally.
     async fn handle_transaction_impl(
@@ -930,6 +945,7 @@ impl AuthorityState {
         let transaction_dependencies = input_objects.transaction_dependencies();
         let temporary_store =
             TemporaryStore::new(self.database.clone(), input_objects, *certificate.digest());
+        let epoch_store = self.epoch_store();
         let (inner_temp_store, effects, _execution_error) =
             execution_engine::execute_transaction_to_effects::<execution_mode::Normal, _>(
                 shared_object_refs,
@@ -937,10 +953,11 @@ impl AuthorityState {
                 certificate.data().intent_message.value.clone(),
                 *certificate.digest(),
                 transaction_dependencies,
-                &self.move_vm,
+                epoch_store.move_vm(),
                 &self._native_functions,
                 gas_status,
-                self.epoch(),
+                epoch_store.epoch(),
+                epoch_store.protocol_config(),
             );
 
         // TODO: Distribute gas charge and rebate, which can be retrieved from effects.
@@ -967,6 +984,7 @@ impl AuthorityState {
         let transaction_dependencies = input_objects.transaction_dependencies();
         let temporary_store =
             TemporaryStore::new(self.database.clone(), input_objects, transaction_digest);
+        let epoch_store = self.epoch_store();
         let (_inner_temp_store, effects, _execution_error) =
             execution_engine::execute_transaction_to_effects::<execution_mode::Normal, _>(
                 shared_object_refs,
@@ -974,10 +992,11 @@ impl AuthorityState {
                 transaction,
                 transaction_digest,
                 transaction_dependencies,
-                &self.move_vm,
+                epoch_store.move_vm(),
                 &self._native_functions,
                 gas_status,
-                self.epoch(),
+                epoch_store.epoch(),
+                epoch_store.protocol_config(),
             );
         SuiTransactionEffects::try_from(effects, self.module_cache.as_ref())
     }
@@ -995,6 +1014,7 @@ impl AuthorityState {
         let transaction_dependencies = input_objects.transaction_dependencies();
         let temporary_store =
             TemporaryStore::new(self.database.clone(), input_objects, transaction_digest);
+        let epoch_store = self.epoch_store();
         let (_inner_temp_store, effects, execution_result) =
             execution_engine::execute_transaction_to_effects::<execution_mode::DevInspect, _>(
                 shared_object_refs,
@@ -1002,10 +1022,11 @@ impl AuthorityState {
                 transaction,
                 transaction_digest,
                 transaction_dependencies,
-                &self.move_vm,
+                epoch_store.move_vm(),
                 &self._native_functions,
                 gas_status,
-                self.epoch(),
+                epoch_store.epoch(),
+                epoch_store.protocol_config(),
             );
         DevInspectResults::new(effects, execution_result, self.module_cache.as_ref())
     }
@@ -1023,8 +1044,13 @@ impl AuthorityState {
         let shared_object_refs = input_objects.filter_shared_objects();
 
         let transaction_dependencies = input_objects.transaction_dependencies();
-        let transaction_digest =
-            execution_engine::manual_execute_move_call_fake_txn_digest(sender, move_call.clone());
+        let epoch_store = self.epoch_store();
+        let protocol_config = epoch_store.protocol_config();
+        let transaction_digest = execution_engine::manual_execute_move_call_fake_txn_digest(
+            sender,
+            move_call.clone(),
+            protocol_config,
+        );
         let temporary_store =
             TemporaryStore::new(self.database.clone(), input_objects, transaction_digest);
         let storage_gas_price = self
@@ -1033,8 +1059,12 @@ impl AuthorityState {
             .parameters
             .storage_gas_price
             .into();
-        let gas_status =
-            SuiGasStatus::new_with_budget(MAX_TX_GAS, storage_gas_price, storage_gas_price);
+        let gas_status = SuiGasStatus::new_with_budget(
+            protocol_config.max_tx_gas(),
+            storage_gas_price,
+            storage_gas_price,
+            SuiCostTable::new(protocol_config),
+        );
         let (effects, execution_result) =
             execution_engine::manual_execute_move_call::<execution_mode::DevInspect, _>(
                 shared_object_refs,
@@ -1043,9 +1073,10 @@ impl AuthorityState {
                 move_call,
                 transaction_digest,
                 transaction_dependencies,
-                &self.move_vm,
+                epoch_store.move_vm(),
                 gas_status,
-                self.epoch(),
+                epoch_store.epoch(),
+                protocol_config,
             );
 
         DevInspectResults::new(effects, execution_result, self.module_cache.as_ref())
@@ -1320,6 +1351,12 @@ impl AuthorityState {
         Ok(())
     }
 
+    /// Indexes a transaction this node executed, but which is missing from the index store,
+    /// e.g. one executed while the index store of a restored backup was captured.
+    pub async fn reindex_transaction(&self, digest: &TransactionDigest) -> SuiResult {
+        self.post_process_one_tx(digest).await
+    }
+
     pub fn unixtime_now_ms() -> u64 {
         let ts_ms = Utc::now().timestamp_millis();
         u64::try_from(ts_ms).expect("Travelling in time machine")
@@ -1478,10 +1515,6 @@ impl AuthorityState {
     ) -> Arc<Self> {
         let native_functions =
             sui_framework::natives::all_natives(MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS);
-        let move_vm = Arc::new(
-            adapter::new_move_vm(native_functions.clone())
-                .expect("We defined natives to not fail here"),
-        );
         let module_cache = Arc::new(SyncModuleCache::new(ResolverWrapper(store.clone())));
         let event_handler = event_store.map(|es| {
             let handler = EventHandler::new(es, module_cache.clone());
@@ -1498,7 +1531,6 @@ impl AuthorityState {
             name,
             secret,
             _native_functions: native_functions,
-            move_vm,
             database: store.clone(),
             indexes,
             // `module_cache` uses a separate in-mem cache from `event_handler`
@@ -1564,6 +1596,8 @@ impl AuthorityState {
                 &genesis_committee,
                 genesis,
                 &AuthorityStorePruningConfig::default(),
+                &AuthorityStoreCacheConfig::default(),
+                &Registry::new(),
             )
             .await
             .unwrap(),
@@ -1688,7 +1722,7 @@ impl AuthorityState {
         db.revert_uncommitted_epoch_transactions().await?;
         db.perpetual_tables
             .set_recovery_epoch(new_committee.epoch)?;
-        db.reopen_epoch_db(new_committee).await;
+        db.reopen_epoch_db(new_committee).await?;
         Ok(())
     }
 
@@ -1824,6 +1858,74 @@ impl AuthorityState {
         }
     }
 
+    /// Batched version of `get_object_read`, results are in the same order as `object_ids`.
+    pub async fn multi_get_object_read(
+        &self,
+        object_ids: &[ObjectID],
+    ) -> Result<Vec<ObjectRead>, SuiError> {
+        let parent_entries = self.database.multi_get_latest_parent_entries(object_ids)?;
+        let live_keys = parent_entries
+            .iter()
+            .flatten()
+            .filter(|(obj_ref, _)| obj_ref.2.is_alive())
+            .map(|(obj_ref, _)| ObjectKey(obj_ref.0, obj_ref.1))
+            .collect::<Vec<_>>();
+        let mut live_objects = self
+            .database
+            .multi_get_object_by_key(&live_keys)?
+            .into_iter();
+
+        let mut reads = Vec::with_capacity(object_ids.len());
+        for (object_id, entry) in object_ids.iter().zip(parent_entries) {
+            let read = match entry {
+                None => ObjectRead::NotExists(*object_id),
+                Some((obj_ref, _)) if !obj_ref.2.is_alive() => ObjectRead::Deleted(obj_ref),
+                Some((obj_ref, _)) => match live_objects.next().flatten() {
+                    None => {
+                        error!("Object with in parent_entry is missing from object store, datastore is inconsistent");
+                        return Err(SuiError::ObjectNotFound {
+                            object_id: *object_id,
+                            version: Some(obj_ref.1),
+                        });
+                    }
+                    Some(object) => {
+                        let layout = object.get_layout(
+                            ObjectFormatOptions::default(),
+                            self.module_cache.as_ref(),
+                        )?;
+                        ObjectRead::Exists(obj_ref, object, layout)
+                    }
+                },
+            };
+            reads.push(read);
+        }
+        Ok(reads)
+    }
+
+    /// Batched version of `get_past_object_read`, results are in the same order as
+    /// `object_keys`. Versions found in the object table are read in one batch, the rest
+    /// fall back to `get_past_object_read` to work out why they are missing.
+    pub async fn multi_get_past_object_read(
+        &self,
+        object_keys: &[ObjectKey],
+    ) -> Result<Vec<PastObjectRead>, SuiError> {
+        let objects = self.database.multi_get_object_by_key(object_keys)?;
+        let mut reads = Vec::with_capacity(object_keys.len());
+        for (ObjectKey(object_id, version), object) in object_keys.iter().zip(objects) {
+            let read = match object {
+                Some(object) => {
+                    let layout = object
+                        .get_layout(ObjectFormatOptions::default(), self.module_cache.as_ref())?;
+                    let obj_ref = object.compute_object_reference();
+                    PastObjectRead::VersionFound(obj_ref, object, layout)
+                }
+                None => self.get_past_object_read(object_id, *version).await?,
+            };
+            reads.push(read);
+        }
+        Ok(reads)
+    }
+
     fn get_owner_at_version(
         &self,
         object_id: &ObjectID,
@@ -1838,9 +1940,15 @@ impl AuthorityState {
             .map(|o| o.owner)
     }
 
-    pub fn get_owner_objects(&self, owner: SuiAddress) -> SuiResult<Vec<ObjectInfo>> {
+    pub fn get_owner_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        limit: usize,
+        filter: Option<ObjectTypeFilter>,
+    ) -> SuiResult<Vec<ObjectInfo>> {
         if let Some(indexes) = &self.indexes {
-            indexes.get_owner_objects(owner)
+            indexes.get_owner_objects(owner, cursor, limit, filter)
         } else {
             Err(SuiError::IndexStoreNotAvailable)
         }
@@ -1849,9 +1957,11 @@ impl AuthorityState {
     pub fn get_owner_objects_iterator(
         &self,
         owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        filter: Option<ObjectTypeFilter>,
     ) -> SuiResult<impl Iterator<Item = ObjectInfo> + '_> {
         if let Some(indexes) = &self.indexes {
-            indexes.get_owner_objects_iterator(owner)
+            indexes.get_owner_objects_iterator(owner, cursor, filter)
         } else {
             Err(SuiError::IndexStoreNotAvailable)
         }
@@ -1870,6 +1980,18 @@ impl AuthorityState {
         }
     }
 
+    pub fn get_dynamic_fields_iterator(
+        &self,
+        owner: ObjectID,
+        cursor: Option<ObjectID>,
+    ) -> SuiResult<impl Iterator<Item = DynamicFieldInfo> + '_> {
+        if let Some(indexes) = &self.indexes {
+            indexes.get_dynamic_fields_iterator(owner, cursor)
+        } else {
+            Err(SuiError::IndexStoreNotAvailable)
+        }
+    }
+
     pub fn get_dynamic_field_object_id(
         &self,
         owner: ObjectID,
@@ -1915,6 +2037,29 @@ impl AuthorityState {
         }
     }
 
+    /// Batched version of `get_transaction`, results are in the same order as `digests`.
+    /// A transaction this node has not executed is returned as `None` instead of failing
+    /// the whole batch.
+    pub async fn multi_get_transactions(
+        &self,
+        digests: &[TransactionDigest],
+    ) -> Result<Vec<Option<(VerifiedCertificate, TransactionEffects)>>, anyhow::Error> {
+        let certificates = self.database.multi_get_certified_transaction(digests)?;
+        let effects = self.database.multi_get_effects(digests)?;
+        Ok(certificates
+            .into_iter()
+            .zip(effects)
+            .map(|(certificate, effects)| certificate.zip(effects))
+            .collect())
+    }
+
+    pub async fn multi_get_timestamp_ms(
+        &self,
+        digests: &[TransactionDigest],
+    ) -> Result<Vec<Option<u64>>, anyhow::Error> {
+        Ok(self.get_indexes()?.multi_get_timestamp_ms(digests)?)
+    }
+
     fn get_indexes(&self) -> SuiResult<Arc<IndexStore>> {
         match &self.indexes {
             Some(i) => Ok(i.clone()),
@@ -2005,6 +2150,10 @@ impl AuthorityState {
                 es.events_by_type(event_type, cursor, limit, descending)
                     .await?
             }
+            query @ (EventQuery::And(..) | EventQuery::Or(..)) => {
+                es.events_by_query(&query, cursor, limit, descending)
+                    .await?
+            }
         };
         let mut events = StoredEvent::into_event_envelopes(stored_events)?;
         // populate parsed json event
@@ -2229,8 +2378,10 @@ impl AuthorityState {
             storage_rebase=?gas_cost_summary.storage_rebate,
             "Creating advance epoch transaction"
         );
+        let next_protocol_version = self.epoch_store().next_protocol_version();
         let tx = VerifiedTransaction::new_change_epoch(
             next_epoch,
+            next_protocol_version,
             gas_cost_summary.storage_cost,
             gas_cost_summary.computation_cost,
             gas_cost_summary.storage_rebate,
@@ -9,7 +9,7 @@ use parking_lot::RwLock;
 use parking_lot::{Mutex, RwLockReadGuard};
 use rocksdb::Options;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::future::Future;
 use std::iter;
 use std::path::{Path, PathBuf};
@@ -18,14 +18,15 @@ use sui_storage::default_db_options;
 use sui_storage::mutex_table::LockGuard;
 use sui_storage::write_ahead_log::{DBWriteAheadLog, TxGuard, WriteAheadLog};
 use sui_types::base_types::{AuthorityName, EpochId, ObjectID, SequenceNumber, TransactionDigest};
-use sui_types::committee::Committee;
+use sui_types::committee::{Committee, StakeUnit};
 use sui_types::crypto::AuthoritySignInfo;
 use sui_types::error::{SuiError, SuiResult};
 use sui_types::messages::{
-    ConsensusTransaction, ConsensusTransactionKey, ConsensusTransactionKind, SenderSignedData,
-    SignedTransactionEffects, TrustedCertificate, VerifiedCertificate, VerifiedSignedTransaction,
+    AuthorityCapabilities, ConsensusTransaction, ConsensusTransactionKey, ConsensusTransactionKind,
+    SenderSignedData, SignedTransactionEffects, TrustedCertificate, VerifiedCertificate,
+    VerifiedSignedTransaction,
 };
-use tracing::{debug, trace, warn};
+use tracing::{debug, info, trace, warn};
 use typed_store::rocks::{DBBatch, DBMap, DBOptions, TypedStoreError};
 use typed_store::traits::{TableSummary, TypedStoreDebug};
 
@@ -38,14 +39,20 @@ use crate::consensus_handler::{
 use crate::epoch::reconfiguration::ReconfigState;
 use crate::notify_once::NotifyOnce;
 use crate::stake_aggregator::StakeAggregator;
+use crate::tbls::beacon::{AddPartialSignaturesResult, RandomnessBeacon};
 use crate::transaction_manager::TransactionManager;
+use move_vm_runtime::move_vm::MoveVM;
 use mysten_metrics::monitored_scope;
 use prometheus::IntCounter;
 use std::cmp::Ordering as CmpOrdering;
+use sui_adapter::adapter;
+use sui_protocol_constants::{ProtocolConfig, ProtocolVersion};
 use sui_types::message_envelope::TrustedEnvelope;
 use sui_types::messages_checkpoint::{CheckpointSequenceNumber, CheckpointSignatureMessage};
+use sui_types::randomness::RandomnessPartialSignatureMessage;
 use sui_types::storage::{transaction_input_object_keys, ObjectKey, ParentSync};
 use sui_types::temporary_store::InnerTemporaryStore;
+use sui_types::{MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS};
 use typed_store::Map;
 use typed_store_derive::DBMapUtils;
 
@@ -63,6 +70,19 @@ pub struct ExecutionIndicesWithHash {
     pub hash: u64,
 }
 
+/// A certificate that exceeded the per shared object budget of a consensus commit, and is
+/// scheduled again in a later commit.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeferredTransaction {
+    /// Position at which the transaction was first sequenced. Deferred transactions are
+    /// scheduled in this order.
+    pub sequenced_at: ExecutionIndices,
+    /// Sub dag index of the last commit that deferred the transaction.
+    pub deferred_in: u64,
+    pub certificate: narwhal_types::Certificate,
+    pub transaction: ConsensusTransaction,
+}
+
 pub struct AuthorityPerEpochStore {
     committee: Committee,
     tables: AuthorityEpochTables,
@@ -87,6 +107,18 @@ pub struct AuthorityPerEpochStore {
     /// A write-ahead/recovery log used to ensure we finish fully processing certs after errors or
     /// crashes.
     wal: Arc<DBWriteAheadLog<TrustedCertificate, (InnerTemporaryStore, SignedTransactionEffects)>>,
+    /// In-memory copy of AuthorityEpochTables::deferred_transactions, ordered by the position at
+    /// which the transactions were first sequenced, so that commits don't scan the table.
+    /// Only accessed from the consensus handler.
+    deferred_transactions: Mutex<BTreeMap<ExecutionIndices, DeferredTransaction>>,
+    /// Aggregates randomness partial signatures sequenced by consensus in this epoch.
+    randomness_beacon: RandomnessBeacon,
+    /// Protocol config of the protocol version in effect during this epoch.
+    protocol_config: ProtocolConfig,
+    /// Reference gas price of this epoch, transactions priced below it are not signed.
+    reference_gas_price: u64,
+    /// Move VM configured with the verifier limits of `protocol_config`.
+    move_vm: Arc<MoveVM>,
 }
 
 /// AuthorityEpochTables contains tables that contain data that is only valid within an epoch.
@@ -182,6 +214,22 @@ pub struct AuthorityEpochTables {
     /// The key in this table is checkpoint sequence number and an arbitrary integer
     pending_checkpoint_signatures:
         DBMap<(CheckpointSequenceNumber, u64), CheckpointSignatureMessage>,
+
+    /// Randomness partial signatures sequenced by consensus for checkpoints whose randomness
+    /// was not aggregated yet. Entries are removed once the randomness of the checkpoint is
+    /// produced.
+    pending_randomness_partial_signatures:
+        DBMap<(CheckpointSequenceNumber, AuthorityName), RandomnessPartialSignatureMessage>,
+
+    /// Capabilities advertised by each authority via consensus in this epoch. Only messages
+    /// sequenced before the end of publish quorum are recorded, so that every validator sees the
+    /// same set when deciding on the protocol version of the next epoch.
+    authority_capabilities: DBMap<AuthorityName, AuthorityCapabilities>,
+
+    /// Certificates deferred by the shared object congestion control. The table is updated
+    /// atomically at the end of each commit, so that a commit replayed after a crash makes the
+    /// same scheduling decisions.
+    deferred_transactions: DBMap<ConsensusTransactionKey, DeferredTransaction>,
 }
 
 impl AuthorityEpochTables {
@@ -214,8 +262,21 @@ impl AuthorityEpochTables {
 }
 
 impl AuthorityPerEpochStore {
-    pub fn new(committee: Committee, parent_path: &Path, db_options: Option<Options>) -> Self {
+    pub fn new(
+        committee: Committee,
+        protocol_version: ProtocolVersion,
+        reference_gas_price: u64,
+        parent_path: &Path,
+        db_options: Option<Options>,
+    ) -> Self {
         let epoch_id = committee.epoch;
+        let protocol_config = ProtocolConfig::get_for_version(protocol_version);
+        let native_functions =
+            sui_framework::natives::all_natives(MOVE_STDLIB_ADDRESS, SUI_FRAMEWORK_ADDRESS);
+        let move_vm = Arc::new(
+            adapter::new_move_vm(native_functions, &protocol_config)
+                .expect("We defined natives to not fail here"),
+        );
         let tables = AuthorityEpochTables::open(epoch_id, parent_path, db_options);
         let end_of_publish =
             StakeAggregator::from_iter(committee.clone(), tables.end_of_publish.iter());
@@ -236,6 +297,18 @@ impl AuthorityPerEpochStore {
                 }
             })
             .collect();
+        let deferred_transactions = tables
+            .deferred_transactions
+            .iter()
+            .map(|(_, deferred)| (deferred.sequenced_at, deferred))
+            .collect();
+        let randomness_beacon = RandomnessBeacon::new(&committee);
+        randomness_beacon.recover(
+            tables
+                .pending_randomness_partial_signatures
+                .iter()
+                .map(|(_, data)| data),
+        );
         Self {
             committee,
             tables,
@@ -246,6 +319,11 @@ impl AuthorityPerEpochStore {
             end_of_publish: Mutex::new(end_of_publish),
             pending_consensus_certificates: Mutex::new(pending_consensus_certificates),
             wal,
+            deferred_transactions: Mutex::new(deferred_transactions),
+            randomness_beacon,
+            protocol_config,
+            reference_gas_price,
+            move_vm,
         }
     }
 
@@ -264,6 +342,72 @@ impl AuthorityPerEpochStore {
         self.committee.epoch
     }
 
+    pub fn randomness_beacon(&self) -> &RandomnessBeacon {
+        &self.randomness_beacon
+    }
+
+    pub fn protocol_config(&self) -> &ProtocolConfig {
+        &self.protocol_config
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_config.version()
+    }
+
+    pub fn reference_gas_price(&self) -> u64 {
+        self.reference_gas_price
+    }
+
+    pub fn move_vm(&self) -> &Arc<MoveVM> {
+        &self.move_vm
+    }
+
+    pub fn get_capabilities(&self) -> Vec<AuthorityCapabilities> {
+        self.tables
+            .authority_capabilities
+            .iter()
+            .map(|(_, capabilities)| capabilities)
+            .collect()
+    }
+
+    /// Protocol version the network should run in the next epoch. The version moves forward by
+    /// one once authorities holding a quorum of stake have advertised support for it, and stays
+    /// the same otherwise.
+    ///
+    /// This is deterministic across validators because it only depends on capabilities sequenced
+    /// by consensus before the end of the epoch.
+    pub fn next_protocol_version(&self) -> ProtocolVersion {
+        let current = self.protocol_version();
+        let candidate = current + 1;
+        let supporting_stake: StakeUnit = self
+            .get_capabilities()
+            .iter()
+            .filter(|capabilities| {
+                capabilities
+                    .supported_protocol_versions
+                    .is_version_supported(candidate)
+            })
+            .map(|capabilities| self.committee.weight(&capabilities.authority))
+            .sum();
+        if supporting_stake >= self.committee.quorum_threshold() {
+            info!(
+                ?supporting_stake,
+                "Protocol version {} is supported by a quorum, upgrading from {}",
+                candidate,
+                current
+            );
+            candidate
+        } else {
+            debug!(
+                ?supporting_stake,
+                "Protocol version {} is not supported by a quorum, staying at {}",
+                candidate,
+                current
+            );
+            current
+        }
+    }
+
     pub async fn acquire_tx_guard(&self, cert: &VerifiedCertificate) -> SuiResult<CertTxGuard> {
         let digest = cert.digest();
         let guard = self.wal.begin_tx(digest, cert.serializable_ref()).await?;
@@ -335,6 +479,16 @@ impl AuthorityPerEpochStore {
         }
     }
 
+    /// Take a RocksDB checkpoint of this epoch's tables into `path`.
+    pub fn checkpoint_db(&self, path: &Path) -> SuiResult {
+        // All epoch tables live in the same db, checkpointing through any of them is enough.
+        self.tables
+            .last_consensus_index
+            .rocksdb
+            .checkpoint(path)
+            .map_err(SuiError::from)
+    }
+
     pub fn get_last_consensus_index(&self) -> SuiResult<ExecutionIndicesWithHash> {
         self.tables
             .last_consensus_index
@@ -524,6 +678,15 @@ impl AuthorityPerEpochStore {
             .contains_key(authority))
     }
 
+    /// Whether any authority has sent EndOfPublish, i.e. the epoch is closing.
+    pub fn has_any_end_of_publish(&self) -> bool {
+        !self
+            .end_of_publish
+            .try_lock()
+            .expect("No contention on end_of_publish lock")
+            .is_empty()
+    }
+
     /// Returns Ok(true) if 2f+1 end of publish messages were recorded at this point
     pub fn record_end_of_publish(
         &self,
@@ -763,6 +926,50 @@ impl AuthorityPerEpochStore {
         self.finish_consensus_transaction_process_with_batch(batch, key, consensus_index)
     }
 
+    /// Returns the transactions deferred by commits before `sub_dag_index`, in the order they
+    /// were first sequenced.
+    pub fn get_deferred_transactions(&self, sub_dag_index: u64) -> Vec<DeferredTransaction> {
+        self.deferred_transactions
+            .lock()
+            .values()
+            .filter(|deferred| deferred.deferred_in < sub_dag_index)
+            .cloned()
+            .collect()
+    }
+
+    pub fn is_transaction_deferred(&self, key: &ConsensusTransactionKey) -> SuiResult<bool> {
+        Ok(self.tables.deferred_transactions.contains_key(key)?)
+    }
+
+    /// Removes the deferred transactions that were scheduled by a commit and stores the ones it
+    /// deferred. Must be called after all the transactions of the commit are processed.
+    pub fn update_deferred_transactions(
+        &self,
+        scheduled: Vec<ConsensusTransactionKey>,
+        deferred: Vec<DeferredTransaction>,
+    ) -> SuiResult {
+        let batch = self.tables.deferred_transactions.batch();
+        let batch = batch.delete_batch(&self.tables.deferred_transactions, scheduled.iter())?;
+        let batch = batch.insert_batch(
+            &self.tables.deferred_transactions,
+            deferred
+                .iter()
+                .map(|deferred| (deferred.transaction.key(), deferred)),
+        )?;
+        batch.write()?;
+
+        let scheduled: HashSet<_> = scheduled.into_iter().collect();
+        let mut deferred_transactions = self.deferred_transactions.lock();
+        deferred_transactions
+            .retain(|_, deferred| !scheduled.contains(&deferred.transaction.key()));
+        deferred_transactions.extend(
+            deferred
+                .into_iter()
+                .map(|deferred| (deferred.sequenced_at, deferred)),
+        );
+        Ok(())
+    }
+
     pub fn final_epoch_checkpoint(&self) -> SuiResult<Option<u64>> {
         Ok(self
             .tables
@@ -923,6 +1130,26 @@ impl AuthorityPerEpochStore {
                     return Err(());
                 }
             }
+            ConsensusTransactionKind::RandomnessPartialSignature(data) => {
+                if transaction.sender_authority() != data.authority {
+                    warn!(
+                        "RandomnessPartialSignature authority {} does not match narwhal certificate source {}",
+                        data.authority,
+                        transaction.certificate.origin()
+                    );
+                    return Err(());
+                }
+            }
+            ConsensusTransactionKind::CapabilityNotification(capabilities) => {
+                if transaction.sender_authority() != capabilities.authority {
+                    warn!(
+                        "CapabilityNotification authority {} does not match narwhal certificate source {}",
+                        capabilities.authority,
+                        transaction.certificate.origin()
+                    );
+                    return Err(());
+                }
+            }
         }
         Ok(VerifiedSequencedConsensusTransaction(transaction))
     }
@@ -977,7 +1204,12 @@ impl AuthorityPerEpochStore {
                     return Ok(None);
                 }
                 let authority = (&consensus_output.header.author).into();
-                if self.has_sent_end_of_publish(&authority)? {
+                // Certificates deferred by the shared object congestion control were sequenced
+                // before the EndOfPublish of their authority, and are still in the deferred table
+                // while they are processed.
+                if self.has_sent_end_of_publish(&authority)?
+                    && !self.is_transaction_deferred(&transaction.key())?
+                {
                     // This can not happen with valid authority
                     // With some edge cases narwhal might sometimes resend previously seen certificate after EndOfPublish
                     // However this certificate will be filtered out before this line by `consensus_message_processed` call in `verify_consensus_transaction`
@@ -1033,7 +1265,92 @@ impl AuthorityPerEpochStore {
                 self.record_end_of_publish(*authority, transaction.key(), consensus_index)?;
                 Ok(None)
             }
+            ConsensusTransactionKind::RandomnessPartialSignature(data) => {
+                debug!(
+                    "Received randomness partial signatures for checkpoint {} from {:?}",
+                    data.round.sequence_number,
+                    data.authority.concise()
+                );
+                self.record_randomness_partial_signatures(
+                    data,
+                    transaction.key(),
+                    consensus_index,
+                    checkpoint_service,
+                )?;
+                Ok(None)
+            }
+            ConsensusTransactionKind::CapabilityNotification(capabilities) => {
+                debug!(
+                    "Received CapabilityNotification from {:?}: {:?}",
+                    capabilities.authority.concise(),
+                    capabilities.supported_protocol_versions
+                );
+                self.record_capabilities(capabilities, transaction.key(), consensus_index)?;
+                Ok(None)
+            }
+        }
+    }
+
+    fn record_capabilities(
+        &self,
+        capabilities: &AuthorityCapabilities,
+        key: ConsensusTransactionKey,
+        consensus_index: ExecutionIndicesWithHash,
+    ) -> SuiResult {
+        let mut write_batch = self.tables.authority_capabilities.batch();
+        // Same as for end of publish messages, it is safe to release the lock right away because
+        // the transition into RejectAllCerts only happens on the consensus task.
+        if self
+            .get_reconfig_state_read_lock_guard()
+            .should_accept_consensus_certs()
+        {
+            write_batch = write_batch.insert_batch(
+                &self.tables.authority_capabilities,
+                [(capabilities.authority, capabilities)],
+            )?;
+        } else {
+            debug!(
+                "Ignoring capabilities of validator {:?} received after the end of publish quorum",
+                capabilities.authority.concise()
+            );
+        }
+        self.finish_consensus_transaction_process_with_batch(write_batch, key, consensus_index)
+    }
+
+    /// Adds partial signatures to the randomness beacon. Once the randomness of a checkpoint is
+    /// aggregated it is handed to the checkpoint service, and the pending partial signatures of
+    /// that checkpoint are removed.
+    fn record_randomness_partial_signatures<C: CheckpointServiceNotify>(
+        &self,
+        data: &RandomnessPartialSignatureMessage,
+        key: ConsensusTransactionKey,
+        consensus_index: ExecutionIndicesWithHash,
+        checkpoint_service: &Arc<C>,
+    ) -> SuiResult {
+        let sequence_number = data.round.sequence_number;
+        let mut write_batch = self.tables.pending_randomness_partial_signatures.batch();
+        match self.randomness_beacon.add_partial_signatures(data) {
+            AddPartialSignaturesResult::Completed(randomness) => {
+                // CheckpointServiceNotify::notify_checkpoint_randomness is idempotent, so it is
+                // fine to aggregate the same randomness again if we crash before the batch below
+                // is written.
+                checkpoint_service.notify_checkpoint_randomness(&randomness)?;
+                // Earlier checkpoints keep collecting shares, see RandomnessBeacon.
+                write_batch = write_batch.delete_range(
+                    &self.tables.pending_randomness_partial_signatures,
+                    &(sequence_number, AuthorityName::ZERO),
+                    &(sequence_number + 1, AuthorityName::ZERO),
+                )?;
+            }
+            AddPartialSignaturesResult::NotEnoughShares => {
+                write_batch = write_batch.insert_batch(
+                    &self.tables.pending_randomness_partial_signatures,
+                    [((sequence_number, data.authority), data)],
+                )?;
+            }
+            AddPartialSignaturesResult::Ignored => {}
         }
+        self.finish_consensus_transaction_process_with_batch(write_batch, key, consensus_index)
     }
 
     pub fn handle_commit_boundary<C: CheckpointServiceNotify>(
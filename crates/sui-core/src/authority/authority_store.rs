@@ -1,11 +1,13 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use super::authority_store_cache::ObjectCache;
 use super::authority_store_pruner::AuthorityStorePruner;
 use super::{authority_store_tables::AuthorityPerpetualTables, *};
-use crate::authority::authority_per_epoch_store::AuthorityPerEpochStore;
+use crate::authority::authority_per_epoch_store::{AuthorityEpochTables, AuthorityPerEpochStore};
 use arc_swap::ArcSwap;
 use once_cell::sync::OnceCell;
+use prometheus::Registry;
 use rocksdb::Options;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -13,7 +15,7 @@ use std::iter;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
-use sui_config::node::AuthorityStorePruningConfig;
+use sui_config::node::{AuthorityStoreCacheConfig, AuthorityStorePruningConfig};
 use sui_storage::mutex_table::{LockGuard, MutexTable};
 use sui_types::object::Owner;
 use sui_types::object::PACKAGE_VERSION;
@@ -38,6 +40,8 @@ pub struct AuthorityStore {
     mutex_table: MutexTable<ObjectDigest>,
 
     pub(crate) perpetual_tables: Arc<AuthorityPerpetualTables>,
+    /// Latest versions of objects read from `perpetual_tables.objects`.
+    object_cache: ObjectCache,
     epoch_store: ArcSwap<AuthorityPerEpochStore>,
 
     // needed for re-opening epoch db.
@@ -58,6 +62,8 @@ impl AuthorityStore {
         genesis: &Genesis,
         committee_store: &Arc<CommitteeStore>,
         pruning_config: &AuthorityStorePruningConfig,
+        cache_config: &AuthorityStoreCacheConfig,
+        registry: &Registry,
     ) -> SuiResult<Self> {
         let perpetual_tables = Arc::new(AuthorityPerpetualTables::open(path, db_options.clone()));
         if perpetual_tables.database_is_empty()? {
@@ -81,6 +87,8 @@ impl AuthorityStore {
             perpetual_tables,
             committee,
             pruning_config,
+            cache_config,
+            registry,
         )
         .await
     }
@@ -91,6 +99,8 @@ impl AuthorityStore {
         committee: &Committee,
         genesis: &Genesis,
         pruning_config: &AuthorityStorePruningConfig,
+        cache_config: &AuthorityStoreCacheConfig,
+        registry: &Registry,
     ) -> SuiResult<Self> {
         // TODO: Since we always start at genesis, the committee should be technically the same
         // as the genesis committee.
@@ -103,6 +113,8 @@ impl AuthorityStore {
             perpetual_tables,
             committee.clone(),
             pruning_config,
+            cache_config,
+            registry,
         )
         .await
     }
@@ -114,9 +126,20 @@ impl AuthorityStore {
         perpetual_tables: Arc<AuthorityPerpetualTables>,
         committee: Committee,
         pruning_config: &AuthorityStorePruningConfig,
+        cache_config: &AuthorityStoreCacheConfig,
+        registry: &Registry,
     ) -> SuiResult<Self> {
+        // The protocol version and reference gas price of the current epoch are recorded in the
+        // system state. Before genesis objects are inserted it can only be the genesis one.
+        let system_state = if perpetual_tables.database_is_empty()? {
+            genesis.sui_system_object()
+        } else {
+            perpetual_tables.get_sui_system_state_object()?
+        };
         let epoch_tables = Arc::new(AuthorityPerEpochStore::new(
             committee,
+            system_state.protocol_version(),
+            system_state.reference_gas_price,
             path,
             db_options.clone(),
         ));
@@ -126,6 +149,7 @@ impl AuthorityStore {
         let store = Self {
             mutex_table: MutexTable::new(NUM_SHARDS, SHARD_SIZE),
             perpetual_tables,
+            object_cache: ObjectCache::new(cache_config, registry),
             epoch_store: epoch_tables.into(),
             path: path.into(),
             db_options,
@@ -176,15 +200,27 @@ impl AuthorityStore {
         Ok(())
     }
 
-    pub(crate) async fn reopen_epoch_db(&self, new_committee: Committee) {
-        info!(new_epoch = ?new_committee.epoch, "re-opening AuthorityEpochTables for new epoch");
+    pub(crate) async fn reopen_epoch_db(&self, new_committee: Committee) -> SuiResult {
+        // The advance epoch transaction has already updated the system state, so it holds the
+        // protocol version and reference gas price of the new epoch.
+        let system_state = self.get_sui_system_state_object()?;
+        let protocol_version = system_state.protocol_version();
+        info!(
+            new_epoch = ?new_committee.epoch,
+            ?protocol_version,
+            reference_gas_price = system_state.reference_gas_price,
+            "re-opening AuthorityEpochTables for new epoch"
+        );
         let epoch_tables = Arc::new(AuthorityPerEpochStore::new(
             new_committee,
+            protocol_version,
+            system_state.reference_gas_price,
             &self.path,
             self.db_options.clone(),
         ));
         let previous_store = self.epoch_store.swap(epoch_tables);
         previous_store.epoch_terminated().await;
+        Ok(())
     }
 
     // TODO: Deprecate this once we replace all calls with load_epoch_store.
@@ -207,6 +243,40 @@ impl AuthorityStore {
         Ok(store)
     }
 
+    /// Take RocksDB checkpoints of the perpetual tables and the current epoch's tables. The
+    /// checkpoint under `path` has the same layout as the live store directory, so it can be
+    /// used in its place once the node is stopped.
+    ///
+    /// The perpetual and epoch tables are separate databases, each checkpoint is consistent
+    /// on its own but the two are not taken atomically. The perpetual tables are taken first,
+    /// so the epoch tables can only be ahead of them, as after a crash between consensus
+    /// processing and execution, which the node recovers from on restart. The checkpoint fails
+    /// if the epoch changes while it is taken, as the perpetual tables may then belong to the
+    /// next epoch.
+    /// Returns the epoch of the checkpointed epoch tables.
+    pub fn checkpoint_db(&self, path: &Path) -> SuiResult<EpochId> {
+        std::fs::create_dir_all(path).map_err(|e| SuiError::GenericStorageError(e.to_string()))?;
+        let epoch_store = self.epoch_store.load();
+        let epoch = epoch_store.epoch();
+        self.perpetual_tables
+            .checkpoint_db(&AuthorityPerpetualTables::path(path))?;
+        epoch_store.checkpoint_db(&AuthorityEpochTables::path(epoch, path))?;
+        let current_epoch = self.epoch_store.load().epoch();
+        if current_epoch != epoch {
+            return Err(SuiError::GenericStorageError(format!(
+                "Epoch changed from {epoch} to {current_epoch} during the checkpoint, retry it"
+            )));
+        }
+        Ok(epoch)
+    }
+
+    /// Prune old object versions now instead of waiting for the next scheduled pruning run,
+    /// retaining the latest `num_versions_to_retain` versions of every object.
+    /// Returns the number of object versions pruned.
+    pub fn prune_objects_immediately(&self, num_versions_to_retain: u64) -> u64 {
+        AuthorityStorePruner::prune_objects(num_versions_to_retain, self.perpetual_tables.clone())
+    }
+
     /// Returns the TransactionEffects if we have an effects structure for this transaction digest
     pub fn get_effects(
         &self,
@@ -221,6 +291,19 @@ impl AuthorityStore {
             })
     }
 
+    pub fn multi_get_effects(
+        &self,
+        transaction_digests: &[TransactionDigest],
+    ) -> SuiResult<Vec<Option<TransactionEffects>>> {
+        Ok(self
+            .perpetual_tables
+            .executed_effects
+            .multi_get(transaction_digests)?
+            .into_iter()
+            .map(|o| o.map(|effects| effects.into_data()))
+            .collect())
+    }
+
     /// Returns true if we have an effects structure for this transaction digest
     pub fn effects_exists(&self, transaction_digest: &TransactionDigest) -> SuiResult<bool> {
         self.perpetual_tables
@@ -268,12 +351,23 @@ impl AuthorityStore {
         object_id: &ObjectID,
         version: VersionNumber,
     ) -> Result<Option<Object>, SuiError> {
+        if let Some(object) = self.object_cache.get_version(object_id, version) {
+            return Ok(Some(object));
+        }
         Ok(self
             .perpetual_tables
             .objects
             .get(&ObjectKey(*object_id, version))?)
     }
 
+    /// Get many objects by key, reading them from the object table in a single batch.
+    pub fn multi_get_object_by_key(
+        &self,
+        object_keys: &[ObjectKey],
+    ) -> Result<Vec<Option<Object>>, SuiError> {
+        Ok(self.perpetual_tables.objects.multi_get(object_keys)?)
+    }
+
     pub fn object_version_exists(
         &self,
         object_id: &ObjectID,
@@ -287,7 +381,8 @@ impl AuthorityStore {
 
     /// Read an object and return it, or Err(ObjectNotFound) if the object was not found.
     pub fn get_object(&self, object_id: &ObjectID) -> Result<Option<Object>, SuiError> {
-        self.perpetual_tables.get_object(object_id)
+        self.object_cache
+            .get_or_load(object_id, || self.perpetual_tables.get_object(object_id))
     }
 
     /// Get many objects
@@ -589,6 +684,7 @@ impl AuthorityStore {
         )?;
 
         write_batch.write()?;
+        self.object_cache.invalidate([&object_ref.0]);
 
         Ok(())
     }
@@ -636,6 +732,8 @@ impl AuthorityStore {
         )?;
 
         batch.write()?;
+        self.object_cache
+            .invalidate(ref_and_objects.iter().map(|(oref, _)| &oref.0));
 
         Ok(())
     }
@@ -879,6 +977,8 @@ impl AuthorityStore {
         write_batch = self.delete_locks(write_batch, &owned_inputs)?;
 
         write_batch.write()?;
+        self.object_cache
+            .invalidate(written.keys().chain(deleted.keys()));
         trace!("Finished writing batch");
 
         Ok(())
@@ -1240,6 +1340,19 @@ impl AuthorityStore {
         )?;
 
         write_batch.write()?;
+        // Deleted and wrapped objects are alive again once their parent_sync entries are gone.
+        self.object_cache.invalidate(
+            effects
+                .all_mutated()
+                .map(|(obj_ref, _, _)| &obj_ref.0)
+                .chain(
+                    effects
+                        .deleted
+                        .iter()
+                        .chain(effects.wrapped.iter())
+                        .map(|obj_ref| &obj_ref.0),
+                ),
+        );
 
         Ok(())
     }
@@ -1275,6 +1388,16 @@ impl AuthorityStore {
         self.perpetual_tables.get_latest_parent_entry(object_id)
     }
 
+    /// Batched version of `get_latest_parent_entry`, results are in the same order as
+    /// `object_ids`.
+    pub fn multi_get_latest_parent_entries(
+        &self,
+        object_ids: &[ObjectID],
+    ) -> Result<Vec<Option<(ObjectRef, TransactionDigest)>>, SuiError> {
+        self.perpetual_tables
+            .multi_get_latest_parent_entries(object_ids)
+    }
+
     pub fn object_exists(&self, object_id: ObjectID) -> SuiResult<bool> {
         match self.get_latest_parent_entry(object_id)? {
             None => Ok(false),
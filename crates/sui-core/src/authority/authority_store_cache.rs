@@ -0,0 +1,228 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use parking_lot::Mutex;
+use prometheus::{
+    register_int_counter_with_registry, register_int_gauge_with_registry, IntCounter, IntGauge,
+    Registry,
+};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::mem::size_of;
+use sui_config::node::AuthorityStoreCacheConfig;
+use sui_types::base_types::{ObjectID, SequenceNumber};
+use sui_types::error::SuiResult;
+use sui_types::object::Object;
+
+#[cfg(test)]
+#[path = "../unit_tests/authority_store_cache_tests.rs"]
+mod authority_store_cache_tests;
+
+const NUM_SHARDS: usize = 64;
+
+/// Bookkeeping memory of a cache entry, on top of the object itself.
+const ENTRY_OVERHEAD: usize =
+    size_of::<ObjectID>() + size_of::<CacheEntry>() + size_of::<(u64, ObjectID)>();
+
+pub struct ObjectCacheMetrics {
+    hits: IntCounter,
+    misses: IntCounter,
+    evictions: IntCounter,
+    size_bytes: IntGauge,
+}
+
+impl ObjectCacheMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            hits: register_int_counter_with_registry!(
+                "authority_store_object_cache_hits",
+                "Number of object reads served from the object cache",
+                registry,
+            )
+            .unwrap(),
+            misses: register_int_counter_with_registry!(
+                "authority_store_object_cache_misses",
+                "Number of object reads that went to the objects table",
+                registry,
+            )
+            .unwrap(),
+            evictions: register_int_counter_with_registry!(
+                "authority_store_object_cache_evictions",
+                "Number of objects evicted from the object cache to stay within its size",
+                registry,
+            )
+            .unwrap(),
+            size_bytes: register_int_gauge_with_registry!(
+                "authority_store_object_cache_size_bytes",
+                "Approximate memory held by the object cache",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
+
+/// A bounded, least-recently-used cache of the latest version of objects, sitting in front of
+/// the objects table.
+///
+/// Entries are looked up by object id and must be invalidated whenever a write changes the
+/// latest version of an object, or deletes or wraps it. The cache is split into shards, each
+/// holding an equal part of the configured memory budget.
+pub(crate) struct ObjectCache {
+    shards: Vec<Mutex<CacheShard>>,
+    shard_capacity: usize,
+    metrics: ObjectCacheMetrics,
+}
+
+#[derive(Default)]
+struct CacheShard {
+    entries: HashMap<ObjectID, CacheEntry>,
+    /// Entries ordered by last access, the least recently used first.
+    lru: BTreeMap<u64, ObjectID>,
+    next_tick: u64,
+    size: usize,
+    /// Bumped by every invalidation in this shard. A load from the objects table is only
+    /// cached if no invalidation happened while it was in flight, as it may be stale otherwise.
+    generation: u64,
+}
+
+struct CacheEntry {
+    object: Object,
+    size: usize,
+    tick: u64,
+}
+
+impl ObjectCache {
+    pub fn new(config: &AuthorityStoreCacheConfig, registry: &Registry) -> Self {
+        Self {
+            shards: (0..NUM_SHARDS).map(|_| Default::default()).collect(),
+            shard_capacity: config.object_cache_size_bytes / NUM_SHARDS,
+            metrics: ObjectCacheMetrics::new(registry),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.shard_capacity > 0
+    }
+
+    fn shard(&self, object_id: &ObjectID) -> &Mutex<CacheShard> {
+        let mut hasher = DefaultHasher::new();
+        object_id.hash(&mut hasher);
+        &self.shards[hasher.finish() as usize % self.shards.len()]
+    }
+
+    /// Return the latest version of the object, calling `load` to read it from the objects
+    /// table if it is not cached.
+    pub fn get_or_load(
+        &self,
+        object_id: &ObjectID,
+        load: impl FnOnce() -> SuiResult<Option<Object>>,
+    ) -> SuiResult<Option<Object>> {
+        if !self.is_enabled() {
+            return load();
+        }
+        let shard = self.shard(object_id);
+        let generation = {
+            let mut shard = shard.lock();
+            if let Some(object) = shard.get(object_id) {
+                self.metrics.hits.inc();
+                return Ok(Some(object));
+            }
+            shard.generation
+        };
+        self.metrics.misses.inc();
+
+        let object = load()?;
+        if let Some(object) = &object {
+            let mut shard = shard.lock();
+            if shard.generation == generation {
+                self.insert(&mut shard, object.clone());
+            }
+        }
+        Ok(object)
+    }
+
+    /// Return the object if its latest version is cached and is `version`.
+    pub fn get_version(&self, object_id: &ObjectID, version: SequenceNumber) -> Option<Object> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let object = self
+            .shard(object_id)
+            .lock()
+            .get(object_id)
+            .filter(|object| object.version() == version);
+        if object.is_some() {
+            self.metrics.hits.inc();
+        } else {
+            self.metrics.misses.inc();
+        }
+        object
+    }
+
+    /// Drop the cached versions of the given objects. Must be called after the write that
+    /// changes them is committed to the objects table.
+    pub fn invalidate<'a>(&self, object_ids: impl IntoIterator<Item = &'a ObjectID>) {
+        if !self.is_enabled() {
+            return;
+        }
+        for object_id in object_ids {
+            let mut shard = self.shard(object_id).lock();
+            shard.generation += 1;
+            if let Some(entry) = shard.remove(object_id) {
+                self.metrics.size_bytes.sub(entry.size as i64);
+            }
+        }
+    }
+
+    fn insert(&self, shard: &mut CacheShard, object: Object) {
+        let size = object.object_size_for_gas_metering() + ENTRY_OVERHEAD;
+        if size > self.shard_capacity {
+            return;
+        }
+        let object_id = object.id();
+        let mut freed = shard.remove(&object_id).map_or(0, |entry| entry.size);
+        while shard.size + size > self.shard_capacity {
+            let lru_id = *shard
+                .lru
+                .values()
+                .next()
+                .expect("size is accounted to entries");
+            let entry = shard.remove(&lru_id).unwrap();
+            freed += entry.size;
+            self.metrics.evictions.inc();
+        }
+
+        let tick = shard.next_tick();
+        shard.lru.insert(tick, object_id);
+        shard
+            .entries
+            .insert(object_id, CacheEntry { object, size, tick });
+        shard.size += size;
+        self.metrics.size_bytes.add(size as i64 - freed as i64);
+    }
+}
+
+impl CacheShard {
+    fn get(&mut self, object_id: &ObjectID) -> Option<Object> {
+        let tick = self.next_tick();
+        let entry = self.entries.get_mut(object_id)?;
+        self.lru.remove(&entry.tick);
+        self.lru.insert(tick, *object_id);
+        entry.tick = tick;
+        Some(entry.object.clone())
+    }
+
+    fn remove(&mut self, object_id: &ObjectID) -> Option<CacheEntry> {
+        let entry = self.entries.remove(object_id)?;
+        self.lru.remove(&entry.tick);
+        self.size -= entry.size;
+        Some(entry)
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.next_tick += 1;
+        self.next_tick
+    }
+}
@@ -23,7 +23,7 @@ pub struct AuthorityStorePruner {
 }
 
 impl AuthorityStorePruner {
-    fn prune_objects(
+    pub(crate) fn prune_objects(
         num_versions_to_retain: u64,
         perpetual_db: Arc<AuthorityPerpetualTables>,
     ) -> u64 {
@@ -164,6 +164,27 @@ impl AuthorityPerpetualTables {
         }))
     }
 
+    /// Batched version of `get_latest_parent_entry`, results are in the same order as
+    /// `object_ids`. A single parent_sync iterator is reused for the whole batch, seeking to
+    /// the max possible entry of each object ID in turn.
+    pub fn multi_get_latest_parent_entries(
+        &self,
+        object_ids: &[ObjectID],
+    ) -> Result<Vec<Option<(ObjectRef, TransactionDigest)>>, SuiError> {
+        let mut iterator = self.parent_sync.iter();
+        let mut entries = Vec::with_capacity(object_ids.len());
+        for object_id in object_ids {
+            iterator =
+                iterator.skip_prior_to(&(*object_id, SequenceNumber::MAX, ObjectDigest::MAX))?;
+            entries.push(
+                iterator
+                    .next()
+                    .filter(|(obj_ref, _)| obj_ref.0 == *object_id),
+            );
+        }
+        Ok(entries)
+    }
+
     pub fn get_sui_system_state_object(&self) -> SuiResult<SuiSystemState> {
         let sui_system_object = self
             .get_object(&SUI_SYSTEM_STATE_OBJECT_ID)?
@@ -197,6 +218,15 @@ impl AuthorityPerpetualTables {
             .next()
             .is_none())
     }
+
+    /// Take a RocksDB checkpoint of the perpetual tables into `path`.
+    pub fn checkpoint_db(&self, path: &Path) -> SuiResult {
+        // This checkpoints the entire db and not just objects table
+        self.objects
+            .rocksdb
+            .checkpoint(path)
+            .map_err(SuiError::from)
+    }
 }
 
 // These functions are used to initialize the DB tables
@@ -2,8 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use prometheus::{
-    register_int_counter_with_registry, register_int_gauge_with_registry, IntCounter, IntGauge,
-    Registry,
+    register_histogram_with_registry, register_int_counter_with_registry,
+    register_int_gauge_with_registry, Histogram, IntCounter, IntGauge, Registry,
 };
 use std::sync::Arc;
 
@@ -12,6 +12,8 @@ pub struct CheckpointExecutorMetrics {
     pub checkpoint_exec_errors: IntCounter,
     pub checkpoint_exec_recv_channel_overflow: IntCounter,
     pub current_local_epoch: IntGauge,
+    pub checkpoint_exec_inflight: IntGauge,
+    pub checkpoint_transaction_count: Histogram,
 }
 
 impl CheckpointExecutorMetrics {
@@ -41,6 +43,19 @@ impl CheckpointExecutorMetrics {
                 registry
             )
             .unwrap(),
+            checkpoint_exec_inflight: register_int_gauge_with_registry!(
+                "checkpoint_exec_inflight",
+                "Number of checkpoints scheduled for execution that have not finished executing",
+                registry
+            )
+            .unwrap(),
+            checkpoint_transaction_count: register_histogram_with_registry!(
+                "checkpoint_transaction_count",
+                "Number of transactions in the checkpoints scheduled for execution",
+                vec![1.0, 5.0, 10.0, 50.0, 100.0, 500.0, 1_000.0, 5_000.0, 10_000.0],
+                registry
+            )
+            .unwrap(),
         };
         Arc::new(this)
     }
@@ -6,9 +6,10 @@
 //! scheduling and monitoring their execution. Its primary goal is to allow
 //! for catching up to the current checkpoint sequence number of the network
 //! as quickly as possible so that a newly joined, or recovering Node can
-//! participate in a timely manner. To that end, CheckpointExecutor attempts
-//! to saturate the CPU with executor tasks (one per checkpoint), each of which
-//! handle scheduling and awaiting checkpoint transaction execution.
+//! participate in a timely manner. To that end, CheckpointExecutor hands the
+//! transactions of many checkpoints at once to the TransactionManager, which
+//! executes independent transactions concurrently according to their object
+//! dependencies. One task per checkpoint awaits the execution of its transactions.
 //!
 //! CheckpointExecutor is made recoverable in the event of Node shutdown by way of a watermark,
 //! highest_executed_checkpoint, which is guaranteed to be updated sequentially in order,
@@ -22,6 +23,7 @@ use std::{cmp::Ordering, collections::HashMap, sync::Arc, time::Duration};
 use futures::stream::FuturesOrdered;
 use mysten_metrics::spawn_monitored_task;
 use prometheus::Registry;
+use sui_config::node::CheckpointExecutorConfig;
 use sui_types::{
     base_types::{ExecutionDigests, TransactionDigest, TransactionEffectsDigest},
     committee::Committee,
@@ -50,9 +52,7 @@ mod metrics;
 #[cfg(test)]
 pub(crate) mod tests;
 
-const TASKS_PER_CORE: usize = 1;
 const END_OF_EPOCH_BROADCAST_CHANNEL_CAPACITY: usize = 2;
-const LOCAL_EXECUTION_TIMEOUT: Duration = Duration::from_secs(10);
 
 type CheckpointExecutionBuffer = FuturesOrdered<
     JoinHandle<(
@@ -65,6 +65,7 @@ pub struct CheckpointExecutor {
     mailbox: broadcast::Receiver<VerifiedCheckpoint>,
     checkpoint_store: Arc<CheckpointStore>,
     authority_state: Arc<AuthorityState>,
+    config: CheckpointExecutorConfig,
     metrics: Arc<CheckpointExecutorMetrics>,
 }
 
@@ -73,12 +74,14 @@ impl CheckpointExecutor {
         mailbox: broadcast::Receiver<VerifiedCheckpoint>,
         checkpoint_store: Arc<CheckpointStore>,
         authority_state: Arc<AuthorityState>,
+        config: CheckpointExecutorConfig,
         prometheus_registry: &Registry,
     ) -> Self {
         Self {
             mailbox,
             checkpoint_store,
             authority_state,
+            config,
             metrics: CheckpointExecutorMetrics::new(prometheus_registry),
         }
     }
@@ -92,6 +95,7 @@ impl CheckpointExecutor {
             mailbox,
             checkpoint_store,
             authority_state,
+            config: Default::default(),
             metrics: CheckpointExecutorMetrics::new_for_tests(),
         }
     }
@@ -101,6 +105,7 @@ impl CheckpointExecutor {
             mailbox,
             checkpoint_store,
             authority_state,
+            config,
             metrics,
         } = self;
 
@@ -112,6 +117,7 @@ impl CheckpointExecutor {
             end_of_epoch_event_sender.clone(),
             checkpoint_store,
             authority_state,
+            config,
             metrics,
         )?;
 
@@ -169,6 +175,7 @@ pub struct CheckpointExecutorEventLoop {
     /// will reschedule the last checkpoint and correctly set end_of_epoch.
     end_of_epoch: bool,
     task_limit: usize,
+    local_execution_timeout: Duration,
     metrics: Arc<CheckpointExecutorMetrics>,
 }
 
@@ -178,6 +185,7 @@ impl CheckpointExecutorEventLoop {
         end_of_epoch_event_sender: broadcast::Sender<Committee>,
         checkpoint_store: Arc<CheckpointStore>,
         authority_state: Arc<AuthorityState>,
+        config: CheckpointExecutorConfig,
         metrics: Arc<CheckpointExecutorMetrics>,
     ) -> Result<Self, TypedStoreError> {
         Ok(Self {
@@ -188,7 +196,8 @@ impl CheckpointExecutorEventLoop {
             highest_scheduled_seq_num: None,
             latest_synced_checkpoint: None,
             end_of_epoch: false,
-            task_limit: TASKS_PER_CORE * num_cpus::get(),
+            task_limit: config.checkpoint_execution_max_concurrency,
+            local_execution_timeout: Duration::from_secs(config.local_execution_timeout_sec),
             metrics,
         })
     }
@@ -260,6 +269,7 @@ impl CheckpointExecutorEventLoop {
                 // be processed (added to FuturesOrdered) in seq_number order, using FuturesOrdered
                 // guarantees that we will also ratchet the watermarks in order.
                 Some(Ok((checkpoint, next_committee))) = pending.next() => {
+                    self.metrics.checkpoint_exec_inflight.set(pending.len() as i64);
                     match next_committee {
                         None => {
                             // Ensure that we are not skipping checkpoints at any point
@@ -431,10 +441,17 @@ impl CheckpointExecutorEventLoop {
         let state = self.authority_state.clone();
         let store = self.checkpoint_store.clone();
         let metrics = self.metrics.clone();
+        let local_execution_timeout = self.local_execution_timeout;
 
         pending.push_back(spawn_monitored_task!(async move {
-            while let Err(err) =
-                execute_checkpoint(checkpoint.clone(), state.clone(), store.clone()).await
+            while let Err(err) = execute_checkpoint(
+                checkpoint.clone(),
+                state.clone(),
+                store.clone(),
+                &metrics,
+                local_execution_timeout,
+            )
+            .await
             {
                 error!(
                     "Error while executing checkpoint, will retry in 1s: {:?}",
@@ -448,6 +465,9 @@ impl CheckpointExecutorEventLoop {
         }));
 
         self.highest_scheduled_seq_num = Some(highest_scheduled);
+        self.metrics
+            .checkpoint_exec_inflight
+            .set(pending.len() as i64);
 
         Ok(())
     }
@@ -480,6 +500,8 @@ pub async fn execute_checkpoint(
     checkpoint: VerifiedCheckpoint,
     authority_state: Arc<AuthorityState>,
     checkpoint_store: Arc<CheckpointStore>,
+    metrics: &CheckpointExecutorMetrics,
+    local_execution_timeout: Duration,
 ) -> SuiResult {
     debug!(
         "Scheduling checkpoint {:?} for execution",
@@ -495,21 +517,73 @@ pub async fn execute_checkpoint(
         })
         .into_inner();
 
-    execute_transactions(txes, authority_state).await
+    metrics
+        .checkpoint_transaction_count
+        .observe(txes.len() as f64);
+    execute_transactions(txes, authority_state, local_execution_timeout).await
 }
 
+/// Enqueues the transactions of a checkpoint into the TransactionManager and waits until all of
+/// them are executed. The TransactionManager executes them concurrently with the transactions of
+/// other checkpoints, as soon as their input objects are available.
 async fn execute_transactions(
     execution_digests: Vec<ExecutionDigests>,
     authority_state: Arc<AuthorityState>,
+    local_execution_timeout: Duration,
 ) -> SuiResult {
     let all_tx_digests: Vec<TransactionDigest> =
         execution_digests.iter().map(|tx| tx.transaction).collect();
 
+    // Transactions executed before, e.g. prior to a restart or through the transaction
+    // orchestrator, do not need to be scheduled again.
+    let executed = authority_state
+        .database
+        .perpetual_tables
+        .executed_effects
+        .multi_get(&all_tx_digests)?;
+    let unexecuted_digests: Vec<ExecutionDigests> = execution_digests
+        .into_iter()
+        .zip(executed)
+        .filter_map(|(digests, effects)| effects.is_none().then_some(digests))
+        .collect();
+
+    if !unexecuted_digests.is_empty() {
+        enqueue_transactions(&unexecuted_digests, &authority_state).await?;
+    }
+
+    // Once the transactions have been executed, all txns should have effects committed.
+    let mut periods = 1;
+    loop {
+        let effects_future = authority_state
+            .database
+            .notify_read_effects(all_tx_digests.clone());
+
+        match timeout(local_execution_timeout, effects_future).await {
+            Err(_elapsed) => {
+                warn!(
+                    "Transaction effects for checkpoint not present within {:?}. ",
+                    local_execution_timeout * periods
+                );
+                periods += 1;
+            }
+            Ok(Err(err)) => return Err(err),
+            Ok(Ok(_)) => return Ok(()),
+        }
+    }
+}
+
+async fn enqueue_transactions(
+    execution_digests: &[ExecutionDigests],
+    authority_state: &AuthorityState,
+) -> SuiResult {
+    let tx_digests: Vec<TransactionDigest> =
+        execution_digests.iter().map(|tx| tx.transaction).collect();
+
     let synced_txns: Vec<VerifiedCertificate> = authority_state
         .database
         .perpetual_tables
         .synced_transactions
-        .multi_get(&all_tx_digests)?
+        .multi_get(&tx_digests)?
         .into_iter()
         .flatten()
         .map(|tx| tx.into())
@@ -534,41 +608,16 @@ async fn execute_transactions(
         })
         .collect();
 
-    for tx in synced_txns.clone() {
+    for tx in synced_txns.iter() {
         if tx.contains_shared_object() {
             authority_state
                 .database
-                .acquire_shared_locks_from_effects(&tx, digest_to_effects.get(tx.digest()).unwrap())
+                .acquire_shared_locks_from_effects(tx, digest_to_effects.get(tx.digest()).unwrap())
                 .await?;
         }
     }
-    authority_state
-        .database
-        .epoch_store()
-        .insert_pending_certificates(&synced_txns)?;
 
     authority_state
-        .transaction_manager()
-        .enqueue(synced_txns)
-        .await?;
-
-    // Once synced_txns have been awaited, all txns should have effects committed.
-    let mut periods = 1;
-    loop {
-        let effects_future = authority_state
-            .database
-            .notify_read_effects(all_tx_digests.clone());
-
-        match timeout(LOCAL_EXECUTION_TIMEOUT, effects_future).await {
-            Err(_elapsed) => {
-                warn!(
-                    "Transaction effects for checkpoint not present within {:?}. ",
-                    LOCAL_EXECUTION_TIMEOUT * periods
-                );
-                periods += 1;
-            }
-            Ok(Err(err)) => return Err(err),
-            Ok(Ok(_)) => return Ok(()),
-        }
-    }
+        .enqueue_certificates_for_execution(synced_txns)
+        .await
 }
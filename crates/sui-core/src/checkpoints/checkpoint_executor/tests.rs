@@ -20,7 +20,7 @@ use sui_network::state_sync::test_utils::{empty_contents, CommitteeFixture};
 /// picks up where it left off in the event of a mid-epoch node crash.
 #[tokio::test]
 pub async fn test_checkpoint_executor_crash_recovery() {
-    let buffer_size = num_cpus::get() * TASKS_PER_CORE * 2;
+    let buffer_size = num_cpus::get() * 2;
     let tempdir = tempdir().unwrap();
     let checkpoint_store = CheckpointStore::new(tempdir.path());
 
@@ -115,6 +115,62 @@ pub async fn test_checkpoint_executor_crash_recovery() {
     }
 }
 
+/// Test that all checkpoints get executed, in order, when fewer checkpoints than
+/// synced can be scheduled for execution at once.
+#[tokio::test]
+pub async fn test_checkpoint_executor_limited_concurrency() {
+    let buffer_size = 20;
+    let tempdir = tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(tempdir.path());
+
+    let (_state, executor, checkpoint_sender, committee): (
+        Arc<AuthorityState>,
+        CheckpointExecutor,
+        Sender<VerifiedCheckpoint>,
+        CommitteeFixture,
+    ) = init_executor_test(buffer_size, checkpoint_store.clone()).await;
+    let executor = CheckpointExecutor {
+        config: CheckpointExecutorConfig {
+            checkpoint_execution_max_concurrency: 2,
+            ..Default::default()
+        },
+        ..executor
+    };
+
+    let _ = sync_new_checkpoints(
+        &checkpoint_store,
+        &checkpoint_sender,
+        buffer_size,
+        None,
+        &committee,
+    );
+    let (executor_handle, _reconfig_channel) = executor.start().unwrap();
+
+    // Poll the execution watermark until the last checkpoint is executed.
+    let last_checkpoint = buffer_size as u64 - 1;
+    timeout(Duration::from_secs(30), async {
+        while checkpoint_store
+            .get_highest_executed_checkpoint_seq_number()
+            .unwrap()
+            != Some(last_checkpoint)
+        {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("All checkpoints should be executed");
+
+    drop(checkpoint_sender);
+    timeout(Duration::from_secs(1), async {
+        executor_handle
+            .join()
+            .await
+            .expect("Should exit gracefully");
+    })
+    .await
+    .unwrap();
+}
+
 /// Test that checkpoint execution correctly signals end of epoch after
 /// receiving last checkpoint of epoch, pauses execution until reconfig,
 /// then resumes checkpoint execution after reconfig.
@@ -1,11 +1,13 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::authority::authority_per_epoch_store::AuthorityPerEpochStore;
 use crate::authority::StableSyncAuthoritySigner;
 use crate::consensus_adapter::SubmitToConsensus;
 use crate::epoch::reconfiguration::ReconfigurationInitiator;
 use async_trait::async_trait;
 use fastcrypto::encoding::{Encoding, Hex};
+use std::sync::Arc;
 use sui_types::base_types::AuthorityName;
 use sui_types::error::SuiResult;
 use sui_types::messages::ConsensusTransaction;
@@ -21,6 +23,7 @@ pub trait CheckpointOutput: Sync + Send + 'static {
         &self,
         summary: &CheckpointSummary,
         contents: &CheckpointContents,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
     ) -> SuiResult;
 }
 
@@ -57,10 +60,11 @@ impl<T: SubmitToConsensus + ReconfigurationInitiator> CheckpointOutput
         &self,
         summary: &CheckpointSummary,
         contents: &CheckpointContents,
+        epoch_store: &Arc<AuthorityPerEpochStore>,
     ) -> SuiResult {
         let checkpoint_seq = summary.sequence_number;
         LogCheckpointOutput
-            .checkpoint_created(summary, contents)
+            .checkpoint_created(summary, contents, epoch_store)
             .await?;
         let summary = SignedCheckpointSummary::new_from_summary(
             summary.clone(),
@@ -70,6 +74,18 @@ impl<T: SubmitToConsensus + ReconfigurationInitiator> CheckpointOutput
         let message = CheckpointSignatureMessage { summary };
         let transaction = ConsensusTransaction::new_checkpoint_signature_message(message);
         self.sender.submit_to_consensus(&transaction).await?;
+        // The beacon keys are predictable until they come from a DKG, see ProtocolConfig.
+        if epoch_store.protocol_config().randomness_beacon() {
+            if let Some(partial_signatures) = epoch_store
+                .randomness_beacon()
+                .sign(self.authority, checkpoint_seq)
+            {
+                let transaction = ConsensusTransaction::new_randomness_partial_signature_message(
+                    partial_signatures,
+                );
+                self.sender.submit_to_consensus(&transaction).await?;
+            }
+        }
         if let Some(checkpoints_per_epoch) = self.checkpoints_per_epoch {
             if checkpoint_seq != 0 && checkpoint_seq % checkpoints_per_epoch == 0 {
                 self.sender.close_epoch()?;
@@ -85,6 +101,7 @@ impl CheckpointOutput for LogCheckpointOutput {
         &self,
         summary: &CheckpointSummary,
         contents: &CheckpointContents,
+        _epoch_store: &Arc<AuthorityPerEpochStore>,
     ) -> SuiResult {
         debug!(
             "Including following transactions in checkpoint {}: {:?}",
@@ -38,6 +38,7 @@ use sui_types::messages_checkpoint::{
     CertifiedCheckpointSummary, CheckpointContents, CheckpointContentsDigest, CheckpointDigest,
     CheckpointSequenceNumber, CheckpointSignatureMessage, CheckpointSummary, VerifiedCheckpoint,
 };
+use sui_types::randomness::CheckpointRandomness;
 use tokio::sync::{mpsc, watch, Notify};
 use tracing::{debug, error, info, warn};
 use typed_store::rocks::{DBMap, TypedStoreError};
@@ -63,6 +64,9 @@ pub struct CheckpointStore {
     /// Watermarks used to determine the highest verified, fully synced, and
     /// fully executed checkpoints
     watermarks: DBMap<CheckpointWatermark, (CheckpointSequenceNumber, CheckpointDigest)>,
+
+    /// Output of the randomness beacon for each checkpoint
+    checkpoint_randomness: DBMap<CheckpointSequenceNumber, CheckpointRandomness>,
 }
 
 impl CheckpointStore {
@@ -70,6 +74,11 @@ impl CheckpointStore {
         Arc::new(Self::open_tables_read_write(path.to_path_buf(), None, None))
     }
 
+    /// Take a RocksDB checkpoint of the checkpoint store into `path`.
+    pub fn checkpoint_db(&self, path: &Path) -> Result<(), TypedStoreError> {
+        self.watermarks.rocksdb.checkpoint(path)
+    }
+
     pub fn get_checkpoint_by_digest(
         &self,
         digest: &CheckpointDigest,
@@ -238,6 +247,21 @@ impl CheckpointStore {
         self.checkpoint_content
             .insert(&contents.digest(), &contents)
     }
+
+    pub fn get_checkpoint_randomness(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<Option<CheckpointRandomness>, TypedStoreError> {
+        self.checkpoint_randomness.get(&sequence_number)
+    }
+
+    pub fn insert_checkpoint_randomness(
+        &self,
+        randomness: &CheckpointRandomness,
+    ) -> Result<(), TypedStoreError> {
+        self.checkpoint_randomness
+            .insert(&randomness.round.sequence_number, randomness)
+    }
 }
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
@@ -363,7 +387,9 @@ impl CheckpointBuilder {
         let content_info = match new_checkpoint {
             Some((summary, contents)) => {
                 // Only create checkpoint if content is not empty
-                self.output.checkpoint_created(&summary, &contents).await?;
+                self.output
+                    .checkpoint_created(&summary, &contents, epoch_store)
+                    .await?;
 
                 self.metrics
                     .transactions_included_in_checkpoint
@@ -726,6 +752,10 @@ pub trait CheckpointServiceNotify {
         roots: Vec<TransactionDigest>,
         last_checkpoint_of_epoch: bool,
     ) -> SuiResult;
+
+    /// Called when the randomness beacon produced the randomness of a checkpoint.
+    /// Must be idempotent.
+    fn notify_checkpoint_randomness(&self, randomness: &CheckpointRandomness) -> SuiResult;
 }
 
 /// This is a service used to communicate with other pieces of sui(for ex. authority)
@@ -870,6 +900,16 @@ impl CheckpointServiceNotify for CheckpointService {
         self.notify_builder.notify_one();
         Ok(())
     }
+
+    fn notify_checkpoint_randomness(&self, randomness: &CheckpointRandomness) -> SuiResult {
+        info!(
+            "Randomness for checkpoint sequence {} is {}",
+            randomness.round.sequence_number,
+            Hex::encode(randomness.randomness())
+        );
+        self.tables.insert_checkpoint_randomness(randomness)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -893,6 +933,10 @@ impl CheckpointServiceNotify for CheckpointServiceNoop {
     ) -> SuiResult {
         Ok(())
     }
+
+    fn notify_checkpoint_randomness(&self, _: &CheckpointRandomness) -> SuiResult {
+        Ok(())
+    }
 }
 
 struct CheckpointTailer {
@@ -1094,6 +1138,7 @@ mod tests {
             &self,
             summary: &CheckpointSummary,
             contents: &CheckpointContents,
+            _epoch_store: &Arc<AuthorityPerEpochStore>,
         ) -> SuiResult {
             self.try_send((contents.clone(), summary.clone())).unwrap();
             Ok(())
@@ -5,23 +5,18 @@ use bytes::Bytes;
 use futures::future::select;
 use futures::future::Either;
 use futures::FutureExt;
-use itertools::Itertools;
 use narwhal_types::TransactionProto;
 use narwhal_types::TransactionsClient;
+use narwhal_types::{BatchStatusProto, BatchStatusRequest};
 use parking_lot::RwLockReadGuard;
 use prometheus::register_int_gauge_with_registry;
 use prometheus::IntCounter;
 use prometheus::IntGauge;
 use prometheus::Registry;
 use prometheus::{register_histogram_with_registry, register_int_counter_with_registry, Histogram};
-use rand::rngs::StdRng;
-use rand::SeedableRng;
-use std::future::Future;
 use std::sync::atomic::AtomicU64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
-use sui_types::base_types::TransactionDigest;
-use sui_types::committee::Committee;
 use sui_types::{
     error::{SuiError, SuiResult},
     messages::ConsensusTransaction,
@@ -33,7 +28,6 @@ use tokio::time;
 
 use crate::authority::AuthorityState;
 use mysten_metrics::spawn_monitored_task;
-use sui_types::base_types::AuthorityName;
 use sui_types::messages::ConsensusTransactionKind;
 use tokio::time::Duration;
 use tracing::{error, warn};
@@ -119,18 +113,44 @@ pub trait SubmitToConsensus: Sync + Send + 'static {
 
 #[async_trait::async_trait]
 impl SubmitToConsensus for TransactionsClient<sui_network::tonic::transport::Channel> {
+    /// Submits the transaction to the narwhal worker and follows the batch that includes it
+    /// until that batch is committed. Returns an error if the batch cannot be followed up to
+    /// its commit (e.g. because the worker restarted), in which case the transaction should be
+    /// submitted again.
     async fn submit_to_consensus(&self, transaction: &ConsensusTransaction) -> SuiResult {
         let serialized =
             bincode::serialize(transaction).expect("Serializing consensus transaction cannot fail");
         let bytes = Bytes::from(serialized.clone());
-        self.clone()
-            .submit_transaction(TransactionProto { transaction: bytes })
+        let mut client = self.clone();
+        let batch_digest = client
+            .submit_transaction_with_status(TransactionProto { transaction: bytes })
             .await
             .map_err(|e| SuiError::ConsensusConnectionBroken(format!("{:?}", e)))
             .tap_err(|r| {
                 error!("Submit transaction failed with: {:?}", r);
-            })?;
-        Ok(())
+            })?
+            .into_inner()
+            .batch_digest;
+        let mut updates = client
+            .subscribe_batch_status(BatchStatusRequest {
+                batch_digest: batch_digest.clone(),
+            })
+            .await
+            .map_err(|e| SuiError::ConsensusConnectionBroken(format!("{:?}", e)))?
+            .into_inner();
+        while let Some(update) = updates
+            .message()
+            .await
+            .map_err(|e| SuiError::ConsensusConnectionBroken(format!("{:?}", e)))?
+        {
+            if let Some(BatchStatusProto::Committed(_)) = update.status {
+                return Ok(());
+            }
+        }
+        Err(SuiError::ConsensusConnectionBroken(format!(
+            "Lost track of batch {:?} before it was committed",
+            batch_digest
+        )))
     }
 }
 
@@ -191,64 +211,6 @@ impl ConsensusAdapter {
         self.num_inflight_transactions.load(Ordering::Relaxed)
     }
 
-    fn await_submit_delay(
-        committee: &Committee,
-        ourselves: &AuthorityName,
-        transaction: &ConsensusTransaction,
-    ) -> impl Future<Output = ()> {
-        tokio::time::sleep(Self::submit_delay(committee, ourselves, transaction))
-    }
-
-    fn submit_delay(
-        committee: &Committee,
-        ourselves: &AuthorityName,
-        transaction: &ConsensusTransaction,
-    ) -> Duration {
-        if let ConsensusTransactionKind::UserTransaction(certificate) = &transaction.kind {
-            Self::submit_delay_certificate(committee, ourselves, certificate.digest())
-        } else {
-            Duration::ZERO
-        }
-    }
-
-    /// Check when this authority should submit the certificate to consensus.
-    /// This sorts all authorities based on pseudo-random distribution derived from transaction hash.
-    /// Authorities higher in the list wait less time.
-    ///
-    /// The function targets having only 1 consensus transaction submitted per user transaction
-    /// when system operates normally
-    fn submit_delay_certificate(
-        committee: &Committee,
-        ourselves: &AuthorityName,
-        tx_digest: &TransactionDigest,
-    ) -> Duration {
-        let position = Self::position_submit_certificate(committee, ourselves, tx_digest);
-        const MAX_DELAY_MUL: usize = 10;
-        // DELAY_STEP is chosen as 1.5 * mean consensus delay
-        // In the future we can actually use information about consensus rounds instead of this delay
-        const DELAY_STEP: Duration = Duration::from_secs(7);
-        DELAY_STEP * std::cmp::min(position, MAX_DELAY_MUL) as u32
-    }
-
-    /// Returns a position of the current validator in ordered list of validator to submit transaction
-    fn position_submit_certificate(
-        committee: &Committee,
-        ourselves: &AuthorityName,
-        tx_digest: &TransactionDigest,
-    ) -> usize {
-        // the 32 is as requirement of the deault StdRng::from_seed choice
-        let digest_bytes = tx_digest.into_bytes();
-
-        // permute the validators deterministically, based on the digest
-        let mut rng = StdRng::from_seed(digest_bytes);
-        let validators = committee.shuffle_by_stake_with_rng(None, None, &mut rng);
-        let (position, _) = validators
-            .into_iter()
-            .find_position(|a| a == ourselves)
-            .expect("Could not find ourselves in shuffled committee");
-        position
-    }
-
     /// This method blocks until transaction is persisted in local database
     /// It then returns handle to async task, user can join this handle to await while transaction is processed by consensus
     ///
@@ -310,44 +272,40 @@ impl ConsensusAdapter {
         let processed_waiter = epoch_store
             .consensus_message_processed_notify(transaction.key())
             .boxed();
-        let await_submit = {
-            let epoch_store = self.authority.epoch_store();
-            Self::await_submit_delay(epoch_store.committee(), &self.authority.name, &transaction)
-                .boxed()
-        };
-        // We need to wait for some delay until we submit transaction to the consensus
-        // However, if transaction is received by consensus while we wait, we don't need to wait
-        let processed_waiter = match select(processed_waiter, await_submit).await {
-            Either::Left((processed, _await_submit)) => {
-                processed.expect("Storage error when waiting for consensus message processed");
-                None
-            }
-            Either::Right(((), processed_waiter)) => Some(processed_waiter),
-        };
-        if let Some(processed_waiter) = processed_waiter {
-            // We enter this branch when in select above await_submit completed and processed_waiter is pending
-            // This means it is time for us to submit transaction to consensus
+        {
             let _timer = self
                 .opt_metrics
                 .as_ref()
                 .map(|m| m.sequencing_acknowledge_latency.start_timer());
-            while let Err(e) = self
-                .consensus_client
-                .submit_to_consensus(&transaction)
-                .await
-            {
-                error!(
-                    "Error submitting transaction to own narwhal worker: {:?}",
-                    e
-                );
-                self.opt_metrics.as_ref().map(|metrics| {
-                    metrics.sequencing_certificate_failures.inc();
-                });
-                time::sleep(Duration::from_secs(10)).await;
+            // The transaction is submitted right away. The submission only completes once the
+            // batch including the transaction is committed, and is retried if the batch is lost.
+            // We stop as soon as the transaction is processed, which might happen earlier if
+            // another validator submitted it as well, or if it was processed before a restart.
+            let submit = async {
+                while let Err(e) = self
+                    .consensus_client
+                    .submit_to_consensus(&transaction)
+                    .await
+                {
+                    error!(
+                        "Error submitting transaction to own narwhal worker: {:?}",
+                        e
+                    );
+                    self.opt_metrics.as_ref().map(|metrics| {
+                        metrics.sequencing_certificate_failures.inc();
+                    });
+                    time::sleep(Duration::from_secs(10)).await;
+                }
+            }
+            .boxed();
+            match select(processed_waiter, submit).await {
+                Either::Left((processed, _submit)) => {
+                    processed.expect("Storage error when waiting for consensus message processed")
+                }
+                Either::Right(((), processed_waiter)) => processed_waiter
+                    .await
+                    .expect("Storage error when waiting for consensus message processed"),
             }
-            processed_waiter
-                .await
-                .expect("Storage error when waiting for consensus message processed");
         }
         epoch_store
             .remove_pending_consensus_transaction(&transaction.key())
@@ -447,56 +405,3 @@ impl SubmitToConsensus for Arc<ConsensusAdapter> {
         self.submit(transaction.clone(), None).map(|_| ())
     }
 }
-
-#[cfg(test)]
-mod adapter_tests {
-    use super::ConsensusAdapter;
-    use fastcrypto::traits::KeyPair;
-    use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
-    use sui_types::{
-        base_types::{TransactionDigest, TRANSACTION_DIGEST_LENGTH},
-        committee::Committee,
-        crypto::{get_key_pair_from_rng, AuthorityKeyPair, AuthorityPublicKeyBytes},
-    };
-
-    #[test]
-    fn test_position_submit_certificate() {
-        // grab a random committee and a random stake distribution
-        let mut rng = StdRng::from_seed([0; 32]);
-        const COMMITTEE_SIZE: usize = 10; // 3 * 3 + 1;
-        let authorities = (0..COMMITTEE_SIZE)
-            .map(|_k| {
-                (
-                    AuthorityPublicKeyBytes::from(
-                        get_key_pair_from_rng::<AuthorityKeyPair, _>(&mut rng)
-                            .1
-                            .public(),
-                    ),
-                    rng.gen_range(0u64..10u64),
-                )
-            })
-            .collect::<Vec<_>>();
-        let committee = Committee::new(0, authorities.iter().cloned().collect()).unwrap();
-
-        // generate random transaction digests, and account for validator selection
-        const NUM_TEST_TRANSACTIONS: usize = 1000;
-
-        for _tx_idx in 0..NUM_TEST_TRANSACTIONS {
-            let mut tx_digest_bytes = [0u8; TRANSACTION_DIGEST_LENGTH];
-            rng.fill_bytes(&mut tx_digest_bytes);
-            let tx_digest = TransactionDigest::new(tx_digest_bytes);
-
-            let mut zero_found = false;
-            for (name, _) in authorities.iter() {
-                let f = ConsensusAdapter::position_submit_certificate(&committee, name, &tx_digest);
-                assert!(f < committee.num_members());
-                if f == 0 {
-                    // One and only one validator gets position 0
-                    assert!(!zero_found);
-                    zero_found = true;
-                }
-            }
-            assert!(zero_found);
-        }
-    }
-}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::authority::authority_per_epoch_store::{
-    AuthorityPerEpochStore, ExecutionIndicesWithHash,
+    AuthorityPerEpochStore, DeferredTransaction, ExecutionIndicesWithHash,
 };
 use crate::authority::AuthorityMetrics;
 use crate::checkpoints::CheckpointService;
@@ -11,11 +11,13 @@ use async_trait::async_trait;
 use mysten_metrics::monitored_scope;
 use narwhal_executor::{ExecutionIndices, ExecutionState};
 use narwhal_types::ConsensusOutput;
+use std::cmp::Reverse;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap};
 use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex};
-use sui_types::base_types::AuthorityName;
-use sui_types::messages::ConsensusTransaction;
+use sui_types::base_types::{AuthorityName, ObjectID};
+use sui_types::messages::{ConsensusTransaction, ConsensusTransactionKind};
 use sui_types::storage::ParentSync;
 use tracing::{debug, instrument, warn};
 
@@ -83,6 +85,78 @@ fn update_hash(
     Some(last_seen)
 }
 
+/// Orders the user certificates of a consensus commit by descending gas price, so that
+/// transactions paying more are scheduled first on contended shared objects. The sort is stable,
+/// so certificates with the same gas price keep their consensus order. Certificates are never
+/// moved across other consensus messages, e.g. a certificate sequenced before the EndOfPublish
+/// of its authority stays before it.
+fn order_by_gas_price<T>(transactions: &mut [(ConsensusTransaction, T)]) {
+    for certificates in
+        transactions.split_mut(|(transaction, _)| user_gas_price(transaction).is_none())
+    {
+        certificates.sort_by_key(|(transaction, _)| Reverse(user_gas_price(transaction)));
+    }
+}
+
+fn user_gas_price(transaction: &ConsensusTransaction) -> Option<u64> {
+    match &transaction.kind {
+        ConsensusTransactionKind::UserTransaction(certificate) => {
+            Some(certificate.data().intent_message.value.gas_price)
+        }
+        _ => None,
+    }
+}
+
+/// A consensus transaction considered for scheduling in a commit.
+struct CommitTransaction {
+    certificate: Arc<narwhal_types::Certificate>,
+    serialized: Vec<u8>,
+    /// Position at which the transaction was first sequenced.
+    sequenced_at: ExecutionIndices,
+    /// Whether the transaction was deferred by an earlier commit.
+    deferred: bool,
+}
+
+/// Applies the per shared object budget to the transactions of a consensus commit, in order.
+/// A certificate is deferred if any of its shared objects already has `budget` certificates
+/// scheduled in this commit. Returns the scheduled and the deferred transactions.
+fn schedule_by_shared_object_budget<T>(
+    transactions: Vec<(ConsensusTransaction, T)>,
+    budget: u64,
+) -> (
+    Vec<(ConsensusTransaction, T)>,
+    Vec<(ConsensusTransaction, T)>,
+) {
+    let mut scheduled_per_object: HashMap<ObjectID, u64> = HashMap::new();
+    let mut scheduled = Vec::new();
+    let mut deferred = Vec::new();
+    for (transaction, data) in transactions {
+        let objects = shared_input_objects(&transaction);
+        let congested = objects
+            .iter()
+            .any(|id| scheduled_per_object.get(id).copied().unwrap_or_default() >= budget);
+        if congested {
+            deferred.push((transaction, data));
+            continue;
+        }
+        for id in objects {
+            *scheduled_per_object.entry(id).or_default() += 1;
+        }
+        scheduled.push((transaction, data));
+    }
+    (scheduled, deferred)
+}
+
+fn shared_input_objects(transaction: &ConsensusTransaction) -> BTreeSet<ObjectID> {
+    match &transaction.kind {
+        ConsensusTransactionKind::UserTransaction(certificate) => certificate
+            .shared_input_objects()
+            .map(|(id, _)| *id)
+            .collect(),
+        _ => BTreeSet::new(),
+    }
+}
+
 #[async_trait]
 impl<T: ParentSync + Send + Sync> ExecutionState for ConsensusHandler<T> {
     /// This function will be called by Narwhal, after Narwhal sequenced this certificate.
@@ -93,8 +167,7 @@ impl<T: ParentSync + Send + Sync> ExecutionState for ConsensusHandler<T> {
         consensus_output: ConsensusOutput,
     ) {
         let _scope = monitored_scope("HandleConsensusOutput");
-        let mut sequenced_transactions = Vec::new();
-        let mut seq = 0;
+        let mut transactions = Vec::new();
 
         let mut bytes = 0usize;
         let round = consensus_output.sub_dag.round();
@@ -118,32 +191,117 @@ impl<T: ParentSync + Send + Sync> ExecutionState for ConsensusHandler<T> {
                             continue;
                         }
                     };
-                    let index = ExecutionIndices {
+                    transactions.push((transaction, (output_cert.clone(), serialized_transaction)));
+                }
+            }
+        }
+
+        if self.epoch_store.protocol_config().order_by_gas_price() {
+            order_by_gas_price(&mut transactions);
+        }
+
+        let sub_dag_index = consensus_output.sub_dag.sub_dag_index;
+        // Certificates deferred by earlier commits are scheduled first, in the order they were
+        // originally sequenced.
+        let mut candidates: Vec<_> = self
+            .epoch_store
+            .get_deferred_transactions(sub_dag_index)
+            .into_iter()
+            .map(|deferred| {
+                let serialized = bincode::serialize(&deferred.transaction)
+                    .expect("Serializing consensus transaction cannot fail");
+                (
+                    deferred.transaction,
+                    CommitTransaction {
+                        certificate: Arc::new(deferred.certificate),
+                        serialized,
+                        sequenced_at: deferred.sequenced_at,
+                        deferred: true,
+                    },
+                )
+            })
+            .collect();
+        for (position, (transaction, (certificate, serialized))) in
+            transactions.into_iter().enumerate()
+        {
+            // The certificate is already waiting in the deferred queue, either because it was
+            // sequenced again or because this commit is replayed after its deferrals were stored.
+            if !shared_input_objects(&transaction).is_empty()
+                && self
+                    .epoch_store
+                    .is_transaction_deferred(&transaction.key())
+                    .expect("Failed to load deferred transactions")
+            {
+                continue;
+            }
+            candidates.push((
+                transaction,
+                CommitTransaction {
+                    certificate,
+                    serialized,
+                    sequenced_at: ExecutionIndices {
                         last_committed_round: round,
-                        sub_dag_index: consensus_output.sub_dag.sub_dag_index,
-                        transaction_index: seq,
-                    };
+                        sub_dag_index,
+                        transaction_index: position as u64,
+                    },
+                    deferred: false,
+                },
+            ));
+        }
 
-                    let index_with_hash =
-                        match update_hash(&self.last_seen, index, &serialized_transaction) {
-                            Some(i) => i,
-                            None => {
-                                debug!(
-                "Ignore consensus transaction at index {:?} as it appear to be already processed",
-                index
-            );
-                                continue;
-                            }
-                        };
-
-                    sequenced_transactions.push(SequencedConsensusTransaction {
-                        certificate: output_cert.clone(),
-                        consensus_index: index_with_hash,
-                        transaction,
-                    });
-                    seq += 1;
+        // Certificates sequenced after the EndOfPublish quorum are not executed in this epoch.
+        // Once an authority has sent EndOfPublish, stop deferring and drain the queue, deferred
+        // certificates being scheduled ahead of the EndOfPublish messages of this commit.
+        let closing_epoch = self.epoch_store.has_any_end_of_publish()
+            || candidates.iter().any(|(transaction, _)| {
+                matches!(transaction.kind, ConsensusTransactionKind::EndOfPublish(_))
+            });
+        let budget = if closing_epoch {
+            u64::MAX
+        } else {
+            self.epoch_store
+                .protocol_config()
+                .max_transactions_per_shared_object_per_commit()
+        };
+        let (scheduled, deferred) = schedule_by_shared_object_budget(candidates, budget);
+        self.metrics
+            .consensus_handler_deferred_transactions
+            .inc_by(deferred.len() as u64);
+        self.metrics
+            .consensus_handler_deferred_queue_depth
+            .set(deferred.len() as i64);
+        let scheduled_deferred_keys: Vec<_> = scheduled
+            .iter()
+            .filter(|(_, data)| data.deferred)
+            .map(|(transaction, _)| transaction.key())
+            .collect();
+
+        let mut sequenced_transactions = Vec::new();
+        let mut seq = 0;
+        for (transaction, data) in scheduled {
+            let index = ExecutionIndices {
+                last_committed_round: round,
+                sub_dag_index,
+                transaction_index: seq,
+            };
+
+            let index_with_hash = match update_hash(&self.last_seen, index, &data.serialized) {
+                Some(i) => i,
+                None => {
+                    debug!(
+                        "Ignore consensus transaction at index {:?} as it appear to be already processed",
+                        index
+                    );
+                    continue;
                 }
-            }
+            };
+
+            sequenced_transactions.push(SequencedConsensusTransaction {
+                certificate: data.certificate,
+                consensus_index: index_with_hash,
+                transaction,
+            });
+            seq += 1;
         }
 
         self.metrics
@@ -170,6 +328,21 @@ impl<T: ParentSync + Send + Sync> ExecutionState for ConsensusHandler<T> {
                 .expect("Unrecoverable error in consensus handler");
         }
 
+        self.epoch_store
+            .update_deferred_transactions(
+                scheduled_deferred_keys,
+                deferred
+                    .into_iter()
+                    .map(|(transaction, data)| DeferredTransaction {
+                        sequenced_at: data.sequenced_at,
+                        deferred_in: sub_dag_index,
+                        certificate: data.certificate.as_ref().clone(),
+                        transaction,
+                    })
+                    .collect(),
+            )
+            .expect("Unrecoverable error in consensus handler when storing deferred transactions");
+
         self.epoch_store
             .handle_commit_boundary(&consensus_output.sub_dag, &self.checkpoint_service)
             .expect("Unrecoverable error in consensus handler when processing commit boundary")
@@ -246,3 +419,139 @@ pub fn test_update_hash() {
     assert!(update_hash(&last_seen, index1, tx).is_none());
     assert!(update_hash(&last_seen, index2, tx).is_some());
 }
+
+#[tokio::test]
+pub async fn test_order_by_gas_price() {
+    use crate::authority::authority_tests::init_state_with_objects;
+    use crate::consensus_adapter::consensus_tests::{test_certificates, test_gas_objects};
+    use sui_types::object::Object;
+
+    let mut objects = test_gas_objects();
+    objects.push(Object::shared_for_testing());
+    let state = init_state_with_objects(objects).await;
+
+    let mut certificates = test_certificates(&state).await;
+    for (certificate, gas_price) in certificates.iter_mut().zip([2, 2, 1, 3]) {
+        certificate
+            .data_mut_for_testing()
+            .intent_message
+            .value
+            .gas_price = gas_price;
+    }
+    let authority = state.name;
+    let mut certificates = certificates
+        .into_iter()
+        .map(|certificate| ConsensusTransaction::new_certificate_message(&authority, certificate));
+
+    let mut transactions: Vec<_> = [
+        certificates.next().unwrap(),
+        certificates.next().unwrap(),
+        ConsensusTransaction::new_end_of_publish(authority),
+        certificates.next().unwrap(),
+        certificates.next().unwrap(),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(position, transaction)| (transaction, position))
+    .collect();
+
+    order_by_gas_price(&mut transactions);
+
+    // Certificates are reordered on either side of the EndOfPublish message, and equal gas
+    // prices keep their consensus order.
+    let positions: Vec<_> = transactions.iter().map(|(_, position)| *position).collect();
+    assert_eq!(positions, vec![0, 1, 2, 4, 3]);
+}
+
+#[tokio::test]
+pub async fn test_schedule_by_shared_object_budget() {
+    use crate::authority::authority_tests::init_state_with_objects;
+    use crate::consensus_adapter::consensus_tests::{test_certificates, test_gas_objects};
+    use sui_types::object::Object;
+
+    let mut objects = test_gas_objects();
+    objects.push(Object::shared_for_testing());
+    let state = init_state_with_objects(objects).await;
+
+    // All the test certificates use the same shared object.
+    let authority = state.name;
+    let mut transactions: Vec<_> = test_certificates(&state)
+        .await
+        .into_iter()
+        .map(|certificate| ConsensusTransaction::new_certificate_message(&authority, certificate))
+        .collect();
+    transactions.insert(1, ConsensusTransaction::new_end_of_publish(authority));
+    let transactions = transactions
+        .into_iter()
+        .enumerate()
+        .map(|(position, transaction)| (transaction, position))
+        .collect();
+
+    let (scheduled, deferred) = schedule_by_shared_object_budget(transactions, 2);
+
+    let positions = |transactions: Vec<(ConsensusTransaction, usize)>| {
+        transactions
+            .into_iter()
+            .map(|(_, position)| position)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(positions(scheduled), vec![0, 1, 2]);
+    assert_eq!(positions(deferred), vec![3, 4]);
+}
+
+#[tokio::test]
+pub async fn test_deferred_transactions_queue() {
+    use crate::authority::authority_tests::init_state_with_objects;
+    use crate::consensus_adapter::consensus_tests::{test_certificates, test_gas_objects};
+    use sui_types::object::Object;
+
+    let mut objects = test_gas_objects();
+    objects.push(Object::shared_for_testing());
+    let state = init_state_with_objects(objects).await;
+    let epoch_store = state.epoch_store();
+
+    let authority = state.name;
+    let transactions: Vec<_> = test_certificates(&state)
+        .await
+        .into_iter()
+        .map(|certificate| ConsensusTransaction::new_certificate_message(&authority, certificate))
+        .collect();
+    let keys: Vec<_> = transactions.iter().map(|t| t.key()).collect();
+    // Stored out of order, returned in the order they were first sequenced.
+    let deferred = transactions
+        .into_iter()
+        .enumerate()
+        .rev()
+        .map(|(position, transaction)| DeferredTransaction {
+            sequenced_at: ExecutionIndices {
+                last_committed_round: 1,
+                sub_dag_index: 1,
+                transaction_index: position as u64,
+            },
+            deferred_in: 1,
+            certificate: Default::default(),
+            transaction,
+        })
+        .collect();
+    epoch_store
+        .update_deferred_transactions(vec![], deferred)
+        .unwrap();
+
+    let deferred_keys = |sub_dag_index| {
+        epoch_store
+            .get_deferred_transactions(sub_dag_index)
+            .into_iter()
+            .map(|deferred| deferred.transaction.key())
+            .collect::<Vec<_>>()
+    };
+    // A replayed commit does not see its own deferrals.
+    assert!(deferred_keys(1).is_empty());
+    assert_eq!(deferred_keys(2), keys);
+
+    epoch_store
+        .update_deferred_transactions(vec![keys[0]], vec![])
+        .unwrap();
+    assert_eq!(deferred_keys(2), keys[1..]);
+    assert!(!epoch_store.is_transaction_deferred(&keys[0]).unwrap());
+    assert!(epoch_store.is_transaction_deferred(&keys[1]).unwrap());
+}
@@ -79,7 +79,19 @@ impl TransactionValidator for SuiTxValidator {
                             idx,
                         )?;
                 }
-                ConsensusTransactionKind::EndOfPublish(_) => {}
+                ConsensusTransactionKind::EndOfPublish(_)
+                | ConsensusTransactionKind::CapabilityNotification(_) => {}
+                ConsensusTransactionKind::RandomnessPartialSignature(data) => {
+                    if !epoch_store.protocol_config().randomness_beacon() {
+                        return Err(eyre::eyre!(
+                            "Malformed batch (randomness beacon is disabled)"
+                        ));
+                    }
+                    self.metrics.randomness_partial_signatures_verified.inc();
+                    epoch_store
+                        .randomness_beacon()
+                        .verify_partial_signatures(&data)?;
+                }
             }
         }
         // verify the user transaction signatures as a batch
@@ -92,6 +104,7 @@ impl TransactionValidator for SuiTxValidator {
 pub struct SuiTxValidatorMetrics {
     certificate_signatures_verified: IntCounter,
     checkpoint_signatures_verified: IntCounter,
+    randomness_partial_signatures_verified: IntCounter,
 }
 
 impl SuiTxValidatorMetrics {
@@ -109,6 +122,12 @@ impl SuiTxValidatorMetrics {
                 registry
             )
             .unwrap(),
+            randomness_partial_signatures_verified: register_int_counter_with_registry!(
+                "randomness_partial_signatures_verified",
+                "Number of randomness partial signature messages verified in narwhal batch verifier",
+                registry
+            )
+            .unwrap(),
         }
     }
 }
@@ -0,0 +1,414 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Online backup of the RocksDB databases of a node, built on RocksDB checkpoints.
+//!
+//! RocksDB can only checkpoint one database at a time, so the databases of a node are
+//! captured one after the other. The checkpoint store is captured first, and its highest
+//! executed checkpoint is recorded as the watermark of the backup. Every database captured
+//! afterwards is at least as far along, so all transactions up to the watermark are in the
+//! backup, and the node executes the checkpoints past it after a restore.
+//!
+//! Transactions are indexed after they are executed, so the index store is captured before
+//! the authority store, and never holds a transaction that is missing from the backup. The
+//! reverse does not hold: transactions executed while the backup was taken can be in the
+//! authority store but not in the index store, and the node never indexes them again since
+//! it skips executed transactions. A restore lists them in [`REINDEX_FILE`], and the node
+//! indexes them on startup with [`reindex_restored_transactions`].
+//!
+//! The SQLite event store and the consensus databases are not part of a backup.
+
+use crate::authority::authority_per_epoch_store::AuthorityEpochTables;
+use crate::authority::authority_store_tables::AuthorityPerpetualTables;
+use crate::authority::{AuthorityState, AuthorityStore};
+use crate::checkpoints::CheckpointStore;
+use crate::epoch::committee_store::CommitteeStore;
+use anyhow::{anyhow, bail, ensure, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use sui_storage::IndexStore;
+use sui_types::base_types::TransactionDigest;
+use sui_types::committee::EpochId;
+use sui_types::error::{SuiError, SuiResult};
+use sui_types::messages_checkpoint::{CheckpointContents, CheckpointSequenceNumber};
+use tracing::{info, warn};
+use typed_store::Map;
+
+#[cfg(test)]
+#[path = "unit_tests/db_backup_tests.rs"]
+mod db_backup_tests;
+
+/// Names of the databases under the db path of a node. Backups use the same layout, so
+/// a restored backup can be used as the db path of a node.
+pub const CHECKPOINTS_DB_DIR: &str = "checkpoints";
+pub const COMMITTEE_DB_DIR: &str = "epochs";
+pub const AUTHORITY_DB_DIR: &str = "store";
+pub const INDEXES_DB_DIR: &str = "indexes";
+
+/// Written once all databases are captured, a backup without it is incomplete.
+pub const BACKUP_INFO_FILE: &str = "backup_info.json";
+
+/// Written by a restore under the db path, lists the executed transactions that are missing
+/// from the index store, in checkpoint order.
+pub const REINDEX_FILE: &str = "reindex_transactions.json";
+
+/// Directory under the db path the backup is copied to and verified in, before the databases
+/// are moved in place.
+const RESTORE_STAGING_DIR: &str = "restore_staging";
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BackupInfo {
+    /// Epoch of the epoch tables in the backup.
+    pub epoch: EpochId,
+    /// All checkpoints up to and including this one were executed when the backup was taken.
+    pub highest_executed_checkpoint: Option<CheckpointSequenceNumber>,
+    pub timestamp_ms: u64,
+    /// Whether the backup was taken on a node with the index store enabled.
+    pub has_indexes: bool,
+}
+
+impl BackupInfo {
+    pub fn load(backup_path: &Path) -> anyhow::Result<Self> {
+        let path = backup_path.join(BACKUP_INFO_FILE);
+        let bytes = fs::read(&path).with_context(|| {
+            format!(
+                "Cannot read {}, the backup is missing or incomplete",
+                path.display()
+            )
+        })?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn save(&self, backup_path: &Path) -> SuiResult {
+        let bytes = serde_json::to_vec_pretty(self)
+            .map_err(|e| SuiError::GenericStorageError(e.to_string()))?;
+        fs::write(backup_path.join(BACKUP_INFO_FILE), bytes)
+            .map_err(|e| SuiError::GenericStorageError(e.to_string()))
+    }
+}
+
+/// Capture the databases of a running node into `backup_path`, which must not exist yet.
+pub fn backup_node_dbs(
+    backup_path: &Path,
+    checkpoint_store: &CheckpointStore,
+    committee_store: &CommitteeStore,
+    authority_store: &AuthorityStore,
+    index_store: Option<&IndexStore>,
+) -> SuiResult<BackupInfo> {
+    if backup_path.exists() {
+        return Err(SuiError::GenericStorageError(format!(
+            "{} already exists",
+            backup_path.display()
+        )));
+    }
+    fs::create_dir_all(backup_path).map_err(|e| SuiError::GenericStorageError(e.to_string()))?;
+
+    // The watermark is read before any database is captured, so that every database in the
+    // backup is at least as recent as it.
+    let highest_executed_checkpoint =
+        checkpoint_store.get_highest_executed_checkpoint_seq_number()?;
+    checkpoint_store.checkpoint_db(&backup_path.join(CHECKPOINTS_DB_DIR))?;
+    committee_store.checkpoint_db(&backup_path.join(COMMITTEE_DB_DIR))?;
+    if let Some(index_store) = index_store {
+        index_store.checkpoint_db(&backup_path.join(INDEXES_DB_DIR))?;
+    }
+    let epoch = authority_store.checkpoint_db(&backup_path.join(AUTHORITY_DB_DIR))?;
+
+    let info = BackupInfo {
+        epoch,
+        highest_executed_checkpoint,
+        timestamp_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Time went backwards")
+            .as_millis() as u64,
+        has_indexes: index_store.is_some(),
+    };
+    info.save(backup_path)?;
+    info!(?info, path = ?backup_path, "Created node db backup");
+    Ok(info)
+}
+
+/// Copy the backup at `backup_path` into the db path of a stopped node and verify the
+/// restored databases. None of the databases in the backup may exist under `db_path`.
+///
+/// The backup is copied to a staging directory first, and only moved in place once it is
+/// verified, so a failed restore leaves `db_path` untouched.
+pub fn restore_node_dbs(backup_path: &Path, db_path: &Path) -> anyhow::Result<BackupInfo> {
+    let info = BackupInfo::load(backup_path)?;
+
+    let db_dirs = backup_db_dirs(&info);
+    for target in db_dirs
+        .iter()
+        .chain([&REINDEX_FILE, &RESTORE_STAGING_DIR])
+        .map(|name| db_path.join(name))
+    {
+        ensure!(
+            !target.exists(),
+            "{} already exists, refusing to overwrite it",
+            target.display()
+        );
+    }
+
+    let staging_path = db_path.join(RESTORE_STAGING_DIR);
+    if let Err(e) = stage_backup(backup_path, &staging_path, &info) {
+        // Leave nothing behind, so the restore can be retried.
+        if let Err(cleanup_error) = fs::remove_dir_all(&staging_path) {
+            warn!(path = ?staging_path, "Failed to clean up the restore: {cleanup_error}");
+        }
+        return Err(e);
+    }
+
+    let mut entries = db_dirs;
+    if info.has_indexes {
+        entries.push(REINDEX_FILE);
+    }
+    for entry in entries {
+        fs::rename(staging_path.join(entry), db_path.join(entry))?;
+    }
+    fs::remove_dir(&staging_path)?;
+    Ok(info)
+}
+
+/// Copy the backup to `staging_path`, list the transactions to index again and verify it.
+fn stage_backup(backup_path: &Path, staging_path: &Path, info: &BackupInfo) -> anyhow::Result<()> {
+    for dir in backup_db_dirs(info) {
+        copy_dir(&backup_path.join(dir), &staging_path.join(dir))?;
+    }
+    if info.has_indexes {
+        let unindexed = {
+            let perpetual_tables =
+                AuthorityPerpetualTables::open(&staging_path.join(AUTHORITY_DB_DIR), None);
+            let checkpoint_store = CheckpointStore::new(&staging_path.join(CHECKPOINTS_DB_DIR));
+            let index_store = IndexStore::new(staging_path.join(INDEXES_DB_DIR));
+            unindexed_transactions(&perpetual_tables, &checkpoint_store, &index_store)?
+        };
+        info!(
+            count = unindexed.len(),
+            "Executed transactions to index again after the restore"
+        );
+        fs::write(
+            staging_path.join(REINDEX_FILE),
+            serde_json::to_vec_pretty(&unindexed)?,
+        )?;
+    }
+    verify_node_dbs(staging_path, info)
+}
+
+/// Executed transactions that are missing from the index store, those in checkpoints first,
+/// in checkpoint order.
+fn unindexed_transactions(
+    perpetual_tables: &AuthorityPerpetualTables,
+    checkpoint_store: &CheckpointStore,
+    index_store: &IndexStore,
+) -> anyhow::Result<Vec<TransactionDigest>> {
+    let mut unindexed = HashSet::new();
+    for digest in perpetual_tables.executed_effects.keys() {
+        if index_store.get_transaction_seq(&digest)?.is_none() {
+            unindexed.insert(digest);
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(unindexed.len());
+    if let Some(highest_executed) = checkpoint_store.get_highest_executed_checkpoint_seq_number()? {
+        for seq in 0..=highest_executed {
+            if unindexed.is_empty() {
+                break;
+            }
+            for digests in checkpoint_contents(checkpoint_store, seq)?.iter() {
+                if unindexed.remove(&digests.transaction) {
+                    ordered.push(digests.transaction);
+                }
+            }
+        }
+    }
+    // Executed but not yet in a checkpoint.
+    ordered.extend(unindexed);
+    Ok(ordered)
+}
+
+/// Index the transactions a restore listed in [`REINDEX_FILE`] under `db_path`, then remove
+/// the file. Must run before the node executes checkpoints, see the module docs.
+///
+/// The transactions are indexed with the current time as their timestamp.
+pub async fn reindex_restored_transactions(state: &AuthorityState, db_path: &Path) -> SuiResult {
+    let path = db_path.join(REINDEX_FILE);
+    if !path.exists() {
+        return Ok(());
+    }
+    let Some(index_store) = state.indexes() else {
+        warn!(path = ?path, "Index store is disabled, not indexing restored transactions");
+        return Ok(());
+    };
+    let bytes = fs::read(&path).map_err(|e| SuiError::GenericStorageError(e.to_string()))?;
+    let digests: Vec<TransactionDigest> =
+        serde_json::from_slice(&bytes).map_err(|e| SuiError::GenericStorageError(e.to_string()))?;
+
+    for digest in &digests {
+        // Skip what an interrupted run already indexed.
+        if index_store.get_transaction_seq(digest)?.is_some() {
+            continue;
+        }
+        state.reindex_transaction(digest).await?;
+        if index_store.get_transaction_seq(digest)?.is_none() {
+            return Err(SuiError::GenericStorageError(format!(
+                "Failed to index restored transaction {:?}",
+                digest
+            )));
+        }
+    }
+    fs::remove_file(&path).map_err(|e| SuiError::GenericStorageError(e.to_string()))?;
+    info!(count = digests.len(), "Indexed restored transactions");
+    Ok(())
+}
+
+/// Check that the databases under `db_path` hold everything `info` claims.
+pub fn verify_node_dbs(db_path: &Path, info: &BackupInfo) -> anyhow::Result<()> {
+    let store_path = db_path.join(AUTHORITY_DB_DIR);
+    let perpetual_tables = AuthorityPerpetualTables::open(&store_path, None);
+    let epoch = perpetual_tables.get_recovery_epoch_at_restart()?;
+    ensure!(
+        epoch == info.epoch,
+        "Authority store is at epoch {}, expected {}",
+        epoch,
+        info.epoch
+    );
+    ensure!(
+        AuthorityEpochTables::path(info.epoch, &store_path).exists(),
+        "Epoch tables of epoch {} are missing",
+        info.epoch
+    );
+
+    let committee_store =
+        CommitteeStore::open_tables_read_write(db_path.join(COMMITTEE_DB_DIR), None, None);
+    ensure!(
+        committee_store.get_committee(&info.epoch)?.is_some(),
+        "Committee of epoch {} is missing",
+        info.epoch
+    );
+
+    let checkpoint_store = CheckpointStore::new(&db_path.join(CHECKPOINTS_DB_DIR));
+    if let Some(watermark) = info.highest_executed_checkpoint {
+        let highest_executed = checkpoint_store
+            .get_highest_executed_checkpoint_seq_number()?
+            .ok_or_else(|| anyhow!("Checkpoint store has no executed checkpoint"))?;
+        ensure!(
+            highest_executed >= watermark,
+            "Highest executed checkpoint is {}, expected at least {}",
+            highest_executed,
+            watermark
+        );
+        for digests in checkpoint_contents(&checkpoint_store, watermark)?.iter() {
+            if !perpetual_tables
+                .executed_effects
+                .contains_key(&digests.transaction)?
+            {
+                bail!(
+                    "Effects of transaction {:?} in checkpoint {} are missing",
+                    digests.transaction,
+                    watermark
+                );
+            }
+        }
+    }
+
+    if info.has_indexes {
+        let index_store = IndexStore::new(db_path.join(INDEXES_DB_DIR));
+        let reindexed: HashSet<TransactionDigest> = match fs::read(db_path.join(REINDEX_FILE)) {
+            Ok(bytes) => serde_json::from_slice::<Vec<_>>(&bytes)?
+                .into_iter()
+                .collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashSet::new(),
+            Err(e) => return Err(e.into()),
+        };
+        verify_indexes(
+            &index_store,
+            &perpetual_tables,
+            &checkpoint_store,
+            info.highest_executed_checkpoint,
+            &reindexed,
+        )?;
+    }
+    Ok(())
+}
+
+/// Check that every indexed transaction was executed, and is indexed under its sequence
+/// number both ways. Also check that every transaction in the checkpoints up to `watermark`
+/// is indexed, or listed in `reindexed` to be indexed on startup.
+fn verify_indexes(
+    index_store: &IndexStore,
+    perpetual_tables: &AuthorityPerpetualTables,
+    checkpoint_store: &CheckpointStore,
+    watermark: Option<CheckpointSequenceNumber>,
+    reindexed: &HashSet<TransactionDigest>,
+) -> anyhow::Result<()> {
+    for (seq, digest) in index_store.transactions_iterator() {
+        ensure!(
+            perpetual_tables.executed_effects.contains_key(&digest)?,
+            "Indexed transaction {:?} at sequence number {} was not executed",
+            digest,
+            seq
+        );
+        let indexed_seq = index_store.get_transaction_seq(&digest)?;
+        ensure!(
+            indexed_seq == Some(seq),
+            "Transaction {:?} is indexed at sequence number {}, but its sequence number is {:?}",
+            digest,
+            seq,
+            indexed_seq
+        );
+    }
+
+    if let Some(watermark) = watermark {
+        for seq in 0..=watermark {
+            for digests in checkpoint_contents(checkpoint_store, seq)?.iter() {
+                ensure!(
+                    reindexed.contains(&digests.transaction)
+                        || index_store
+                            .get_transaction_seq(&digests.transaction)?
+                            .is_some(),
+                    "Transaction {:?} in checkpoint {} is not indexed",
+                    digests.transaction,
+                    seq
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn checkpoint_contents(
+    checkpoint_store: &CheckpointStore,
+    seq: CheckpointSequenceNumber,
+) -> anyhow::Result<CheckpointContents> {
+    let checkpoint = checkpoint_store
+        .get_checkpoint_by_sequence_number(seq)?
+        .ok_or_else(|| anyhow!("Checkpoint {} is missing", seq))?;
+    checkpoint_store
+        .get_checkpoint_contents(&checkpoint.content_digest())?
+        .ok_or_else(|| anyhow!("Contents of checkpoint {} are missing", seq))
+}
+
+fn backup_db_dirs(info: &BackupInfo) -> Vec<&'static str> {
+    let mut dirs = vec![CHECKPOINTS_DB_DIR, COMMITTEE_DB_DIR, AUTHORITY_DB_DIR];
+    if info.has_indexes {
+        dirs.push(INDEXES_DB_DIR);
+    }
+    dirs
+}
+
+fn copy_dir(from: &Path, to: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
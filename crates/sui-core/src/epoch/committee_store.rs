@@ -2,11 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use rocksdb::Options;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use sui_storage::default_db_options;
 use sui_types::base_types::ObjectID;
 use sui_types::committee::{Committee, EpochId};
-use sui_types::error::SuiResult;
+use sui_types::error::{SuiError, SuiResult};
 use typed_store::rocks::{DBMap, DBOptions};
 use typed_store::traits::{TableSummary, TypedStoreDebug};
 
@@ -67,6 +67,14 @@ impl CommitteeStore {
         Ok(self.committee_map.get(epoch_id)?)
     }
 
+    /// Take a RocksDB checkpoint of the committee store into `path`.
+    pub fn checkpoint_db(&self, path: &Path) -> SuiResult {
+        self.committee_map
+            .rocksdb
+            .checkpoint(path)
+            .map_err(SuiError::from)
+    }
+
     pub fn get_latest_committee(&self) -> Committee {
         self.committee_map
             .iter()
@@ -46,6 +46,10 @@ impl ReconfigState {
     pub fn should_accept_consensus_certs(&self) -> bool {
         !matches!(self.status, ReconfigCertStatus::RejectAllCerts)
     }
+
+    pub fn status(&self) -> &ReconfigCertStatus {
+        &self.status
+    }
 }
 
 pub trait ReconfigurationInitiator {
@@ -4,17 +4,21 @@
 use core::time::Duration;
 use std::sync::Arc;
 
+use futures::future::{self, Either};
+use futures::{stream, StreamExt};
 use move_bytecode_utils::module_cache::SyncModuleCache;
 use tokio_stream::Stream;
 use tracing::{debug, error, instrument, trace, warn};
 
-use sui_json_rpc_types::SuiMoveStruct;
-use sui_storage::event_store::{EventStore, EventStoreType};
+use sui_json_rpc_types::{SuiEventEnvelope, SuiMoveStruct};
+use sui_storage::event_store::{
+    EventStore, EventStoreType, StoredEvent, EVENT_STORE_QUERY_MAX_LIMIT,
+};
 use sui_types::base_types::TransactionDigest;
-use sui_types::filter::EventFilter;
+use sui_types::filter::{EventFilter, Filter};
 use sui_types::{
     error::{SuiError, SuiResult},
-    event::{Event, EventEnvelope},
+    event::{Event, EventEnvelope, EventID},
     messages::TransactionEffects,
 };
 
@@ -116,35 +120,139 @@ impl EventHandler {
         seq_num: u64,
         timestamp_ms: u64,
     ) -> Result<EventEnvelope, SuiError> {
-        let json_value = match event {
+        let json_value = Self::move_struct_json_value(event, &self.module_cache)?;
+        Ok(EventEnvelope::new(
+            timestamp_ms,
+            Some(digest),
+            seq_num,
+            event_num,
+            event.clone(),
+            json_value,
+        ))
+    }
+
+    fn move_struct_json_value(
+        event: &Event,
+        module_cache: &SyncModuleCache<ResolverWrapper<AuthorityStore>>,
+    ) -> SuiResult<Option<serde_json::Value>> {
+        match event {
             Event::MoveEvent {
                 type_, contents, ..
             } => {
                 debug!(event =? event, "Process MoveEvent.");
-                let move_struct =
-                    Event::move_event_to_move_struct(type_, contents, self.module_cache.as_ref())?;
+                let move_struct = Event::move_event_to_move_struct(type_, contents, module_cache)?;
                 // Convert into `SuiMoveStruct` which is a mirror of MoveStruct but with additional type supports, (e.g. ascii::String).
                 let sui_move_struct = SuiMoveStruct::from(move_struct);
-                Some(sui_move_struct.to_json_value().map_err(|e| {
+                Ok(Some(sui_move_struct.to_json_value().map_err(|e| {
                     SuiError::ObjectSerializationError {
                         error: e.to_string(),
                     }
-                })?)
+                })?))
             }
-            _ => None,
-        };
+            _ => Ok(None),
+        }
+    }
 
+    /// Rebuild the envelope that was dispatched to live subscribers from an event read back
+    /// from the event store.
+    fn stored_envelope(
+        id: EventID,
+        envelope: SuiEventEnvelope,
+        module_cache: &SyncModuleCache<ResolverWrapper<AuthorityStore>>,
+    ) -> Result<EventEnvelope, anyhow::Error> {
+        let event = Event::try_from(envelope.event)?;
+        let json_value = Self::move_struct_json_value(&event, module_cache)?;
         Ok(EventEnvelope::new(
-            timestamp_ms,
-            Some(digest),
-            seq_num,
-            event_num,
-            event.clone(),
+            envelope.timestamp,
+            envelope.tx_digest,
+            id.tx_seq as u64,
+            id.event_seq as u64,
+            event,
             json_value,
         ))
     }
 
+    /// Stream every stored event from `cursor` (inclusive) onwards, in ascending order.
+    /// The store is read one page at a time as the stream is polled.
+    fn stored_events_from(&self, cursor: EventID) -> impl Stream<Item = EventEnvelope> {
+        let event_store = self.event_store.clone();
+        let module_cache = self.module_cache.clone();
+        stream::unfold(Some(cursor), move |cursor| {
+            let event_store = event_store.clone();
+            let module_cache = module_cache.clone();
+            async move {
+                let cursor = cursor?;
+                let page = match event_store
+                    .all_events(cursor, EVENT_STORE_QUERY_MAX_LIMIT, false)
+                    .await
+                    .map_err(anyhow::Error::from)
+                    .and_then(StoredEvent::into_event_envelopes)
+                {
+                    Ok(page) => page,
+                    Err(e) => {
+                        error!(error =? e, "Failed to read events from event store");
+                        return None;
+                    }
+                };
+                let next_cursor = match page.last() {
+                    Some((id, _)) if page.len() == EVENT_STORE_QUERY_MAX_LIMIT => {
+                        Some((id.tx_seq, id.event_seq + 1).into())
+                    }
+                    _ => None,
+                };
+                let envelopes = page
+                    .into_iter()
+                    .filter_map(|(id, envelope)| {
+                        match Self::stored_envelope(id.clone(), envelope, &module_cache) {
+                            Ok(envelope) => Some(envelope),
+                            Err(e) => {
+                                error!(error =? e, event_id =? id, "Failed to replay stored event");
+                                None
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                Some((stream::iter(envelopes), next_cursor))
+            }
+        })
+        .flatten()
+    }
+
     pub fn subscribe(&self, filter: EventFilter) -> impl Stream<Item = EventEnvelope> {
         self.event_streamer.subscribe(filter)
     }
+
+    /// Subscribe to events matching `filter` that come after `cursor`. Matching events already
+    /// in the event store are replayed first, then the stream switches over to live events.
+    pub fn subscribe_after(
+        &self,
+        filter: EventFilter,
+        cursor: EventID,
+    ) -> impl Stream<Item = EventEnvelope> {
+        // Subscribe before reading the store, so that events committed while the history is
+        // being replayed are still delivered by the live stream.
+        let live = self.event_streamer.subscribe(filter.clone());
+        let history = self
+            .stored_events_from((cursor.tx_seq, cursor.event_seq + 1).into())
+            .filter(move |envelope| future::ready(filter.matches(envelope)));
+
+        // Events are written to the store before they are dispatched, so any live event that
+        // is not newer than the last replayed one has already been delivered.
+        history
+            .map(Either::Left)
+            .chain(live.map(Either::Right))
+            .scan(None, |replayed_up_to, envelope| {
+                let envelope = match envelope {
+                    Either::Left(envelope) => {
+                        *replayed_up_to = Some((envelope.seq_num, envelope.event_num));
+                        Some(envelope)
+                    }
+                    Either::Right(envelope) => (Some((envelope.seq_num, envelope.event_num))
+                        > *replayed_up_to)
+                        .then_some(envelope),
+                };
+                future::ready(Some(envelope))
+            })
+            .filter_map(future::ready)
+    }
 }
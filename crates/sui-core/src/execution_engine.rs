@@ -11,7 +11,7 @@ use sui_types::crypto::sha3_hash;
 use tracing::{debug, instrument};
 
 use sui_adapter::adapter;
-use sui_protocol_constants::{MAX_TX_GAS, STORAGE_FUND_REINVEST_RATE};
+use sui_protocol_constants::ProtocolConfig;
 use sui_types::coin::{transfer_coin, update_input_coins, Coin};
 use sui_types::committee::EpochId;
 use sui_types::error::{ExecutionError, ExecutionErrorKind};
@@ -65,6 +65,7 @@ pub fn execute_transaction_to_effects<
     native_functions: &NativeFunctionTable,
     gas_status: SuiGasStatus,
     epoch: EpochId,
+    protocol_config: &ProtocolConfig,
 ) -> (
     InnerTemporaryStore,
     TransactionEffects,
@@ -81,6 +82,7 @@ pub fn execute_transaction_to_effects<
         move_vm,
         native_functions,
         gas_status,
+        protocol_config,
     );
 
     let (status, execution_result) = match execution_result {
@@ -141,6 +143,7 @@ fn execute_transaction<
     move_vm: &Arc<MoveVM>,
     native_functions: &NativeFunctionTable,
     mut gas_status: SuiGasStatus,
+    protocol_config: &ProtocolConfig,
 ) -> (
     GasCostSummary,
     Result<Mode::ExecutionResults, ExecutionError>,
@@ -157,6 +160,7 @@ fn execute_transaction<
             move_vm,
             native_functions,
             &mut gas_status,
+            protocol_config,
         );
         if execution_result.is_err() {
             // Roll back the temporary store if execution failed.
@@ -189,6 +193,7 @@ fn execution_loop<
     move_vm: &Arc<MoveVM>,
     native_functions: &NativeFunctionTable,
     gas_status: &mut SuiGasStatus,
+    protocol_config: &ProtocolConfig,
 ) -> Result<Mode::ExecutionResults, ExecutionError> {
     let mut results = Mode::empty_results();
     // TODO: Since we require all mutable objects to not show up more than
@@ -271,6 +276,7 @@ fn execution_loop<
                     arguments,
                     gas_status.create_move_gas_status(),
                     tx_ctx,
+                    protocol_config,
                 )?;
                 Mode::add_result(&mut results, idx, result);
             }
@@ -285,6 +291,7 @@ fn execution_loop<
                     modules,
                     tx_ctx,
                     gas_status.create_move_gas_status(),
+                    protocol_config,
                 )?;
             }
             SingleTransactionKind::Pay(Pay {
@@ -304,6 +311,7 @@ fn execution_loop<
                 storage_charge,
                 computation_charge,
                 storage_rebate,
+                protocol_version,
             }) => {
                 let module_id =
                     ModuleId::new(SUI_FRAMEWORK_ADDRESS, SUI_SYSTEM_MODULE_NAME.to_owned());
@@ -323,10 +331,14 @@ fn execution_loop<
                         CallArg::Pure(bcs::to_bytes(&storage_charge).unwrap()),
                         CallArg::Pure(bcs::to_bytes(&computation_charge).unwrap()),
                         CallArg::Pure(bcs::to_bytes(&storage_rebate).unwrap()),
-                        CallArg::Pure(bcs::to_bytes(&STORAGE_FUND_REINVEST_RATE).unwrap()),
+                        CallArg::Pure(
+                            bcs::to_bytes(&protocol_config.storage_fund_reinvest_rate()).unwrap(),
+                        ),
+                        CallArg::Pure(bcs::to_bytes(&protocol_version.as_u64()).unwrap()),
                     ],
                     gas_status.create_move_gas_status(),
                     tx_ctx,
+                    protocol_config,
                 )?;
             }
         };
@@ -520,8 +532,7 @@ fn pay<S>(
             let new_contents = bcs::to_bytes(&coin).expect("Coin serialization should not fail");
             // unwrap safe because we checked that it was a coin object above
             let move_obj = coin_object.data.try_as_move_mut().unwrap();
-            // unwrap safe because coin object is always below maximum size
-            move_obj.update_contents(new_contents).unwrap();
+            move_obj.update_coin_contents(new_contents);
             temporary_store.write_object(&ctx, coin_object, WriteKind::Mutate);
         }
     }
@@ -615,8 +626,7 @@ fn transfer_sui<S>(
             .try_as_move_mut()
             .expect("Gas object must be Move object");
         let new_contents = bcs::to_bytes(&gas_coin).expect("Serializing gas coin can never fail");
-        // unwrap safe because coin object is always below maximum size
-        move_object.update_contents(new_contents).unwrap();
+        move_object.update_coin_contents(new_contents);
 
         // Creat a new gas coin with the amount.  Set a blank version, to be updated by the store
         // when it is committed to effects.
@@ -650,12 +660,13 @@ const FAKE_GAS_OBJECT: ObjectRef = (
 pub(crate) fn manual_execute_move_call_fake_txn_digest(
     sender: SuiAddress,
     move_call: MoveCall,
+    protocol_config: &ProtocolConfig,
 ) -> TransactionDigest {
     let txn_data = TransactionData::new(
         TransactionKind::Single(SingleTransactionKind::Call(move_call)),
         sender,
         FAKE_GAS_OBJECT,
-        MAX_TX_GAS,
+        protocol_config.max_tx_gas(),
     );
     TransactionDigest::new(sha3_hash(&txn_data))
 }
@@ -673,6 +684,7 @@ pub(crate) fn manual_execute_move_call<
     move_vm: &Arc<MoveVM>,
     mut gas_status: SuiGasStatus,
     epoch: EpochId,
+    protocol_config: &ProtocolConfig,
 ) -> (
     TransactionEffects,
     Result<Mode::ExecutionResults, ExecutionError>,
@@ -696,6 +708,7 @@ pub(crate) fn manual_execute_move_call<
         arguments,
         gas_status.create_move_gas_status(),
         &mut tx_ctx,
+        protocol_config,
     )
     .map(|result| {
         let mut results = Mode::empty_results();
@@ -21,6 +21,7 @@ use sui_types::{
         CallArg, EntryArgumentErrorKind, EntryTypeArgumentErrorKind, ExecutionFailureStatus,
         ExecutionStatus, ObjectArg, ObjectInfoRequestKind, SingleTransactionKind, TransactionKind,
     },
+    messages_checkpoint::{CheckpointContentsDigest, CheckpointDigest},
     object::{Data, Owner},
     storage::DeleteKind,
 };
@@ -70,6 +71,12 @@ fn get_registry() -> Result<Registry> {
     let teff = TransactionEffectsDigest::random();
     tracer.trace_value(&mut samples, &teff)?;
 
+    // Checkpoint digests are fixed-size arrays on the wire, pin that down.
+    let ccd = CheckpointContentsDigest([0u8; 32]);
+    let cd = CheckpointDigest([0u8; 32]);
+    tracer.trace_value(&mut samples, &ccd)?;
+    tracer.trace_value(&mut samples, &cd)?;
+
     // 2. Trace the main entry point(s) + every enum separately.
     tracer.trace_type::<Owner>(&samples)?;
     tracer.trace_type::<ExecutionStatus>(&samples)?;
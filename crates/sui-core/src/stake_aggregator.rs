@@ -56,6 +56,10 @@ impl<V: Clone, const STRENGTH: bool> StakeAggregator<V, STRENGTH> {
         self.data.contains_key(authority)
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     pub fn committee(&self) -> &Committee {
         &self.committee
     }
@@ -0,0 +1,258 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::tbls::tbls_ids::{TBlsId, TBlsIds};
+use fastcrypto::groups::bls12381;
+use fastcrypto::groups::{GroupElement, Scalar};
+use fastcrypto_tbls::tbls::ThresholdBls;
+use fastcrypto_tbls::types::ThresholdBls12381MinSig;
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Range;
+use sui_types::base_types::AuthorityName;
+use sui_types::committee::{Committee, EpochId};
+use sui_types::error::{SuiError, SuiResult};
+use sui_types::fp_ensure;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::randomness::{
+    CheckpointRandomness, MockedDkgOutput, RandomnessPartialSignature,
+    RandomnessPartialSignatureMessage, RandomnessRound,
+};
+use tracing::{debug, error, warn};
+
+/// Randomness beacon of a single epoch.
+///
+/// Every validator signs each checkpoint it builds with all the tBLS shares it owns and sends the
+/// partial signatures through consensus. Partial signatures are aggregated in consensus order, so
+/// all honest validators aggregate the same set of shares, and once the number of shares reaches
+/// the threshold the (unique) threshold signature of the checkpoint becomes the beacon output.
+pub struct RandomnessBeacon {
+    epoch: EpochId,
+    ids: TBlsIds,
+    threshold: u32,
+    /// Generating the key material is expensive, so it is deferred until the beacon is used.
+    dkg: OnceCell<MockedDkgOutput>,
+    rounds: Mutex<Rounds>,
+}
+
+#[derive(Default)]
+struct Rounds {
+    /// Partial signatures sequenced by consensus, for the rounds that are not completed yet.
+    /// Rounds complete independently of each other: a checkpoint can reach the threshold before
+    /// an earlier one, which keeps collecting shares until it completes or the epoch ends.
+    pending: BTreeMap<CheckpointSequenceNumber, RoundAggregator>,
+    /// Rounds whose output was produced. Messages for these rounds are ignored.
+    completed: HashSet<CheckpointSequenceNumber>,
+}
+
+#[derive(Default)]
+struct RoundAggregator {
+    partial_signatures: HashMap<AuthorityName, Vec<RandomnessPartialSignature>>,
+    num_of_shares: u32,
+}
+
+pub enum AddPartialSignaturesResult {
+    /// The partial signatures were recorded, but the threshold was not reached yet.
+    NotEnoughShares,
+    /// The partial signatures completed the round.
+    Completed(CheckpointRandomness),
+    /// The partial signatures were not recorded, e.g. because the round was already completed.
+    Ignored,
+}
+
+impl RandomnessBeacon {
+    pub fn new(committee: &Committee) -> Self {
+        let stakes: Vec<_> = committee.members().cloned().collect();
+        let ids = TBlsIds::new(&stakes);
+        // Shares are allocated proportionally to stake, so requiring more than a third of all the
+        // shares keeps the output unpredictable as long as byzantine validators hold less than a
+        // third of the stake.
+        let threshold = ids.num_of_shares() / 3 + 1;
+        Self {
+            epoch: committee.epoch,
+            ids,
+            threshold,
+            dkg: OnceCell::new(),
+            rounds: Default::default(),
+        }
+    }
+
+    pub fn epoch(&self) -> EpochId {
+        self.epoch
+    }
+
+    pub fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    fn dkg(&self) -> &MockedDkgOutput {
+        self.dkg
+            .get_or_init(|| MockedDkgOutput::new(self.epoch, self.threshold))
+    }
+
+    /// Signs the given checkpoint with all the shares of `authority`. Returns None if the
+    /// authority did not receive any share in this epoch.
+    pub fn sign(
+        &self,
+        authority: AuthorityName,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Option<RandomnessPartialSignatureMessage> {
+        let ids = self.ids.get_ids(&authority)?;
+        let round = RandomnessRound::new(self.epoch, sequence_number);
+        let msg = round.signing_message();
+        let partial_signatures = share_indices(ids)
+            .map(|id| ThresholdBls12381MinSig::partial_sign(&self.dkg().share(id), &msg))
+            .collect();
+        Some(RandomnessPartialSignatureMessage {
+            authority,
+            round,
+            partial_signatures,
+        })
+    }
+
+    /// Checks that the message carries exactly the shares assigned to its authority and that
+    /// every partial signature is valid.
+    ///
+    /// The partial signatures are verified as a batch: with random scalars r_i, the combination
+    /// sum(r_i * sig_i) is a valid signature under sum(r_i * pk_i), where pk_i is the public key
+    /// of share i, if and only if every partial signature is valid (except with negligible
+    /// probability). This takes a single signature verification instead of one per share.
+    pub fn verify_partial_signatures(&self, data: &RandomnessPartialSignatureMessage) -> SuiResult {
+        fp_ensure!(
+            data.round.epoch == self.epoch,
+            SuiError::WrongEpoch {
+                expected_epoch: self.epoch,
+                actual_epoch: data.round.epoch,
+            }
+        );
+        let ids = self
+            .ids
+            .get_ids(&data.authority)
+            .ok_or_else(|| SuiError::from("Authority does not own randomness shares"))?;
+        fp_ensure!(
+            data.partial_signatures
+                .iter()
+                .map(|partial| partial.index)
+                .eq(share_indices(ids)),
+            SuiError::from("Unexpected randomness share indices")
+        );
+        let coefficients = self.dkg().public_coefficients();
+        let mut rng = rand::thread_rng();
+        let mut signature = bls12381::G1Element::zero();
+        // weights[j] = sum(r_i * i^j), so that sum(r_i * pk_i) = sum(weights[j] * c_j) for the
+        // coefficients c_j of the public polynomial.
+        let mut weights = vec![bls12381::Scalar::zero(); coefficients.len()];
+        for partial in &data.partial_signatures {
+            let r = bls12381::Scalar::rand(&mut rng);
+            signature = signature + partial.value * r;
+            let index = bls12381::Scalar::from(partial.index.get() as u64);
+            let mut power = r;
+            for weight in weights.iter_mut() {
+                *weight = *weight + power;
+                power = power * index;
+            }
+        }
+        let public_key = coefficients
+            .iter()
+            .zip(weights)
+            .fold(bls12381::G2Element::zero(), |acc, (coefficient, weight)| {
+                acc + *coefficient * weight
+            });
+        ThresholdBls12381MinSig::verify(&public_key, &data.round.signing_message(), &signature)
+            .map_err(|_| SuiError::InvalidSignature {
+                error: format!(
+                    "Invalid randomness partial signatures from {:?} for {:?}",
+                    data.authority.concise(),
+                    data.round
+                ),
+            })
+    }
+
+    /// Restores partial signatures that were sequenced before a restart. Must be called before
+    /// any new message is added.
+    pub fn recover(&self, pending: impl Iterator<Item = RandomnessPartialSignatureMessage>) {
+        for data in pending {
+            if let AddPartialSignaturesResult::Completed(_) = self.add_partial_signatures(&data) {
+                error!(
+                    "Recovered partial signatures already reach the threshold for {:?}",
+                    data.round
+                );
+            }
+        }
+    }
+
+    /// Adds partial signatures sequenced by consensus. Returns the beacon output of the round
+    /// once enough shares were collected; the round is then dropped, and subsequent partial
+    /// signatures for it are ignored. Other pending rounds are not affected.
+    ///
+    /// Partial signatures are expected to be verified by the consensus validator.
+    pub fn add_partial_signatures(
+        &self,
+        data: &RandomnessPartialSignatureMessage,
+    ) -> AddPartialSignaturesResult {
+        let sequence_number = data.round.sequence_number;
+        let mut rounds = self.rounds.lock();
+        if rounds.completed.contains(&sequence_number) {
+            debug!(
+                "Ignoring partial signatures from {:?} for already completed {:?}",
+                data.authority.concise(),
+                data.round
+            );
+            return AddPartialSignaturesResult::Ignored;
+        }
+        let round = rounds.pending.entry(sequence_number).or_default();
+        if round.partial_signatures.contains_key(&data.authority) {
+            warn!(
+                "Validator {:?} sent partial signatures for {:?} twice",
+                data.authority.concise(),
+                data.round
+            );
+            return AddPartialSignaturesResult::Ignored;
+        }
+        round.num_of_shares += data.partial_signatures.len() as u32;
+        round
+            .partial_signatures
+            .insert(data.authority, data.partial_signatures.clone());
+        if round.num_of_shares < self.threshold {
+            debug!(
+                "Collected {} out of {} randomness shares for checkpoint {}",
+                round.num_of_shares, self.threshold, sequence_number
+            );
+            return AddPartialSignaturesResult::NotEnoughShares;
+        }
+        let round = rounds
+            .pending
+            .remove(&sequence_number)
+            .expect("Round was just updated");
+        rounds.completed.insert(sequence_number);
+        let partials: Vec<_> = round.partial_signatures.into_values().flatten().collect();
+        let signature = match ThresholdBls12381MinSig::aggregate(self.threshold, &partials) {
+            Ok(signature) => signature,
+            Err(err) => {
+                error!(
+                    "Failed to aggregate randomness for checkpoint {}: {:?}",
+                    sequence_number, err
+                );
+                return AddPartialSignaturesResult::Ignored;
+            }
+        };
+        let randomness = CheckpointRandomness {
+            round: data.round,
+            signature,
+        };
+        if let Err(err) = randomness.verify() {
+            error!(
+                "Aggregated randomness for checkpoint {} is invalid: {:?}",
+                sequence_number, err
+            );
+            return AddPartialSignaturesResult::Ignored;
+        }
+        AddPartialSignaturesResult::Completed(randomness)
+    }
+}
+
+fn share_indices(ids: &Range<TBlsId>) -> impl Iterator<Item = TBlsId> {
+    // NonZeroU32 does not implement Step, so the range itself can't be iterated.
+    (ids.start.get()..ids.end.get()).map(|id| TBlsId::new(id).unwrap())
+}
@@ -3,5 +3,7 @@
 
 /// Modules for working with threshold BLS (tBLS), needed for the randomness beacon.
 
+/// Randomness beacon built on top of the tBLS shares.
+pub mod beacon;
 /// Assignment of IDs to validators.
 pub mod tbls_ids;
@@ -26,7 +26,7 @@ pub struct TBlsIds {
     number_of_ids: u32,
 }
 
-type TBlsId = NonZeroU32;
+pub type TBlsId = NonZeroU32;
 const MAX_NUM_OF_SHARES: u16 = 1000;
 
 impl TBlsIds {
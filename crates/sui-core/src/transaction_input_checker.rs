@@ -9,7 +9,7 @@ use sui_types::{
     base_types::{SequenceNumber, SuiAddress},
     error::{SuiError, SuiResult},
     fp_ensure,
-    gas::{self, SuiGasStatus},
+    gas::{self, SuiCostTable, SuiGasStatus},
     messages::{
         InputObjectKind, InputObjects, SingleTransactionKind, TransactionData, VerifiedCertificate,
     },
@@ -41,6 +41,22 @@ async fn get_gas_status(
     .await
 }
 
+/// Rejects transactions priced below the reference gas price of the current epoch. This is only
+/// checked before signing, certificates are executed whatever their gas price.
+fn check_gas_price(store: &AuthorityStore, transaction: &TransactionData) -> SuiResult {
+    // Read from the epoch store, where it is cached for the epoch, rather than deserializing
+    // the system state object for every transaction.
+    let reference_gas_price = store.epoch_store().reference_gas_price();
+    fp_ensure!(
+        transaction.gas_price >= reference_gas_price,
+        SuiError::GasPriceUnderRGP {
+            gas_price: transaction.gas_price,
+            reference_gas_price,
+        }
+    );
+    Ok(())
+}
+
 #[instrument(level = "trace", skip_all)]
 pub async fn check_transaction_input(
     store: &AuthorityStore,
@@ -48,6 +64,7 @@ pub async fn check_transaction_input(
 ) -> SuiResult<(SuiGasStatus<'static>, InputObjects)> {
     transaction.validity_check()?;
     transaction.kind.validity_check()?;
+    check_gas_price(store, transaction)?;
     let gas_status = get_gas_status(store, transaction).await?;
     let input_objects = transaction.input_objects()?;
     let objects = store.check_input_objects(&input_objects)?;
@@ -147,6 +164,8 @@ async fn check_gas(
             .parameters
             .storage_gas_price;
 
+        let cost_table = SuiCostTable::new(store.epoch_store().protocol_config());
+
         // If the transaction is TransferSui, we ensure that the gas balance is enough to cover
         // both gas budget and the transfer amount.
         let extra_amount = match tx_kind {
@@ -177,13 +196,25 @@ async fn check_gas(
                 gas_price,
                 extra_amount,
                 additional_objs,
+                &cost_table,
             )?;
         } else {
-            gas::check_gas_balance(&gas_object, gas_budget, gas_price, extra_amount, vec![])?;
+            gas::check_gas_balance(
+                &gas_object,
+                gas_budget,
+                gas_price,
+                extra_amount,
+                vec![],
+                &cost_table,
+            )?;
         }
 
-        let gas_status =
-            gas::start_gas_metering(gas_budget, computation_gas_price, storage_gas_price)?;
+        let gas_status = gas::start_gas_metering(
+            gas_budget,
+            computation_gas_price,
+            storage_gas_price,
+            cost_table,
+        )?;
         Ok(gas_status)
     }
 }
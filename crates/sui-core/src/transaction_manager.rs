@@ -18,7 +18,9 @@ use crate::authority::{AuthorityMetrics, AuthorityStore};
 /// pending certificates, and getting notified about committed objects. Executing driver
 /// subscribes to the stream of ready certificates published by the TransactionManager, and can
 /// execute them in parallel.
-/// TODO: use TransactionManager for fullnode.
+/// On validators, certificates are enqueued after they are certified or sequenced by consensus.
+/// On fullnodes, CheckpointExecutor enqueues the certificates of synced checkpoints, and
+/// TransactionOrchestrator the certificates it has seen effects for.
 pub struct TransactionManager {
     authority_store: Arc<AuthorityStore>,
     tx_ready_certificates: UnboundedSender<VerifiedCertificate>,
@@ -157,6 +159,16 @@ impl TransactionManager {
         Ok(())
     }
 
+    /// Returns the number of certificates waiting on missing inputs, and the number of
+    /// certificates ready or being executed.
+    pub async fn inflight_counts(&self) -> (usize, usize) {
+        let inner = self.inner.read().await;
+        (
+            inner.pending_certificates.len(),
+            inner.executing_certificates.len(),
+        )
+    }
+
     /// Notifies TransactionManager that the given objects have been committed.
     pub(crate) async fn objects_committed(&self, object_keys: Vec<ObjectKey>) {
         let mut ready_digests = Vec::new();
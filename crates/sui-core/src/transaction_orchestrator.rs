@@ -34,6 +34,7 @@ use tokio::task::JoinHandle;
 use tokio::time::timeout;
 use tracing::{debug, error, instrument, warn};
 
+use sui_types::base_types::TransactionDigest;
 use sui_types::messages::VerifiedTransaction;
 
 // How long to wait for local execution (including parents) before a timeout
@@ -301,6 +302,13 @@ where
     pub fn load_all_pending_transactions(&self) -> Vec<VerifiedTransaction> {
         self.pending_tx_log.load_all_pending_transactions()
     }
+
+    pub fn get_pending_transaction(
+        &self,
+        digest: &TransactionDigest,
+    ) -> SuiResult<Option<VerifiedTransaction>> {
+        self.pending_tx_log.get_pending_transaction(digest)
+    }
 }
 
 /// Prometheus metrics which can be displayed in Grafana, queried and alerted on
@@ -17,6 +17,7 @@ use sui_types::crypto::{KeypairTraits, Signature};
 use test_utils::sui_system_state::{test_sui_system_state, test_validator};
 
 use sui_macros::sim_test;
+use sui_protocol_constants::ProtocolConfig;
 use sui_types::messages::*;
 use sui_types::object::{MoveObject, Object, Owner, GAS_VALUE_FOR_TESTING};
 use test_utils::messages::make_random_certified_transaction;
@@ -310,7 +311,7 @@ async fn test_quorum_map_and_reduce_timeout() {
         .into_iter()
         .cloned()
         .collect();
-    let pkg = Object::new_package(modules, TransactionDigest::genesis()).unwrap();
+    let pkg = Object::new_package_for_testing(modules, TransactionDigest::genesis()).unwrap();
     let pkg_ref = pkg.compute_object_reference();
     let (addr1, key1): (_, AccountKeyPair) = get_key_pair();
     let gas_object1 = Object::with_owner_for_testing(addr1);
@@ -1076,6 +1077,7 @@ pub fn make_response_from_sui_system_state(
             false,
             SequenceNumber::from_u64(1),
             move_content,
+            &ProtocolConfig::get_for_max_version(),
         )
         .unwrap()
     };
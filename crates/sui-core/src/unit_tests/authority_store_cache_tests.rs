@@ -0,0 +1,98 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use sui_types::base_types::dbg_addr;
+
+fn new_cache(object_cache_size_bytes: usize) -> ObjectCache {
+    ObjectCache::new(
+        &AuthorityStoreCacheConfig {
+            object_cache_size_bytes,
+        },
+        &Registry::new(),
+    )
+}
+
+fn object(id: ObjectID, version: u64) -> Object {
+    Object::with_id_owner_version_for_testing(id, SequenceNumber::from(version), dbg_addr(1))
+}
+
+#[test]
+fn test_read_through_and_invalidate() {
+    let cache = new_cache(1024 * 1024);
+    let id = ObjectID::random();
+
+    let loaded = cache.get_or_load(&id, || Ok(Some(object(id, 1)))).unwrap();
+    assert_eq!(loaded.unwrap().version(), SequenceNumber::from(1));
+    let cached = cache
+        .get_or_load(&id, || panic!("object should be cached"))
+        .unwrap();
+    assert_eq!(cached.unwrap().version(), SequenceNumber::from(1));
+    assert_eq!(cache.metrics.hits.get(), 1);
+    assert_eq!(cache.metrics.misses.get(), 1);
+
+    assert!(cache.get_version(&id, SequenceNumber::from(1)).is_some());
+    assert!(cache.get_version(&id, SequenceNumber::from(2)).is_none());
+
+    cache.invalidate([&id]);
+    assert_eq!(cache.metrics.size_bytes.get(), 0);
+    let reloaded = cache.get_or_load(&id, || Ok(Some(object(id, 2)))).unwrap();
+    assert_eq!(reloaded.unwrap().version(), SequenceNumber::from(2));
+
+    // Objects that are not found are not cached.
+    let missing = ObjectID::random();
+    assert!(cache.get_or_load(&missing, || Ok(None)).unwrap().is_none());
+    assert!(cache
+        .get_version(&missing, SequenceNumber::from(1))
+        .is_none());
+}
+
+#[test]
+fn test_load_racing_invalidation_is_not_cached() {
+    let cache = new_cache(1024 * 1024);
+    let id = ObjectID::random();
+
+    // The write of version 2 is committed and invalidated while version 1 is being loaded.
+    let loaded = cache
+        .get_or_load(&id, || {
+            cache.invalidate([&id]);
+            Ok(Some(object(id, 1)))
+        })
+        .unwrap();
+    assert_eq!(loaded.unwrap().version(), SequenceNumber::from(1));
+
+    let reloaded = cache.get_or_load(&id, || Ok(Some(object(id, 2)))).unwrap();
+    assert_eq!(reloaded.unwrap().version(), SequenceNumber::from(2));
+}
+
+#[test]
+fn test_size_is_bounded() {
+    let entry_size = object(ObjectID::random(), 1).object_size_for_gas_metering() + ENTRY_OVERHEAD;
+    // Room for two objects in every shard.
+    let cache = new_cache(2 * entry_size * NUM_SHARDS);
+
+    let ids: Vec<_> = (0..10 * NUM_SHARDS).map(|_| ObjectID::random()).collect();
+    for id in &ids {
+        cache.get_or_load(id, || Ok(Some(object(*id, 1)))).unwrap();
+    }
+    assert!(cache.metrics.size_bytes.get() as usize <= 2 * entry_size * NUM_SHARDS);
+    assert!(cache.metrics.evictions.get() >= 8 * NUM_SHARDS as u64);
+    for shard in &cache.shards {
+        let shard = shard.lock();
+        assert!(shard.size <= cache.shard_capacity);
+        assert_eq!(shard.entries.len(), shard.lru.len());
+    }
+
+    // The most recently loaded object is never the one evicted.
+    let last = ids.last().unwrap();
+    assert!(cache.get_version(last, SequenceNumber::from(1)).is_some());
+}
+
+#[test]
+fn test_disabled_cache() {
+    let cache = new_cache(0);
+    let id = ObjectID::random();
+    cache.get_or_load(&id, || Ok(Some(object(id, 1)))).unwrap();
+    assert!(cache.get_version(&id, SequenceNumber::from(1)).is_none());
+    assert_eq!(cache.metrics.size_bytes.get(), 0);
+}
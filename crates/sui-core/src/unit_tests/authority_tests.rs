@@ -29,13 +29,12 @@ use std::fs;
 use std::future::Future;
 use std::pin::Pin;
 use std::task::{Context, Poll};
-use sui_json_rpc_types::SuiExecutionResult;
+use sui_json_rpc_types::{SuiExecutionResult, SuiMoveStruct, SuiMoveValue};
 use sui_types::utils::to_sender_signed_transaction;
 
 use std::{convert::TryInto, env};
 use sui_adapter::genesis;
 use sui_macros::sim_test;
-use sui_protocol_constants::MAX_MOVE_PACKAGE_SIZE;
 use sui_types::{
     base_types::dbg_addr,
     crypto::{get_key_pair, Signature},
@@ -246,6 +245,7 @@ async fn test_dev_inspect_object_by_bytes() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert!(mutable_reference_outputs.is_empty());
@@ -310,6 +310,7 @@ async fn test_dev_inspect_object_by_bytes() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert_eq!(mutable_reference_outputs.len(), 1);
@@ -382,6 +383,7 @@ async fn test_dev_inspect_unowned_gas() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert!(mutable_reference_outputs.is_empty());
@@ -453,6 +455,7 @@ async fn test_dev_inspect_unowned_object() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert_eq!(mutable_reference_outputs.len(), 1);
@@ -553,6 +556,7 @@ async fn test_dev_inspect_dynamic_field() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert_eq!(mutable_reference_outputs.len(), 1);
@@ -617,6 +621,8 @@ async fn test_dev_inspect_return_values() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         mut return_values,
+        mut parsed_mutable_reference_outputs,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert_eq!(mutable_reference_outputs.len(), 1);
@@ -626,6 +632,23 @@ async fn test_dev_inspect_return_values() {
     assert_eq!(init_value, deserialized_rv1);
     let type_tag: TypeTag = return_type.try_into().unwrap();
     assert!(matches!(type_tag, TypeTag::U64));
+    // the mutably borrowed object is decoded into a struct with its fields
+    assert_eq!(parsed_mutable_reference_outputs.len(), 1);
+    let Some(Some(SuiMoveValue::Struct(SuiMoveStruct::WithTypes { type_, fields }))) =
+        parsed_mutable_reference_outputs.pop() else {
+        panic!("mutable reference output should be decoded into a struct");
+    };
+    assert!(type_.ends_with("::object_basics::Object"));
+    assert_eq!(
+        fields.get("id"),
+        Some(&SuiMoveValue::UID {
+            id: created_object_id
+        })
+    );
+    assert_eq!(
+        fields.get("value"),
+        Some(&SuiMoveValue::String(init_value.to_string()))
+    );
 
     // borrow a value from it's bytes
     let DevInspectResults { results, .. } = call_dev_inspect(
@@ -647,6 +670,7 @@ async fn test_dev_inspect_return_values() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         mut return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert!(mutable_reference_outputs.is_empty());
@@ -677,6 +701,8 @@ async fn test_dev_inspect_return_values() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         mut return_values,
+        parsed_return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert!(mutable_reference_outputs.is_empty());
@@ -686,6 +712,11 @@ async fn test_dev_inspect_return_values() {
     assert_eq!(init_value, deserialized_rv1);
     let type_tag: TypeTag = return_type.try_into().unwrap();
     assert!(matches!(type_tag, TypeTag::U64));
+    // the return value is also decoded, u64s are rendered as strings like object fields
+    assert_eq!(
+        parsed_return_values,
+        vec![Some(SuiMoveValue::String(init_value.to_string()))]
+    );
 
     // read two values from it's bytes
     let DevInspectResults { results, .. } = call_dev_inspect(
@@ -707,6 +738,7 @@ async fn test_dev_inspect_return_values() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         mut return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert!(mutable_reference_outputs.is_empty());
@@ -716,6 +748,44 @@ async fn test_dev_inspect_return_values() {
     let returned_id: ObjectID = bcs::from_bytes(&returned_id_bytes).unwrap();
     assert_eq!(return_value_1, return_value_2);
     assert_eq!(created_object_id, returned_id);
+
+    // return a struct
+    let framework = authority_state.get_framework_object_ref().await.unwrap();
+    let DevInspectResults { results, .. } = call_dev_inspect(
+        &authority_state,
+        &gas_object_id,
+        &sender,
+        &sender_key,
+        &framework,
+        "vec_set",
+        "singleton",
+        vec![TypeTag::U64],
+        vec![TestCallArg::Pure(bcs::to_bytes(&init_value).unwrap())],
+    )
+    .await
+    .unwrap();
+    let mut results = results.unwrap();
+    assert_eq!(results.len(), 1);
+    let (idx, exec_results) = results.pop().unwrap();
+    let SuiExecutionResult {
+        return_values,
+        mut parsed_return_values,
+        ..
+    } = exec_results;
+    assert_eq!(idx, 0);
+    assert_eq!(return_values.len(), 1);
+    assert_eq!(parsed_return_values.len(), 1);
+    let Some(Some(SuiMoveValue::Struct(SuiMoveStruct::WithTypes { type_, fields }))) =
+        parsed_return_values.pop() else {
+        panic!("return value should be decoded into a struct");
+    };
+    assert!(type_.ends_with("::vec_set::VecSet<u64>"));
+    assert_eq!(
+        fields.get("contents"),
+        Some(&SuiMoveValue::Vector(vec![SuiMoveValue::String(
+            init_value.to_string()
+        )]))
+    );
 }
 
 #[tokio::test]
@@ -785,6 +855,7 @@ async fn test_dev_inspect_move_call() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         mut return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert!(mutable_reference_outputs.is_empty());
@@ -813,6 +884,7 @@ async fn test_dev_inspect_move_call() {
     let SuiExecutionResult {
         mutable_reference_outputs,
         mut return_values,
+        ..
     } = exec_results;
     assert_eq!(idx, 0);
     assert!(mutable_reference_outputs.is_empty());
@@ -840,8 +912,7 @@ async fn test_dev_inspect_move_call() {
     let mut results = results.unwrap();
     let (_, exec_results) = results.pop().unwrap();
     let SuiExecutionResult {
-        mutable_reference_outputs: _,
-        mut return_values,
+        mut return_values, ..
     } = exec_results;
     let (return_value_3, _return_type) = return_values.pop().unwrap();
     // check the value is the same as via the direct move call
@@ -1413,10 +1484,15 @@ async fn test_package_size_limit() {
     let gas_payment_object =
         Object::with_id_owner_gas_for_testing(gas_payment_object_id, sender, u64::MAX);
     let gas_payment_object_ref = gas_payment_object.compute_object_reference();
+    let authority = init_state_with_objects(vec![gas_payment_object]).await;
+    let max_move_package_size = authority
+        .epoch_store()
+        .protocol_config()
+        .max_move_package_size();
     let mut package = Vec::new();
     let mut package_size = 0;
     // create a package larger than the max size
-    while package_size <= MAX_MOVE_PACKAGE_SIZE {
+    while package_size <= max_move_package_size {
         let mut module = file_format::empty_module();
         // generate unique name
         module.identifiers[0] = Identifier::new(format!("TestModule{:?}", package_size)).unwrap();
@@ -1428,7 +1504,6 @@ async fn test_package_size_limit() {
         package_size += module_bytes.len() as u64;
         package.push(module_bytes);
     }
-    let authority = init_state_with_objects(vec![gas_payment_object]).await;
     let data = TransactionData::new_module(sender, gas_payment_object_ref, package, MAX_GAS);
     let transaction = to_sender_signed_transaction(data, &sender_key);
     let response = send_and_confirm_transaction(&authority, transaction)
@@ -1439,7 +1514,7 @@ async fn test_package_size_limit() {
         ExecutionStatus::Failure {
             error: ExecutionFailureStatus::MovePackageTooBig {
                 object_size: package_size,
-                max_object_size: MAX_MOVE_PACKAGE_SIZE
+                max_object_size: max_move_package_size
             }
         }
     )
@@ -2500,6 +2575,8 @@ async fn test_authority_persist() {
             &committee,
             &Genesis::get_default_genesis(),
             &AuthorityStorePruningConfig::default(),
+            &AuthorityStoreCacheConfig::default(),
+            &prometheus::Registry::new(),
         )
         .await
         .unwrap(),
@@ -2532,6 +2609,8 @@ async fn test_authority_persist() {
             &committee,
             &Genesis::get_default_genesis(),
             &AuthorityStorePruningConfig::default(),
+            &AuthorityStoreCacheConfig::default(),
+            &prometheus::Registry::new(),
         )
         .await
         .unwrap(),
@@ -3311,7 +3390,7 @@ pub async fn init_state_with_ids_and_object_basics<
         .into_iter()
         .cloned()
         .collect();
-    let pkg = Object::new_package(modules, TransactionDigest::genesis()).unwrap();
+    let pkg = Object::new_package_for_testing(modules, TransactionDigest::genesis()).unwrap();
     let pkg_ref = pkg.compute_object_reference();
     state.insert_genesis_object(pkg).await;
     (state, pkg_ref)
@@ -9,7 +9,11 @@ use move_core_types::{account_address::AccountAddress, ident_str};
 use multiaddr::Multiaddr;
 use narwhal_types::Transactions;
 use narwhal_types::TransactionsServer;
-use narwhal_types::{Empty, TransactionProto};
+use narwhal_types::{
+    BatchDigest, BatchStatus, BatchStatusRequest, BatchStatusUpdate, CertificateDigest, Empty,
+    SubmitTransactionResponse, TransactionProto,
+};
+use std::pin::Pin;
 use sui_network::tonic;
 use sui_types::crypto::deterministic_random_account_key;
 use sui_types::utils::to_sender_signed_transaction;
@@ -159,6 +163,9 @@ impl ConsensusMockServer {
 
 #[tonic::async_trait]
 impl Transactions for ConsensusMockServer {
+    type SubscribeBatchStatusStream =
+        Pin<Box<dyn futures::Stream<Item = Result<BatchStatusUpdate, tonic::Status>> + Send>>;
+
     /// Submit a Transactions
     async fn submit_transaction(
         &self,
@@ -174,4 +181,35 @@ impl Transactions for ConsensusMockServer {
     ) -> Result<tonic::Response<Empty>, tonic::Status> {
         unimplemented!()
     }
+    /// Submit a Transactions and report its batch as committed right away
+    async fn submit_transaction_with_status(
+        &self,
+        request: tonic::Request<TransactionProto>,
+    ) -> Result<tonic::Response<SubmitTransactionResponse>, tonic::Status> {
+        self.sender.send(request.into_inner()).await.unwrap();
+        Ok(tonic::Response::new(SubmitTransactionResponse {
+            batch_digest: Some(BatchDigest::new([0; 32]).into()),
+        }))
+    }
+    /// Stream the status of a batch
+    async fn subscribe_batch_status(
+        &self,
+        request: tonic::Request<BatchStatusRequest>,
+    ) -> Result<tonic::Response<Self::SubscribeBatchStatusStream>, tonic::Status> {
+        let digest = request
+            .into_inner()
+            .batch_digest
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let committed = BatchStatus::Committed {
+            certificate: CertificateDigest::new([0; 32]),
+            round: 0,
+            sub_dag_index: 0,
+        };
+        let update = Ok((digest, committed).into());
+        Ok(tonic::Response::new(Box::pin(futures::stream::iter(vec![
+            update,
+        ]))))
+    }
 }
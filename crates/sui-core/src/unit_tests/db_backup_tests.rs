@@ -0,0 +1,252 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::authority::authority_tests::{
+    init_state, init_state_with_committee, init_transfer_transaction, send_and_confirm_transaction,
+};
+use fastcrypto::traits::KeyPair;
+use std::collections::BTreeMap;
+use sui_storage::indexes::ObjectIndexChanges;
+use sui_types::base_types::{AuthorityName, ObjectID, SuiAddress, TransactionDigest};
+use sui_types::committee::Committee;
+use sui_types::crypto::{get_key_pair, AccountKeyPair, AuthorityKeyPair};
+use sui_types::gas::GasCostSummary;
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, SignedCheckpointSummary, VerifiedCheckpoint,
+};
+use sui_types::object::Object;
+use sui_types::SUI_SYSTEM_STATE_OBJECT_ID;
+
+#[tokio::test]
+async fn test_backup_and_restore() {
+    let state = init_state().await;
+    let dir = tempfile::tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(&dir.path().join(CHECKPOINTS_DB_DIR));
+
+    let backup_path = dir.path().join("backup");
+    let info = backup_node_dbs(
+        &backup_path,
+        &checkpoint_store,
+        state.committee_store(),
+        &state.db(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(info.epoch, 0);
+    assert_eq!(info.highest_executed_checkpoint, None);
+    assert!(!info.has_indexes);
+    assert_eq!(BackupInfo::load(&backup_path).unwrap(), info);
+
+    // An existing backup is never overwritten.
+    assert!(backup_node_dbs(
+        &backup_path,
+        &checkpoint_store,
+        state.committee_store(),
+        &state.db(),
+        None,
+    )
+    .is_err());
+
+    let db_path = dir.path().join("restored");
+    assert_eq!(restore_node_dbs(&backup_path, &db_path).unwrap(), info);
+    let perpetual_tables = AuthorityPerpetualTables::open(&db_path.join(AUTHORITY_DB_DIR), None);
+    assert!(perpetual_tables
+        .get_object(&SUI_SYSTEM_STATE_OBJECT_ID)
+        .unwrap()
+        .is_some());
+    drop(perpetual_tables);
+
+    // Restoring on top of existing databases is rejected.
+    assert!(restore_node_dbs(&backup_path, &db_path).is_err());
+
+    // A backup without its info file is incomplete and cannot be restored.
+    fs::remove_file(backup_path.join(BACKUP_INFO_FILE)).unwrap();
+    assert!(restore_node_dbs(&backup_path, &dir.path().join("incomplete")).is_err());
+}
+
+#[tokio::test]
+async fn test_restore_verifies_indexes() {
+    let state = init_state().await;
+    let dir = tempfile::tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(&dir.path().join(CHECKPOINTS_DB_DIR));
+    let index_store = IndexStore::new(dir.path().join(INDEXES_DB_DIR));
+
+    let backup_path = dir.path().join("backup");
+    let info = backup_node_dbs(
+        &backup_path,
+        &checkpoint_store,
+        state.committee_store(),
+        &state.db(),
+        Some(&index_store),
+    )
+    .unwrap();
+    assert!(info.has_indexes);
+    assert_eq!(
+        restore_node_dbs(&backup_path, &dir.path().join("restored")).unwrap(),
+        info
+    );
+
+    // Indexes ahead of the authority store are rejected.
+    index_store
+        .index_tx(
+            SuiAddress::random_for_testing_only(),
+            std::iter::empty(),
+            std::iter::empty(),
+            std::iter::empty(),
+            ObjectIndexChanges {
+                deleted_owners: vec![],
+                deleted_dynamic_fields: vec![],
+                new_owners: vec![],
+                new_dynamic_fields: vec![],
+            },
+            &TransactionDigest::random(),
+            0,
+        )
+        .unwrap();
+    let backup_path = dir.path().join("backup_ahead");
+    backup_node_dbs(
+        &backup_path,
+        &checkpoint_store,
+        state.committee_store(),
+        &state.db(),
+        Some(&index_store),
+    )
+    .unwrap();
+    assert!(restore_node_dbs(&backup_path, &dir.path().join("restored_ahead")).is_err());
+}
+
+#[tokio::test]
+async fn test_restore_indexes_executed_transactions() {
+    let (_, authority_key): (_, AuthorityKeyPair) = get_key_pair();
+    let name: AuthorityName = authority_key.public().into();
+    let committee = Committee::new(0, BTreeMap::from([(name, 1)])).unwrap();
+    let state = init_state_with_committee(Some((committee.clone(), authority_key.copy()))).await;
+
+    // Execute transfers, and include them in an executed checkpoint.
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let recipient = SuiAddress::random_for_testing_only();
+    let mut digests = vec![];
+    for _ in 0..2 {
+        let object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+        let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+        state.insert_genesis_object(object.clone()).await;
+        state.insert_genesis_object(gas_object.clone()).await;
+        let transaction = init_transfer_transaction(
+            sender,
+            &sender_key,
+            recipient,
+            object.compute_object_reference(),
+            gas_object.compute_object_reference(),
+        );
+        let effects = send_and_confirm_transaction(&state, transaction)
+            .await
+            .unwrap()
+            .signed_effects
+            .unwrap()
+            .into_data();
+        digests.push(effects.execution_digests());
+    }
+    let contents =
+        CheckpointContents::new_with_causally_ordered_transactions(digests.iter().cloned());
+    let signed = SignedCheckpointSummary::new(
+        0,
+        0,
+        digests.len() as u64,
+        state.name,
+        &authority_key,
+        &contents,
+        None,
+        GasCostSummary::default(),
+        None,
+    );
+    let checkpoint = VerifiedCheckpoint::new_unchecked(
+        CertifiedCheckpointSummary::aggregate(vec![signed], &committee).unwrap(),
+    );
+    let dir = tempfile::tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(&dir.path().join(CHECKPOINTS_DB_DIR));
+    checkpoint_store
+        .insert_verified_checkpoint(checkpoint.clone())
+        .unwrap();
+    checkpoint_store
+        .insert_checkpoint_contents(contents)
+        .unwrap();
+    checkpoint_store
+        .update_highest_executed_checkpoint(&checkpoint)
+        .unwrap();
+
+    // The index store was captured before the transfers were indexed, as happens when they
+    // execute while the backup is taken.
+    let stale_index_store = IndexStore::new(dir.path().join("stale_indexes"));
+    let backup_path = dir.path().join("backup");
+    let info = backup_node_dbs(
+        &backup_path,
+        &checkpoint_store,
+        state.committee_store(),
+        &state.db(),
+        Some(&stale_index_store),
+    )
+    .unwrap();
+    assert_eq!(info.highest_executed_checkpoint, Some(0));
+
+    let db_path = dir.path().join("restored");
+    restore_node_dbs(&backup_path, &db_path).unwrap();
+    assert!(!db_path.join(RESTORE_STAGING_DIR).exists());
+    let reindexed: Vec<TransactionDigest> =
+        serde_json::from_slice(&fs::read(db_path.join(REINDEX_FILE)).unwrap()).unwrap();
+    let transactions: Vec<_> = digests.iter().map(|d| d.transaction).collect();
+    assert_eq!(reindexed, transactions);
+
+    let restored =
+        AuthorityState::new_for_testing(committee, &authority_key, Some(db_path.clone()), None)
+            .await;
+    let index_store = restored.indexes().unwrap();
+    assert!(index_store
+        .get_transactions_from_addr(sender, 0, None, false)
+        .unwrap()
+        .is_empty());
+
+    reindex_restored_transactions(&restored, &db_path)
+        .await
+        .unwrap();
+    assert!(!db_path.join(REINDEX_FILE).exists());
+    assert_eq!(
+        index_store
+            .get_transactions_from_addr(sender, 0, None, false)
+            .unwrap(),
+        transactions
+    );
+    assert_eq!(
+        index_store
+            .get_transactions_to_addr(recipient, 0, None, false)
+            .unwrap(),
+        transactions
+    );
+    for digest in &transactions {
+        assert!(index_store.get_transaction_seq(digest).unwrap().is_some());
+    }
+}
+
+#[tokio::test]
+async fn test_failed_restore_leaves_db_path_untouched() {
+    let state = init_state().await;
+    let dir = tempfile::tempdir().unwrap();
+    let checkpoint_store = CheckpointStore::new(&dir.path().join(CHECKPOINTS_DB_DIR));
+
+    let backup_path = dir.path().join("backup");
+    let mut info = backup_node_dbs(
+        &backup_path,
+        &checkpoint_store,
+        state.committee_store(),
+        &state.db(),
+        None,
+    )
+    .unwrap();
+
+    // Claim an epoch the backup does not hold, so that verification fails.
+    info.epoch += 1;
+    info.save(&backup_path).unwrap();
+    let db_path = dir.path().join("restored");
+    assert!(restore_node_dbs(&backup_path, &db_path).is_err());
+    assert_eq!(fs::read_dir(&db_path).unwrap().count(), 0);
+}
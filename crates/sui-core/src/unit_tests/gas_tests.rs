@@ -8,7 +8,9 @@ use super::move_integration_tests::build_and_try_publish_test_package;
 use crate::authority::authority_tests::{init_state, init_state_with_ids_and_object_basics};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::ident_str;
+use once_cell::sync::Lazy;
 use sui_adapter::genesis;
+use sui_protocol_constants::ProtocolConfig;
 use sui_types::crypto::AccountKeyPair;
 use sui_types::gas_coin::GasCoin;
 use sui_types::object::GAS_VALUE_FOR_TESTING;
@@ -16,10 +18,16 @@ use sui_types::utils::to_sender_signed_transaction;
 use sui_types::{
     base_types::dbg_addr,
     crypto::get_key_pair,
-    gas::{SuiGasStatus, MAX_GAS_BUDGET, MIN_GAS_BUDGET},
+    gas::{SuiCostTable, SuiGasStatus},
 };
 use sui_types::{MOVE_STDLIB_OBJECT_ID, SUI_FRAMEWORK_OBJECT_ID};
 
+// Gas budget limits of the protocol version the test authorities start at.
+static MAX_GAS_BUDGET: Lazy<u64> =
+    Lazy::new(|| SuiCostTable::new(&ProtocolConfig::get_for_min_version()).max_gas_budget());
+static MIN_GAS_BUDGET: Lazy<u64> =
+    Lazy::new(|| SuiCostTable::new(&ProtocolConfig::get_for_min_version()).min_gas_budget());
+
 #[tokio::test]
 async fn test_tx_less_than_minimum_gas_budget() {
     // This test creates a transaction that sets a gas_budget less than the minimum
@@ -55,6 +63,31 @@ async fn test_tx_more_than_maximum_gas_budget() {
     );
 }
 
+#[tokio::test]
+async fn test_tx_gas_price_less_than_reference_gas_price() {
+    // This test creates a transaction that sets a gas_price below the reference
+    // gas price of the epoch. It's expected to fail early during transaction
+    // handling phase.
+    let result = execute_transfer_with_price(*MAX_GAS_BUDGET, *MAX_GAS_BUDGET, 0, false).await;
+    let reference_gas_price = result.authority_state.epoch_store().reference_gas_price();
+    assert_eq!(
+        reference_gas_price,
+        result
+            .authority_state
+            .get_sui_system_state_object()
+            .unwrap()
+            .reference_gas_price
+    );
+    let err = result.response.unwrap_err();
+    assert_eq!(
+        err,
+        SuiError::GasPriceUnderRGP {
+            gas_price: 0,
+            reference_gas_price,
+        }
+    );
+}
+
 #[tokio::test]
 async fn test_tx_gas_balance_less_than_budget() {
     // This test creates a transaction that uses a gas object whose balance
@@ -108,7 +141,12 @@ async fn test_native_transfer_sufficient_gas() -> SuiResult {
 
     // Mimic the process of gas charging, to check that we are charging
     // exactly what we should be charging.
-    let mut gas_status = SuiGasStatus::new_with_budget(*MAX_GAS_BUDGET, 1.into(), 1.into());
+    let mut gas_status = SuiGasStatus::new_with_budget(
+        *MAX_GAS_BUDGET,
+        1.into(),
+        1.into(),
+        SuiCostTable::new_for_testing(),
+    );
     gas_status.charge_min_tx_gas()?;
     let obj_size = object.object_size_for_gas_metering();
     let gas_size = gas_object.object_size_for_gas_metering();
@@ -293,7 +331,12 @@ async fn test_publish_gas() -> anyhow::Result<()> {
     };
 
     // Mimic the gas charge behavior and cross check the result with above.
-    let mut gas_status = SuiGasStatus::new_with_budget(*MAX_GAS_BUDGET, 1.into(), 1.into());
+    let mut gas_status = SuiGasStatus::new_with_budget(
+        *MAX_GAS_BUDGET,
+        1.into(),
+        1.into(),
+        SuiCostTable::new_for_testing(),
+    );
     gas_status.charge_min_tx_gas()?;
     gas_status.charge_vm_gas()?;
     gas_status.charge_storage_read(
@@ -419,7 +462,12 @@ async fn test_move_call_gas() -> SuiResult {
     // Mimic the gas charge behavior and cross check the result with above. Do not include
     // computation cost calculation as it would require hard-coding a constant representing VM
     // execution cost which is quite fragile.
-    let mut gas_status = SuiGasStatus::new_with_budget(GAS_VALUE_FOR_TESTING, 1.into(), 1.into());
+    let mut gas_status = SuiGasStatus::new_with_budget(
+        GAS_VALUE_FOR_TESTING,
+        1.into(),
+        1.into(),
+        SuiCostTable::new_for_testing(),
+    );
     gas_status.charge_min_tx_gas()?;
     let package_object = authority_state
         .get_object(&package_object_ref.0)
@@ -508,10 +556,20 @@ async fn test_move_call_gas() -> SuiResult {
 
 #[tokio::test]
 async fn test_storage_gas_unit_price() -> SuiResult {
-    let mut gas_status1 = SuiGasStatus::new_with_budget(*MAX_GAS_BUDGET, 1.into(), 1.into());
+    let mut gas_status1 = SuiGasStatus::new_with_budget(
+        *MAX_GAS_BUDGET,
+        1.into(),
+        1.into(),
+        SuiCostTable::new_for_testing(),
+    );
     gas_status1.charge_storage_mutation(100, 200, 5.into())?;
     let gas_cost1 = gas_status1.summary(true);
-    let mut gas_status2 = SuiGasStatus::new_with_budget(*MAX_GAS_BUDGET, 1.into(), 3.into());
+    let mut gas_status2 = SuiGasStatus::new_with_budget(
+        *MAX_GAS_BUDGET,
+        1.into(),
+        3.into(),
+        SuiCostTable::new_for_testing(),
+    );
     gas_status2.charge_storage_mutation(100, 200, 5.into())?;
     let gas_cost2 = gas_status2.summary(true);
     // Computation unit price is the same, hence computation cost should be the same.
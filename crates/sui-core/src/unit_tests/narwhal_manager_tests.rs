@@ -15,7 +15,7 @@ use narwhal_worker::TrivialTransactionValidator;
 use prometheus::Registry;
 use std::sync::Arc;
 use std::time::Duration;
-use sui_config::node::AuthorityStorePruningConfig;
+use sui_config::node::{AuthorityStoreCacheConfig, AuthorityStorePruningConfig};
 use sui_types::crypto::KeypairTraits;
 use test_utils::authority::test_and_configure_authority_configs;
 use tokio::sync::mpsc::channel;
@@ -56,6 +56,8 @@ async fn test_narwhal_manager() {
             genesis,
             &committee_store,
             &AuthorityStorePruningConfig::default(),
+            &AuthorityStoreCacheConfig::default(),
+            &Registry::new(),
         )
         .await
         .unwrap(),
@@ -1,13 +1,15 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::tbls::beacon::{AddPartialSignaturesResult, RandomnessBeacon};
 use crate::tbls::tbls_ids::TBlsIds;
 use fastcrypto::traits::{ToFromBytes, VerifyingKey};
 use std::num::NonZeroU32;
 use std::ops::Range;
 use sui_types::base_types::AuthorityName;
-use sui_types::committee::StakeUnit;
+use sui_types::committee::{Committee, StakeUnit};
 use sui_types::crypto::{AuthorityPublicKey, AuthorityPublicKeyBytes};
+use sui_types::randomness::verify_randomness_signature;
 
 fn get_key(id: u16) -> AuthorityName {
     let mut buffer = [0u8; AuthorityPublicKey::LENGTH];
@@ -88,3 +90,114 @@ fn test_validator_without_shares() {
     assert_eq!(tbls_ids.participants().len(), 10);
     assert_eq!(tbls_ids.num_of_shares(), 990);
 }
+
+#[test]
+fn test_randomness_beacon_partial_signatures() {
+    let (committee, _) = Committee::new_simple_test_committee();
+    let beacon = RandomnessBeacon::new(&committee);
+    let names: Vec<_> = committee.names().cloned().collect();
+
+    for name in &names {
+        let data = beacon.sign(*name, 7).unwrap();
+        assert_eq!(data.partial_signatures.len(), 250);
+        beacon.verify_partial_signatures(&data).unwrap();
+    }
+
+    // Partial signatures must match the shares of the authority that sends them.
+    let mut data = beacon.sign(names[0], 7).unwrap();
+    data.authority = names[1];
+    assert!(beacon.verify_partial_signatures(&data).is_err());
+
+    // Partial signatures of another round are rejected.
+    let mut data = beacon.sign(names[0], 7).unwrap();
+    data.round.sequence_number = 8;
+    assert!(beacon.verify_partial_signatures(&data).is_err());
+
+    assert!(beacon.sign(get_key(1), 7).is_none());
+}
+
+#[test]
+fn test_randomness_beacon_aggregation() {
+    let (committee, _) = Committee::new_simple_test_committee();
+    let names: Vec<_> = committee.names().cloned().collect();
+
+    // 4 validators with equal stake receive 250 shares each, so the threshold of 334 shares is
+    // reached with the partial signatures of two validators.
+    let beacon = RandomnessBeacon::new(&committee);
+    assert_eq!(beacon.threshold(), 334);
+    let aggregate = |beacon: &RandomnessBeacon, first: usize, second: usize| {
+        assert!(matches!(
+            beacon.add_partial_signatures(&beacon.sign(names[first], 3).unwrap()),
+            AddPartialSignaturesResult::NotEnoughShares
+        ));
+        match beacon.add_partial_signatures(&beacon.sign(names[second], 3).unwrap()) {
+            AddPartialSignaturesResult::Completed(randomness) => randomness,
+            _ => panic!("Expected the round to be completed"),
+        }
+    };
+    let randomness = aggregate(&beacon, 0, 1);
+    verify_randomness_signature(&randomness.round, &randomness.signature).unwrap();
+    assert!(matches!(
+        beacon.add_partial_signatures(&beacon.sign(names[2], 3).unwrap()),
+        AddPartialSignaturesResult::Ignored
+    ));
+
+    // The output does not depend on which validators contributed.
+    let other_beacon = RandomnessBeacon::new(&committee);
+    let other_randomness = aggregate(&other_beacon, 3, 2);
+    assert_eq!(randomness.randomness(), other_randomness.randomness());
+}
+
+#[test]
+fn test_randomness_beacon_rounds_complete_independently() {
+    let (committee, _) = Committee::new_simple_test_committee();
+    let names: Vec<_> = committee.names().cloned().collect();
+    let beacon = RandomnessBeacon::new(&committee);
+
+    // Round 5 completes before round 4, which keeps collecting shares.
+    assert!(matches!(
+        beacon.add_partial_signatures(&beacon.sign(names[0], 4).unwrap()),
+        AddPartialSignaturesResult::NotEnoughShares
+    ));
+    beacon.add_partial_signatures(&beacon.sign(names[0], 5).unwrap());
+    assert!(matches!(
+        beacon.add_partial_signatures(&beacon.sign(names[1], 5).unwrap()),
+        AddPartialSignaturesResult::Completed(_)
+    ));
+    assert!(matches!(
+        beacon.add_partial_signatures(&beacon.sign(names[2], 5).unwrap()),
+        AddPartialSignaturesResult::Ignored
+    ));
+    match beacon.add_partial_signatures(&beacon.sign(names[1], 4).unwrap()) {
+        AddPartialSignaturesResult::Completed(randomness) => {
+            assert_eq!(randomness.round.sequence_number, 4);
+            randomness.verify().unwrap();
+        }
+        _ => panic!("Expected round 4 to be completed"),
+    }
+    assert!(matches!(
+        beacon.add_partial_signatures(&beacon.sign(names[2], 4).unwrap()),
+        AddPartialSignaturesResult::Ignored
+    ));
+}
+
+#[test]
+fn test_randomness_beacon_batch_verification_rejects_single_bad_share() {
+    let (committee, _) = Committee::new_simple_test_committee();
+    let names: Vec<_> = committee.names().cloned().collect();
+    let beacon = RandomnessBeacon::new(&committee);
+
+    // Swapping the signatures of two shares keeps the indices and every signature well formed,
+    // but neither signature matches its share anymore.
+    let mut data = beacon.sign(names[0], 7).unwrap();
+    let value = data.partial_signatures[0].value;
+    data.partial_signatures[0].value = data.partial_signatures[1].value;
+    data.partial_signatures[1].value = value;
+    assert!(beacon.verify_partial_signatures(&data).is_err());
+
+    // A single share signing another round is detected too.
+    let mut data = beacon.sign(names[0], 7).unwrap();
+    data.partial_signatures[100] =
+        beacon.sign(names[0], 8).unwrap().partial_signatures[100].clone();
+    assert!(beacon.verify_partial_signatures(&data).is_err());
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::units_types::GasCost;
-use sui_protocol_constants::*;
+use sui_protocol_constants::ProtocolConfig;
 
 //
 // Native function costs
@@ -25,11 +25,17 @@ pub enum SuiNativeCostIndex {
 
     TX_CONTEXT_DERIVE_ID = 7,
     TX_CONTEXT_NEW_SIGNER_FROM_ADDR = 8,
+
+    RANDOMNESS_CHECKPOINT_RANDOMNESS = 9,
 }
 
+/// Flat cost of verifying the randomness beacon signature of a checkpoint, which is dominated by
+/// the BLS pairings and the derivation of the group public key.
+pub const CHECKPOINT_RANDOMNESS_COST: u64 = 1_000_000;
+
 // Native costs are currently flat
 // TODO recalibrate wrt bytecode costs
-pub fn _native_cost_schedule() -> Vec<GasCost> {
+pub fn _native_cost_schedule(protocol_config: &ProtocolConfig) -> Vec<GasCost> {
     use SuiNativeCostIndex as N;
 
     let mut native_table = vec![
@@ -37,7 +43,10 @@ pub fn _native_cost_schedule() -> Vec<GasCost> {
         // We will change this in https://github.com/MystenLabs/sui/issues/3341
         (
             N::EVENT_EMIT,
-            GasCost::new(MAX_TX_GAS / MAX_NUM_EVENT_EMIT, 1),
+            GasCost::new(
+                protocol_config.max_tx_gas() / protocol_config.max_num_event_emit(),
+                1,
+            ),
         ),
         (N::OBJECT_BYTES_TO_ADDR, GasCost::new(30, 1)),
         (N::OBJECT_BORROW_UUID, GasCost::new(150, 1)),
@@ -47,6 +56,10 @@ pub fn _native_cost_schedule() -> Vec<GasCost> {
         (N::TRANSFER_SHARE_OBJECT, GasCost::new(80, 1)),
         (N::TX_CONTEXT_DERIVE_ID, GasCost::new(110, 1)),
         (N::TX_CONTEXT_NEW_SIGNER_FROM_ADDR, GasCost::new(200, 1)),
+        (
+            N::RANDOMNESS_CHECKPOINT_RANDOMNESS,
+            GasCost::new(CHECKPOINT_RANDOMNESS_COST, 1),
+        ),
     ];
     native_table.sort_by_key(|cost| cost.0 as u64);
     native_table
@@ -5,35 +5,34 @@
 // NOTE: all values in this file are subject to change
 
 use move_core_types::gas_algebra::{Byte, InternalGas, InternalGasPerByte, InternalGasUnit};
-use sui_protocol_constants::*;
+use sui_protocol_constants::ProtocolConfig;
 
 use crate::units_types::LinearEquation;
 
-pub const PUBLISH_COST_EQUATION: LinearEquation<InternalGasUnit, Byte> = publish_cost_equation();
-
-pub const TRANSACTION_ACCEPTANCE_COST_EQUATION: LinearEquation<InternalGasUnit, Byte> =
-    transaction_acceptance_cost_equation();
-
-const fn publish_cost_equation() -> LinearEquation<InternalGasUnit, Byte> {
-    let offset = InternalGas::new(PACKAGE_PUBLISH_COST_FIXED);
-    let slope = InternalGasPerByte::new(PACKAGE_PUBLISH_COST_PER_BYTE);
+pub fn publish_cost_equation(
+    protocol_config: &ProtocolConfig,
+) -> LinearEquation<InternalGasUnit, Byte> {
+    let offset = InternalGas::new(protocol_config.package_publish_cost_fixed());
+    let slope = InternalGasPerByte::new(protocol_config.package_publish_cost_per_byte());
 
     LinearEquation::new(
         slope,
         offset,
-        InternalGas::new(PACKAGE_PUBLISH_COST_FIXED),
-        InternalGas::new(MAX_TX_GAS),
+        InternalGas::new(protocol_config.package_publish_cost_fixed()),
+        InternalGas::new(protocol_config.max_tx_gas()),
     )
 }
 
-const fn transaction_acceptance_cost_equation() -> LinearEquation<InternalGasUnit, Byte> {
-    let offset = InternalGas::new(BASE_TX_COST_FIXED);
-    let slope = InternalGasPerByte::new(BASE_TX_COST_PER_BYTE);
+pub fn transaction_acceptance_cost_equation(
+    protocol_config: &ProtocolConfig,
+) -> LinearEquation<InternalGasUnit, Byte> {
+    let offset = InternalGas::new(protocol_config.base_tx_cost_fixed());
+    let slope = InternalGasPerByte::new(protocol_config.base_tx_cost_per_byte());
 
     LinearEquation::new(
         slope,
         offset,
-        InternalGas::new(PACKAGE_PUBLISH_COST_FIXED),
-        InternalGas::new(MAX_TX_GAS),
+        InternalGas::new(protocol_config.package_publish_cost_fixed()),
+        InternalGas::new(protocol_config.max_tx_gas()),
     )
 }
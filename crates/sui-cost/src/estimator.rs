@@ -16,8 +16,8 @@ use sui_types::crypto::AccountKeyPair;
 use sui_types::error::SuiResult;
 use sui_types::gas::start_gas_metering;
 use sui_types::gas::GasCostSummary;
+use sui_types::gas::SuiCostTable;
 use sui_types::gas::SuiGas;
-use sui_types::gas::MAX_GAS_BUDGET;
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages::SingleTransactionKind;
 use sui_types::messages::TransactionData;
@@ -68,15 +68,17 @@ fn estimate_transaction_inner<S>(
     mutated_object_sizes_after: Option<usize>,
     storage_rebate: SuiGas,
     temporary_store: &TemporaryStore<S>,
+    cost_table: SuiCostTable,
 ) -> SuiResult<GasCostSummary> {
     let computation_gas_unit_price =
         computation_gas_unit_price.unwrap_or(DEFAULT_COMPUTATION_GAS_UNIT_PRICE);
     let storage_gas_unit_price = storage_gas_unit_price.unwrap_or(DEFAULT_STORAGE_GAS_UNIT_PRICE);
 
     let mut gas_status = start_gas_metering(
-        *MAX_GAS_BUDGET,
+        cost_table.max_gas_budget(),
         computation_gas_unit_price,
         storage_gas_unit_price,
+        cost_table,
     )?;
 
     // Step 1: charge min tx fee : can be computed precisely
@@ -142,6 +144,7 @@ pub async fn estimate_transaction_computation_cost(
     .await?;
     let in_mem_temporary_store =
         TemporaryStore::new(state.db(), input_objects, TransactionDigest::random());
+    let cost_table = SuiCostTable::new(state.epoch_store().protocol_config());
 
     estimate_transaction_inner(
         tx.into_inner().into_data().intent_message.value.kind,
@@ -150,6 +153,7 @@ pub async fn estimate_transaction_computation_cost(
         mutated_object_sizes_after,
         SuiGas::new(storage_rebate.unwrap_or(DEFAULT_STORAGE_REBATE)),
         &in_mem_temporary_store,
+        cost_table,
     )
     .map_err(|e| anyhow!("{e}"))
 }
@@ -0,0 +1,36 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// The beacon uses an insecure, deterministic key generation until validators run a real DKG:
+/// anyone can compute its output in advance. The functions of this module are test-only so that
+/// contracts can't rely on it until then.
+module sui::randomness {
+    #[test_only]
+    use std::vector;
+    #[test_only]
+    use sui::digest;
+
+    /// The signature is not the output of the randomness beacon for the given checkpoint.
+    #[test_only]
+    const EInvalidRandomnessSignature: u64 = 0;
+
+    /// @param epoch: the epoch of the checkpoint.
+    /// @param checkpoint: the sequence number of the checkpoint.
+    /// @param signature: the BCS encoded threshold signature produced by the beacon for the checkpoint.
+    /// A native move wrapper around the verification of the beacon output. Returns the 32 bytes
+    /// of randomness derived from the signature, or an empty vector if the signature is invalid.
+    #[test_only]
+    native fun native_checkpoint_randomness(epoch: u64, checkpoint: u64, signature: &vector<u8>): vector<u8>;
+
+    /// @param epoch: the epoch of the checkpoint.
+    /// @param checkpoint: the sequence number of the checkpoint.
+    /// @param signature: the BCS encoded threshold signature produced by the beacon for the checkpoint.
+    /// Verifies the output of the validators' randomness beacon for a checkpoint and returns the
+    /// randomness derived from it. Aborts if the signature is not valid.
+    #[test_only]
+    public fun checkpoint_randomness(epoch: u64, checkpoint: u64, signature: &vector<u8>): digest::Sha3256Digest {
+        let randomness = native_checkpoint_randomness(epoch, checkpoint, signature);
+        assert!(!vector::is_empty(&randomness), EInvalidRandomnessSignature);
+        digest::sha3_256_digest_from_bytes(randomness)
+    }
+}
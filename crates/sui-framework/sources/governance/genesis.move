@@ -44,6 +44,7 @@ module sui::genesis {
         validator_stakes: vector<u64>,
         validator_gas_prices: vector<u64>,
         validator_commission_rates: vector<u64>,
+        protocol_version: u64,
         ctx: &mut TxContext,
     ) {
         let sui_supply = sui::new(ctx);
@@ -102,6 +103,7 @@ module sui::genesis {
             INIT_MIN_VALIDATOR_STAKE,
             INIT_STORAGE_GAS_PRICE,
             INIT_STAKE_SUBSIDY_AMOUNT,
+            protocol_version,
         );
     }
 }
@@ -7,6 +7,7 @@ module sui::sui_system {
     use sui::staking_pool::{Delegation, StakedSui};
     use sui::object::{Self, UID};
     use sui::locked_coin::{Self, LockedCoin};
+    use sui::pay;
     use sui::sui::SUI;
     use sui::transfer;
     use sui::tx_context::{Self, TxContext};
@@ -16,7 +17,8 @@ module sui::sui_system {
     use sui::stake_subsidy::{Self, StakeSubsidy};
     use sui::vec_map::{Self, VecMap};
     use sui::vec_set::{Self, VecSet};
-    use std::option;
+    use std::option::{Self, Option};
+    use std::vector;
 
     friend sui::genesis;
 
@@ -46,6 +48,8 @@ module sui::sui_system {
         chain_id: u8,
         /// The current epoch ID, starting from 0.
         epoch: u64,
+        /// The current protocol version, starting from 1.
+        protocol_version: u64,
         /// Contains all information about the validators.
         validators: ValidatorSet,
         /// The SUI treasury capability needed to mint SUI.
@@ -88,6 +92,7 @@ module sui::sui_system {
         min_validator_stake: u64,
         storage_gas_price: u64,
         initial_stake_subsidy_amount: u64,
+        protocol_version: u64,
     ) {
         assert!(chain_id >= 1 && chain_id <= 127, 1);
         let validators = validator_set::new(validators);
@@ -97,6 +102,7 @@ module sui::sui_system {
             id: object::sui_system_state(),
             chain_id,
             epoch: 0,
+            protocol_version,
             validators,
             sui_supply,
             storage_fund,
@@ -281,6 +287,19 @@ module sui::sui_system {
         validator_set::request_add_delegation(&mut self.validators, validator_address, balance, option::some(lock), ctx);
     }
 
+    /// Add delegated stake to a validator's staking pool using multiple coins.
+    /// Only `stake_amount` is delegated if it is set, the remainder is returned to the sender.
+    public entry fun request_add_delegation_mul_coin(
+        self: &mut SuiSystemState,
+        delegate_stakes: vector<Coin<SUI>>,
+        stake_amount: Option<u64>,
+        validator_address: address,
+        ctx: &mut TxContext,
+    ) {
+        let balance = extract_coin_balance(delegate_stakes, stake_amount, ctx);
+        validator_set::request_add_delegation(&mut self.validators, validator_address, balance, option::none(), ctx);
+    }
+
     /// Withdraw some portion of a delegation from a validator's staking pool.
     public entry fun request_withdraw_delegation(
         self: &mut SuiSystemState,
@@ -312,6 +331,25 @@ module sui::sui_system {
         );
     }
 
+    /// Extract `amount` from the merged `coins`, or all of it if `amount` is not set.
+    /// The remainder is transferred back to the sender.
+    fun extract_coin_balance(coins: vector<Coin<SUI>>, amount: Option<u64>, ctx: &mut TxContext): Balance<SUI> {
+        let merged_coin = vector::pop_back(&mut coins);
+        pay::join_vec(&mut merged_coin, coins);
+        let total_balance = coin::into_balance(merged_coin);
+        if (option::is_some(&amount)) {
+            let balance = balance::split(&mut total_balance, option::destroy_some(amount));
+            if (balance::value(&total_balance) > 0) {
+                transfer::transfer(coin::from_balance(total_balance, ctx), tx_context::sender(ctx));
+            } else {
+                balance::destroy_zero(total_balance);
+            };
+            balance
+        } else {
+            total_balance
+        }
+    }
+
     /// Report a validator as a bad or non-performant actor in the system.
     /// Suceeds iff both the sender and the input `validator_addr` are active validators
     /// and they are not the same address. This function is idempotent within an epoch.
@@ -366,6 +404,7 @@ module sui::sui_system {
         storage_rebate: u64,
         storage_fund_reinvest_rate: u64, // share of storage fund's rewards that's reinvested 
                                          // into storage fund, in basis point.
+        next_protocol_version: u64,
         ctx: &mut TxContext,
     ) {
         // Validator will make a special system call with sender set as 0x0.
@@ -402,6 +441,9 @@ module sui::sui_system {
         self.epoch = self.epoch + 1;
         // Sanity check to make sure we are advancing to the right epoch.
         assert!(new_epoch == self.epoch, 0);
+        // The protocol version can only stay the same or move forward.
+        assert!(next_protocol_version >= self.protocol_version, 0);
+        self.protocol_version = next_protocol_version;
         validator_set::advance_epoch(
             &mut self.validators,
             &mut computation_reward,
@@ -440,6 +482,11 @@ module sui::sui_system {
         self.epoch
     }
 
+    /// Return the protocol version the network runs in the current epoch.
+    public fun protocol_version(self: &SuiSystemState): u64 {
+        self.protocol_version
+    }
+
     /// Returns the amount of stake delegated to `validator_addr`.
     /// Aborts if `validator_addr` is not an active validator.
     public fun validator_delegate_amount(self: &SuiSystemState, validator_addr: address): u64 {
@@ -0,0 +1,46 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::InternalGas;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+use sui_cost_tables::natives_tables::CHECKPOINT_RANDOMNESS_COST;
+use sui_types::randomness::{CheckpointRandomness, RandomnessRound, RandomnessSignature};
+
+pub fn checkpoint_randomness(
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let signature_bytes = pop_arg!(args, VectorRef);
+    let checkpoint = pop_arg!(args, u64);
+    let epoch = pop_arg!(args, u64);
+
+    let cost = InternalGas::new(CHECKPOINT_RANDOMNESS_COST);
+
+    let signature = match bcs::from_bytes::<RandomnessSignature>(&signature_bytes.as_bytes_ref()) {
+        Ok(signature) => signature,
+        Err(_) => return Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(vec![])])),
+    };
+    let randomness = CheckpointRandomness {
+        round: RandomnessRound::new(epoch, checkpoint),
+        signature,
+    };
+    match randomness.verify() {
+        Ok(_) => Ok(NativeResult::ok(
+            cost,
+            smallvec![Value::vector_u8(randomness.randomness().to_vec())],
+        )),
+        Err(_) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(vec![])])),
+    }
+}
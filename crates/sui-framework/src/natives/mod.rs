@@ -23,7 +23,9 @@ use move_vm_types::{
 };
 use std::sync::Arc;
 
-use self::crypto::{bls12381, bulletproofs, ecdsa_k1, ed25519, elliptic_curve, groth16, hmac};
+use self::crypto::{
+    bls12381, bulletproofs, ecdsa_k1, ed25519, elliptic_curve, groth16, hmac, randomness,
+};
 
 pub fn all_natives(
     move_stdlib_addr: AccountAddress,
@@ -148,6 +150,11 @@ pub fn all_natives(
             "record_new_uid",
             make_native!(object::record_new_uid),
         ),
+        (
+            "randomness",
+            "native_checkpoint_randomness",
+            make_native!(randomness::checkpoint_randomness),
+        ),
         (
             "test_scenario",
             "take_from_address_by_id",
@@ -7,6 +7,7 @@ module sui::delegation_tests {
     use sui::test_scenario::{Self, Scenario};
     use sui::sui_system::{Self, SuiSystemState};
     use sui::staking_pool::{Self, Delegation, StakedSui};
+    use std::option;
     use std::vector;
 
     use sui::governance_test_utils::{
@@ -142,6 +143,45 @@ module sui::delegation_tests {
         test_scenario::end(scenario_val);
     }
 
+    #[test]
+    fun test_add_delegation_mul_coin() {
+        let scenario_val = test_scenario::begin(VALIDATOR_ADDR_1);
+        let scenario = &mut scenario_val;
+        set_up_sui_system_state(scenario);
+
+        test_scenario::next_tx(scenario, DELEGATOR_ADDR_1);
+        {
+            let system_state = test_scenario::take_shared<SuiSystemState>(scenario);
+
+            let ctx = test_scenario::ctx(scenario);
+            let coins = vector[coin::mint_for_testing(30, ctx), coin::mint_for_testing(40, ctx)];
+
+            // Delegate 60 out of the 70 SUI to VALIDATOR_ADDR_1.
+            sui_system::request_add_delegation_mul_coin(
+                &mut system_state, coins, option::some(60), VALIDATOR_ADDR_1, ctx);
+
+            test_scenario::return_shared(system_state);
+        };
+
+        governance_test_utils::advance_epoch(scenario);
+
+        test_scenario::next_tx(scenario, DELEGATOR_ADDR_1);
+        {
+            let staked_sui = test_scenario::take_from_sender<StakedSui>(scenario);
+            assert!(staking_pool::staked_sui_amount(&staked_sui) == 60, 101);
+            test_scenario::return_to_sender(scenario, staked_sui);
+
+            // The remainder is returned to the delegator.
+            let balance = governance_test_utils::total_sui_balance(DELEGATOR_ADDR_1, scenario);
+            assert!(balance == 10, 102);
+
+            let system_state = test_scenario::take_shared<SuiSystemState>(scenario);
+            assert!(sui_system::validator_delegate_amount(&mut system_state, VALIDATOR_ADDR_1) == 60, 103);
+            test_scenario::return_shared(system_state);
+        };
+        test_scenario::end(scenario_val);
+    }
+
     #[test]
     fun test_switch_delegation() {
         let scenario_val = test_scenario::begin(VALIDATOR_ADDR_1);
@@ -48,6 +48,7 @@ module sui::governance_test_utils {
             0, // min_validator_stake
             1, // storage_gas_price
             0, // stake subsidy
+            1, // protocol version
         )
     }
 
@@ -78,7 +79,7 @@ module sui::governance_test_utils {
 
         let ctx = test_scenario::ctx(scenario);
 
-        sui_system::advance_epoch(&mut system_state, new_epoch, storage_charge, computation_charge, 0, 0, ctx);
+        sui_system::advance_epoch(&mut system_state, new_epoch, storage_charge, computation_charge, 0, 0, 1, ctx);
         test_scenario::return_shared(system_state);
     }
 
@@ -0,0 +1,18 @@
+DROP INDEX events_transaction_digest;
+DROP INDEX events_event_time;
+DROP INDEX events_event_type;
+DROP INDEX events_package_id_module_name;
+DROP INDEX events_move_event_name;
+DROP INDEX events_sender;
+DROP INDEX events_recipient;
+DROP INDEX events_object_id;
+
+ALTER TABLE events
+    DROP COLUMN package_id,
+    DROP COLUMN module_name,
+    DROP COLUMN move_event_name,
+    DROP COLUMN sender,
+    DROP COLUMN recipient,
+    DROP COLUMN object_id;
+
+ALTER TABLE transactions DROP COLUMN transaction_effects_content;
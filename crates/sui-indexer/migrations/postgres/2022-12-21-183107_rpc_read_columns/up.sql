@@ -0,0 +1,31 @@
+-- serialized transaction effects, so that transactions can be served without a fullnode
+ALTER TABLE transactions ADD COLUMN transaction_effects_content TEXT;
+
+-- below are extracted from event_content for event queries,
+-- they are null for events that do not carry the field.
+ALTER TABLE events
+    ADD COLUMN package_id TEXT,
+    ADD COLUMN module_name TEXT,
+    ADD COLUMN move_event_name TEXT,
+    ADD COLUMN sender VARCHAR(255),
+    -- JSON serialized owner
+    ADD COLUMN recipient TEXT,
+    ADD COLUMN object_id VARCHAR(255);
+
+CREATE INDEX events_transaction_digest ON events (transaction_digest);
+CREATE INDEX events_event_time ON events (event_time);
+CREATE INDEX events_event_type ON events (event_type);
+CREATE INDEX events_package_id_module_name ON events (package_id, module_name);
+CREATE INDEX events_move_event_name ON events (move_event_name);
+CREATE INDEX events_sender ON events (sender);
+CREATE INDEX events_recipient ON events (recipient);
+CREATE INDEX events_object_id ON events (object_id);
+
+-- Rows indexed before have none of the columns above. Index them again from the start,
+-- and rewind the processors, which walk the events and transactions tables by id.
+TRUNCATE events, transactions RESTART IDENTITY;
+UPDATE event_logs SET next_cursor_tx_seq = NULL, next_cursor_event_seq = NULL;
+UPDATE transaction_logs SET next_cursor_tx_digest = NULL;
+UPDATE object_logs SET last_processed_id = 0;
+UPDATE address_logs SET last_processed_id = 0;
+UPDATE package_logs SET last_processed_id = 0;
@@ -0,0 +1,6 @@
+CREATE TABLE error_logs (
+    id INTEGER PRIMARY KEY,
+    error_type VARCHAR(63) NOT NULL,
+    error TEXT NOT NULL,
+    error_time TIMESTAMP NOT NULL
+);
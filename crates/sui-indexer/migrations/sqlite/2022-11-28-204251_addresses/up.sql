@@ -0,0 +1,13 @@
+CREATE TABLE addresses (
+    account_address VARCHAR(255) PRIMARY KEY,
+    first_appearance_tx VARCHAR(255) NOT NULL,
+    first_appearance_time TIMESTAMP
+);
+
+CREATE TABLE address_logs (
+    last_processed_id BIGINT PRIMARY KEY
+);
+
+-- last processed serial number, as the serial number starts from 1,
+-- initial value of last_processed_id should be 0.
+INSERT INTO address_logs VALUES (0);
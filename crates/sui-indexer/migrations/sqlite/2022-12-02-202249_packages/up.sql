@@ -0,0 +1,13 @@
+CREATE TABLE packages (
+    package_id TEXT PRIMARY KEY,
+    author TEXT NOT NULL,
+    -- JSON array of module names
+    module_names TEXT NOT NULL,
+    package_content TEXT NOT NULL
+);
+
+CREATE TABLE package_logs (
+    last_processed_id BIGINT PRIMARY KEY
+);
+
+INSERT INTO package_logs (last_processed_id) VALUES (0);
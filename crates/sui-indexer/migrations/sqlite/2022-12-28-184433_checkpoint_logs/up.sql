@@ -0,0 +1,21 @@
+-- Ingestion walks certified checkpoints in order, the fullnode-local cursors below
+-- are replaced by the sequence number of the next checkpoint to index.
+DROP TABLE transaction_logs;
+DROP TABLE event_logs;
+DROP TABLE object_logs;
+
+-- Rows indexed from the fullnode-local cursors are sequenced differently from the rows
+-- indexed from checkpoints, and would shadow them on conflict. Start over from genesis,
+-- and rewind the processors, which walk the transactions table by id.
+-- Transaction ids restart from 1 once the table is empty, as it has no AUTOINCREMENT.
+DELETE FROM events;
+DELETE FROM transactions;
+DELETE FROM objects;
+UPDATE address_logs SET last_processed_id = 0;
+UPDATE package_logs SET last_processed_id = 0;
+
+CREATE TABLE checkpoint_logs (
+    next_checkpoint_sequence_number BIGINT PRIMARY KEY
+);
+
+INSERT INTO checkpoint_logs (next_checkpoint_sequence_number) VALUES (0);
@@ -0,0 +1,10 @@
+DROP INDEX objects_owner;
+DROP INDEX objects_owner_coin_type;
+DROP INDEX objects_object_type;
+
+ALTER TABLE objects DROP COLUMN object_content;
+ALTER TABLE objects DROP COLUMN coin_type;
+ALTER TABLE objects DROP COLUMN coin_balance;
+
+DROP INDEX transactions_transaction_sequence;
+ALTER TABLE transactions DROP COLUMN transaction_sequence;
@@ -0,0 +1,27 @@
+-- Transactions indexed before have no sequence number, and the objects indexed before were
+-- never fetched. Index everything again from genesis, and rewind the processors, which walk
+-- the transactions table by id. Events are complete and kept.
+-- Transaction ids restart from 1 once the table is empty, as it has no AUTOINCREMENT.
+DELETE FROM transactions;
+DELETE FROM objects;
+UPDATE checkpoint_logs SET next_checkpoint_sequence_number = 0;
+UPDATE address_logs SET last_processed_id = 0;
+UPDATE package_logs SET last_processed_id = 0;
+
+-- position of the transaction since genesis, the same on every fullnode
+-- SQLite cannot add a NOT NULL column without a default, the table is empty anyway.
+ALTER TABLE transactions ADD COLUMN transaction_sequence BIGINT NOT NULL DEFAULT 0;
+CREATE UNIQUE INDEX transactions_transaction_sequence ON transactions (transaction_sequence);
+
+-- below are copied from the object fetched at the indexed version, so that objects and
+-- coins can be served without a fullnode.
+-- JSON serialized object, null until the object is fetched
+ALTER TABLE objects ADD COLUMN object_content TEXT;
+-- only non-null for coins, the type parameter of the coin
+ALTER TABLE objects ADD COLUMN coin_type TEXT;
+-- only non-null for coins, the u64 balance stored bit for bit
+ALTER TABLE objects ADD COLUMN coin_balance BIGINT;
+
+CREATE INDEX objects_owner ON objects (owner_type, owner_address, object_id);
+CREATE INDEX objects_owner_coin_type ON objects (owner_address, coin_type, object_id);
+CREATE INDEX objects_object_type ON objects (object_type);
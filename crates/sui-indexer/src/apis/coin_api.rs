@@ -0,0 +1,299 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::RpcModule;
+
+use sui_json_rpc::api::{cap_page_limit, CoinReadApiServer};
+use sui_json_rpc::SuiRpcModule;
+use sui_json_rpc_types::{
+    Balance, CoinPage, SuiCoinMetadata, SuiMoveStruct, SuiMoveValue, SuiParsedData,
+};
+use sui_open_rpc::Module;
+use sui_types::balance::Supply;
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::coin::{CoinMetadata, TreasuryCap};
+use sui_types::gas_coin::GAS;
+use sui_types::{parse_sui_struct_tag, SUI_SYSTEM_STATE_OBJECT_ID};
+
+use crate::errors::IndexerError;
+use crate::models::objects::{
+    read_coin_balances, read_coins, read_object_by_type, read_objects, Object,
+};
+use crate::{get_db_pool_connection, DbConnectionPool};
+
+/// Serves coins, balances, metadata and supplies from the objects table.
+pub struct CoinReadApi {
+    db_connection_pool: Arc<DbConnectionPool>,
+}
+
+impl CoinReadApi {
+    pub fn new(db_connection_pool: Arc<DbConnectionPool>) -> Self {
+        Self { db_connection_pool }
+    }
+
+    fn read_coin_page(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<CoinPage> {
+        let limit = cap_page_limit(limit);
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        let mut rows = read_coins(
+            &mut db_pool_conn,
+            owner.to_string(),
+            coin_type,
+            cursor.map(|cursor| cursor.to_string()),
+            limit + 1,
+        )?;
+        let next_cursor = match rows.get(limit) {
+            Some(row) => Some(parse_object_id(&row.object_id)?),
+            None => None,
+        };
+        rows.truncate(limit);
+        let data = rows.iter().map(Object::to_coin).collect::<Result<_, _>>()?;
+        Ok(CoinPage { data, next_cursor })
+    }
+
+    fn read_balances(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+    ) -> RpcResult<Vec<Balance>> {
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        let mut balances: BTreeMap<String, (u128, usize)> = BTreeMap::new();
+        for (coin_type, balance) in
+            read_coin_balances(&mut db_pool_conn, owner.to_string(), coin_type)?
+        {
+            let (total_balance, coin_object_count) = balances.entry(coin_type).or_default();
+            *total_balance += balance as u128;
+            *coin_object_count += 1;
+        }
+        Ok(balances
+            .into_iter()
+            .map(|(coin_type, (total_balance, coin_object_count))| Balance {
+                coin_type,
+                coin_object_count,
+                total_balance,
+            })
+            .collect())
+    }
+
+    /// Read the fields of the only live object of the given type.
+    fn read_fields_by_type(&self, object_type: String) -> RpcResult<SuiMoveStruct> {
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        let object =
+            read_object_by_type(&mut db_pool_conn, object_type.clone())?.ok_or_else(|| {
+                IndexerError::DbReadError(format!(
+                    "Object of type {} is not indexed",
+                    object_type
+                ))
+            })?;
+        object_fields(&object)
+    }
+
+    fn read_fields_by_id(&self, object_id: ObjectID) -> RpcResult<SuiMoveStruct> {
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        let object = read_objects(&mut db_pool_conn, vec![object_id.to_string()])?
+            .pop()
+            .ok_or_else(|| {
+                IndexerError::DbReadError(format!("Object {} is not indexed", object_id))
+            })?;
+        object_fields(&object)
+    }
+}
+
+/// Coin types are stored as printed by `StructTag`, requested types are normalized the same
+/// way so that, for example, `0x2::sui::SUI` and `0x0000..02::sui::SUI` both match.
+fn normalize_coin_type(coin_type: &str) -> RpcResult<String> {
+    Ok(parse_sui_struct_tag(coin_type)?.to_string())
+}
+
+fn parse_object_id(object_id: &str) -> Result<ObjectID, IndexerError> {
+    object_id.parse().map_err(|e| {
+        IndexerError::ObjectDeserializationError(format!(
+            "Failed parsing object ID {:?} with error: {:?}",
+            object_id, e
+        ))
+    })
+}
+
+fn object_fields(object: &Object) -> RpcResult<SuiMoveStruct> {
+    match object.sui_object()?.map(|object| object.data) {
+        Some(SuiParsedData::MoveObject(move_object)) => Ok(move_object.fields),
+        _ => Err(IndexerError::ObjectDeserializationError(format!(
+            "Object {:?} is not an indexed Move object",
+            object.object_id
+        ))
+        .into()),
+    }
+}
+
+fn field<'a>(fields: &'a SuiMoveStruct, name: &str) -> Result<&'a SuiMoveValue, IndexerError> {
+    let value = match fields {
+        SuiMoveStruct::WithFields(fields) | SuiMoveStruct::WithTypes { type_: _, fields } => {
+            fields.get(name)
+        }
+        SuiMoveStruct::Runtime(_) => None,
+    };
+    value.ok_or_else(|| {
+        IndexerError::ObjectDeserializationError(format!(
+            "Missing field {:?} in {:?}",
+            name, fields
+        ))
+    })
+}
+
+fn field_struct<'a>(
+    fields: &'a SuiMoveStruct,
+    name: &str,
+) -> Result<&'a SuiMoveStruct, IndexerError> {
+    match field(fields, name)? {
+        SuiMoveValue::Struct(value) => Ok(value),
+        value => Err(unexpected_value(name, value)),
+    }
+}
+
+fn field_string(fields: &SuiMoveStruct, name: &str) -> Result<String, IndexerError> {
+    match field(fields, name)? {
+        SuiMoveValue::String(value) => Ok(value.clone()),
+        value => Err(unexpected_value(name, value)),
+    }
+}
+
+/// Integers up to u32 are parsed as numbers, larger ones as strings.
+fn field_u64(fields: &SuiMoveStruct, name: &str) -> Result<u64, IndexerError> {
+    match field(fields, name)? {
+        SuiMoveValue::Number(value) => Ok(*value),
+        SuiMoveValue::String(value) => value
+            .parse()
+            .map_err(|_| unexpected_value(name, &SuiMoveValue::String(value.clone()))),
+        value => Err(unexpected_value(name, value)),
+    }
+}
+
+fn unexpected_value(name: &str, value: &SuiMoveValue) -> IndexerError {
+    IndexerError::ObjectDeserializationError(format!(
+        "Unexpected value {:?} of field {:?}",
+        value, name
+    ))
+}
+
+fn coin_metadata_from_fields(fields: &SuiMoveStruct) -> Result<SuiCoinMetadata, IndexerError> {
+    let icon_url = match field(fields, "icon_url")? {
+        SuiMoveValue::String(url) => Some(url.clone()),
+        SuiMoveValue::Option(url) => match url.as_ref() {
+            Some(SuiMoveValue::String(url)) => Some(url.clone()),
+            None => None,
+            Some(value) => return Err(unexpected_value("icon_url", value)),
+        },
+        value => return Err(unexpected_value("icon_url", value)),
+    };
+    let id = match field(fields, "id")? {
+        SuiMoveValue::UID { id } => *id,
+        value => return Err(unexpected_value("id", value)),
+    };
+    Ok(SuiCoinMetadata {
+        decimals: field_u64(fields, "decimals")? as u8,
+        name: field_string(fields, "name")?,
+        symbol: field_string(fields, "symbol")?,
+        description: field_string(fields, "description")?,
+        icon_url,
+        id: Some(id),
+    })
+}
+
+#[async_trait]
+impl CoinReadApiServer for CoinReadApi {
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<CoinPage> {
+        // Default coin_type to 0x2::sui::SUI
+        let coin_type = match coin_type {
+            Some(coin_type) => normalize_coin_type(&coin_type)?,
+            None => GAS::type_().to_string(),
+        };
+        self.read_coin_page(owner, Some(coin_type), cursor, limit)
+    }
+
+    async fn get_all_coins(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<CoinPage> {
+        self.read_coin_page(owner, None, cursor, limit)
+    }
+
+    async fn get_balance(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+    ) -> RpcResult<Balance> {
+        let coin_type = match coin_type {
+            Some(coin_type) => normalize_coin_type(&coin_type)?,
+            None => GAS::type_().to_string(),
+        };
+        Ok(self
+            .read_balances(owner, Some(coin_type.clone()))?
+            .pop()
+            .unwrap_or(Balance {
+                coin_type,
+                coin_object_count: 0,
+                total_balance: 0,
+            }))
+    }
+
+    async fn get_all_balances(&self, owner: SuiAddress) -> RpcResult<Vec<Balance>> {
+        self.read_balances(owner, None)
+    }
+
+    async fn get_coin_metadata(&self, coin_type: String) -> RpcResult<SuiCoinMetadata> {
+        let coin_struct = parse_sui_struct_tag(&coin_type)?;
+        if GAS::is_gas(&coin_struct) {
+            // Same as the fullnode, the metadata of SUI is created at genesis and not looked up.
+            return Ok(SuiCoinMetadata {
+                id: None,
+                decimals: 9,
+                symbol: "SUI".to_string(),
+                name: "Sui".to_string(),
+                description: "".to_string(),
+                icon_url: None,
+            });
+        }
+        let fields = self.read_fields_by_type(CoinMetadata::type_(coin_struct).to_string())?;
+        Ok(coin_metadata_from_fields(&fields)?)
+    }
+
+    async fn get_total_supply(&self, coin_type: String) -> RpcResult<Supply> {
+        let coin_struct = parse_sui_struct_tag(&coin_type)?;
+        let supply = if GAS::is_gas(&coin_struct) {
+            let fields = self.read_fields_by_id(SUI_SYSTEM_STATE_OBJECT_ID)?;
+            field_u64(field_struct(&fields, "treasury_cap")?, "value")?
+        } else {
+            let fields = self.read_fields_by_type(TreasuryCap::type_(coin_struct).to_string())?;
+            field_u64(field_struct(&fields, "total_supply")?, "value")?
+        };
+        Ok(Supply { value: supply })
+    }
+}
+
+impl SuiRpcModule for CoinReadApi {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+
+    fn rpc_doc_module() -> Module {
+        sui_json_rpc::api::CoinReadApiOpenRpc::module_doc()
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::RpcModule;
+
+use sui_json_rpc::api::{cap_page_limit, EventReadApiServer};
+use sui_json_rpc::SuiRpcModule;
+use sui_json_rpc_types::EventPage;
+use sui_open_rpc::Module;
+use sui_types::event::EventID;
+use sui_types::query::EventQuery;
+
+use crate::models::events::{event_to_sui_event_envelope, read_events_by_query};
+use crate::{get_db_pool_connection, DbConnectionPool};
+
+pub struct EventReadApiImpl {
+    db_connection_pool: Arc<DbConnectionPool>,
+}
+
+impl EventReadApiImpl {
+    pub fn new(db_connection_pool: Arc<DbConnectionPool>) -> Self {
+        Self { db_connection_pool }
+    }
+}
+
+#[async_trait]
+impl EventReadApiServer for EventReadApiImpl {
+    async fn get_events(
+        &self,
+        query: EventQuery,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<EventPage> {
+        let descending = descending_order.unwrap_or_default();
+        let limit = cap_page_limit(limit);
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        // Retrieve 1 extra item for next cursor
+        let mut events =
+            read_events_by_query(&mut db_pool_conn, query, cursor, limit + 1, descending)?;
+        let next_cursor = events
+            .get(limit)
+            .map(|event| EventID::from((event.transaction_sequence, event.event_sequence)));
+        events.truncate(limit);
+        let data = events
+            .into_iter()
+            .map(event_to_sui_event_envelope)
+            .collect::<Result<_, _>>()?;
+        Ok(EventPage { data, next_cursor })
+    }
+}
+
+impl SuiRpcModule for EventReadApiImpl {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+
+    fn rpc_doc_module() -> Module {
+        sui_json_rpc::api::EventReadApiOpenRpc::module_doc()
+    }
+}
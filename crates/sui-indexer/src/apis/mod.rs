@@ -0,0 +1,10 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+pub use coin_api::CoinReadApi;
+pub use event_read_api::EventReadApiImpl;
+pub use read_api::ReadApi;
+
+mod coin_api;
+mod event_read_api;
+mod read_api;
@@ -0,0 +1,284 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::http_client::HttpClient;
+use jsonrpsee::RpcModule;
+
+use sui_json_rpc::api::{cap_page_limit, check_multi_get_size, RpcReadApiClient, RpcReadApiServer};
+use sui_json_rpc::SuiRpcModule;
+use sui_json_rpc_types::{
+    DynamicFieldPage, GetObjectDataResponse, ObjectsPage, SuiObjectInfo, SuiObjectRead,
+    SuiTransactionAuthSignersResponse, SuiTransactionResponse,
+};
+use sui_open_rpc::Module;
+use sui_types::base_types::{
+    ObjectID, ObjectType, SuiAddress, TransactionDigest, TxSequenceNumber,
+};
+use sui_types::parse_sui_struct_tag;
+use sui_types::query::ObjectTypeFilter;
+
+use crate::errors::IndexerError;
+use crate::models::objects::{
+    read_objects, read_owned_objects, Object, ADDRESS_OWNER_TYPE, OBJECT_OWNER_TYPE,
+};
+use crate::models::transactions::{
+    count_transactions, parse_transaction_digest, read_transaction_digests_in_range,
+    read_transactions_by_digests, transaction_to_response,
+};
+use crate::{get_db_pool_connection, DbConnectionPool};
+
+/// Serves transactions and objects from the indexer database. Dynamic fields and transaction
+/// signers are not indexed, reads of those are proxied to the fullnode.
+pub struct ReadApi {
+    db_connection_pool: Arc<DbConnectionPool>,
+    fullnode: HttpClient,
+}
+
+impl ReadApi {
+    pub fn new(db_connection_pool: Arc<DbConnectionPool>, fullnode: HttpClient) -> Self {
+        Self {
+            db_connection_pool,
+            fullnode,
+        }
+    }
+
+    /// Read the given transactions from the database, in the same order. It is an error if any
+    /// of them is not indexed.
+    fn read_indexed_transactions(
+        &self,
+        digests: &[TransactionDigest],
+    ) -> RpcResult<Vec<SuiTransactionResponse>> {
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        let txns = read_transactions_by_digests(
+            &mut db_pool_conn,
+            digests.iter().map(|digest| digest.encode()).collect(),
+        )?;
+        let mut responses = HashMap::new();
+        for txn in txns {
+            if let Some(resp) = transaction_to_response(txn)? {
+                responses.insert(resp.certificate.transaction_digest, resp);
+            }
+        }
+        Ok(digests
+            .iter()
+            .map(|digest| {
+                responses.remove(digest).ok_or_else(|| {
+                    IndexerError::DbReadError(format!(
+                        "Transaction {:?} is not indexed",
+                        digest
+                    ))
+                })
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    /// Read a page of the objects of an owner. Type filters are applied to the rows read, so
+    /// more pages of rows are read until the page is full or the owner has no more objects.
+    fn read_owned_objects_page(
+        &self,
+        owner_type: &str,
+        owner: String,
+        object_type: Option<ObjectTypeFilter>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        let limit = cap_page_limit(limit);
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        let mut data = vec![];
+        let mut next_row = cursor.map(|cursor| cursor.to_string());
+        while data.len() <= limit {
+            let mut rows = read_owned_objects(
+                &mut db_pool_conn,
+                owner_type,
+                owner.clone(),
+                next_row,
+                limit + 1,
+            )?;
+            next_row = if rows.len() > limit {
+                rows.pop().map(|row| row.object_id)
+            } else {
+                None
+            };
+            for row in rows {
+                if matches_type_filter(&row, &object_type)? {
+                    data.push(row.to_object_info()?);
+                }
+            }
+            if next_row.is_none() {
+                break;
+            }
+        }
+        let next_cursor = data.get(limit).map(|info: &SuiObjectInfo| info.object_id);
+        data.truncate(limit);
+        Ok(ObjectsPage { data, next_cursor })
+    }
+
+    fn read_indexed_objects(
+        &self,
+        object_ids: &[ObjectID],
+    ) -> RpcResult<Vec<GetObjectDataResponse>> {
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        let mut rows: HashMap<String, Object> = read_objects(
+            &mut db_pool_conn,
+            object_ids.iter().map(|id| id.to_string()).collect(),
+        )?
+        .into_iter()
+        .map(|row| (row.object_id.clone(), row))
+        .collect();
+        Ok(object_ids
+            .iter()
+            .map(|id| match rows.remove(&id.to_string()) {
+                Some(row) => row.to_object_read(),
+                None => Ok(SuiObjectRead::NotExists(*id)),
+            })
+            .collect::<Result<_, _>>()?)
+    }
+}
+
+fn matches_type_filter(
+    row: &Object,
+    object_type: &Option<ObjectTypeFilter>,
+) -> Result<bool, IndexerError> {
+    let (filter, type_) = match (object_type, &row.object_type) {
+        (None, _) => return Ok(true),
+        (Some(filter), Some(type_)) => (filter, type_),
+        (Some(_), None) => return Ok(false),
+    };
+    if type_ == &ObjectType::Package.to_string() {
+        return Ok(filter.matches(&ObjectType::Package));
+    }
+    let tag = parse_sui_struct_tag(type_).map_err(|e| {
+        IndexerError::ObjectDeserializationError(format!(
+            "Failed parsing type {:?} of object {:?} with error: {:?}",
+            type_, row.object_id, e
+        ))
+    })?;
+    Ok(filter.matches(&ObjectType::Struct(tag)))
+}
+
+#[async_trait]
+impl RpcReadApiServer for ReadApi {
+    async fn get_objects_owned_by_address(
+        &self,
+        address: SuiAddress,
+        object_type: Option<ObjectTypeFilter>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        self.read_owned_objects_page(
+            ADDRESS_OWNER_TYPE,
+            address.to_string(),
+            object_type,
+            cursor,
+            limit,
+        )
+    }
+
+    async fn get_objects_owned_by_object(
+        &self,
+        object_id: ObjectID,
+        object_type: Option<ObjectTypeFilter>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        self.read_owned_objects_page(
+            OBJECT_OWNER_TYPE,
+            SuiAddress::from(object_id).to_string(),
+            object_type,
+            cursor,
+            limit,
+        )
+    }
+
+    async fn get_dynamic_fields(
+        &self,
+        parent_object_id: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<DynamicFieldPage> {
+        self.fullnode
+            .get_dynamic_fields(parent_object_id, cursor, limit)
+            .await
+    }
+
+    async fn get_total_transaction_number(&self) -> RpcResult<u64> {
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        Ok(count_transactions(&mut db_pool_conn)? as u64)
+    }
+
+    async fn get_transactions_in_range(
+        &self,
+        start: TxSequenceNumber,
+        end: TxSequenceNumber,
+    ) -> RpcResult<Vec<TransactionDigest>> {
+        if end <= start {
+            return Ok(vec![]);
+        }
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+        let digests =
+            read_transaction_digests_in_range(&mut db_pool_conn, start as i64, end as i64)?;
+        Ok(digests
+            .iter()
+            .map(|digest| parse_transaction_digest(digest))
+            .collect::<Result<_, _>>()?)
+    }
+
+    async fn get_transaction(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionResponse> {
+        Ok(self.read_indexed_transactions(&[digest])?.remove(0))
+    }
+
+    async fn multi_get_transactions(
+        &self,
+        digests: Vec<TransactionDigest>,
+    ) -> RpcResult<Vec<SuiTransactionResponse>> {
+        check_multi_get_size(digests.len())?;
+        self.read_indexed_transactions(&digests)
+    }
+
+    async fn get_transaction_auth_signers(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionAuthSignersResponse> {
+        self.fullnode.get_transaction_auth_signers(digest).await
+    }
+
+    async fn get_object(&self, object_id: ObjectID) -> RpcResult<GetObjectDataResponse> {
+        Ok(self.read_indexed_objects(&[object_id])?.remove(0))
+    }
+
+    async fn multi_get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>> {
+        check_multi_get_size(object_ids.len())?;
+        self.read_indexed_objects(&object_ids)
+    }
+
+    async fn get_dynamic_field_object(
+        &self,
+        parent_object_id: ObjectID,
+        name: String,
+    ) -> RpcResult<GetObjectDataResponse> {
+        self.fullnode
+            .get_dynamic_field_object(parent_object_id, name)
+            .await
+    }
+}
+
+impl SuiRpcModule for ReadApi {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+
+    fn rpc_doc_module() -> Module {
+        sui_json_rpc::api::RpcReadApiOpenRpc::module_doc()
+    }
+}
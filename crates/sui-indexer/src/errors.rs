@@ -1,6 +1,8 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use jsonrpsee::core::Error as RpcError;
+use jsonrpsee::types::error::CallError;
 use thiserror::Error;
 
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
@@ -8,43 +10,57 @@ pub enum IndexerError {
     #[error("Indexer failed to convert timestamp to NaiveDateTime with error: `{0}`")]
     DateTimeParsingError(String),
 
+    #[error("Indexer failed to run DB migrations with error: `{0}`")]
+    DbMigrationError(String),
+
     #[error("Indexer failed to deserialize event from events table with error: `{0}`")]
     EventDeserializationError(String),
 
     #[error("Indexer failed to read fullnode with error: `{0}`")]
     FullNodeReadingError(String),
 
+    #[error("Indexer failed to start JSON-RPC server with error: `{0}`")]
+    JsonRpcServerError(String),
+
     #[error("Indexer failed to convert structs to diesel Insertable with error: `{0}`")]
     InsertableParsingError(String),
 
+    #[error("Indexer failed to deserialize object from objects table with error: `{0}`")]
+    ObjectDeserializationError(String),
+
     #[error("Indexer failed to find object mutations, which should never happen.")]
     ObjectMutationNotAvailable,
 
-    #[error("Indexer failed to build PG connection pool with error: `{0}`")]
-    PgConnectionPoolInitError(String),
+    #[error("Indexer failed to build DB connection pool with error: `{0}`")]
+    DbConnectionPoolInitError(String),
 
-    #[error("Indexer failed to get a pool connection from PG connection pool with error: `{0}`")]
-    PgPoolConnectionError(String),
+    #[error("Indexer failed to get a pool connection from DB connection pool with error: `{0}`")]
+    DbPoolConnectionError(String),
 
-    #[error("Indexer failed to read PostgresDB with error: `{0}`")]
-    PostgresReadError(String),
+    #[error("Indexer failed to read from the database with error: `{0}`")]
+    DbReadError(String),
 
-    #[error("Indexer failed to commit changes to PostgresDB with error: `{0}`")]
-    PostgresWriteError(String),
+    #[error("Indexer failed to commit changes to the database with error: `{0}`")]
+    DbWriteError(String),
 
     #[error("Indexer failed to initialize fullnode RPC client with error: `{0}`")]
     RpcClientInitError(String),
 
     #[error("Indexer failed to parse transaction digest read from DB with error: `{0}`")]
     TransactionDigestParsingError(String),
+
+    #[error("Indexer failed to deserialize transaction from transactions table with error: `{0}`")]
+    TransactionDeserializationError(String),
 }
 
 impl IndexerError {
     pub fn name(&self) -> String {
+        // Names are persisted in error_logs, so variants renamed since support for SQLite was
+        // added keep their previous names.
         match self {
             IndexerError::FullNodeReadingError(_) => "FullNodeReadingError".into(),
-            IndexerError::PostgresReadError(_) => "PostgresReadError".into(),
-            IndexerError::PostgresWriteError(_) => "PostgresWriteError".into(),
+            IndexerError::DbReadError(_) => "PostgresReadError".into(),
+            IndexerError::DbWriteError(_) => "PostgresWriteError".into(),
             IndexerError::InsertableParsingError(_) => "InsertableParsingError".into(),
             IndexerError::DateTimeParsingError(_) => "DateTimeParsingError".into(),
             IndexerError::TransactionDigestParsingError(_) => {
@@ -52,9 +68,21 @@ impl IndexerError {
             }
             IndexerError::ObjectMutationNotAvailable => "ObjectMutationNotAvailable".into(),
             IndexerError::EventDeserializationError(_) => "EventDeserializationError".into(),
-            IndexerError::PgConnectionPoolInitError(_) => "PgConnectionPoolInitError".into(),
+            IndexerError::ObjectDeserializationError(_) => "ObjectDeserializationError".into(),
+            IndexerError::DbConnectionPoolInitError(_) => "PgConnectionPoolInitError".into(),
             IndexerError::RpcClientInitError(_) => "RpcClientInitError".into(),
-            IndexerError::PgPoolConnectionError(_) => "PgPoolConnectionError".into(),
+            IndexerError::DbPoolConnectionError(_) => "PgPoolConnectionError".into(),
+            IndexerError::TransactionDeserializationError(_) => {
+                "TransactionDeserializationError".into()
+            }
+            IndexerError::JsonRpcServerError(_) => "JsonRpcServerError".into(),
+            IndexerError::DbMigrationError(_) => "DbMigrationError".into(),
         }
     }
 }
+
+impl From<IndexerError> for RpcError {
+    fn from(e: IndexerError) -> Self {
+        RpcError::Call(CallError::Failed(e.into()))
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::future::try_join_all;
+use std::sync::Arc;
+use std::time::Duration;
+use sui_json_rpc::api::MULTI_GET_MAX_SIZE;
+use sui_json_rpc_types::{
+    SuiCheckpointContents, SuiCheckpointSummary, SuiGetPastObjectRequest, SuiObject, SuiParsedData,
+    SuiPastObjectRead, SuiTransactionResponse,
+};
+use sui_sdk::SuiClient;
+use sui_types::base_types::TransactionDigest;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use tokio::time::sleep;
+use tracing::info;
+
+use sui_indexer::errors::IndexerError;
+use sui_indexer::models::checkpoint_logs::read_checkpoint_log;
+use sui_indexer::models::checkpoints::commit_checkpoint;
+use sui_indexer::models::objects::object_writes;
+use sui_indexer::{get_db_pool_connection, DbConnectionPool};
+
+pub struct CheckpointHandler {
+    rpc_client: SuiClient,
+    db_connection_pool: Arc<DbConnectionPool>,
+}
+
+impl CheckpointHandler {
+    pub fn new(rpc_client: SuiClient, db_connection_pool: Arc<DbConnectionPool>) -> Self {
+        Self {
+            rpc_client,
+            db_connection_pool,
+        }
+    }
+
+    pub async fn start(&self) -> Result<(), IndexerError> {
+        info!("Indexer checkpoint handler started...");
+        let mut db_pool_conn = get_db_pool_connection(self.db_connection_pool.clone())?;
+
+        let checkpoint_log = read_checkpoint_log(&mut db_pool_conn)?;
+        let mut next_checkpoint = checkpoint_log.next_checkpoint_sequence_number as u64;
+
+        loop {
+            let latest_checkpoint = self.get_latest_checkpoint_sequence_number().await?;
+            while next_checkpoint <= latest_checkpoint {
+                let checkpoint = self.get_checkpoint_summary(next_checkpoint).await?;
+                let contents = self.get_checkpoint_contents(next_checkpoint).await?;
+                let tx_digests: Vec<TransactionDigest> = contents
+                    .transactions
+                    .iter()
+                    .map(|digests| digests.transaction)
+                    .collect();
+                let tx_resps = self.get_transaction_responses(tx_digests).await?;
+                let objects = self
+                    .get_past_objects(object_writes(&tx_resps).written)
+                    .await?;
+                // The whole checkpoint is committed at once, including the watermark,
+                // so that a restart resumes from the first checkpoint not fully indexed.
+                commit_checkpoint(&mut db_pool_conn, &checkpoint, tx_resps, objects)?;
+                next_checkpoint += 1;
+            }
+            sleep(Duration::from_secs_f32(0.1)).await;
+        }
+    }
+
+    async fn get_latest_checkpoint_sequence_number(
+        &self,
+    ) -> Result<CheckpointSequenceNumber, IndexerError> {
+        self.rpc_client
+            .read_api()
+            .get_latest_checkpoint_sequence_number()
+            .await
+            .map_err(|e| {
+                IndexerError::FullNodeReadingError(format!(
+                    "Failed reading latest checkpoint sequence number with err: {:?}",
+                    e
+                ))
+            })
+    }
+
+    async fn get_checkpoint_summary(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<SuiCheckpointSummary, IndexerError> {
+        self.rpc_client
+            .read_api()
+            .get_checkpoint_summary(sequence_number)
+            .await
+            .map_err(|e| {
+                IndexerError::FullNodeReadingError(format!(
+                    "Failed reading summary of checkpoint {:?} with err: {:?}",
+                    sequence_number, e
+                ))
+            })
+    }
+
+    async fn get_checkpoint_contents(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<SuiCheckpointContents, IndexerError> {
+        self.rpc_client
+            .read_api()
+            .get_checkpoint_contents(sequence_number)
+            .await
+            .map_err(|e| {
+                IndexerError::FullNodeReadingError(format!(
+                    "Failed reading contents of checkpoint {:?} with err: {:?}",
+                    sequence_number, e
+                ))
+            })
+    }
+
+    /// Fetch the responses of the given transactions, in the same order. The transactions of a
+    /// checkpoint must all be available on the fullnode.
+    async fn get_transaction_responses(
+        &self,
+        tx_digests: Vec<TransactionDigest>,
+    ) -> Result<Vec<SuiTransactionResponse>, IndexerError> {
+        let batches = try_join_all(tx_digests.chunks(MULTI_GET_MAX_SIZE).map(|batch| {
+            self.rpc_client
+                .read_api()
+                .multi_get_transactions(batch.to_vec())
+        }))
+        .await
+        .map_err(|e| {
+            IndexerError::FullNodeReadingError(format!(
+                "Failed reading transaction responses with err: {:?}",
+                e
+            ))
+        })?;
+        batches
+            .into_iter()
+            .flatten()
+            .map(|read| {
+                read.into_transaction().map_err(|e| {
+                    IndexerError::FullNodeReadingError(format!(
+                        "Failed reading transaction responses with err: {:?}",
+                        e
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Fetch the given objects at the requested versions, which must all still be available
+    /// on the fullnode.
+    async fn get_past_objects(
+        &self,
+        requests: Vec<SuiGetPastObjectRequest>,
+    ) -> Result<Vec<SuiObject<SuiParsedData>>, IndexerError> {
+        let batches = try_join_all(requests.chunks(MULTI_GET_MAX_SIZE).map(|batch| {
+            self.rpc_client
+                .read_api()
+                .try_multi_get_parsed_past_objects(batch.to_vec())
+        }))
+        .await
+        .map_err(|e| {
+            IndexerError::FullNodeReadingError(format!(
+                "Failed reading past objects with err: {:?}",
+                e
+            ))
+        })?;
+        batches
+            .into_iter()
+            .flatten()
+            .map(|object_read| match object_read {
+                SuiPastObjectRead::VersionFound(object) => Ok(object),
+                object_read => Err(IndexerError::FullNodeReadingError(format!(
+                    "Failed reading past object, got {:?}",
+                    object_read
+                ))),
+            })
+            .collect()
+    }
+}
@@ -1,6 +1,5 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-pub mod event_handler;
+pub mod checkpoint_handler;
 pub mod handler_orchestrator;
-pub mod transaction_handler;
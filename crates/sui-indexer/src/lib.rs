@@ -1,24 +1,104 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use std::net::SocketAddr;
 use std::sync::Arc;
+use sui_json_rpc::{JsonRpcServerBuilder, ServerHandle};
 use sui_sdk::SuiClient;
 
 use backoff::retry;
 use backoff::ExponentialBackoff;
+use diesel::connection::SimpleConnection;
 use diesel::pg::PgConnection;
 use diesel::prelude::*;
-use diesel::r2d2::{ConnectionManager, Pool, PooledConnection};
+use diesel::r2d2::{ConnectionManager, CustomizeConnection, Pool, PooledConnection};
+use diesel::sqlite::SqliteConnection;
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use jsonrpsee::http_client::HttpClientBuilder;
+use prometheus::Registry;
 use tracing::info;
 
+pub mod apis;
 pub mod errors;
 pub mod models;
 pub mod schema;
+pub mod types;
 pub mod utils;
 
 pub type PgConnectionPool = Pool<ConnectionManager<PgConnection>>;
 pub type PgPoolConnection = PooledConnection<ConnectionManager<PgConnection>>;
+pub type SqliteConnectionPool = Pool<ConnectionManager<SqliteConnection>>;
+pub type SqlitePoolConnection = PooledConnection<ConnectionManager<SqliteConnection>>;
 
+/// Database URLs with this prefix are opened as an embedded SQLite database at the path
+/// following it, any other URL is a Postgres connection URL.
+pub const SQLITE_URL_PREFIX: &str = "sqlite://";
+
+/// SQLite databases are embedded, so their schema is created and migrated by the indexer itself
+/// rather than with the diesel CLI.
+pub const SQLITE_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations/sqlite");
+
+#[derive(Clone)]
+pub enum DbConnectionPool {
+    Postgres(PgConnectionPool),
+    Sqlite(SqliteConnectionPool),
+}
+
+pub enum DbPoolConnection {
+    Postgres(PgPoolConnection),
+    Sqlite(SqlitePoolConnection),
+}
+
+/// Run the diesel query `$query` in a read-only transaction of a `DbPoolConnection`, with
+/// `$conn` bound to the connection of its backend, so that the query is checked against
+/// every supported backend.
+#[macro_export]
+macro_rules! read_only {
+    ($pool_conn:expr, |$conn:ident| $query:expr) => {
+        match $pool_conn {
+            $crate::DbPoolConnection::Postgres(pool_conn) => pool_conn
+                .build_transaction()
+                .read_only()
+                .run::<_, diesel::result::Error, _>(|$conn| $query),
+            $crate::DbPoolConnection::Sqlite(pool_conn) => {
+                $crate::sqlite_read_only_transaction(&mut **pool_conn, |$conn| $query)
+            }
+        }
+    };
+}
+
+/// SQLite has no read-only transactions, run `query` in a transaction of a connection that
+/// refuses writes while `query_only` is set instead. Used by `read_only!`.
+pub fn sqlite_read_only_transaction<T>(
+    conn: &mut SqliteConnection,
+    query: impl FnOnce(&mut SqliteConnection) -> Result<T, diesel::result::Error>,
+) -> Result<T, diesel::result::Error> {
+    conn.batch_execute("PRAGMA query_only = ON")?;
+    let result = conn.transaction(query);
+    // The connection goes back to the pool, it must accept writes again.
+    conn.batch_execute("PRAGMA query_only = OFF")?;
+    result
+}
+
+/// Same as `read_only!`, in a read-write transaction.
+#[macro_export]
+macro_rules! read_write {
+    ($pool_conn:expr, |$conn:ident| $query:expr) => {
+        match $pool_conn {
+            $crate::DbPoolConnection::Postgres(pool_conn) => pool_conn
+                .build_transaction()
+                .read_write()
+                .run::<_, diesel::result::Error, _>(|$conn| $query),
+            // Take the write lock upfront, instead of failing to upgrade a read lock when
+            // another connection is writing.
+            $crate::DbPoolConnection::Sqlite(pool_conn) => {
+                pool_conn.immediate_transaction::<_, diesel::result::Error, _>(|$conn| $query)
+            }
+        }
+    };
+}
+
+use apis::{CoinReadApi, EventReadApiImpl, ReadApi};
 use errors::IndexerError;
 
 pub async fn new_rpc_client(http_url: String) -> Result<SuiClient, IndexerError> {
@@ -37,29 +117,117 @@ pub fn establish_connection(db_url: String) -> PgConnection {
     PgConnection::establish(&db_url).unwrap_or_else(|_| panic!("Error connecting to {}", db_url))
 }
 
-pub async fn new_pg_connection_pool(db_url: String) -> Result<Arc<PgConnectionPool>, IndexerError> {
-    let manager = ConnectionManager::<PgConnection>::new(db_url);
-    // default connection pool max size is 10
-    let pool = Pool::builder().build(manager).map_err(|e| {
-        IndexerError::PgConnectionPoolInitError(format!(
+#[derive(Debug)]
+struct SqliteConnectionCustomizer;
+
+impl CustomizeConnection<SqliteConnection, diesel::r2d2::Error> for SqliteConnectionCustomizer {
+    fn on_acquire(&self, conn: &mut SqliteConnection) -> Result<(), diesel::r2d2::Error> {
+        // Handlers and processors write concurrently from different connections, wait for
+        // the lock instead of failing with SQLITE_BUSY, and let readers run during writes.
+        conn.batch_execute("PRAGMA busy_timeout = 5000; PRAGMA journal_mode = WAL;")
+            .map_err(diesel::r2d2::Error::QueryError)
+    }
+}
+
+pub async fn new_db_connection_pool(db_url: String) -> Result<Arc<DbConnectionPool>, IndexerError> {
+    let to_pool_error = |e: diesel::r2d2::PoolError| {
+        IndexerError::DbConnectionPoolInitError(format!(
             "Failed to initialize connection pool with error: {:?}",
             e
         ))
-    })?;
+    };
+    // default connection pool max size is 10
+    let pool = match db_url.strip_prefix(SQLITE_URL_PREFIX) {
+        Some(path) => {
+            let manager = ConnectionManager::<SqliteConnection>::new(path);
+            let pool = Pool::builder()
+                .connection_customizer(Box::new(SqliteConnectionCustomizer))
+                .build(manager)
+                .map_err(to_pool_error)?;
+            run_sqlite_migrations(&pool)?;
+            DbConnectionPool::Sqlite(pool)
+        }
+        None => {
+            let manager = ConnectionManager::<PgConnection>::new(db_url);
+            DbConnectionPool::Postgres(Pool::builder().build(manager).map_err(to_pool_error)?)
+        }
+    };
     Ok(Arc::new(pool))
 }
 
-pub fn get_pg_pool_connection(
-    pool: Arc<PgConnectionPool>,
-) -> Result<PgPoolConnection, IndexerError> {
+fn run_sqlite_migrations(pool: &SqliteConnectionPool) -> Result<(), IndexerError> {
+    let mut conn = pool.get().map_err(|e| {
+        IndexerError::DbPoolConnectionError(format!(
+            "Failed to get pool connection from SQLite connection pool with error: {:?}",
+            e
+        ))
+    })?;
+    let migrations = conn
+        .run_pending_migrations(SQLITE_MIGRATIONS)
+        .map_err(|e| {
+            IndexerError::DbMigrationError(format!(
+                "Failed to run SQLite migrations with error: {:?}",
+                e
+            ))
+        })?;
+    info!("Applied {} pending SQLite migrations", migrations.len());
+    Ok(())
+}
+
+pub fn get_db_pool_connection(
+    pool: Arc<DbConnectionPool>,
+) -> Result<DbPoolConnection, IndexerError> {
     retry(ExponentialBackoff::default(), || {
-        let pool_conn = pool.get()?;
+        let pool_conn = match pool.as_ref() {
+            DbConnectionPool::Postgres(pool) => DbPoolConnection::Postgres(pool.get()?),
+            DbConnectionPool::Sqlite(pool) => DbPoolConnection::Sqlite(pool.get()?),
+        };
         Ok(pool_conn)
     })
     .map_err(|e| {
-        IndexerError::PgPoolConnectionError(format!(
-            "Failed to get pool connection from PG connection pool with error: {:?}",
+        IndexerError::DbPoolConnectionError(format!(
+            "Failed to get pool connection from DB connection pool with error: {:?}",
             e
         ))
     })
 }
+
+/// Start a JSON-RPC server answering the read APIs from the indexer database, dynamic fields and
+/// transaction signers are not indexed and are proxied to the fullnode at `fullnode_url`.
+pub async fn build_json_rpc_server(
+    prometheus_registry: &Registry,
+    db_connection_pool: Arc<DbConnectionPool>,
+    fullnode_url: &str,
+    rpc_server_port: u16,
+) -> Result<ServerHandle, IndexerError> {
+    let to_server_error = |e: anyhow::Error| {
+        IndexerError::JsonRpcServerError(format!(
+            "Failed to start JSON-RPC server with error: {:?}",
+            e
+        ))
+    };
+    let fullnode = HttpClientBuilder::default()
+        .build(fullnode_url)
+        .map_err(|e| {
+            IndexerError::RpcClientInitError(format!(
+                "Failed to initialize fullnode RPC client with error: {:?}",
+                e
+            ))
+        })?;
+
+    let mut builder = JsonRpcServerBuilder::new(env!("CARGO_PKG_VERSION"), prometheus_registry)
+        .map_err(to_server_error)?;
+    builder
+        .register_module(ReadApi::new(db_connection_pool.clone(), fullnode.clone()))
+        .map_err(to_server_error)?;
+    builder
+        .register_module(CoinReadApi::new(db_connection_pool.clone()))
+        .map_err(to_server_error)?;
+    builder
+        .register_module(EventReadApiImpl::new(db_connection_pool))
+        .map_err(to_server_error)?;
+
+    let address = SocketAddr::from(([0, 0, 0, 0], rpc_server_port));
+    info!("Starting indexer JSON-RPC server on {}", address);
+    builder.start(address).await.map_err(to_server_error)
+}
@@ -3,11 +3,12 @@
 
 use std::env;
 use sui_indexer::errors::IndexerError;
-use sui_indexer::{new_pg_connection_pool, new_rpc_client};
+use sui_indexer::{build_json_rpc_server, new_db_connection_pool, new_rpc_client};
 
 use backoff::future::retry;
 use backoff::ExponentialBackoff;
 use futures::future::try_join_all;
+use prometheus::Registry;
 use tracing::info;
 
 use clap::Parser;
@@ -26,21 +27,35 @@ async fn main() -> Result<(), IndexerError> {
     info!("Sui indexer started...");
 
     let indexer_config = IndexerConfig::parse();
+    if indexer_config.rpc_server_worker {
+        let db_connection_pool = new_db_connection_pool(indexer_config.db_url.clone()).await?;
+        let registry = Registry::default();
+        let handle = build_json_rpc_server(
+            &registry,
+            db_connection_pool,
+            &indexer_config.rpc_client_url,
+            indexer_config.rpc_server_port,
+        )
+        .await?;
+        handle.stopped().await;
+        return Ok(());
+    }
+
     retry(ExponentialBackoff::default(), || async {
         let rpc_client = new_rpc_client(indexer_config.rpc_client_url.clone()).await?;
-        let pg_connection_pool = new_pg_connection_pool(indexer_config.db_url.clone()).await?;
-        // NOTE: Each handler is responsible for one type of data from nodes,like transactions and events;
+        let db_connection_pool = new_db_connection_pool(indexer_config.db_url.clone()).await?;
+        // NOTE: Handlers ingest data from the fullnode, by walking certified checkpoints in order;
         // Handler orchestrator runs these handlers in parallel and manage them upon errors etc.
         let handler_rpc_client = rpc_client.clone();
-        let handler_pg_pool = pg_connection_pool.clone();
+        let handler_db_pool = db_connection_pool.clone();
         let handler_handle = tokio::spawn(async move {
-            HandlerOrchestrator::new(handler_rpc_client, handler_pg_pool)
+            HandlerOrchestrator::new(handler_rpc_client, handler_db_pool)
                 .run_forever()
                 .await;
         });
 
         let processor_handle = tokio::spawn(async move {
-            ProcessorOrchestrator::new(rpc_client.clone(), pg_connection_pool.clone())
+            ProcessorOrchestrator::new(rpc_client.clone(), db_connection_pool.clone())
                 .run_forever()
                 .await;
         });
@@ -61,8 +76,14 @@ async fn main() -> Result<(), IndexerError> {
 )]
 
 struct IndexerConfig {
+    /// Postgres connection URL, or `sqlite://<path>` for an embedded SQLite database.
     #[clap(long)]
     db_url: String,
     #[clap(long)]
     rpc_client_url: String,
+    /// Serve the read JSON-RPC APIs from the database instead of indexing the fullnode.
+    #[clap(long)]
+    rpc_server_worker: bool,
+    #[clap(long, default_value = "3030")]
+    rpc_server_port: u16,
 }
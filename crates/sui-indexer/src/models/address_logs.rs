@@ -4,7 +4,8 @@
 use crate::errors::IndexerError;
 use crate::schema::address_logs;
 use crate::schema::address_logs::dsl::*;
-use crate::PgPoolConnection;
+use crate::DbPoolConnection;
+use crate::{read_only, read_write};
 use diesel::prelude::*;
 use diesel::result::Error;
 
@@ -14,32 +15,29 @@ pub struct AddressLog {
     pub last_processed_id: i64,
 }
 
-pub fn read_address_log(pg_pool_conn: &mut PgPoolConnection) -> Result<AddressLog, IndexerError> {
-    let addr_log_read_result: Result<AddressLog, Error> = pg_pool_conn
-        .build_transaction()
-        .read_only()
-        .run::<_, Error, _>(|conn| address_logs.limit(1).first::<AddressLog>(conn));
+pub fn read_address_log(db_pool_conn: &mut DbPoolConnection) -> Result<AddressLog, IndexerError> {
+    let addr_log_read_result: Result<AddressLog, Error> =
+        read_only!(db_pool_conn, |conn| address_logs
+            .limit(1)
+            .first::<AddressLog>(conn));
 
     addr_log_read_result.map_err(|e| {
-        IndexerError::PostgresReadError(format!("Failed reading address log with error: {:?}", e))
+        IndexerError::DbReadError(format!("Failed reading address log with error: {:?}", e))
     })
 }
 
 pub fn commit_address_log(
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     id: i64,
 ) -> Result<usize, IndexerError> {
-    let addr_log_commit_result: Result<usize, Error> = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
-            diesel::update(address_logs::table)
-                .set(last_processed_id.eq(id))
-                .execute(conn)
-        });
+    let addr_log_commit_result: Result<usize, Error> = read_write!(db_pool_conn, |conn| {
+        diesel::update(address_logs::table)
+            .set(last_processed_id.eq(id))
+            .execute(conn)
+    });
 
     addr_log_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
+        IndexerError::DbWriteError(format!(
             "Failed to commit address log with id: {:?} and error: {:?}",
             id, e
         ))
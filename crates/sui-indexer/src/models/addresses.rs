@@ -3,9 +3,10 @@
 
 use crate::errors::IndexerError;
 use crate::models::transactions::Transaction;
+use crate::read_write;
 use crate::schema::addresses;
 use crate::schema::addresses::dsl::*;
-use crate::PgPoolConnection;
+use crate::DbPoolConnection;
 
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
@@ -28,13 +29,10 @@ pub struct NewAddress {
 }
 
 pub fn commit_addresses(
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     new_addr_vec: Vec<NewAddress>,
 ) -> Result<usize, IndexerError> {
-    let addr_commit_result: Result<usize, Error> = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
+    let addr_commit_result: Result<usize, Error> = read_write!(db_pool_conn, |conn| {
         diesel::insert_into(addresses::table)
             .values(&new_addr_vec)
             .on_conflict(account_address)
@@ -43,8 +41,8 @@ pub fn commit_addresses(
     });
 
     addr_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
-            "Failed writing addresses to Postgres DB with addresses {:?} and error: {:?}",
+        IndexerError::DbWriteError(format!(
+            "Failed writing addresses to DB with addresses {:?} and error: {:?}",
             new_addr_vec, e
         ))
     })
@@ -0,0 +1,30 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::IndexerError;
+use crate::read_only;
+use crate::schema::checkpoint_logs::dsl::*;
+use crate::DbPoolConnection;
+
+use diesel::prelude::*;
+use diesel::result::Error;
+
+#[derive(Queryable, Debug, Identifiable)]
+#[diesel(primary_key(next_checkpoint_sequence_number))]
+pub struct CheckpointLog {
+    pub next_checkpoint_sequence_number: i64,
+}
+
+pub fn read_checkpoint_log(
+    db_pool_conn: &mut DbPoolConnection,
+) -> Result<CheckpointLog, IndexerError> {
+    // NOTE: always read one row, as checkpoint logs only have one row
+    let checkpoint_log_read_result: Result<CheckpointLog, Error> =
+        read_only!(db_pool_conn, |conn| checkpoint_logs
+            .limit(1)
+            .first::<CheckpointLog>(conn));
+
+    checkpoint_log_read_result.map_err(|e| {
+        IndexerError::DbReadError(format!("Failed reading checkpoint log with error {:?}", e))
+    })
+}
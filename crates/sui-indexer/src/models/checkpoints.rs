@@ -0,0 +1,216 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::IndexerError;
+use crate::models::events::{event_to_new_event, NewEvent};
+use crate::models::objects::{
+    object_changes_from_events, object_writes, sui_object_to_new_object, NewObject, Object,
+    ObjectChanges, DELETED_STATUS,
+};
+use crate::models::transactions::{transaction_response_to_new_transaction, NewTransaction};
+use crate::read_write;
+use crate::schema::checkpoint_logs::dsl::{
+    checkpoint_logs, next_checkpoint_sequence_number as next_checkpoint_column,
+};
+use crate::schema::objects::dsl::{
+    coin_balance as coin_balance_column, coin_type as coin_type_column,
+    initial_shared_version as initial_shared_version_column,
+    object_content as object_content_column, object_id as object_id_column,
+    object_status as object_status_column, object_type as object_type_column,
+    objects as objects_table, owner_address as owner_address_column,
+    owner_type as owner_type_column, version as version_column,
+};
+use crate::schema::{events, objects, transactions};
+use crate::DbPoolConnection;
+
+use diesel::prelude::*;
+use diesel::result::Error;
+use diesel::upsert::excluded;
+use std::collections::BTreeMap;
+use sui_json_rpc_types::{
+    SuiCheckpointSummary, SuiEventEnvelope, SuiObject, SuiParsedData, SuiTransactionResponse,
+};
+use sui_types::event::EventID;
+
+/// Commit the transactions of a checkpoint, together with their events and object changes,
+/// and advance the checkpoint watermark, all in one database transaction.
+/// `tx_resps` must be in the same order as the checkpoint contents, and `objects` must hold
+/// the objects listed by [`object_writes`] for them. Nothing is written if any of them fails
+/// to convert, so that the checkpoint is retried.
+pub fn commit_checkpoint(
+    db_pool_conn: &mut DbPoolConnection,
+    checkpoint: &SuiCheckpointSummary,
+    tx_resps: Vec<SuiTransactionResponse>,
+    objects: Vec<SuiObject<SuiParsedData>>,
+) -> Result<(), IndexerError> {
+    // Transactions are sequenced by their position since genesis, which is the same on
+    // every fullnode, unlike the fullnode-local sequence numbers of the event store.
+    let first_tx_seq = checkpoint.network_total_transactions - tx_resps.len() as u64;
+
+    let mut sui_events = vec![];
+    for (tx_index, tx_resp) in tx_resps.iter().enumerate() {
+        let tx_seq = (first_tx_seq + tx_index as u64) as i64;
+        for (event_index, event) in tx_resp.effects.events.iter().enumerate() {
+            sui_events.push(SuiEventEnvelope {
+                timestamp: tx_resp.timestamp_ms.unwrap_or_default(),
+                tx_digest: Some(tx_resp.certificate.transaction_digest),
+                id: EventID::from((tx_seq, event_index as i64)),
+                event: event.clone(),
+            });
+        }
+    }
+    let ObjectChanges {
+        new_objects,
+        object_transfers,
+        object_mutations,
+        object_deletions,
+    } = object_changes_from_events(sui_events.iter().map(|e| e.event.clone()).collect());
+
+    let removed_objects = object_writes(&tx_resps).removed;
+
+    // A checkpoint is committed whole or not at all, the watermark must not move past
+    // transactions, events or objects that failed to convert.
+    let new_txns = tx_resps
+        .into_iter()
+        .enumerate()
+        .map(|(tx_index, tx_resp)| {
+            transaction_response_to_new_transaction(
+                tx_resp,
+                (first_tx_seq + tx_index as u64) as i64,
+            )
+        })
+        .collect::<Result<Vec<NewTransaction>, _>>()?;
+    let new_events = sui_events
+        .into_iter()
+        .map(event_to_new_event)
+        .collect::<Result<Vec<NewEvent>, _>>()?;
+    let fetched_objects = objects
+        .iter()
+        .map(sui_object_to_new_object)
+        .collect::<Result<Vec<NewObject>, _>>()?;
+
+    let object_mutation_map = BTreeMap::from_iter(object_mutations.into_iter());
+    let mutated_object_ids: Vec<String> = object_mutation_map.keys().cloned().collect();
+    let next_checkpoint = checkpoint.sequence_number as i64 + 1;
+
+    let checkpoint_commit_result: Result<(), Error> = read_write!(db_pool_conn, |conn| {
+        if !new_txns.is_empty() {
+            diesel::insert_into(transactions::table)
+                .values(&new_txns)
+                .on_conflict(transactions::transaction_digest)
+                .do_nothing()
+                .execute(conn)?;
+        }
+        if !new_events.is_empty() {
+            diesel::insert_into(events::table)
+                .values(&new_events)
+                .on_conflict((events::transaction_sequence, events::event_sequence))
+                .do_nothing()
+                .execute(conn)?;
+        }
+        if !new_objects.is_empty() {
+            diesel::insert_into(objects::table)
+                .values(&new_objects)
+                .on_conflict(object_id_column)
+                .do_nothing()
+                .execute(conn)?;
+        }
+        if !object_transfers.is_empty() {
+            diesel::insert_into(objects::table)
+                .values(&object_transfers)
+                .on_conflict(object_id_column)
+                .do_nothing()
+                .execute(conn)?;
+        }
+        if !mutated_object_ids.is_empty() {
+            // Multi-row update is not supported by Diesel, see https://github.com/diesel-rs/diesel/discussions/2879
+            // As a work-around, read all mutated rows, update the columns and upsert them back.
+            let updated_new_objs: Vec<NewObject> = objects_table
+                .filter(object_id_column.eq_any(mutated_object_ids.clone()))
+                .load::<Object>(conn)?
+                .into_iter()
+                .map(|obj| {
+                    let (mut version, mut status) = (obj.version, obj.object_status);
+                    if let Some((new_version, new_status)) = object_mutation_map.get(&obj.object_id)
+                    {
+                        version = *new_version;
+                        status = new_status.clone();
+                    }
+                    NewObject {
+                        object_id: obj.object_id,
+                        version,
+                        owner_type: obj.owner_type,
+                        owner_address: obj.owner_address,
+                        initial_shared_version: obj.initial_shared_version,
+                        package_id: obj.package_id,
+                        transaction_module: obj.transaction_module,
+                        object_type: obj.object_type,
+                        object_status: status,
+                        object_content: obj.object_content,
+                        coin_type: obj.coin_type,
+                        coin_balance: obj.coin_balance,
+                    }
+                })
+                .collect();
+            diesel::insert_into(objects::table)
+                .values(&updated_new_objs)
+                .on_conflict(object_id_column)
+                .do_update()
+                .set((
+                    version_column.eq(excluded(version_column)),
+                    object_status_column.eq(excluded(object_status_column)),
+                ))
+                .execute(conn)?;
+        }
+        if !object_deletions.is_empty() {
+            diesel::update(
+                objects::table.filter(object_id_column.eq_any(object_deletions.clone())),
+            )
+            .set(object_status_column.eq(DELETED_STATUS.to_string()))
+            .execute(conn)?;
+        }
+        // Events do not carry the object contents nor every change, such as gas coin
+        // mutations, so the state of every object written by the checkpoint is stored as
+        // fetched. Status, package and module of indexed objects are left as set by events.
+        if !fetched_objects.is_empty() {
+            diesel::insert_into(objects::table)
+                .values(&fetched_objects)
+                .on_conflict(object_id_column)
+                .do_update()
+                .set((
+                    version_column.eq(excluded(version_column)),
+                    owner_type_column.eq(excluded(owner_type_column)),
+                    owner_address_column.eq(excluded(owner_address_column)),
+                    initial_shared_version_column.eq(excluded(initial_shared_version_column)),
+                    object_type_column.eq(excluded(object_type_column)),
+                    object_content_column.eq(excluded(object_content_column)),
+                    coin_type_column.eq(excluded(coin_type_column)),
+                    coin_balance_column.eq(excluded(coin_balance_column)),
+                ))
+                .execute(conn)?;
+        }
+        // Wrapped objects emit no event, and are not readable until unwrapped.
+        for removed in &removed_objects {
+            diesel::update(
+                objects::table.filter(object_id_column.eq(removed.object_id.to_string())),
+            )
+            .set((
+                object_status_column.eq(DELETED_STATUS.to_string()),
+                version_column.eq(removed.version.value() as i64),
+            ))
+            .execute(conn)?;
+        }
+
+        diesel::update(checkpoint_logs)
+            .set(next_checkpoint_column.eq(next_checkpoint))
+            .execute(conn)?;
+        Ok(())
+    });
+
+    checkpoint_commit_result.map_err(|e| {
+        IndexerError::DbWriteError(format!(
+            "Failed committing checkpoint {} with error: {:?}",
+            checkpoint.sequence_number, e
+        ))
+    })
+}
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use crate::read_write;
 use crate::schema::error_logs;
-use crate::PgPoolConnection;
+use crate::DbPoolConnection;
 
 use chrono::{NaiveDateTime, Utc};
 use diesel::prelude::*;
@@ -35,20 +36,17 @@ pub fn err_to_error_log(error: IndexerError) -> NewErrorLog {
 }
 
 pub fn commit_error_logs(
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     new_error_logs: Vec<NewErrorLog>,
 ) -> Result<usize, IndexerError> {
-    let error_commit_result: Result<usize, Error> = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
+    let error_commit_result: Result<usize, Error> = read_write!(db_pool_conn, |conn| {
         diesel::insert_into(error_logs::table)
             .values(&new_error_logs)
             .execute(conn)
     });
     error_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
-            "Failed writing error logs to PostgresDB with error logs  {:?} and error: {:?}",
+        IndexerError::DbWriteError(format!(
+            "Failed writing error logs to DB with error logs  {:?} and error: {:?}",
             new_error_logs, e
         ))
     })
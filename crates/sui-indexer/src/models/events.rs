@@ -4,14 +4,29 @@
 use crate::errors::IndexerError;
 use crate::schema::events;
 use crate::schema::events::dsl::{events as events_table, id};
-use crate::schema::events::{event_sequence, transaction_sequence};
-use crate::utils::log_errors_to_pg;
-use crate::PgPoolConnection;
+use crate::schema::events::{
+    event_sequence, event_time, event_type, module_name, move_event_name, object_id, package_id,
+    recipient, sender, transaction_digest, transaction_sequence,
+};
+use crate::utils::log_errors_to_db;
+use crate::DbPoolConnection;
+use crate::read_only;
 
 use chrono::NaiveDateTime;
+use diesel::backend::Backend;
+use diesel::dsl::sql;
 use diesel::prelude::*;
 use diesel::result::Error;
-use sui_json_rpc_types::{EventPage, SuiEvent, SuiEventEnvelope};
+use diesel::serialize::ToSql;
+use diesel::sql_types::{BigInt, Bool, Text, Timestamp};
+use fastcrypto::encoding::{Encoding, Hex};
+use sui_json_rpc_types::{SuiEvent, SuiEventEnvelope};
+use sui_types::base_types::TransactionDigest;
+use sui_types::event::{EventID, EventType};
+use sui_types::query::EventQuery;
+
+/// A filter on the events table, generic over the diesel backend it is run on.
+type EventFilter<DB> = Box<dyn BoxableExpression<events::table, DB, SqlType = Bool>>;
 
 #[derive(Queryable, Debug)]
 pub struct Event {
@@ -22,6 +37,12 @@ pub struct Event {
     pub event_time: Option<NaiveDateTime>,
     pub event_type: String,
     pub event_content: String,
+    pub package_id: Option<String>,
+    pub module_name: Option<String>,
+    pub move_event_name: Option<String>,
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    pub object_id: Option<String>,
 }
 
 #[derive(Debug, Insertable)]
@@ -33,25 +54,28 @@ pub struct NewEvent {
     pub event_time: Option<NaiveDateTime>,
     pub event_type: String,
     pub event_content: String,
+    pub package_id: Option<String>,
+    pub module_name: Option<String>,
+    pub move_event_name: Option<String>,
+    pub sender: Option<String>,
+    pub recipient: Option<String>,
+    pub object_id: Option<String>,
 }
 
 pub fn read_events(
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     last_processed_id: i64,
     limit: usize,
 ) -> Result<Vec<Event>, IndexerError> {
-    let event_read_result: Result<Vec<Event>, Error> = pg_pool_conn
-        .build_transaction()
-        .read_only()
-        .run::<_, Error, _>(|conn| {
-            events_table
-                .filter(id.gt(last_processed_id))
-                .limit(limit as i64)
-                .load::<Event>(conn)
-        });
+    let event_read_result: Result<Vec<Event>, Error> = read_only!(db_pool_conn, |conn| {
+        events_table
+            .filter(id.gt(last_processed_id))
+            .limit(limit as i64)
+            .load::<Event>(conn)
+    });
 
     event_read_result.map_err(|e| {
-        IndexerError::PostgresReadError(format!(
+        IndexerError::DbReadError(format!(
             "Failed reading events with last_processed_id {} and error: {:?}",
             last_processed_id, e
         ))
@@ -73,6 +97,19 @@ pub fn event_to_new_event(e: SuiEventEnvelope) -> Result<NewEvent, IndexerError>
             e.timestamp
         ))
     })?;
+    // Columns for event queries are extracted the same way as in the fullnode event store.
+    let event = sui_types::event::Event::try_from(e.event.clone()).map_err(|err| {
+        IndexerError::InsertableParsingError(format!(
+            "Failed converting event {:?} with error: {:?}",
+            e.event, err
+        ))
+    })?;
+    let recipient = event.recipient_serialized().map_err(|err| {
+        IndexerError::InsertableParsingError(format!(
+            "Failed serializing recipient of event {:?} with error: {:?}",
+            e.event, err
+        ))
+    })?;
     Ok(NewEvent {
         transaction_digest: e.tx_digest.map(|digest| digest.to_string()),
         transaction_sequence: e.id.tx_seq,
@@ -80,44 +117,17 @@ pub fn event_to_new_event(e: SuiEventEnvelope) -> Result<NewEvent, IndexerError>
         event_time: Some(timestamp),
         event_type: e.event.get_event_type(),
         event_content: event_json,
-    })
-}
-
-pub fn commit_events(
-    pg_pool_conn: &mut PgPoolConnection,
-    event_page: EventPage,
-) -> Result<usize, IndexerError> {
-    let events = event_page.data;
-    let mut errors = vec![];
-    let new_events: Vec<NewEvent> = events
-        .into_iter()
-        .map(event_to_new_event)
-        .filter_map(|r| r.map_err(|e| errors.push(e)).ok())
-        .collect();
-
-    log_errors_to_pg(pg_pool_conn, errors);
-
-    let event_commit_result: Result<usize, Error> = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
-        diesel::insert_into(events::table)
-            .values(&new_events)
-            .on_conflict((transaction_sequence, event_sequence))
-            .do_nothing()
-            .execute(conn)
-    });
-
-    event_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
-            "Failed writing events to PostgresDB with events {:?} and error: {:?}",
-            new_events, e
-        ))
+        package_id: event.package_id().map(|id| id.to_string()),
+        module_name: event.module_name().map(|name| name.to_string()),
+        move_event_name: event.move_event_name(),
+        sender: event.sender().map(|sender| sender.to_string()),
+        recipient,
+        object_id: event.object_id().map(|id| id.to_string()),
     })
 }
 
 pub fn events_to_sui_events(
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     events: Vec<Event>,
 ) -> Vec<SuiEvent> {
     let mut errors = vec![];
@@ -141,6 +151,167 @@ pub fn events_to_sui_events(
         })
         .collect();
 
-    log_errors_to_pg(pg_pool_conn, errors);
+    log_errors_to_db(db_pool_conn, errors);
     sui_events_to_process
 }
+
+/// Read a page of events matching `query`, starting from `cursor` inclusively.
+pub fn read_events_by_query(
+    db_pool_conn: &mut DbPoolConnection,
+    query: EventQuery,
+    cursor: Option<EventID>,
+    limit: usize,
+    descending: bool,
+) -> Result<Vec<Event>, IndexerError> {
+    let event_read_result: Result<Vec<Event>, Error> = read_only!(db_pool_conn, |conn| {
+        let mut boxed_query = events_table.into_boxed().filter(event_query_filter(&query));
+        if let Some(cursor) = cursor.clone() {
+            boxed_query = boxed_query.filter(cursor_filter(cursor, descending));
+        }
+        boxed_query = if descending {
+            boxed_query.order((transaction_sequence.desc(), event_sequence.desc()))
+        } else {
+            boxed_query.order((transaction_sequence.asc(), event_sequence.asc()))
+        };
+        boxed_query.limit(limit as i64).load::<Event>(conn)
+    });
+
+    event_read_result.map_err(|e| {
+        IndexerError::DbReadError(format!(
+            "Failed reading events with query {:?}, cursor {:?} and error: {:?}",
+            query, cursor, e
+        ))
+    })
+}
+
+fn cursor_filter<DB>(cursor: EventID, descending: bool) -> EventFilter<DB>
+where
+    DB: Backend + 'static,
+    i64: ToSql<BigInt, DB>,
+{
+    // (transaction_sequence, event_sequence) compared as a row value, so that the cursor
+    // is ordered the same way as the results.
+    if descending {
+        Box::new(
+            transaction_sequence
+                .lt(cursor.tx_seq)
+                .or(transaction_sequence
+                    .eq(cursor.tx_seq)
+                    .and(event_sequence.le(cursor.event_seq))),
+        )
+    } else {
+        Box::new(
+            transaction_sequence
+                .gt(cursor.tx_seq)
+                .or(transaction_sequence
+                    .eq(cursor.tx_seq)
+                    .and(event_sequence.ge(cursor.event_seq))),
+        )
+    }
+}
+
+fn event_query_filter<DB>(query: &EventQuery) -> EventFilter<DB>
+where
+    DB: Backend + 'static,
+    String: ToSql<Text, DB>,
+    NaiveDateTime: ToSql<Timestamp, DB>,
+{
+    match query {
+        EventQuery::All => Box::new(sql::<Bool>("TRUE")),
+        EventQuery::Transaction(digest) => {
+            Box::new(transaction_digest.assume_not_null().eq(digest.to_string()))
+        }
+        EventQuery::MoveModule { package, module } => Box::new(
+            package_id
+                .assume_not_null()
+                .eq(package.to_string())
+                .and(module_name.assume_not_null().eq(module.clone())),
+        ),
+        EventQuery::MoveEvent(move_event_struct_name) => Box::new(
+            move_event_name
+                .assume_not_null()
+                .eq(move_event_struct_name.clone()),
+        ),
+        EventQuery::EventType(type_) => Box::new(event_type.eq(event_type_name(type_).to_string())),
+        EventQuery::Sender(address) => Box::new(sender.assume_not_null().eq(address.to_string())),
+        EventQuery::Recipient(owner) => {
+            // Owner serialization cannot fail, it is a plain enum of IDs and versions.
+            let owner_json = serde_json::to_string(owner).unwrap_or_default();
+            Box::new(recipient.assume_not_null().eq(owner_json))
+        }
+        EventQuery::Object(id) => Box::new(object_id.assume_not_null().eq(id.to_string())),
+        EventQuery::TimeRange {
+            start_time,
+            end_time,
+        } => Box::new(
+            event_time
+                .assume_not_null()
+                .ge(millis_to_naive_date_time(*start_time))
+                .and(
+                    event_time
+                        .assume_not_null()
+                        .lt(millis_to_naive_date_time(*end_time)),
+                ),
+        ),
+        EventQuery::And(left, right) => {
+            Box::new(event_query_filter(left).and(event_query_filter(right)))
+        }
+        EventQuery::Or(left, right) => {
+            Box::new(event_query_filter(left).or(event_query_filter(right)))
+        }
+    }
+}
+
+// Same names as SuiEvent::get_event_type, which fills the event_type column.
+fn event_type_name(type_: &EventType) -> &'static str {
+    match type_ {
+        EventType::MoveEvent => "MoveEvent",
+        EventType::Publish => "Publish",
+        EventType::TransferObject => "TransferObject",
+        EventType::DeleteObject => "DeleteObject",
+        EventType::NewObject => "NewObject",
+        EventType::EpochChange => "EpochChange",
+        EventType::Checkpoint => "CheckPoint",
+        EventType::CoinBalanceChange => "CoinBalanceChange",
+        EventType::MutateObject => "MutateObject",
+    }
+}
+
+fn millis_to_naive_date_time(millis: u64) -> NaiveDateTime {
+    NaiveDateTime::from_timestamp_millis(millis as i64).unwrap_or(NaiveDateTime::MAX)
+}
+
+pub fn event_to_sui_event_envelope(event: Event) -> Result<SuiEventEnvelope, IndexerError> {
+    let sui_event: SuiEvent = serde_json::from_str(&event.event_content).map_err(|e| {
+        IndexerError::EventDeserializationError(format!(
+            "Failed deserializing event {:?} with error: {:?}",
+            event.event_content, e
+        ))
+    })?;
+    let tx_digest = event
+        .transaction_digest
+        .map(|digest| {
+            // Stored with the Display format of TransactionDigest, i.e. 0x prefixed hex.
+            Hex::decode(digest.trim_start_matches("0x"))
+                .map_err(|e| e.to_string())
+                .and_then(|bytes| {
+                    TransactionDigest::try_from(bytes.as_slice()).map_err(|e| e.to_string())
+                })
+                .map_err(|e| {
+                    IndexerError::TransactionDigestParsingError(format!(
+                        "Failed parsing transaction digest {:?} with error: {:?}",
+                        digest, e
+                    ))
+                })
+        })
+        .transpose()?;
+    Ok(SuiEventEnvelope {
+        timestamp: event
+            .event_time
+            .map(|time| time.timestamp_millis() as u64)
+            .unwrap_or_default(),
+        tx_digest,
+        id: EventID::from((event.transaction_sequence, event.event_sequence)),
+        event: sui_event,
+    })
+}
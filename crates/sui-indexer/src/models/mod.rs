@@ -3,12 +3,11 @@
 
 pub mod address_logs;
 pub mod addresses;
+pub mod checkpoint_logs;
+pub mod checkpoints;
 pub mod error_logs;
-pub mod event_logs;
 pub mod events;
-pub mod object_logs;
 pub mod objects;
 pub mod package_logs;
 pub mod packages;
-pub mod transaction_logs;
 pub mod transactions;
@@ -2,19 +2,27 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use crate::read_only;
 use crate::schema::objects;
 use crate::schema::objects::dsl::{
-    object_id as object_id_column, object_status as object_status_column, objects as objects_table,
-    version as version_column,
+    coin_balance, coin_type as coin_type_column, object_content, object_id as object_id_column,
+    object_status, object_type as object_type_column, objects as objects_table, owner_address,
+    owner_type as owner_type_column,
 };
-use crate::PgPoolConnection;
+use crate::DbPoolConnection;
 
-use diesel::pg::upsert::excluded;
 use diesel::prelude::*;
 use diesel::result::Error;
 use std::collections::BTreeMap;
-use sui_json_rpc_types::SuiEvent;
+use std::str::FromStr;
+use sui_json_rpc_types::{
+    Coin, GetObjectDataResponse, SuiEvent, SuiGetPastObjectRequest, SuiMoveStruct, SuiMoveValue,
+    SuiObject, SuiObjectInfo, SuiObjectRead, SuiObjectRef, SuiParsedData, SuiTransactionResponse,
+};
+use sui_types::base_types::{ObjectDigest, ObjectID, ObjectType, SequenceNumber};
+use sui_types::coin::Coin as MoveCoin;
 use sui_types::object::Owner;
+use sui_types::parse_sui_struct_tag;
 
 #[derive(Queryable, Debug, Identifiable)]
 #[diesel(primary_key(object_id))]
@@ -28,6 +36,9 @@ pub struct Object {
     pub transaction_module: String,
     pub object_type: Option<String>,
     pub object_status: String,
+    pub object_content: Option<String>,
+    pub coin_type: Option<String>,
+    pub coin_balance: Option<i64>,
 }
 
 #[derive(Insertable, Debug)]
@@ -42,163 +53,27 @@ pub struct NewObject {
     pub transaction_module: String,
     pub object_type: Option<String>,
     pub object_status: String,
+    pub object_content: Option<String>,
+    pub coin_type: Option<String>,
+    pub coin_balance: Option<i64>,
 }
 
-pub fn commit_new_objects(
-    pg_pool_conn: &mut PgPoolConnection,
-    new_objects: Vec<NewObject>,
-) -> Result<usize, IndexerError> {
-    if new_objects.is_empty() {
-        return Ok(0);
-    }
-    let new_obj_commit_result = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
-            diesel::insert_into(objects::table)
-                .values(&new_objects)
-                .on_conflict(object_id_column)
-                .do_nothing()
-                .execute(conn)
-        });
-
-    new_obj_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
-            "Failed inserting new objects into database with objects {:?} and error: {:?}",
-            new_objects, e
-        ))
-    })
-}
-
-pub fn commit_object_transfers(
-    pg_pool_conn: &mut PgPoolConnection,
-    object_transfers: Vec<NewObject>,
-) -> Result<usize, IndexerError> {
-    if object_transfers.is_empty() {
-        return Ok(0);
-    }
-
-    let obj_transfer_commit_result = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
-            diesel::insert_into(objects::table)
-                .values(&object_transfers)
-                .on_conflict(object_id_column)
-                .do_nothing()
-                .execute(conn)
-        });
-
-    obj_transfer_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
-            "Failed inserting object transfers into database with objects {:?} and error: {:?}",
-            object_transfers, e
-        ))
-    })
-}
-
-// Multi-row update is not supported by Diesel, see https://github.com/diesel-rs/diesel/discussions/2879
-// even though Postgres can do it via UPDATE ... FROM.
-// As a work-around, this function will read all related rows into memory, update the columns and upsert back to DB.
-pub fn commit_object_mutations(
-    pg_pool_conn: &mut PgPoolConnection,
-    object_mutations: Vec<(String, (i64, String))>,
-) -> Result<usize, IndexerError> {
-    if object_mutations.is_empty() {
-        return Ok(0);
-    }
-    let object_map = BTreeMap::from_iter(object_mutations.into_iter());
-    let object_ids: Vec<String> = object_map.keys().cloned().collect();
-
-    let obj_read_result: Result<Vec<Object>, Error> = pg_pool_conn
-        .build_transaction()
-        .read_only()
-        .run::<_, Error, _>(|conn| {
-        objects_table
-            .filter(object_id_column.eq_any(object_ids))
-            .load::<Object>(conn)
-    });
-
-    let objs = obj_read_result.map_err(|e| {
-        IndexerError::PostgresReadError(format!(
-            "Failed reading selected objects from database for object mutations with error: {:?}",
-            e
-        ))
-    })?;
-    let updated_new_objs: Vec<NewObject> = objs
-        .into_iter()
-        .map(|obj| {
-            let updates = object_map.get(&obj.object_id);
-            let (mut version, mut status) = (obj.version, obj.object_status);
-            if let Some((new_version, new_status)) = updates {
-                version = *new_version;
-                status = new_status.clone();
-            }
-            NewObject {
-                object_id: obj.object_id,
-                version,
-                owner_type: obj.owner_type,
-                owner_address: obj.owner_address,
-                initial_shared_version: obj.initial_shared_version,
-                package_id: obj.package_id,
-                transaction_module: obj.transaction_module,
-                object_type: obj.object_type,
-                object_status: status,
-            }
-        })
-        .collect();
-
-    let obj_mutation_commit_result = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
-            diesel::insert_into(objects::table)
-                .values(&updated_new_objs)
-                .on_conflict(object_id_column)
-                .do_update()
-                .set((
-                    version_column.eq(excluded(version_column)),
-                    object_status_column.eq(excluded(object_status_column)),
-                ))
-                .execute(conn)
-        });
-
-    obj_mutation_commit_result.map_err(|e| IndexerError::PostgresWriteError(
-        format!("Failed writing object mutations to Postgres DB with mutations updated_new_objs {:?} and error: {:?} ", updated_new_objs, e) ))
-}
-
-pub fn commit_object_deletions(
-    pg_pool_conn: &mut PgPoolConnection,
-    object_deletions: Vec<String>,
-) -> Result<usize, IndexerError> {
-    if object_deletions.is_empty() {
-        return Ok(0);
-    }
-
-    let obj_deletion_commit_result = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
-            diesel::update(objects::table.filter(object_id_column.eq_any(object_deletions.clone())))
-                .set(object_status_column.eq(DELETED_STATUS.to_string()))
-                .execute(conn)
-        });
-
-    obj_deletion_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
-            "Failed writing object deletions to Postgres DB with IDs {:?} and error: {:?} ",
-            object_deletions, e
-        ))
-    })
-}
+pub const ADDRESS_OWNER_TYPE: &str = "AddressOwner";
+pub const OBJECT_OWNER_TYPE: &str = "ObjectOwner";
 
 // return owner_type, owner_address and initial_shared_version
 fn owner_to_owner_info(owner: &Owner) -> (String, Option<String>, Option<i64>) {
     match owner {
-        Owner::AddressOwner(address) => {
-            ("AddressOwner".to_string(), Some(address.to_string()), None)
-        }
-        Owner::ObjectOwner(address) => ("ObjectOwner".to_string(), Some(address.to_string()), None),
+        Owner::AddressOwner(address) => (
+            ADDRESS_OWNER_TYPE.to_string(),
+            Some(address.to_string()),
+            None,
+        ),
+        Owner::ObjectOwner(address) => (
+            OBJECT_OWNER_TYPE.to_string(),
+            Some(address.to_string()),
+            None,
+        ),
         Owner::Shared {
             initial_shared_version,
         } => (
@@ -213,12 +88,20 @@ fn owner_to_owner_info(owner: &Owner) -> (String, Option<String>, Option<i64>) {
 const CREATED_STATUS: &str = "CREATED";
 const MUTATED_STATUS: &str = "MUTATED";
 const TRANSFERRED_STATUS: &str = "TRANSFERRED";
-const DELETED_STATUS: &str = "DELETED";
+pub const DELETED_STATUS: &str = "DELETED";
+
+/// Object changes derived from the events of a checkpoint, to be committed in the order
+/// of the fields: new objects, transfers, mutations and then deletions.
+#[derive(Debug, Default)]
+pub struct ObjectChanges {
+    pub new_objects: Vec<NewObject>,
+    pub object_transfers: Vec<NewObject>,
+    /// object ID -> (version, status)
+    pub object_mutations: Vec<(String, (i64, String))>,
+    pub object_deletions: Vec<String>,
+}
 
-pub fn commit_objects_from_events(
-    pg_pool_conn: &mut PgPoolConnection,
-    events: Vec<SuiEvent>,
-) -> Result<(), IndexerError> {
+pub fn object_changes_from_events(events: Vec<SuiEvent>) -> ObjectChanges {
     let mut new_objects = vec![];
     let mut object_transfers = vec![];
     let mut object_mutations = vec![];
@@ -247,6 +130,9 @@ pub fn commit_objects_from_events(
                     transaction_module,
                     object_type: Some(object_type),
                     object_status: CREATED_STATUS.to_string(),
+                    object_content: None,
+                    coin_type: None,
+                    coin_balance: None,
                 });
             }
             SuiEvent::TransferObject {
@@ -270,6 +156,9 @@ pub fn commit_objects_from_events(
                     transaction_module,
                     object_type: Some(object_type),
                     object_status: TRANSFERRED_STATUS.to_string(),
+                    object_content: None,
+                    coin_type: None,
+                    coin_balance: None,
                 });
             }
             SuiEvent::MutateObject {
@@ -286,10 +175,358 @@ pub fn commit_objects_from_events(
             _ => {}
         }
     }
-    commit_new_objects(pg_pool_conn, new_objects)?;
-    commit_object_transfers(pg_pool_conn, object_transfers)?;
-    commit_object_mutations(pg_pool_conn, object_mutations)?;
-    commit_object_deletions(pg_pool_conn, object_deletions)?;
+    ObjectChanges {
+        new_objects,
+        object_transfers,
+        object_mutations,
+        object_deletions,
+    }
+}
+
+/// Objects written or removed by the transactions of a checkpoint, only the last change of
+/// every object is kept.
+#[derive(Debug, Default)]
+pub struct ObjectWrites {
+    /// Objects to fetch at the version they were last written at.
+    pub written: Vec<SuiGetPastObjectRequest>,
+    /// Objects last deleted or wrapped, with the reference they were removed at.
+    pub removed: Vec<SuiObjectRef>,
+}
 
-    Ok(())
+pub fn object_writes(tx_resps: &[SuiTransactionResponse]) -> ObjectWrites {
+    let mut last_changes = BTreeMap::new();
+    for effects in tx_resps.iter().map(|tx_resp| &tx_resp.effects) {
+        for written in effects
+            .created
+            .iter()
+            .chain(&effects.mutated)
+            .chain(&effects.unwrapped)
+        {
+            last_changes.insert(
+                written.reference.object_id,
+                (true, written.reference.clone()),
+            );
+        }
+        for removed in effects.deleted.iter().chain(&effects.wrapped) {
+            last_changes.insert(removed.object_id, (false, removed.clone()));
+        }
+    }
+    let mut writes = ObjectWrites::default();
+    for (is_written, object_ref) in last_changes.into_values() {
+        if is_written {
+            writes.written.push(SuiGetPastObjectRequest {
+                object_id: object_ref.object_id,
+                version: object_ref.version,
+            });
+        } else {
+            writes.removed.push(object_ref);
+        }
+    }
+    writes
+}
+
+/// Convert an object fetched from the fullnode. Objects that are not indexed yet, such as
+/// genesis objects which have no creation event, are attributed to the module of their type.
+pub fn sui_object_to_new_object(
+    object: &SuiObject<SuiParsedData>,
+) -> Result<NewObject, IndexerError> {
+    let object_json = serde_json::to_string(object).map_err(|err| {
+        IndexerError::InsertableParsingError(format!(
+            "Failed converting object {:?} to JSON with error: {:?}",
+            object.reference, err
+        ))
+    })?;
+    let (owner_type, owner_address, initial_shared_version) = owner_to_owner_info(&object.owner);
+    let (package_id, transaction_module, object_type, coin) = match &object.data {
+        SuiParsedData::MoveObject(move_object) => {
+            let tag = parse_sui_struct_tag(&move_object.type_).map_err(|err| {
+                IndexerError::InsertableParsingError(format!(
+                    "Failed parsing type {:?} of object {:?} with error: {:?}",
+                    move_object.type_, object.reference, err
+                ))
+            })?;
+            let coin = if MoveCoin::is_coin(&tag) {
+                tag.type_params
+                    .first()
+                    .map(|coin_type| coin_type.to_string())
+                    .zip(coin_balance_field(&move_object.fields))
+            } else {
+                None
+            };
+            (
+                ObjectID::from(tag.address).to_string(),
+                tag.module.to_string(),
+                move_object.type_.clone(),
+                coin,
+            )
+        }
+        SuiParsedData::Package(_) => (
+            object.reference.object_id.to_string(),
+            String::new(),
+            ObjectType::Package.to_string(),
+            None,
+        ),
+    };
+    let (coin_type, balance) = match coin {
+        Some((coin_type, balance)) => (Some(coin_type), Some(balance)),
+        None => (None, None),
+    };
+    Ok(NewObject {
+        object_id: object.reference.object_id.to_string(),
+        version: object.reference.version.value() as i64,
+        owner_type,
+        owner_address,
+        initial_shared_version,
+        package_id,
+        transaction_module,
+        object_type: Some(object_type),
+        object_status: MUTATED_STATUS.to_string(),
+        object_content: Some(object_json),
+        coin_type,
+        // u64 to i64 casts round-trip, balances are never compared in SQL.
+        coin_balance: balance.map(|balance| balance as i64),
+    })
+}
+
+fn coin_balance_field(fields: &SuiMoveStruct) -> Option<u64> {
+    match fields {
+        SuiMoveStruct::WithFields(fields) | SuiMoveStruct::WithTypes { type_: _, fields } => {
+            match fields.get("balance")? {
+                SuiMoveValue::String(balance) => balance.parse().ok(),
+                SuiMoveValue::Number(balance) => Some(*balance),
+                _ => None,
+            }
+        }
+        SuiMoveStruct::Runtime(_) => None,
+    }
+}
+
+impl Object {
+    fn parse_object_id(&self) -> Result<ObjectID, IndexerError> {
+        ObjectID::from_str(&self.object_id).map_err(|e| {
+            IndexerError::ObjectDeserializationError(format!(
+                "Failed parsing object ID {:?} with error: {:?}",
+                self.object_id, e
+            ))
+        })
+    }
+
+    /// The object as fetched from the fullnode, None if it was not fetched.
+    pub fn sui_object(&self) -> Result<Option<SuiObject<SuiParsedData>>, IndexerError> {
+        self.object_content
+            .as_ref()
+            .map(|object_json| {
+                serde_json::from_str(object_json).map_err(|e| {
+                    IndexerError::ObjectDeserializationError(format!(
+                        "Failed deserializing object {:?} with error: {:?}",
+                        self.object_id, e
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    pub fn to_object_read(&self) -> Result<GetObjectDataResponse, IndexerError> {
+        if self.object_status == DELETED_STATUS {
+            return Ok(SuiObjectRead::Deleted(SuiObjectRef {
+                object_id: self.parse_object_id()?,
+                version: SequenceNumber::from_u64(self.version as u64),
+                digest: ObjectDigest::OBJECT_DIGEST_DELETED,
+            }));
+        }
+        Ok(match self.sui_object()? {
+            Some(object) => SuiObjectRead::Exists(object),
+            None => SuiObjectRead::NotExists(self.parse_object_id()?),
+        })
+    }
+
+    pub fn to_object_info(&self) -> Result<SuiObjectInfo, IndexerError> {
+        let object = self.fetched_object()?;
+        let type_ = match &object.data {
+            SuiParsedData::MoveObject(move_object) => move_object.type_.clone(),
+            SuiParsedData::Package(_) => ObjectType::Package.to_string(),
+        };
+        Ok(SuiObjectInfo {
+            object_id: object.reference.object_id,
+            version: object.reference.version,
+            digest: object.reference.digest,
+            type_,
+            owner: object.owner,
+            previous_transaction: object.previous_transaction,
+        })
+    }
+
+    pub fn to_coin(&self) -> Result<Coin, IndexerError> {
+        let object = self.fetched_object()?;
+        match (&self.coin_type, self.coin_balance) {
+            (Some(coin_type), Some(balance)) => Ok(Coin {
+                coin_type: coin_type.clone(),
+                coin_object_id: object.reference.object_id,
+                version: object.reference.version,
+                digest: object.reference.digest,
+                balance: balance as u64,
+            }),
+            _ => Err(IndexerError::ObjectDeserializationError(format!(
+                "Object {:?} is not a coin",
+                self.object_id
+            ))),
+        }
+    }
+
+    fn fetched_object(&self) -> Result<SuiObject<SuiParsedData>, IndexerError> {
+        self.sui_object()?.ok_or_else(|| {
+            IndexerError::ObjectDeserializationError(format!(
+                "Object {:?} was not fetched",
+                self.object_id
+            ))
+        })
+    }
+}
+
+pub fn read_objects(
+    db_pool_conn: &mut DbPoolConnection,
+    object_ids: Vec<String>,
+) -> Result<Vec<Object>, IndexerError> {
+    let object_read_result: Result<Vec<Object>, Error> = read_only!(db_pool_conn, |conn| {
+        objects_table
+            .filter(object_id_column.eq_any(object_ids.clone()))
+            .load::<Object>(conn)
+    });
+
+    object_read_result.map_err(|e| {
+        IndexerError::DbReadError(format!(
+            "Failed reading objects {:?} with error: {:?}",
+            object_ids, e
+        ))
+    })
+}
+
+/// Read a page of the live objects of an owner, ordered by object ID and starting from
+/// `cursor` inclusively.
+pub fn read_owned_objects(
+    db_pool_conn: &mut DbPoolConnection,
+    owner_type: &str,
+    owner: String,
+    cursor: Option<String>,
+    limit: usize,
+) -> Result<Vec<Object>, IndexerError> {
+    let object_read_result: Result<Vec<Object>, Error> = read_only!(db_pool_conn, |conn| {
+        let mut boxed_query = objects_table
+            .into_boxed()
+            .filter(owner_type_column.eq(owner_type))
+            .filter(owner_address.eq(owner.clone()))
+            .filter(object_status.ne(DELETED_STATUS))
+            .filter(object_content.is_not_null());
+        if let Some(cursor) = cursor.clone() {
+            boxed_query = boxed_query.filter(object_id_column.ge(cursor));
+        }
+        boxed_query
+            .order(object_id_column.asc())
+            .limit(limit as i64)
+            .load::<Object>(conn)
+    });
+
+    object_read_result.map_err(|e| {
+        IndexerError::DbReadError(format!(
+            "Failed reading objects owned by {} with cursor {:?} and error: {:?}",
+            owner, cursor, e
+        ))
+    })
+}
+
+/// Read a page of the coins of an address, of any type if `coin_type` is None, ordered by
+/// object ID and starting from `cursor` inclusively.
+pub fn read_coins(
+    db_pool_conn: &mut DbPoolConnection,
+    owner: String,
+    coin_type: Option<String>,
+    cursor: Option<String>,
+    limit: usize,
+) -> Result<Vec<Object>, IndexerError> {
+    let coin_read_result: Result<Vec<Object>, Error> = read_only!(db_pool_conn, |conn| {
+        let mut boxed_query = objects_table
+            .into_boxed()
+            .filter(owner_type_column.eq(ADDRESS_OWNER_TYPE))
+            .filter(owner_address.eq(owner.clone()))
+            .filter(object_status.ne(DELETED_STATUS))
+            .filter(coin_type_column.is_not_null());
+        if let Some(coin_type) = coin_type.clone() {
+            boxed_query = boxed_query.filter(coin_type_column.eq(coin_type));
+        }
+        if let Some(cursor) = cursor.clone() {
+            boxed_query = boxed_query.filter(object_id_column.ge(cursor));
+        }
+        boxed_query
+            .order(object_id_column.asc())
+            .limit(limit as i64)
+            .load::<Object>(conn)
+    });
+
+    coin_read_result.map_err(|e| {
+        IndexerError::DbReadError(format!(
+            "Failed reading coins of type {:?} owned by {} with cursor {:?} and error: {:?}",
+            coin_type, owner, cursor, e
+        ))
+    })
+}
+
+/// Read the type and balance of every coin of an address, of any type if `coin_type` is None.
+pub fn read_coin_balances(
+    db_pool_conn: &mut DbPoolConnection,
+    owner: String,
+    coin_type: Option<String>,
+) -> Result<Vec<(String, u64)>, IndexerError> {
+    let balance_read_result: Result<Vec<(String, i64)>, Error> = read_only!(db_pool_conn, |conn| {
+        let mut boxed_query = objects_table
+            .into_boxed()
+            .select((
+                coin_type_column.assume_not_null(),
+                coin_balance.assume_not_null(),
+            ))
+            .filter(owner_type_column.eq(ADDRESS_OWNER_TYPE))
+            .filter(owner_address.eq(owner.clone()))
+            .filter(object_status.ne(DELETED_STATUS))
+            .filter(coin_type_column.is_not_null());
+        if let Some(coin_type) = coin_type.clone() {
+            boxed_query = boxed_query.filter(coin_type_column.eq(coin_type));
+        }
+        boxed_query.load::<(String, i64)>(conn)
+    });
+
+    balance_read_result
+        .map(|balances| {
+            balances
+                .into_iter()
+                .map(|(coin_type, balance)| (coin_type, balance as u64))
+                .collect()
+        })
+        .map_err(|e| {
+            IndexerError::DbReadError(format!(
+                "Failed reading balances of type {:?} owned by {} with error: {:?}",
+                coin_type, owner, e
+            ))
+        })
+}
+
+/// Read a live object of the given type, for types with a single instance such as coin
+/// metadata.
+pub fn read_object_by_type(
+    db_pool_conn: &mut DbPoolConnection,
+    object_type: String,
+) -> Result<Option<Object>, IndexerError> {
+    let object_read_result: Result<Option<Object>, Error> = read_only!(db_pool_conn, |conn| {
+        objects_table
+            .filter(object_type_column.eq(object_type.clone()))
+            .filter(object_status.ne(DELETED_STATUS))
+            .filter(object_content.is_not_null())
+            .first::<Object>(conn)
+            .optional()
+    });
+
+    object_read_result.map_err(|e| {
+        IndexerError::DbReadError(format!(
+            "Failed reading object of type {} with error: {:?}",
+            object_type, e
+        ))
+    })
 }
@@ -4,7 +4,8 @@
 use crate::errors::IndexerError;
 use crate::schema::package_logs;
 use crate::schema::package_logs::dsl::*;
-use crate::PgPoolConnection;
+use crate::DbPoolConnection;
+use crate::{read_only, read_write};
 use diesel::prelude::*;
 use diesel::result::Error;
 
@@ -14,32 +15,29 @@ pub struct PackageLog {
     pub last_processed_id: i64,
 }
 
-pub fn read_package_log(pg_pool_conn: &mut PgPoolConnection) -> Result<PackageLog, IndexerError> {
-    let pkg_log_read_result: Result<PackageLog, Error> = pg_pool_conn
-        .build_transaction()
-        .read_only()
-        .run::<_, Error, _>(|conn| package_logs.limit(1).first::<PackageLog>(conn));
+pub fn read_package_log(db_pool_conn: &mut DbPoolConnection) -> Result<PackageLog, IndexerError> {
+    let pkg_log_read_result: Result<PackageLog, Error> =
+        read_only!(db_pool_conn, |conn| package_logs
+            .limit(1)
+            .first::<PackageLog>(conn));
 
     pkg_log_read_result.map_err(|e| {
-        IndexerError::PostgresReadError(format!("Failed reading package log with error {:?}", e))
+        IndexerError::DbReadError(format!("Failed reading package log with error {:?}", e))
     })
 }
 
 pub fn commit_package_log(
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     id: i64,
 ) -> Result<usize, IndexerError> {
-    let pkg_log_commit_result: Result<usize, Error> = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
-            diesel::update(package_logs::table)
-                .set(last_processed_id.eq(id))
-                .execute(conn)
-        });
+    let pkg_log_commit_result: Result<usize, Error> = read_write!(db_pool_conn, |conn| {
+        diesel::update(package_logs::table)
+            .set(last_processed_id.eq(id))
+            .execute(conn)
+    });
 
     pkg_log_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
+        IndexerError::DbWriteError(format!(
             "Failed to commit package log with id {:?} and error {:?}",
             id, e
         ))
@@ -2,17 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::errors::IndexerError;
+use crate::read_write;
 use crate::schema::packages;
 use crate::schema::packages::dsl::{
     author as author_column, module_names as module_names_column,
     package_content as package_content_column, package_id as package_id_column,
 };
-use crate::utils::log_errors_to_pg;
-use crate::PgPoolConnection;
+use crate::types::StringArray;
+use crate::utils::log_errors_to_db;
+use crate::DbPoolConnection;
 
-use diesel::pg::upsert::excluded;
 use diesel::prelude::*;
 use diesel::result::Error;
+use diesel::upsert::excluded;
 use futures::future::join_all;
 use sui_json_rpc_types::SuiEvent;
 use sui_sdk::SuiClient;
@@ -23,7 +25,7 @@ use sui_types::base_types::{ObjectID, SuiAddress};
 pub struct Package {
     pub package_id: String,
     pub author: String,
-    pub module_names: Vec<Option<String>>,
+    pub module_names: StringArray,
     pub package_content: String,
 }
 
@@ -32,13 +34,13 @@ pub struct Package {
 pub struct NewPackage {
     pub package_id: String,
     pub author: String,
-    pub module_names: Vec<Option<String>>,
+    pub module_names: StringArray,
     pub package_content: String,
 }
 
 pub async fn commit_packages_from_events(
     rpc_client: SuiClient,
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     events: Vec<SuiEvent>,
 ) -> Result<usize, IndexerError> {
     let sender_pkg_pair_iter = events.into_iter().filter_map(|event| match event {
@@ -59,22 +61,19 @@ pub async fn commit_packages_from_events(
         .filter_map(|f| f.map_err(|e| errors.push(e)).ok())
         .collect();
 
-    log_errors_to_pg(pg_pool_conn, errors);
-    commit_new_packages(pg_pool_conn, new_pkgs)
+    log_errors_to_db(db_pool_conn, errors);
+    commit_new_packages(db_pool_conn, new_pkgs)
 }
 
 fn commit_new_packages(
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     new_pkgs: Vec<NewPackage>,
 ) -> Result<usize, IndexerError> {
     if new_pkgs.is_empty() {
         return Ok(0);
     }
 
-    let pkg_commit_result: Result<usize, Error> = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
+    let pkg_commit_result: Result<usize, Error> = read_write!(db_pool_conn, |conn| {
         diesel::insert_into(packages::table)
             .values(&new_pkgs)
             .on_conflict(package_id_column)
@@ -88,7 +87,7 @@ fn commit_new_packages(
     });
 
     pkg_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
+        IndexerError::DbWriteError(format!(
             "Failed writing or updating packages {:?} with error: {:?}",
             new_pkgs, e
         ))
@@ -110,7 +109,7 @@ async fn generate_new_package(
                 package, e
             ))
         })?;
-    let module_names: Vec<Option<String>> = pkg_module_map.keys().cloned().map(Some).collect();
+    let module_names = StringArray(pkg_module_map.keys().cloned().map(Some).collect());
     let pkg_module_map_json = serde_json::to_string(&pkg_module_map).map_err(|err| {
         IndexerError::InsertableParsingError(format!(
             "Failed converting package module map to JSON with error: {:?}",
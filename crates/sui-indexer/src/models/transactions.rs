@@ -3,16 +3,22 @@
 
 use crate::schema::transactions;
 use crate::schema::transactions::dsl::{id, transactions as transactions_table};
-use crate::utils::log_errors_to_pg;
 
 use chrono::NaiveDateTime;
 use diesel::prelude::*;
 use diesel::result::Error;
-use sui_json_rpc_types::{OwnedObjectRef, SuiObjectRef, SuiTransactionResponse};
+use fastcrypto::encoding::{Base64, Encoding};
+use sui_json_rpc_types::{
+    OwnedObjectRef, SuiCertifiedTransaction, SuiObjectRef, SuiTransactionEffects,
+    SuiTransactionResponse,
+};
+use sui_types::base_types::TransactionDigest;
 
 use crate::errors::IndexerError;
-use crate::schema::transactions::transaction_digest;
-use crate::PgPoolConnection;
+use crate::schema::transactions::{transaction_digest, transaction_sequence};
+use crate::types::StringArray;
+use crate::DbPoolConnection;
+use crate::read_only;
 
 #[derive(Debug, Queryable)]
 pub struct Transaction {
@@ -20,12 +26,12 @@ pub struct Transaction {
     pub transaction_digest: String,
     pub sender: String,
     pub transaction_time: Option<NaiveDateTime>,
-    pub transaction_kinds: Vec<Option<String>>,
-    pub created: Vec<Option<String>>,
-    pub mutated: Vec<Option<String>>,
-    pub deleted: Vec<Option<String>>,
-    pub unwrapped: Vec<Option<String>>,
-    pub wrapped: Vec<Option<String>>,
+    pub transaction_kinds: StringArray,
+    pub created: StringArray,
+    pub mutated: StringArray,
+    pub deleted: StringArray,
+    pub unwrapped: StringArray,
+    pub wrapped: StringArray,
     pub gas_object_id: String,
     pub gas_object_sequence: i64,
     pub gas_object_digest: String,
@@ -35,6 +41,8 @@ pub struct Transaction {
     pub storage_cost: i64,
     pub storage_rebate: i64,
     pub transaction_content: String,
+    pub transaction_effects_content: Option<String>,
+    pub transaction_sequence: i64,
 }
 
 #[derive(Debug, Insertable)]
@@ -43,12 +51,12 @@ pub struct NewTransaction {
     pub transaction_digest: String,
     pub sender: String,
     pub transaction_time: Option<NaiveDateTime>,
-    pub transaction_kinds: Vec<Option<String>>,
-    pub created: Vec<Option<String>>,
-    pub mutated: Vec<Option<String>>,
-    pub deleted: Vec<Option<String>>,
-    pub unwrapped: Vec<Option<String>>,
-    pub wrapped: Vec<Option<String>>,
+    pub transaction_kinds: StringArray,
+    pub created: StringArray,
+    pub mutated: StringArray,
+    pub deleted: StringArray,
+    pub unwrapped: StringArray,
+    pub wrapped: StringArray,
     pub gas_object_id: String,
     pub gas_object_sequence: i64,
     pub gas_object_digest: String,
@@ -58,66 +66,85 @@ pub struct NewTransaction {
     pub storage_cost: i64,
     pub storage_rebate: i64,
     pub transaction_content: String,
+    pub transaction_effects_content: Option<String>,
+    pub transaction_sequence: i64,
 }
 
 pub fn read_transactions(
-    pg_pool_conn: &mut PgPoolConnection,
+    db_pool_conn: &mut DbPoolConnection,
     last_processed_id: i64,
     limit: usize,
 ) -> Result<Vec<Transaction>, IndexerError> {
-    let txn_read_result: Result<Vec<Transaction>, Error> = pg_pool_conn
-        .build_transaction()
-        .read_only()
-        .run::<_, Error, _>(|conn| {
-            transactions_table
-                .filter(id.gt(last_processed_id))
-                .limit(limit as i64)
-                .load::<Transaction>(conn)
-        });
+    let txn_read_result: Result<Vec<Transaction>, Error> = read_only!(db_pool_conn, |conn| {
+        transactions_table
+            .filter(id.gt(last_processed_id))
+            .limit(limit as i64)
+            .load::<Transaction>(conn)
+    });
 
     txn_read_result.map_err(|e| {
-        IndexerError::PostgresReadError(format!(
+        IndexerError::DbReadError(format!(
             "Failed reading transactions with last_processed_id {} and err: {:?}",
             last_processed_id, e
         ))
     })
 }
 
-pub fn commit_transactions(
-    pg_pool_conn: &mut PgPoolConnection,
-    tx_resps: Vec<SuiTransactionResponse>,
-) -> Result<usize, IndexerError> {
-    let new_txn_iter = tx_resps
-        .into_iter()
-        .map(transaction_response_to_new_transaction);
+pub fn read_transactions_by_digests(
+    db_pool_conn: &mut DbPoolConnection,
+    digests: Vec<String>,
+) -> Result<Vec<Transaction>, IndexerError> {
+    let txn_read_result: Result<Vec<Transaction>, Error> = read_only!(db_pool_conn, |conn| {
+        transactions_table
+            .filter(transaction_digest.eq_any(digests.clone()))
+            .load::<Transaction>(conn)
+    });
 
-    let mut errors = vec![];
-    let new_txns: Vec<NewTransaction> = new_txn_iter
-        .filter_map(|r| r.map_err(|e| errors.push(e)).ok())
-        .collect();
-    log_errors_to_pg(pg_pool_conn, errors);
-
-    let txn_commit_result: Result<usize, Error> = pg_pool_conn
-        .build_transaction()
-        .read_write()
-        .run::<_, Error, _>(|conn| {
-        diesel::insert_into(transactions::table)
-            .values(&new_txns)
-            .on_conflict(transaction_digest)
-            .do_nothing()
-            .execute(conn)
+    txn_read_result.map_err(|e| {
+        IndexerError::DbReadError(format!(
+            "Failed reading transactions with digests {:?} and err: {:?}",
+            digests, e
+        ))
+    })
+}
+
+pub fn count_transactions(db_pool_conn: &mut DbPoolConnection) -> Result<i64, IndexerError> {
+    let count_result: Result<i64, Error> = read_only!(db_pool_conn, |conn| transactions_table
+        .count()
+        .get_result(conn));
+
+    count_result.map_err(|e| {
+        IndexerError::DbReadError(format!("Failed counting transactions with err: {:?}", e))
+    })
+}
+
+/// Read the digests of the transactions with a sequence number in [start, end), in order.
+pub fn read_transaction_digests_in_range(
+    db_pool_conn: &mut DbPoolConnection,
+    start: i64,
+    end: i64,
+) -> Result<Vec<String>, IndexerError> {
+    let digest_read_result: Result<Vec<String>, Error> = read_only!(db_pool_conn, |conn| {
+        transactions_table
+            .select(transaction_digest)
+            .filter(transaction_sequence.ge(start))
+            .filter(transaction_sequence.lt(end))
+            .order(transaction_sequence.asc())
+            .load::<String>(conn)
     });
 
-    txn_commit_result.map_err(|e| {
-        IndexerError::PostgresWriteError(format!(
-            "Failed writing transactions to PostgresDB with transactions {:?} and error: {:?}",
-            new_txns, e
+    digest_read_result.map_err(|e| {
+        IndexerError::DbReadError(format!(
+            "Failed reading transaction digests in range [{}, {}) with err: {:?}",
+            start, end, e
         ))
     })
 }
 
+/// `tx_seq` is the position of the transaction since genesis.
 pub fn transaction_response_to_new_transaction(
     tx_resp: SuiTransactionResponse,
+    tx_seq: i64,
 ) -> Result<NewTransaction, IndexerError> {
     let cer = tx_resp.certificate;
     let txn_json = serde_json::to_string(&cer).map_err(|err| {
@@ -175,6 +202,13 @@ pub fn transaction_response_to_new_transaction(
         None => None,
     };
 
+    let effects_json = serde_json::to_string(&tx_resp.effects).map_err(|err| {
+        IndexerError::InsertableParsingError(format!(
+            "Failed converting transaction effects {:?} to JSON with error: {:?}",
+            tx_resp.effects, err
+        ))
+    })?;
+
     let gas_object_ref = tx_resp.effects.gas_object.reference.clone();
     let gas_object_id = gas_object_ref.object_id.to_string();
     let gas_object_seq = gas_object_ref.version;
@@ -189,13 +223,13 @@ pub fn transaction_response_to_new_transaction(
     Ok(NewTransaction {
         transaction_digest: tx_digest,
         sender,
-        transaction_kinds: txn_kind_iter.map(Some).collect::<Vec<Option<String>>>(),
+        transaction_kinds: StringArray(txn_kind_iter.map(Some).collect()),
         transaction_time: timestamp,
-        created: vec_string_to_vec_opt_string(created),
-        mutated: vec_string_to_vec_opt_string(mutated),
-        unwrapped: vec_string_to_vec_opt_string(unwrapped),
-        deleted: vec_string_to_vec_opt_string(deleted),
-        wrapped: vec_string_to_vec_opt_string(wrapped),
+        created: vec_string_to_string_array(created),
+        mutated: vec_string_to_string_array(mutated),
+        unwrapped: vec_string_to_string_array(unwrapped),
+        deleted: vec_string_to_string_array(deleted),
+        wrapped: vec_string_to_string_array(wrapped),
         gas_object_id,
         gas_object_sequence: gas_object_seq.value() as i64,
         gas_object_digest,
@@ -207,9 +241,54 @@ pub fn transaction_response_to_new_transaction(
         storage_cost: storage_cost as i64,
         storage_rebate: storage_rebate as i64,
         transaction_content: txn_json,
+        transaction_effects_content: Some(effects_json),
+        transaction_sequence: tx_seq,
     })
 }
 
+/// Rebuild the fullnode response of a transaction, returns None if the transaction was
+/// indexed before its effects were stored.
+pub fn transaction_to_response(
+    txn: Transaction,
+) -> Result<Option<SuiTransactionResponse>, IndexerError> {
+    let effects_json = match txn.transaction_effects_content {
+        Some(effects_json) => effects_json,
+        None => return Ok(None),
+    };
+    let certificate: SuiCertifiedTransaction = serde_json::from_str(&txn.transaction_content)
+        .map_err(|e| {
+            IndexerError::TransactionDeserializationError(format!(
+                "Failed deserializing transaction {:?} with error: {:?}",
+                txn.transaction_digest, e
+            ))
+        })?;
+    let effects: SuiTransactionEffects = serde_json::from_str(&effects_json).map_err(|e| {
+        IndexerError::TransactionDeserializationError(format!(
+            "Failed deserializing effects of transaction {:?} with error: {:?}",
+            txn.transaction_digest, e
+        ))
+    })?;
+    Ok(Some(SuiTransactionResponse {
+        certificate,
+        effects,
+        timestamp_ms: txn.transaction_time.map(|t| t.timestamp_millis() as u64),
+        parsed_data: None,
+    }))
+}
+
+/// Parse a digest stored in the transactions table, which is Base64 encoded.
+pub fn parse_transaction_digest(digest: &str) -> Result<TransactionDigest, IndexerError> {
+    Base64::decode(digest)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| TransactionDigest::try_from(bytes.as_slice()).map_err(|e| e.to_string()))
+        .map_err(|e| {
+            IndexerError::TransactionDigestParsingError(format!(
+                "Failed parsing transaction digest {:?} with error: {:?}",
+                digest, e
+            ))
+        })
+}
+
 fn owned_obj_ref_to_obj_id_string(owned_obj_ref: OwnedObjectRef) -> String {
     owned_obj_ref.reference.object_id.to_string()
 }
@@ -218,6 +297,6 @@ fn obj_ref_to_obj_id_string(obj_ref: SuiObjectRef) -> String {
     obj_ref.object_id.to_string()
 }
 
-fn vec_string_to_vec_opt_string(v: Vec<String>) -> Vec<Option<String>> {
-    v.into_iter().map(Some).collect::<Vec<Option<String>>>()
+fn vec_string_to_string_array(v: Vec<String>) -> StringArray {
+    StringArray(v.into_iter().map(Some).collect())
 }
@@ -5,21 +5,20 @@ use backoff::future::retry;
 use backoff::ExponentialBackoff;
 use futures::future::try_join_all;
 use std::sync::Arc;
-use sui_indexer::PgConnectionPool;
+use sui_indexer::DbConnectionPool;
 use sui_sdk::SuiClient;
 use tracing::{error, info, warn};
 
 use crate::processors::address_processor::AddressProcessor;
-use crate::processors::object_processor::ObjectProcessor;
 use crate::processors::package_processor::PackageProcessor;
 
 pub struct ProcessorOrchestrator {
     rpc_client: SuiClient,
-    conn_pool: Arc<PgConnectionPool>,
+    conn_pool: Arc<DbConnectionPool>,
 }
 
 impl ProcessorOrchestrator {
-    pub fn new(rpc_client: SuiClient, conn_pool: Arc<PgConnectionPool>) -> Self {
+    pub fn new(rpc_client: SuiClient, conn_pool: Arc<DbConnectionPool>) -> Self {
         Self {
             rpc_client,
             conn_pool,
@@ -29,7 +28,6 @@ impl ProcessorOrchestrator {
     pub async fn run_forever(&mut self) {
         info!("Processor orchestrator started...");
         let address_processor = AddressProcessor::new(self.conn_pool.clone());
-        let object_processor = ObjectProcessor::new(self.conn_pool.clone());
         let package_processor =
             PackageProcessor::new(self.rpc_client.clone(), self.conn_pool.clone());
 
@@ -52,25 +50,6 @@ impl ProcessorOrchestrator {
                 );
             }
         });
-        let obj_handle = tokio::task::spawn(async move {
-            let obj_result = retry(ExponentialBackoff::default(), || async {
-                let obj_processor_exec_res = object_processor.start().await;
-                if let Err(e) = obj_processor_exec_res.clone() {
-                    warn!(
-                        "Indexer object processor failed with error: {:?}, retrying...",
-                        e
-                    );
-                }
-                Ok(obj_processor_exec_res?)
-            })
-            .await;
-            if let Err(e) = obj_result {
-                error!(
-                    "Indexer object processor failed after retrials with error {:?}",
-                    e
-                );
-            }
-        });
         let pkg_handle = tokio::task::spawn(async move {
             let pkg_result = retry(ExponentialBackoff::default(), || async {
                 let pkg_processor_exec_res = package_processor.start().await;
@@ -90,7 +69,7 @@ impl ProcessorOrchestrator {
                 );
             }
         });
-        try_join_all(vec![addr_handle, pkg_handle, obj_handle])
+        try_join_all(vec![addr_handle, pkg_handle])
             .await
             .expect("Processor orchestrator should not run into errors.");
     }
@@ -16,6 +16,12 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    checkpoint_logs (next_checkpoint_sequence_number) {
+        next_checkpoint_sequence_number -> Int8,
+    }
+}
+
 diesel::table! {
     error_logs (id) {
         id -> Int8,
@@ -25,14 +31,6 @@ diesel::table! {
     }
 }
 
-diesel::table! {
-    event_logs (id) {
-        id -> Int4,
-        next_cursor_tx_seq -> Nullable<Int8>,
-        next_cursor_event_seq -> Nullable<Int8>,
-    }
-}
-
 diesel::table! {
     events (id) {
         id -> Int8,
@@ -42,12 +40,12 @@ diesel::table! {
         event_time -> Nullable<Timestamp>,
         event_type -> Varchar,
         event_content -> Varchar,
-    }
-}
-
-diesel::table! {
-    object_logs (last_processed_id) {
-        last_processed_id -> Int8,
+        package_id -> Nullable<Text>,
+        module_name -> Nullable<Text>,
+        move_event_name -> Nullable<Text>,
+        sender -> Nullable<Varchar>,
+        recipient -> Nullable<Text>,
+        object_id -> Nullable<Varchar>,
     }
 }
 
@@ -62,6 +60,9 @@ diesel::table! {
         transaction_module -> Text,
         object_type -> Nullable<Text>,
         object_status -> Varchar,
+        object_content -> Nullable<Text>,
+        coin_type -> Nullable<Text>,
+        coin_balance -> Nullable<Int8>,
     }
 }
 
@@ -72,33 +73,32 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use crate::types::TextArray;
+
     packages (package_id) {
         package_id -> Text,
         author -> Text,
-        module_names -> Array<Nullable<Text>>,
+        module_names -> TextArray,
         package_content -> Text,
     }
 }
 
 diesel::table! {
-    transaction_logs (id) {
-        id -> Int4,
-        next_cursor_tx_digest -> Nullable<Text>,
-    }
-}
+    use diesel::sql_types::*;
+    use crate::types::TextArray;
 
-diesel::table! {
     transactions (id) {
         id -> Int8,
         transaction_digest -> Varchar,
         sender -> Varchar,
         transaction_time -> Nullable<Timestamp>,
-        transaction_kinds -> Array<Nullable<Text>>,
-        created -> Array<Nullable<Text>>,
-        mutated -> Array<Nullable<Text>>,
-        deleted -> Array<Nullable<Text>>,
-        unwrapped -> Array<Nullable<Text>>,
-        wrapped -> Array<Nullable<Text>>,
+        transaction_kinds -> TextArray,
+        created -> TextArray,
+        mutated -> TextArray,
+        deleted -> TextArray,
+        unwrapped -> TextArray,
+        wrapped -> TextArray,
         gas_object_id -> Varchar,
         gas_object_sequence -> Int8,
         gas_object_digest -> Varchar,
@@ -108,19 +108,19 @@ diesel::table! {
         storage_cost -> Int8,
         storage_rebate -> Int8,
         transaction_content -> Text,
+        transaction_effects_content -> Nullable<Text>,
+        transaction_sequence -> Int8,
     }
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
     address_logs,
     addresses,
+    checkpoint_logs,
     error_logs,
-    event_logs,
     events,
-    object_logs,
     objects,
     package_logs,
     packages,
-    transaction_logs,
     transactions,
 );
@@ -0,0 +1,47 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::pg::{Pg, PgValue};
+use diesel::query_builder::QueryId;
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::{Array, Nullable, SqlType, Text};
+use diesel::sqlite::{Sqlite, SqliteValue};
+
+/// A list of strings column, stored as a `TEXT[]` in Postgres and as a JSON array in a
+/// `TEXT` column in SQLite, which has no array type.
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(postgres_type(oid = 1009, array_oid = 1009))]
+#[diesel(sqlite_type(name = "Text"))]
+pub struct TextArray;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, AsExpression, FromSqlRow)]
+#[diesel(sql_type = TextArray)]
+pub struct StringArray(pub Vec<Option<String>>);
+
+impl ToSql<TextArray, Pg> for StringArray {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Array<Nullable<Text>>, Pg>::to_sql(&self.0, out)
+    }
+}
+
+impl FromSql<TextArray, Pg> for StringArray {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<Array<Nullable<Text>>, Pg>::from_sql(bytes).map(Self)
+    }
+}
+
+impl ToSql<TextArray, Sqlite> for StringArray {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(serde_json::to_string(&self.0)?);
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<TextArray, Sqlite> for StringArray {
+    fn from_sql(bytes: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let json = <String as FromSql<Text, Sqlite>>::from_sql(bytes)?;
+        Ok(Self(serde_json::from_str(&json)?))
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+
+use jsonrpsee::http_client::HttpClientBuilder;
+use sui_indexer::apis::{CoinReadApi, ReadApi};
+use sui_indexer::models::checkpoint_logs::read_checkpoint_log;
+use sui_indexer::models::checkpoints::commit_checkpoint;
+use sui_indexer::models::objects::object_writes;
+use sui_indexer::models::transactions::{count_transactions, read_transactions_by_digests};
+use sui_indexer::{
+    get_db_pool_connection, new_db_connection_pool, DbPoolConnection, SQLITE_URL_PREFIX,
+};
+use sui_json_rpc::api::{CoinReadApiServer, RpcReadApiServer, MULTI_GET_MAX_SIZE};
+use sui_json_rpc_types::{SuiCheckpointSummary, SuiPastObjectRead};
+use sui_sdk::SuiClient;
+use sui_types::base_types::TransactionDigest;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use test_utils::network::TestClusterBuilder;
+use test_utils::transaction::transfer_sui;
+use tokio::time::{sleep, Duration};
+
+/// Fetch a checkpoint with its transactions and objects, and commit it.
+async fn index_checkpoint(
+    client: &SuiClient,
+    db_pool_conn: &mut DbPoolConnection,
+    sequence_number: CheckpointSequenceNumber,
+) -> Result<(SuiCheckpointSummary, Vec<TransactionDigest>), anyhow::Error> {
+    let checkpoint = client
+        .read_api()
+        .get_checkpoint_summary(sequence_number)
+        .await?;
+    let contents = client
+        .read_api()
+        .get_checkpoint_contents(sequence_number)
+        .await?;
+    let tx_digests: Vec<TransactionDigest> = contents
+        .transactions
+        .iter()
+        .map(|digests| digests.transaction)
+        .collect();
+    let mut tx_resps = vec![];
+    for batch in tx_digests.chunks(MULTI_GET_MAX_SIZE) {
+        for read in client
+            .read_api()
+            .multi_get_transactions(batch.to_vec())
+            .await?
+        {
+            tx_resps.push(read.into_transaction()?);
+        }
+    }
+    let mut objects = vec![];
+    for batch in object_writes(&tx_resps).written.chunks(MULTI_GET_MAX_SIZE) {
+        for object_read in client
+            .read_api()
+            .try_multi_get_parsed_past_objects(batch.to_vec())
+            .await?
+        {
+            match object_read {
+                SuiPastObjectRead::VersionFound(object) => objects.push(object),
+                object_read => anyhow::bail!("Unexpected past object {:?}", object_read),
+            }
+        }
+    }
+    commit_checkpoint(db_pool_conn, &checkpoint, tx_resps, objects)?;
+    Ok((checkpoint, tx_digests))
+}
+
+/// Ingest checkpoints in order from `next_checkpoint` until the one including `digest`.
+async fn index_until(
+    client: &SuiClient,
+    db_pool_conn: &mut DbPoolConnection,
+    mut next_checkpoint: CheckpointSequenceNumber,
+    digest: TransactionDigest,
+) -> Result<SuiCheckpointSummary, anyhow::Error> {
+    loop {
+        if next_checkpoint
+            > client
+                .read_api()
+                .get_latest_checkpoint_sequence_number()
+                .await?
+        {
+            sleep(Duration::from_millis(100)).await;
+            continue;
+        }
+        let (checkpoint, tx_digests) =
+            index_checkpoint(client, db_pool_conn, next_checkpoint).await?;
+        next_checkpoint += 1;
+        if tx_digests.contains(&digest) {
+            return Ok(checkpoint);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_commit_checkpoint_round_trip() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, _, _, digest) = transfer_sui(cluster.wallet_mut(), None, None).await?;
+    let client = SuiClient::new(cluster.rpc_url(), None, None).await?;
+
+    let dir = tempfile::tempdir()?;
+    let db_url = format!(
+        "{}{}",
+        SQLITE_URL_PREFIX,
+        dir.path().join("indexer.db").display()
+    );
+    let pool = new_db_connection_pool(db_url).await?;
+    let mut db_pool_conn = get_db_pool_connection(pool)?;
+
+    let last_checkpoint = index_until(&client, &mut db_pool_conn, 0, digest).await?;
+
+    let checkpoint_log = read_checkpoint_log(&mut db_pool_conn)?;
+    assert_eq!(
+        last_checkpoint.sequence_number as i64 + 1,
+        checkpoint_log.next_checkpoint_sequence_number
+    );
+    assert_eq!(
+        last_checkpoint.network_total_transactions as i64,
+        count_transactions(&mut db_pool_conn)?
+    );
+    let txns = read_transactions_by_digests(&mut db_pool_conn, vec![digest.encode()])?;
+    assert_eq!(1, txns.len());
+
+    // Committing a checkpoint again does not duplicate its transactions.
+    index_checkpoint(&client, &mut db_pool_conn, last_checkpoint.sequence_number).await?;
+    assert_eq!(
+        last_checkpoint.network_total_transactions as i64,
+        count_transactions(&mut db_pool_conn)?
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_apis_match_fullnode() -> Result<(), anyhow::Error> {
+    let mut cluster = TestClusterBuilder::new().build().await?;
+    let (_, sender, recipient, digest) = transfer_sui(cluster.wallet_mut(), None, None).await?;
+    let client = SuiClient::new(cluster.rpc_url(), None, None).await?;
+
+    let dir = tempfile::tempdir()?;
+    let db_url = format!(
+        "{}{}",
+        SQLITE_URL_PREFIX,
+        dir.path().join("indexer.db").display()
+    );
+    let pool = new_db_connection_pool(db_url).await?;
+    let mut db_pool_conn = get_db_pool_connection(pool.clone())?;
+    let last_checkpoint = index_until(&client, &mut db_pool_conn, 0, digest).await?;
+
+    let fullnode = HttpClientBuilder::default().build(cluster.rpc_url())?;
+    let read_api = ReadApi::new(pool.clone(), fullnode);
+    let coin_api = CoinReadApi::new(pool);
+
+    // Transactions are sequenced in checkpoint order, every indexed one is in range.
+    let total = last_checkpoint.network_total_transactions;
+    let digests = read_api.get_transactions_in_range(0, total).await?;
+    assert_eq!(total as usize, digests.len());
+    assert!(digests.contains(&digest));
+    assert_eq!(
+        digests[1..].to_vec(),
+        read_api.get_transactions_in_range(1, total).await?
+    );
+    assert_eq!(
+        digest,
+        read_api
+            .get_transaction(digest)
+            .await?
+            .certificate
+            .transaction_digest
+    );
+
+    for owner in [sender, recipient] {
+        // Nothing else is executed after the transfer, so the fullnode state is the same.
+        let expected = client
+            .read_api()
+            .get_objects_owned_by_address(owner, None, None, None)
+            .await?
+            .data;
+        let expected_ids: BTreeSet<_> = expected.iter().map(|info| info.object_id).collect();
+
+        // Read the objects one per page to exercise the cursor.
+        let mut indexed_ids = BTreeSet::new();
+        let mut cursor = None;
+        loop {
+            let page = read_api
+                .get_objects_owned_by_address(owner, None, cursor, Some(1))
+                .await?;
+            indexed_ids.extend(page.data.iter().map(|info| info.object_id));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(expected_ids, indexed_ids);
+
+        for info in &expected {
+            let object = read_api.get_object(info.object_id).await?.into_object()?;
+            assert_eq!(info.version, object.reference.version);
+            assert_eq!(info.digest, object.reference.digest);
+        }
+
+        let expected_balance = client.coin_read_api().get_balance(owner, None).await?;
+        let balance = coin_api.get_balance(owner, None).await?;
+        assert_eq!(expected_balance.total_balance, balance.total_balance);
+        assert_eq!(
+            expected_balance.coin_object_count,
+            balance.coin_object_count
+        );
+
+        let coins = coin_api.get_coins(owner, None, None, None).await?;
+        assert_eq!(balance.coin_object_count, coins.data.len());
+        assert_eq!(
+            balance.total_balance,
+            coins
+                .data
+                .iter()
+                .map(|coin| coin.balance as u128)
+                .sum::<u128>()
+        );
+    }
+
+    let expected_supply = client
+        .coin_read_api()
+        .get_total_supply("0x2::sui::SUI".to_string())
+        .await?;
+    let supply = coin_api
+        .get_total_supply("0x2::sui::SUI".to_string())
+        .await?;
+    assert_eq!(expected_supply.value, supply.value);
+    Ok(())
+}
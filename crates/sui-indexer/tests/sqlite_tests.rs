@@ -0,0 +1,86 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use sui_indexer::models::address_logs::{commit_address_log, read_address_log};
+use sui_indexer::models::checkpoint_logs::read_checkpoint_log;
+use sui_indexer::models::transactions::{
+    count_transactions, read_transactions_by_digests, NewTransaction,
+};
+use sui_indexer::schema::transactions;
+use sui_indexer::types::StringArray;
+use sui_indexer::{
+    get_db_pool_connection, new_db_connection_pool, read_only, read_write, SQLITE_URL_PREFIX,
+};
+
+/// Every connection to a plain `:memory:` database opens a new empty one, share a named
+/// in-memory database across the connections of the pool instead.
+const IN_MEMORY_DB: &str = "file:indexer_round_trip?mode=memory&cache=shared";
+
+#[tokio::test]
+async fn test_in_memory_sqlite_round_trip() -> Result<(), anyhow::Error> {
+    let pool = new_db_connection_pool(format!("{}{}", SQLITE_URL_PREFIX, IN_MEMORY_DB)).await?;
+    let mut db_pool_conn = get_db_pool_connection(pool)?;
+
+    // Migrations create the tables and seed the logs.
+    assert_eq!(0, read_address_log(&mut db_pool_conn)?.last_processed_id);
+    assert_eq!(
+        0,
+        read_checkpoint_log(&mut db_pool_conn)?.next_checkpoint_sequence_number
+    );
+    assert_eq!(0, count_transactions(&mut db_pool_conn)?);
+
+    commit_address_log(&mut db_pool_conn, 42)?;
+    assert_eq!(42, read_address_log(&mut db_pool_conn)?.last_processed_id);
+
+    // List columns are stored as JSON text in SQLite.
+    let created = StringArray(vec![Some("0x1".to_string()), None]);
+    let transaction_time = NaiveDateTime::from_timestamp_opt(1_672_531_200, 0);
+    let new_transaction = NewTransaction {
+        transaction_digest: "digest".to_string(),
+        sender: "0x2".to_string(),
+        transaction_time,
+        transaction_kinds: StringArray(vec![Some("TransferSui".to_string())]),
+        created: created.clone(),
+        mutated: StringArray::default(),
+        deleted: StringArray::default(),
+        unwrapped: StringArray::default(),
+        wrapped: StringArray::default(),
+        gas_object_id: "0x3".to_string(),
+        gas_object_sequence: 1,
+        gas_object_digest: "gas_digest".to_string(),
+        gas_budget: 1000,
+        total_gas_cost: 100,
+        computation_cost: 60,
+        storage_cost: 50,
+        storage_rebate: 10,
+        transaction_content: "content".to_string(),
+        transaction_effects_content: None,
+        transaction_sequence: 0,
+    };
+    // Read-only transactions refuse writes, and leave the connection writable.
+    assert!(read_only!(&mut db_pool_conn, |conn| {
+        diesel::insert_into(transactions::table)
+            .values(&new_transaction)
+            .execute(conn)
+    })
+    .is_err());
+    assert_eq!(0, count_transactions(&mut db_pool_conn)?);
+    read_write!(&mut db_pool_conn, |conn| {
+        diesel::insert_into(transactions::table)
+            .values(&new_transaction)
+            .execute(conn)
+    })?;
+
+    let txns = read_transactions_by_digests(&mut db_pool_conn, vec!["digest".to_string()])?;
+    assert_eq!(1, txns.len());
+    let txn = &txns[0];
+    assert_eq!("0x2", txn.sender);
+    assert_eq!(transaction_time, txn.transaction_time);
+    assert_eq!(created, txn.created);
+    assert_eq!(StringArray::default(), txn.mutated);
+    assert_eq!(None, txn.transaction_effects_content);
+    assert_eq!(1, count_transactions(&mut db_pool_conn)?);
+    Ok(())
+}
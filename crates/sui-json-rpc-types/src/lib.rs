@@ -17,6 +17,7 @@ use move_binary_format::normalized::{
     Field as NormalizedField, Function as SuiNormalizedFunction, Module as NormalizedModule,
     Struct as NormalizedStruct, Type as NormalizedType,
 };
+use move_bytecode_utils::layout::TypeLayoutBuilder;
 use move_bytecode_utils::module_cache::GetModule;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{StructTag, TypeTag};
@@ -30,12 +31,13 @@ use serde_with::serde_as;
 
 use fastcrypto::encoding::{Base64, Encoding};
 use sui_json::SuiJsonValue;
+use sui_protocol_constants::ProtocolConfig;
 use sui_types::base_types::{
-    AuthorityName, ObjectDigest, ObjectID, ObjectInfo, ObjectRef, SequenceNumber, SuiAddress,
-    TransactionDigest, TransactionEffectsDigest,
+    AuthorityName, ExecutionDigests, ObjectDigest, ObjectID, ObjectInfo, ObjectRef, SequenceNumber,
+    SuiAddress, TransactionDigest, TransactionEffectsDigest,
 };
 use sui_types::coin::CoinMetadata;
-use sui_types::committee::EpochId;
+use sui_types::committee::{EpochId, StakeUnit};
 use sui_types::crypto::{AuthorityStrongQuorumSignInfo, Signature};
 use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::error::{ExecutionError, SuiError};
@@ -50,7 +52,10 @@ use sui_types::messages::{
     SingleTransactionKind, TransactionData, TransactionEffects, TransactionKind,
     VerifiedCertificate,
 };
-use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::messages_checkpoint::{
+    CheckpointContents, CheckpointContentsDigest, CheckpointDigest, CheckpointSequenceNumber,
+    CheckpointSummary,
+};
 use sui_types::move_package::{disassemble_modules, MovePackage};
 use sui_types::object::{
     Data, MoveObject, Object, ObjectFormatOptions, ObjectRead, Owner, PastObjectRead,
@@ -67,6 +72,7 @@ pub type TransactionsPage = Page<TransactionDigest, TransactionDigest>;
 pub type EventPage = Page<SuiEventEnvelope, EventID>;
 pub type CoinPage = Page<Coin, ObjectID>;
 pub type DynamicFieldPage = Page<DynamicFieldInfo, ObjectID>;
+pub type ObjectsPage = Page<SuiObjectInfo, ObjectID>;
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(rename_all = "camelCase")]
@@ -350,6 +356,25 @@ pub struct SuiTransactionResponse {
     pub parsed_data: Option<SuiParsedTransactionResponse>,
 }
 
+/// The result of reading one of the transactions of a batch.
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(tag = "status", content = "details", rename = "TransactionRead")]
+pub enum SuiTransactionRead {
+    Found(SuiTransactionResponse),
+    NotFound(TransactionDigest),
+}
+
+impl SuiTransactionRead {
+    /// Returns the transaction response if it was found, otherwise an Err.
+    pub fn into_transaction(self) -> Result<SuiTransactionResponse, SuiError> {
+        match self {
+            Self::Found(response) => Ok(response),
+            Self::NotFound(digest) => Err(SuiError::TransactionNotFound { digest }),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub struct SuiTransactionAuthSignersResponse {
     pub signers: Vec<AuthorityName>,
@@ -548,6 +573,9 @@ impl TryInto<Object> for SuiObject<SuiRawData> {
     type Error = anyhow::Error;
 
     fn try_into(self) -> Result<Object, Self::Error> {
+        // The object was already checked against the limits of the epoch it was written in, so
+        // only the limits of the newest protocol version this build knows about are enforced.
+        let protocol_config = ProtocolConfig::get_for_max_version();
         let data = match self.data {
             SuiRawData::MoveObject(o) => {
                 let struct_tag = parse_sui_struct_tag(o.type_())?;
@@ -557,10 +585,15 @@ impl TryInto<Object> for SuiObject<SuiRawData> {
                         o.has_public_transfer,
                         o.version,
                         o.bcs_bytes,
+                        &protocol_config,
                     )?
                 })
             }
-            SuiRawData::Package(p) => Data::Package(MovePackage::new(p.id, &p.module_map)?),
+            SuiRawData::Package(p) => Data::Package(MovePackage::new(
+                p.id,
+                &p.module_map,
+                protocol_config.max_move_package_size(),
+            )?),
         };
         Ok(Object {
             data,
@@ -1067,6 +1100,15 @@ impl<T: SuiData> TryFrom<ObjectRead> for SuiObjectRead<T> {
 
 pub type GetPastObjectDataResponse = SuiPastObjectRead<SuiParsedData>;
 
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiGetPastObjectRequest {
+    /// the ID of the queried object
+    pub object_id: ObjectID,
+    /// the version of the queried object.
+    pub version: SequenceNumber,
+}
+
 #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
 #[serde(tag = "status", content = "details", rename = "ObjectRead")]
 pub enum SuiPastObjectRead<T: SuiData> {
@@ -1978,6 +2020,14 @@ pub struct SuiExecutionResult {
     /// The return values from the function
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub return_values: Vec<(Vec<u8>, SuiTypeTag)>,
+    /// `mutable_reference_outputs` decoded into JSON, in the same order.
+    /// A value is null if its type layout could not be resolved.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parsed_mutable_reference_outputs: Vec<Option<SuiMoveValue>>,
+    /// `return_values` decoded into JSON, in the same order.
+    /// A value is null if its type layout could not be resolved.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parsed_return_values: Vec<Option<SuiMoveValue>>,
 }
 
 type ExecutionResult = (
@@ -1998,6 +2048,14 @@ impl DevInspectResults {
                 .into_iter()
                 .map(|(idx, srv)| {
                     let (mutable_reference_outputs, return_values) = srv;
+                    let parsed_mutable_reference_outputs = mutable_reference_outputs
+                        .iter()
+                        .map(|(_, bytes, tag)| parse_move_value(bytes, tag, resolver))
+                        .collect();
+                    let parsed_return_values = return_values
+                        .iter()
+                        .map(|(bytes, tag)| parse_move_value(bytes, tag, resolver))
+                        .collect();
                     let mutable_reference_outputs = mutable_reference_outputs
                         .into_iter()
                         .map(|(i, bytes, tag)| (i, bytes, SuiTypeTag::from(tag)))
@@ -2009,6 +2067,8 @@ impl DevInspectResults {
                     let res = SuiExecutionResult {
                         mutable_reference_outputs,
                         return_values,
+                        parsed_mutable_reference_outputs,
+                        parsed_return_values,
                     };
                     (idx, res)
                 })
@@ -2018,6 +2078,22 @@ impl DevInspectResults {
     }
 }
 
+/// Decode a BCS encoded Move value of type `type_`, resolving its layout from `resolver`, into
+/// the same representation used for object fields.
+fn parse_move_value(
+    bytes: &[u8],
+    type_: &TypeTag,
+    resolver: &impl GetModule,
+) -> Option<SuiMoveValue> {
+    let layout = TypeLayoutBuilder::build_with_types(type_, resolver)
+        .map_err(|e| warn!(?type_, error = ?e, "Cannot resolve layout of Move value"))
+        .ok()?;
+    let value = MoveValue::simple_deserialize(bytes, &layout)
+        .map_err(|e| warn!(?type_, error = ?e, "Cannot deserialize Move value"))
+        .ok()?;
+    Some(value.into())
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub enum SuiTransactionBuilderMode {
     /// Regular Sui Transactions that are committed on chain
@@ -2088,6 +2164,52 @@ impl From<GasCostSummary> for SuiGasCostSummary {
     }
 }
 
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "CheckpointSummary", rename_all = "camelCase")]
+pub struct SuiCheckpointSummary {
+    pub epoch: EpochId,
+    pub sequence_number: CheckpointSequenceNumber,
+    /// Total number of transactions committed since genesis, including those in this
+    /// checkpoint.
+    pub network_total_transactions: u64,
+    pub content_digest: CheckpointContentsDigest,
+    pub previous_digest: Option<CheckpointDigest>,
+    /// The running total gas costs of all transactions included in the current epoch so far
+    /// until this checkpoint.
+    pub epoch_rolling_gas_cost_summary: SuiGasCostSummary,
+    /// The committee of the next epoch, only present on the last checkpoint of an epoch.
+    pub next_epoch_committee: Option<Vec<(AuthorityName, StakeUnit)>>,
+}
+
+impl From<CheckpointSummary> for SuiCheckpointSummary {
+    fn from(summary: CheckpointSummary) -> Self {
+        Self {
+            epoch: summary.epoch,
+            sequence_number: summary.sequence_number,
+            network_total_transactions: summary.network_total_transactions,
+            content_digest: summary.content_digest,
+            previous_digest: summary.previous_digest,
+            epoch_rolling_gas_cost_summary: summary.epoch_rolling_gas_cost_summary.into(),
+            next_epoch_committee: summary.next_epoch_committee,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "CheckpointContents", rename_all = "camelCase")]
+pub struct SuiCheckpointContents {
+    /// The transactions of the checkpoint, in execution order.
+    pub transactions: Vec<ExecutionDigests>,
+}
+
+impl From<CheckpointContents> for SuiCheckpointContents {
+    fn from(contents: CheckpointContents) -> Self {
+        Self {
+            transactions: contents.into_inner(),
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename = "ObjectRef")]
 pub struct OwnedObjectRef {
@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::anyhow;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee_proc_macros::rpc;
 use std::collections::BTreeMap;
@@ -10,11 +11,12 @@ use fastcrypto::encoding::Base64;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
     Balance, CoinPage, DevInspectResults, DynamicFieldPage, EventPage, GetObjectDataResponse,
-    GetPastObjectDataResponse, GetRawObjectDataResponse, MoveFunctionArgType,
-    RPCTransactionRequestParams, SuiCoinMetadata, SuiEventEnvelope, SuiEventFilter,
-    SuiExecuteTransactionResponse, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
-    SuiMoveNormalizedStruct, SuiObjectInfo, SuiTransactionAuthSignersResponse,
-    SuiTransactionBuilderMode, SuiTransactionEffects, SuiTransactionFilter, SuiTransactionResponse,
+    GetPastObjectDataResponse, GetRawObjectDataResponse, MoveFunctionArgType, ObjectsPage,
+    RPCTransactionRequestParams, SuiCheckpointContents, SuiCheckpointSummary, SuiCoinMetadata,
+    SuiEventEnvelope, SuiEventFilter, SuiExecuteTransactionResponse, SuiGetPastObjectRequest,
+    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
+    SuiTransactionAuthSignersResponse, SuiTransactionBuilderMode, SuiTransactionData,
+    SuiTransactionEffects, SuiTransactionFilter, SuiTransactionRead, SuiTransactionResponse,
     SuiTypeTag, TransactionBytes, TransactionsPage,
 };
 use sui_open_rpc_macros::open_rpc;
@@ -27,7 +29,8 @@ use sui_types::crypto::SignatureScheme;
 use sui_types::event::EventID;
 use sui_types::messages::CommitteeInfoResponse;
 use sui_types::messages::ExecuteTransactionRequestType;
-use sui_types::query::{EventQuery, TransactionQuery};
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::query::{EventQuery, ObjectTypeFilter, TransactionQuery};
 
 /// Maximum number of events returned in an event query.
 /// This is equivalent to EVENT_QUERY_MAX_LIMIT in `sui-storage` crate.
@@ -35,6 +38,9 @@ use sui_types::query::{EventQuery, TransactionQuery};
 /// for document purposes.
 pub const QUERY_MAX_RESULT_LIMIT: usize = 1000;
 
+/// Maximum number of objects or transactions that can be requested in one multi-get call.
+pub const MULTI_GET_MAX_SIZE: usize = 50;
+
 #[open_rpc(namespace = "sui", tag = "Coin Query API")]
 #[rpc(server, client, namespace = "sui")]
 pub trait CoinReadApi {
@@ -102,21 +108,33 @@ pub trait CoinReadApi {
 #[open_rpc(namespace = "sui", tag = "Read API")]
 #[rpc(server, client, namespace = "sui")]
 pub trait RpcReadApi {
-    /// Return the list of objects owned by an address.
+    /// Return the list of objects owned by an address, ordered by object ID.
     #[method(name = "getObjectsOwnedByAddress")]
     async fn get_objects_owned_by_address(
         &self,
         /// the owner's Sui address
         address: SuiAddress,
-    ) -> RpcResult<Vec<SuiObjectInfo>>;
+        /// optional filter on the object's package, module or struct type
+        object_type: Option<ObjectTypeFilter>,
+        /// optional paging cursor
+        cursor: Option<ObjectID>,
+        /// maximum number of items per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage>;
 
-    /// Return the list of objects owned by an object.
+    /// Return the list of objects owned by an object, ordered by object ID.
     #[method(name = "getObjectsOwnedByObject")]
     async fn get_objects_owned_by_object(
         &self,
         /// the ID of the owner object
         object_id: ObjectID,
-    ) -> RpcResult<Vec<SuiObjectInfo>>;
+        /// optional filter on the object's package, module or struct type
+        object_type: Option<ObjectTypeFilter>,
+        /// optional paging cursor
+        cursor: Option<ObjectID>,
+        /// maximum number of items per page, default to [QUERY_MAX_RESULT_LIMIT] if not specified.
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage>;
 
     /// Return the list of dynamic field objects owned by an object.
     #[method(name = "getDynamicFields")]
@@ -152,6 +170,15 @@ pub trait RpcReadApi {
         digest: TransactionDigest,
     ) -> RpcResult<SuiTransactionResponse>;
 
+    /// Return the transaction response objects for a list of transactions, in the same order.
+    /// Transactions that are not found are reported per item, without failing the request.
+    #[method(name = "multiGetTransactions")]
+    async fn multi_get_transactions(
+        &self,
+        /// the digests of the queried transactions, at most [MULTI_GET_MAX_SIZE]
+        digests: Vec<TransactionDigest>,
+    ) -> RpcResult<Vec<SuiTransactionRead>>;
+
     /// Return the authority public keys that commits to the authority signature of the transaction.
     #[method(name = "getTransactionAuthSigners")]
     async fn get_transaction_auth_signers(
@@ -168,6 +195,14 @@ pub trait RpcReadApi {
         object_id: ObjectID,
     ) -> RpcResult<GetObjectDataResponse>;
 
+    /// Return the object information for a list of objects, in the same order.
+    #[method(name = "multiGetObjects")]
+    async fn multi_get_objects(
+        &self,
+        /// the IDs of the queried objects, at most [MULTI_GET_MAX_SIZE]
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>>;
+
     /// Return the dynamic field object information for a specified object
     #[method(name = "getDynamicFieldObject")]
     async fn get_dynamic_field_object(
@@ -262,6 +297,17 @@ pub trait RpcFullNodeReadApi {
         version: SequenceNumber,
     ) -> RpcResult<GetPastObjectDataResponse>;
 
+    /// Note there is no software-level guarantee/SLA that objects with past versions
+    /// can be retrieved by this API, even if the object and version exists/existed.
+    /// The result may vary across nodes depending on their pruning policies.
+    /// Return the object information for a list of object versions, in the same order.
+    #[method(name = "multiGetPastObjects")]
+    async fn multi_get_past_objects(
+        &self,
+        /// the IDs and versions of the queried objects, at most [MULTI_GET_MAX_SIZE]
+        past_objects: Vec<SuiGetPastObjectRequest>,
+    ) -> RpcResult<Vec<GetPastObjectDataResponse>>;
+
     /// Return the committee information for the asked epoch
     #[method(name = "getCommitteeInfo")]
     async fn get_committee_info(
@@ -273,6 +319,26 @@ pub trait RpcFullNodeReadApi {
     /// Return SuiSystemState
     #[method(name = "getSuiSystemState")]
     async fn get_sui_system_state(&self) -> RpcResult<SuiSystemState>;
+
+    /// Return the sequence number of the latest checkpoint that has been executed
+    #[method(name = "getLatestCheckpointSequenceNumber")]
+    async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<CheckpointSequenceNumber>;
+
+    /// Return a checkpoint summary based on a checkpoint sequence number
+    #[method(name = "getCheckpointSummary")]
+    async fn get_checkpoint_summary(
+        &self,
+        /// the sequence number of the queried checkpoint
+        sequence_number: CheckpointSequenceNumber,
+    ) -> RpcResult<SuiCheckpointSummary>;
+
+    /// Return the transaction digests contained in a checkpoint, in execution order
+    #[method(name = "getCheckpointContents")]
+    async fn get_checkpoint_contents(
+        &self,
+        /// the sequence number of the queried checkpoint
+        sequence_number: CheckpointSequenceNumber,
+    ) -> RpcResult<SuiCheckpointContents>;
 }
 
 #[open_rpc(namespace = "sui", tag = "Transaction Builder API")]
@@ -512,6 +578,8 @@ pub trait EventStreamingApi {
         &self,
         /// the filter criteria of the event stream, see the [Sui docs](https://docs.sui.io/build/pubsub#event-filters) for detailed examples.
         filter: SuiEventFilter,
+        /// optional event ID to resume from, matching events emitted after it are replayed from the event store before live events are streamed.
+        cursor: Option<EventID>,
     );
 }
 
@@ -571,6 +639,29 @@ pub trait TransactionExecutionApi {
         /// The request type
         request_type: ExecuteTransactionRequestType,
     ) -> RpcResult<SuiExecuteTransactionResponse>;
+
+    /// Return the digests of the transactions submitted to this fullnode for execution,
+    /// which are not finalized yet.
+    #[method(name = "getPendingTransactions")]
+    async fn get_pending_transactions(&self) -> RpcResult<Vec<TransactionDigest>>;
+
+    /// Return a transaction submitted to this fullnode for execution, which is not finalized yet.
+    #[method(name = "getPendingTransaction")]
+    async fn get_pending_transaction(
+        &self,
+        /// the digest of the queried transaction
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionData>;
+}
+
+pub fn check_multi_get_size(size: usize) -> RpcResult<()> {
+    if size > MULTI_GET_MAX_SIZE {
+        return Err(anyhow!(
+            "Requested {size} items, which exceeds the multi-get limit of {MULTI_GET_MAX_SIZE}"
+        )
+        .into());
+    }
+    Ok(())
 }
 
 pub fn cap_page_limit(limit: Option<usize>) -> usize {
@@ -66,8 +66,7 @@ impl CoinReadApi {
         // TODO: Add index to improve performance?
         let limit = cap_page_limit(limit);
         let mut coins = self
-            .get_owner_coin_iterator(owner, &coin_type)?
-            .skip_while(|o| matches!(&cursor, Some(cursor) if cursor != o))
+            .get_owner_coin_iterator(owner, &coin_type, cursor)?
             .take(limit + 1)
             .collect::<Vec<_>>();
 
@@ -95,10 +94,11 @@ impl CoinReadApi {
         &'a self,
         owner: SuiAddress,
         coin_type: &'a Option<String>,
+        cursor: Option<ObjectID>,
     ) -> Result<impl Iterator<Item = ObjectID> + '_, Error> {
         Ok(self
             .state
-            .get_owner_objects_iterator(owner)?
+            .get_owner_objects_iterator(owner, cursor, None)?
             .filter(move |o| matches!(&o.type_, ObjectType::Struct(type_) if is_coin_type(type_, coin_type)))
             .map(|info|info.object_id))
     }
@@ -177,7 +177,7 @@ impl CoinReadApiServer for CoinReadApi {
         let coin_type = coin_type.or_else(|| Some(GAS::type_().to_string()));
 
         // TODO: Add index to improve performance?
-        let coins = self.get_owner_coin_iterator(owner, &coin_type)?;
+        let coins = self.get_owner_coin_iterator(owner, &coin_type, None)?;
         let mut total_balance = 0u128;
         let mut coin_object_count = 0;
 
@@ -196,7 +196,7 @@ impl CoinReadApiServer for CoinReadApi {
 
     async fn get_all_balances(&self, owner: SuiAddress) -> RpcResult<Vec<Balance>> {
         // TODO: Add index to improve performance?
-        let coins = self.get_owner_coin_iterator(owner, &None)?;
+        let coins = self.get_owner_coin_iterator(owner, &None, None)?;
         let mut data: HashMap<String, (u128, usize)> = HashMap::new();
 
         for coin in coins {
@@ -41,6 +41,7 @@ impl EventStreamingApiServer for EventStreamingApiImpl {
         &self,
         mut sink: SubscriptionSink,
         filter: SuiEventFilter,
+        cursor: Option<EventID>,
     ) -> SubscriptionResult {
         let filter = match filter.try_into() {
             Ok(filter) => filter,
@@ -52,7 +53,10 @@ impl EventStreamingApiServer for EventStreamingApiImpl {
         };
 
         let state = self.state.clone();
-        let stream = self.event_handler.subscribe(filter);
+        let stream = match cursor {
+            Some(cursor) => self.event_handler.subscribe_after(filter, cursor).boxed(),
+            None => self.event_handler.subscribe(filter).boxed(),
+        };
         let stream = stream.map(move |e: EventEnvelope| {
             let event = SuiEvent::try_from(e.event, state.module_cache.as_ref());
             event.map(|event| SuiEventEnvelope {
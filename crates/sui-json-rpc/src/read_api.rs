@@ -15,26 +15,33 @@ use tap::TapFallible;
 use fastcrypto::encoding::Base64;
 use jsonrpsee::RpcModule;
 use sui_core::authority::AuthorityState;
+use sui_core::checkpoints::CheckpointStore;
 use sui_json_rpc_types::{
     DevInspectResults, DynamicFieldPage, GetObjectDataResponse, GetPastObjectDataResponse,
-    MoveFunctionArgType, ObjectValueKind, Page, SuiMoveNormalizedFunction, SuiMoveNormalizedModule,
-    SuiMoveNormalizedStruct, SuiObjectInfo, SuiTransactionAuthSignersResponse,
-    SuiTransactionEffects, SuiTransactionResponse, TransactionsPage,
+    MoveFunctionArgType, ObjectValueKind, ObjectsPage, Page, SuiCheckpointContents,
+    SuiCheckpointSummary, SuiGetPastObjectRequest, SuiMoveNormalizedFunction,
+    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo,
+    SuiTransactionAuthSignersResponse, SuiTransactionEffects, SuiTransactionRead,
+    SuiTransactionResponse, TransactionsPage,
 };
 use sui_open_rpc::Module;
 use sui_types::base_types::SequenceNumber;
-use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest, TxSequenceNumber};
+use sui_types::base_types::{
+    ObjectID, ObjectInfo, SuiAddress, TransactionDigest, TxSequenceNumber,
+};
 use sui_types::committee::EpochId;
 use sui_types::crypto::sha3_hash;
 use sui_types::messages::{CommitteeInfoRequest, CommitteeInfoResponse, TransactionData};
+use sui_types::messages_checkpoint::{CheckpointSequenceNumber, VerifiedCheckpoint};
 use sui_types::move_package::normalize_modules;
 use sui_types::object::{Data, ObjectRead};
-use sui_types::query::TransactionQuery;
+use sui_types::query::{ObjectTypeFilter, TransactionQuery};
+use sui_types::storage::ObjectKey;
 
 use tracing::debug;
 
 use crate::api::RpcFullNodeReadApiServer;
-use crate::api::{cap_page_limit, RpcReadApiServer};
+use crate::api::{cap_page_limit, check_multi_get_size, RpcReadApiServer};
 use crate::SuiRpcModule;
 
 // An implementation of the read portion of the JSON-RPC interface intended for use in
@@ -45,11 +52,25 @@ pub struct ReadApi {
 
 pub struct FullNodeApi {
     pub state: Arc<AuthorityState>,
+    pub checkpoint_store: Arc<CheckpointStore>,
 }
 
 impl FullNodeApi {
-    pub fn new(state: Arc<AuthorityState>) -> Self {
-        Self { state }
+    pub fn new(state: Arc<AuthorityState>, checkpoint_store: Arc<CheckpointStore>) -> Self {
+        Self {
+            state,
+            checkpoint_store,
+        }
+    }
+
+    fn get_verified_checkpoint(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> Result<VerifiedCheckpoint, anyhow::Error> {
+        self.checkpoint_store
+            .get_checkpoint_by_sequence_number(sequence_number)
+            .map_err(|e| anyhow!("{e}"))?
+            .ok_or_else(|| anyhow!("Checkpoint {sequence_number} not found"))
     }
 }
 
@@ -64,46 +85,57 @@ impl RpcReadApiServer for ReadApi {
     async fn get_objects_owned_by_address(
         &self,
         address: SuiAddress,
-    ) -> RpcResult<Vec<SuiObjectInfo>> {
-        Ok(self
+        object_type: Option<ObjectTypeFilter>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        let limit = cap_page_limit(limit);
+        let mut data = self
             .state
-            .get_owner_objects(address)
-            .map_err(|e| anyhow!("{e}"))?
-            .into_iter()
-            .map(SuiObjectInfo::from)
-            .collect())
+            .get_owner_objects(address, cursor, limit + 1, object_type)
+            .map_err(|e| anyhow!("{e}"))?;
+        let next_cursor = data.get(limit).map(|info| info.object_id);
+        data.truncate(limit);
+        let data = data.into_iter().map(SuiObjectInfo::from).collect();
+        Ok(ObjectsPage { data, next_cursor })
     }
 
     // TODO: Remove this
-    // This is very expensive, it's only for backward compatibilities and should be removed asap.
+    // This reads every dynamic field object, it's only for backward compatibilities and should be
+    // removed asap.
     async fn get_objects_owned_by_object(
         &self,
         object_id: ObjectID,
-    ) -> RpcResult<Vec<SuiObjectInfo>> {
-        let dynamic_fields = self
+        object_type: Option<ObjectTypeFilter>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        let limit = cap_page_limit(limit);
+        let mut data = vec![];
+        for info in self
             .state
-            .get_dynamic_fields(object_id, None, usize::MAX)
-            .map_err(|e| anyhow!("{e}"))?;
-
-        let mut object_info = vec![];
-        for info in dynamic_fields {
+            .get_dynamic_fields_iterator(object_id, cursor)
+            .map_err(|e| anyhow!("{e}"))?
+        {
             let object = self
                 .state
-                .get_object_read(&info.object_id)
-                .await
-                .and_then(|read| read.into_object())
-                .map_err(|e| anyhow!(e))?;
-            object_info.push(SuiObjectInfo {
-                object_id: object.id(),
-                version: object.version(),
-                digest: object.digest(),
-                // Package cannot be owned by object, safe to unwrap.
-                type_: format!("{}", object.type_().unwrap()),
-                owner: object.owner,
-                previous_transaction: object.previous_transaction,
-            });
+                .db()
+                .get_object(&info.object_id)
+                .map_err(|e| anyhow!(e))?
+                .ok_or_else(|| anyhow!("Cannot find dynamic field object [{}].", info.object_id))?;
+            let info = ObjectInfo::new(&object.compute_object_reference(), &object);
+            if matches!(&object_type, Some(filter) if !filter.matches(&info.type_)) {
+                continue;
+            }
+            data.push(info);
+            if data.len() > limit {
+                break;
+            }
         }
-        Ok(object_info)
+        let next_cursor = data.get(limit).map(|info| info.object_id);
+        data.truncate(limit);
+        let data = data.into_iter().map(SuiObjectInfo::from).collect();
+        Ok(ObjectsPage { data, next_cursor })
     }
 
     async fn get_dynamic_fields(
@@ -134,6 +166,25 @@ impl RpcReadApiServer for ReadApi {
             .try_into()?)
     }
 
+    async fn multi_get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> RpcResult<Vec<GetObjectDataResponse>> {
+        check_multi_get_size(object_ids.len())?;
+        let reads = self
+            .state
+            .multi_get_object_read(&object_ids)
+            .await
+            .map_err(|e| {
+                debug!(?object_ids, "Failed to get objects: {:?}", e);
+                anyhow!("{e}")
+            })?;
+        Ok(reads
+            .into_iter()
+            .map(GetObjectDataResponse::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+
     async fn get_dynamic_field_object(
         &self,
         parent_object_id: ObjectID,
@@ -183,6 +234,36 @@ impl RpcReadApiServer for ReadApi {
         })
     }
 
+    async fn multi_get_transactions(
+        &self,
+        digests: Vec<TransactionDigest>,
+    ) -> RpcResult<Vec<SuiTransactionRead>> {
+        check_multi_get_size(digests.len())?;
+        let transactions =
+            self.state.multi_get_transactions(&digests).await.tap_err(
+                |err| debug!(tx_digests=?digests, "Failed to get transactions: {:?}", err),
+            )?;
+        let timestamps = self.state.multi_get_timestamp_ms(&digests).await?;
+        digests
+            .into_iter()
+            .zip(transactions.into_iter().zip(timestamps))
+            .map(|(digest, (transaction, timestamp_ms))| -> RpcResult<_> {
+                let Some((cert, effects)) = transaction else {
+                    return Ok(SuiTransactionRead::NotFound(digest));
+                };
+                Ok(SuiTransactionRead::Found(SuiTransactionResponse {
+                    certificate: cert.try_into()?,
+                    effects: SuiTransactionEffects::try_from(
+                        effects,
+                        self.state.module_cache.as_ref(),
+                    )?,
+                    timestamp_ms,
+                    parsed_data: None,
+                }))
+            })
+            .collect()
+    }
+
     async fn get_transaction_auth_signers(
         &self,
         digest: TransactionDigest,
@@ -376,6 +457,26 @@ impl RpcFullNodeReadApiServer for FullNodeApi {
             .try_into()?)
     }
 
+    async fn multi_get_past_objects(
+        &self,
+        past_objects: Vec<SuiGetPastObjectRequest>,
+    ) -> RpcResult<Vec<GetPastObjectDataResponse>> {
+        check_multi_get_size(past_objects.len())?;
+        let object_keys = past_objects
+            .iter()
+            .map(|request| ObjectKey(request.object_id, request.version))
+            .collect::<Vec<_>>();
+        let reads = self
+            .state
+            .multi_get_past_object_read(&object_keys)
+            .await
+            .map_err(|e| anyhow!("{e}"))?;
+        Ok(reads
+            .into_iter()
+            .map(GetPastObjectDataResponse::try_from)
+            .collect::<Result<_, _>>()?)
+    }
+
     async fn get_committee_info(&self, epoch: Option<EpochId>) -> RpcResult<CommitteeInfoResponse> {
         Ok(self
             .state
@@ -389,6 +490,38 @@ impl RpcFullNodeReadApiServer for FullNodeApi {
             .get_sui_system_state_object()
             .map_err(|e| anyhow!("{e}"))?)
     }
+
+    async fn get_latest_checkpoint_sequence_number(&self) -> RpcResult<CheckpointSequenceNumber> {
+        Ok(self
+            .checkpoint_store
+            .get_highest_executed_checkpoint_seq_number()
+            .map_err(|e| anyhow!("{e}"))?
+            .ok_or_else(|| anyhow!("No checkpoint has been executed yet"))?)
+    }
+
+    async fn get_checkpoint_summary(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> RpcResult<SuiCheckpointSummary> {
+        Ok(self
+            .get_verified_checkpoint(sequence_number)?
+            .summary()
+            .clone()
+            .into())
+    }
+
+    async fn get_checkpoint_contents(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> RpcResult<SuiCheckpointContents> {
+        let checkpoint = self.get_verified_checkpoint(sequence_number)?;
+        let contents = self
+            .checkpoint_store
+            .get_checkpoint_contents(&checkpoint.content_digest())
+            .map_err(|e| anyhow!("{e}"))?
+            .ok_or_else(|| anyhow!("Contents of checkpoint {sequence_number} not found"))?;
+        Ok(contents.into())
+    }
 }
 
 impl SuiRpcModule for FullNodeApi {
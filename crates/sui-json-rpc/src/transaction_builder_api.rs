@@ -54,7 +54,7 @@ impl DataReader for AuthorityStateDataReader {
     ) -> Result<Vec<SuiObjectInfo>, anyhow::Error> {
         let refs: Vec<SuiObjectInfo> = self
             .0
-            .get_owner_objects(address)?
+            .get_owner_objects(address, None, usize::MAX, None)?
             .into_iter()
             .map(SuiObjectInfo::from)
             .collect();
@@ -15,8 +15,9 @@ use std::sync::Arc;
 use sui_core::authority::{AuthorityStore, ResolverWrapper};
 use sui_core::authority_client::NetworkAuthorityClient;
 use sui_core::transaction_orchestrator::TransactiondOrchestrator;
-use sui_json_rpc_types::SuiExecuteTransactionResponse;
+use sui_json_rpc_types::{SuiExecuteTransactionResponse, SuiTransactionData};
 use sui_open_rpc::Module;
+use sui_types::base_types::TransactionDigest;
 use sui_types::crypto::SignatureScheme;
 use sui_types::intent::Intent;
 use sui_types::messages::{ExecuteTransactionRequest, ExecuteTransactionRequestType};
@@ -110,6 +111,31 @@ impl TransactionExecutionApiServer for FullNodeTransactionExecutionApi {
         )
         .map_err(jsonrpsee::core::Error::from)
     }
+
+    async fn get_pending_transactions(&self) -> RpcResult<Vec<TransactionDigest>> {
+        Ok(self
+            .transaction_orchestrator
+            .load_all_pending_transactions()
+            .iter()
+            .map(|tx| *tx.digest())
+            .collect())
+    }
+
+    async fn get_pending_transaction(
+        &self,
+        digest: TransactionDigest,
+    ) -> RpcResult<SuiTransactionData> {
+        let tx = self
+            .transaction_orchestrator
+            .get_pending_transaction(&digest)?
+            .ok_or_else(|| anyhow!("Transaction {digest:?} is not pending"))?;
+        Ok(tx
+            .into_inner()
+            .into_data()
+            .intent_message
+            .value
+            .try_into()?)
+    }
 }
 
 impl SuiRpcModule for FullNodeTransactionExecutionApi {
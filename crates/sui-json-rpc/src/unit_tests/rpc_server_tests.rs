@@ -9,23 +9,26 @@ use sui_config::SUI_KEYSTORE_FILENAME;
 use sui_framework_build::compiled_package::BuildConfig;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    Balance, CoinPage, GetObjectDataResponse, SuiCoinMetadata, SuiEvent,
-    SuiExecuteTransactionResponse, SuiExecutionStatus, SuiTransactionResponse, TransactionBytes,
+    Balance, CoinPage, GetObjectDataResponse, GetPastObjectDataResponse, SuiCoinMetadata, SuiEvent,
+    SuiExecuteTransactionResponse, SuiExecutionStatus, SuiGetPastObjectRequest, SuiTransactionRead,
+    SuiTransactionResponse, TransactionBytes,
 };
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
 use sui_types::balance::Supply;
 use sui_types::base_types::ObjectID;
 use sui_types::base_types::TransactionDigest;
-use sui_types::coin::{TreasuryCap, COIN_MODULE_NAME};
+use sui_types::coin::{TreasuryCap, COIN_MODULE_NAME, COIN_STRUCT_NAME};
 use sui_types::gas_coin::GAS;
 use sui_types::messages::ExecuteTransactionRequestType;
 use sui_types::object::Owner;
-use sui_types::query::{EventQuery, TransactionQuery};
+use sui_types::query::{EventQuery, ObjectTypeFilter, TransactionQuery};
 use sui_types::utils::to_sender_signed_transaction;
-use sui_types::{parse_sui_struct_tag, parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS};
+use sui_types::{
+    parse_sui_struct_tag, parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS, SUI_FRAMEWORK_OBJECT_ID,
+};
 use test_utils::network::TestClusterBuilder;
 
-use crate::api::CoinReadApiClient;
+use crate::api::{CoinReadApiClient, MULTI_GET_MAX_SIZE};
 use crate::api::{RpcFullNodeReadApiClient, TransactionExecutionApiClient};
 use crate::api::{RpcReadApiClient, RpcTransactionBuilderClient};
 
@@ -40,18 +43,85 @@ async fn test_get_objects() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
     assert_eq!(5, objects.len());
     Ok(())
 }
 
+#[sim_test]
+async fn test_get_objects_paginated() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+
+    let first_page = http_client
+        .get_objects_owned_by_address(*address, None, None, Some(3))
+        .await?;
+    assert_eq!(3, first_page.data.len());
+    assert!(first_page.next_cursor.is_some());
+
+    let second_page = http_client
+        .get_objects_owned_by_address(*address, None, first_page.next_cursor, Some(3))
+        .await?;
+    assert_eq!(2, second_page.data.len());
+    assert!(second_page.next_cursor.is_none());
+
+    let mut object_ids = first_page
+        .data
+        .iter()
+        .chain(&second_page.data)
+        .map(|info| info.object_id)
+        .collect::<Vec<_>>();
+    let all_ids = object_ids.clone();
+    object_ids.sort();
+    object_ids.dedup();
+    assert_eq!(all_ids, object_ids);
+
+    // All genesis objects of the account are gas coins.
+    let coins = http_client
+        .get_objects_owned_by_address(
+            *address,
+            Some(ObjectTypeFilter::StructType {
+                package: SUI_FRAMEWORK_OBJECT_ID,
+                module: COIN_MODULE_NAME.to_string(),
+                name: COIN_STRUCT_NAME.to_string(),
+            }),
+            None,
+            None,
+        )
+        .await?;
+    assert_eq!(5, coins.data.len());
+
+    let nfts = http_client
+        .get_objects_owned_by_address(
+            *address,
+            Some(ObjectTypeFilter::MoveModule {
+                package: SUI_FRAMEWORK_OBJECT_ID,
+                module: "devnet_nft".to_string(),
+            }),
+            None,
+            None,
+        )
+        .await?;
+    assert!(nfts.data.is_empty());
+    assert!(nfts.next_cursor.is_none());
+    Ok(())
+}
+
 #[sim_test]
 async fn test_public_transfer_object() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
 
     let transaction_bytes: TransactionBytes = http_client
         .transfer_object(
@@ -92,7 +162,10 @@ async fn test_publish() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
     let gas = objects.first().unwrap();
 
     let compiled_modules = BuildConfig::default()
@@ -125,7 +198,10 @@ async fn test_move_call() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
     let gas = objects.first().unwrap();
     let coin = &objects[1];
 
@@ -175,7 +251,10 @@ async fn test_get_object_info() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
 
     for oref in objects {
         let result: GetObjectDataResponse = http_client.get_object(oref.object_id).await?;
@@ -186,6 +265,49 @@ async fn test_get_object_info() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+#[sim_test]
+async fn test_multi_get_objects() -> Result<(), anyhow::Error> {
+    let cluster = TestClusterBuilder::new().build().await?;
+    let http_client = cluster.rpc_client();
+    let address = cluster.accounts.first().unwrap();
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
+
+    let mut object_ids = objects.iter().map(|o| o.object_id).collect::<Vec<_>>();
+    let missing = ObjectID::random();
+    object_ids.push(missing);
+    let results = http_client.multi_get_objects(object_ids).await?;
+    assert_eq!(results.len(), objects.len() + 1);
+    for (oref, result) in objects.iter().zip(&results) {
+        assert!(
+            matches!(result, GetObjectDataResponse::Exists(object) if oref.object_id == object.id() && object.reference.version == oref.version)
+        );
+    }
+    assert!(matches!(results.last(), Some(GetObjectDataResponse::NotExists(id)) if *id == missing));
+
+    let past_objects = objects
+        .iter()
+        .map(|o| SuiGetPastObjectRequest {
+            object_id: o.object_id,
+            version: o.version,
+        })
+        .collect();
+    let results = http_client.multi_get_past_objects(past_objects).await?;
+    assert_eq!(results.len(), objects.len());
+    for (oref, result) in objects.iter().zip(&results) {
+        assert!(
+            matches!(result, GetPastObjectDataResponse::VersionFound(object) if oref.object_id == object.id() && object.reference.version == oref.version)
+        );
+    }
+
+    // Batch size is capped on the server
+    let too_many = vec![objects[0].object_id; MULTI_GET_MAX_SIZE + 1];
+    assert!(http_client.multi_get_objects(too_many).await.is_err());
+    Ok(())
+}
+
 #[sim_test]
 async fn test_get_coins() -> Result<(), anyhow::Error> {
     let cluster = TestClusterBuilder::new().build().await?;
@@ -249,7 +371,10 @@ async fn test_get_metadata() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
     let gas = objects.first().unwrap();
 
     // Publish test coin package
@@ -310,7 +435,10 @@ async fn test_get_total_supply() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
     let gas = objects.first().unwrap();
 
     // Publish test coin package
@@ -433,7 +561,10 @@ async fn test_get_transaction() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client();
     let address = cluster.accounts.first().unwrap();
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None, None)
+        .await?
+        .data;
     let gas_id = objects.last().unwrap().object_id;
 
     // Make some transactions
@@ -468,13 +599,27 @@ async fn test_get_transaction() -> Result<(), anyhow::Error> {
     assert_eq!(2, tx.len());
 
     // test get_transaction
-    for tx_digest in tx {
-        let response: SuiTransactionResponse = http_client.get_transaction(tx_digest).await?;
+    for tx_digest in &tx {
+        let response: SuiTransactionResponse = http_client.get_transaction(*tx_digest).await?;
         assert!(tx_responses.iter().any(
             |resp| matches!(resp, SuiExecuteTransactionResponse::EffectsCert {effects, ..} if effects.effects.transaction_digest == response.effects.transaction_digest)
         ))
     }
 
+    // test multi_get_transactions, a missing transaction doesn't fail the others
+    let missing = TransactionDigest::random();
+    let mut digests = tx.clone();
+    digests.insert(1, missing);
+    let mut reads: Vec<SuiTransactionRead> = http_client.multi_get_transactions(digests).await?;
+    assert!(matches!(reads.remove(1), SuiTransactionRead::NotFound(digest) if digest == missing));
+    assert_eq!(
+        reads
+            .into_iter()
+            .map(|r| r.into_transaction().unwrap().effects.transaction_digest)
+            .collect::<Vec<_>>(),
+        tx
+    );
+
     Ok(())
 }
 
@@ -493,9 +638,10 @@ async fn test_get_fullnode_transaction() -> Result<(), anyhow::Error> {
     for address in cluster.accounts.iter() {
         let objects = client
             .read_api()
-            .get_objects_owned_by_address(*address)
+            .get_objects_owned_by_address(*address, None, None, None)
             .await
-            .unwrap();
+            .unwrap()
+            .data;
         let gas_id = objects.last().unwrap().object_id;
 
         // Make some transactions
@@ -629,9 +775,10 @@ async fn test_get_fullnode_events() -> Result<(), anyhow::Error> {
     for address in cluster.accounts.iter() {
         let objects = client
             .read_api()
-            .get_objects_owned_by_address(*address)
+            .get_objects_owned_by_address(*address, None, None, None)
             .await
-            .unwrap();
+            .unwrap()
+            .data;
         let gas_id = objects.last().unwrap().object_id;
 
         // Make some transactions
@@ -718,9 +865,10 @@ async fn test_get_fullnode_events() -> Result<(), anyhow::Error> {
 
     let object = client
         .read_api()
-        .get_objects_owned_by_address(cluster.accounts[2])
+        .get_objects_owned_by_address(cluster.accounts[2], None, None, None)
         .await
         .unwrap()
+        .data
         .last()
         .unwrap()
         .object_id;
@@ -403,7 +403,7 @@ fn test_basic_args_linter_top_level() {
         Path::new(env!("CARGO_MANIFEST_DIR")).join("../../sui_programmability/examples/nfts");
     let compiled_modules = BuildConfig::default().build(path).unwrap().into_modules();
     let example_package =
-        Object::new_package(compiled_modules, TransactionDigest::genesis()).unwrap();
+        Object::new_package_for_testing(compiled_modules, TransactionDigest::genesis()).unwrap();
     let example_package = example_package.data.try_as_package().unwrap();
 
     let module = Identifier::new("geniteam").unwrap();
@@ -520,7 +520,7 @@ fn test_basic_args_linter_top_level() {
         Path::new(env!("CARGO_MANIFEST_DIR")).join("../../sui_programmability/examples/basics");
     let compiled_modules = BuildConfig::default().build(path).unwrap().into_modules();
     let example_package =
-        Object::new_package(compiled_modules, TransactionDigest::genesis()).unwrap();
+        Object::new_package_for_testing(compiled_modules, TransactionDigest::genesis()).unwrap();
     let framework_pkg = example_package.data.try_as_package().unwrap();
 
     let module = Identifier::new("object_basics").unwrap();
@@ -616,7 +616,7 @@ fn test_basic_args_linter_top_level() {
         .join("../sui-core/src/unit_tests/data/entry_point_vector");
     let compiled_modules = BuildConfig::default().build(path).unwrap().into_modules();
     let example_package =
-        Object::new_package(compiled_modules, TransactionDigest::genesis()).unwrap();
+        Object::new_package_for_testing(compiled_modules, TransactionDigest::genesis()).unwrap();
     let example_package = example_package.data.try_as_package().unwrap();
 
     let module = Identifier::new("entry_point_vector").unwrap();
@@ -1,26 +1,56 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::SuiNode;
 use axum::{
-    extract::Extension,
+    extract::{Extension, Query},
     http::StatusCode,
     routing::{get, post},
-    Router,
+    Json, Router,
 };
 use mysten_metrics::spawn_monitored_task;
+use serde::{Deserialize, Serialize};
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use sui_core::db_backup::backup_node_dbs;
+use sui_core::epoch::reconfiguration::ReconfigCertStatus;
+use sui_types::committee::EpochId;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use telemetry_subscribers::FilterHandle;
-use tracing::info;
+use tokio::sync::OnceCell;
+use tracing::{error, info};
 
 const LOGGING_ROUTE: &str = "/logging";
+const STATUS_ROUTE: &str = "/status";
+const CHECKPOINT_DB_ROUTE: &str = "/checkpoint-db";
+const PRUNE_OBJECTS_ROUTE: &str = "/prune-objects";
+const BACKUP_ROUTE: &str = "/backup";
 
-pub fn start_admin_server(port: u16, filter_handle: FilterHandle) {
+// Directories under the node's db path where RocksDB checkpoints and backups are written
+// when the request does not name a destination.
+const DB_CHECKPOINTS_DIR: &str = "db_checkpoints";
+const BACKUPS_DIR: &str = "backups";
+
+/// The node served by the admin server, set once the node has started. The admin server is
+/// started first so that the log filter can be changed while the node starts up, the other
+/// routes answer `503 Service Unavailable` until then.
+pub type AdminNode = Arc<OnceCell<Arc<SuiNode>>>;
+
+pub fn start_admin_server(port: u16, filter_handle: FilterHandle) -> AdminNode {
     let filter = filter_handle.get().unwrap();
+    let node = AdminNode::default();
 
     let app = Router::new()
         .route(LOGGING_ROUTE, get(get_filter))
         .route(LOGGING_ROUTE, post(set_filter))
-        .layer(Extension(filter_handle));
+        .route(STATUS_ROUTE, get(get_status))
+        .route(CHECKPOINT_DB_ROUTE, post(checkpoint_db))
+        .route(PRUNE_OBJECTS_ROUTE, post(prune_objects))
+        .route(BACKUP_ROUTE, post(backup))
+        .layer(Extension(filter_handle))
+        .layer(Extension(node.clone()));
 
     let socket_address = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port);
     info!(
@@ -35,6 +65,16 @@ pub fn start_admin_server(port: u16, filter_handle: FilterHandle) {
             .await
             .unwrap();
     });
+    node
+}
+
+fn started_node(node: &AdminNode) -> Result<Arc<SuiNode>, (StatusCode, String)> {
+    node.get().cloned().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "the node is starting up".into(),
+        )
+    })
 }
 
 async fn get_filter(Extension(filter_handle): Extension<FilterHandle>) -> (StatusCode, String) {
@@ -56,3 +96,206 @@ async fn set_filter(
         Err(err) => (StatusCode::BAD_REQUEST, err.to_string()),
     }
 }
+
+#[derive(Serialize)]
+struct NodeStatus {
+    epoch: EpochId,
+    reconfig_state: ReconfigCertStatus,
+    highest_synced_checkpoint: Option<CheckpointSequenceNumber>,
+    highest_executed_checkpoint: Option<CheckpointSequenceNumber>,
+    highest_verified_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Only reported by validators.
+    consensus: Option<ConsensusStatus>,
+    connected_peers: usize,
+    /// Certificates waiting on missing input objects.
+    pending_certificates: usize,
+    /// Certificates with all inputs available that are being executed.
+    executing_certificates: usize,
+}
+
+#[derive(Serialize)]
+struct ConsensusStatus {
+    last_committed_round: u64,
+    last_committed_sub_dag_index: u64,
+}
+
+async fn get_status(
+    Extension(node): Extension<AdminNode>,
+) -> Result<Json<NodeStatus>, (StatusCode, String)> {
+    let node = started_node(&node)?;
+    node_status(&node)
+        .await
+        .map(Json)
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))
+}
+
+async fn node_status(node: &SuiNode) -> anyhow::Result<NodeStatus> {
+    let state = node.state();
+    let epoch_store = state.epoch_store();
+    let checkpoint_store = node.checkpoint_store();
+
+    let reconfig_state = epoch_store
+        .get_reconfig_state_read_lock_guard()
+        .status()
+        .clone();
+    let consensus = if state.is_validator() {
+        let last_index = epoch_store.get_last_consensus_index()?.index;
+        Some(ConsensusStatus {
+            last_committed_round: last_index.last_committed_round,
+            last_committed_sub_dag_index: last_index.sub_dag_index,
+        })
+    } else {
+        None
+    };
+    let (pending_certificates, executing_certificates) =
+        state.transaction_manager().inflight_counts().await;
+
+    Ok(NodeStatus {
+        epoch: epoch_store.epoch(),
+        reconfig_state,
+        highest_synced_checkpoint: checkpoint_store.get_highest_synced_checkpoint_seq_number()?,
+        highest_executed_checkpoint: checkpoint_store
+            .get_highest_executed_checkpoint_seq_number()?,
+        highest_verified_checkpoint: checkpoint_store
+            .get_highest_verified_checkpoint()?
+            .map(|checkpoint| checkpoint.sequence_number()),
+        consensus,
+        connected_peers: node.p2p_network().peers().len(),
+        pending_certificates,
+        executing_certificates,
+    })
+}
+
+#[derive(Deserialize)]
+struct DestinationParams {
+    /// Destination directory, it must not exist yet.
+    path: Option<PathBuf>,
+}
+
+impl DestinationParams {
+    fn path_or_default(self, node: &SuiNode, dir: &str) -> PathBuf {
+        self.path.unwrap_or_else(|| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_millis();
+            node.config().db_path().join(dir).join(format!(
+                "epoch_{}_{}",
+                node.state().epoch(),
+                now
+            ))
+        })
+    }
+}
+
+async fn checkpoint_db(
+    Extension(node): Extension<AdminNode>,
+    Query(params): Query<DestinationParams>,
+) -> (StatusCode, String) {
+    let node = match started_node(&node) {
+        Ok(node) => node,
+        Err(err) => return err,
+    };
+    let state = node.state();
+    let path = params.path_or_default(&node, DB_CHECKPOINTS_DIR);
+    if path.exists() {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("{} already exists", path.display()),
+        );
+    }
+
+    let checkpoint_path = path.clone();
+    let result =
+        tokio::task::spawn_blocking(move || state.db().checkpoint_db(&checkpoint_path)).await;
+    match result {
+        Ok(Ok(_)) => {
+            info!(path =% path.display(), "Created authority store db checkpoint");
+            (StatusCode::OK, path.display().to_string())
+        }
+        Ok(Err(err)) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
+
+async fn prune_objects(Extension(node): Extension<AdminNode>) -> (StatusCode, String) {
+    let node = match started_node(&node) {
+        Ok(node) => node,
+        Err(err) => return err,
+    };
+    let num_versions_to_retain = node
+        .config()
+        .authority_store_pruning_config
+        .objects_num_latest_versions_to_retain;
+    if num_versions_to_retain == u64::MAX {
+        return (
+            StatusCode::BAD_REQUEST,
+            "object pruning is disabled on this node".into(),
+        );
+    }
+
+    let store = node.state().db();
+    // Pruning scans the whole objects table, so it runs in the background rather than
+    // holding the request open.
+    spawn_monitored_task!(async move {
+        info!(
+            num_versions_to_retain,
+            "Starting on-demand pruning of objects table"
+        );
+        match tokio::task::spawn_blocking(move || {
+            store.prune_objects_immediately(num_versions_to_retain)
+        })
+        .await
+        {
+            Ok(num_pruned) => info!(
+                "Finished on-demand pruning with total object versions pruned = {}",
+                num_pruned
+            ),
+            Err(err) => error!("On-demand pruning of objects table failed: {}", err),
+        }
+    });
+    (StatusCode::ACCEPTED, "object pruning started".into())
+}
+
+async fn backup(
+    Extension(node): Extension<AdminNode>,
+    Query(params): Query<DestinationParams>,
+) -> (StatusCode, String) {
+    let node = match started_node(&node) {
+        Ok(node) => node,
+        Err(err) => return err,
+    };
+    let path = params.path_or_default(&node, BACKUPS_DIR);
+    if path.exists() {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("{} already exists", path.display()),
+        );
+    }
+
+    let backup_path = path.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        let state = node.state();
+        backup_node_dbs(
+            &backup_path,
+            &node.checkpoint_store(),
+            state.committee_store(),
+            &state.db(),
+            state.indexes().map(|indexes| indexes.as_ref()),
+        )
+    })
+    .await;
+    match result {
+        Ok(Ok(info)) => (
+            StatusCode::OK,
+            format!(
+                "{} at epoch {}, highest executed checkpoint {:?}",
+                path.display(),
+                info.epoch,
+                info.highest_executed_checkpoint
+            ),
+        ),
+        Ok(Err(err)) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()),
+    }
+}
@@ -44,6 +44,7 @@ use sui_json_rpc::{JsonRpcServerBuilder, ServerHandle};
 use sui_network::api::ValidatorServer;
 use sui_network::discovery;
 use sui_network::state_sync;
+use sui_protocol_constants::SupportedProtocolVersions;
 use sui_storage::{
     event_store::{EventStoreType, SqlEventStore},
     IndexStore,
@@ -52,6 +53,7 @@ use sui_types::committee::Committee;
 use sui_types::crypto::KeypairTraits;
 use sui_types::messages::VerifiedCertificate;
 use sui_types::messages::VerifiedCertifiedTransactionEffects;
+use sui_types::messages::{AuthorityCapabilities, ConsensusTransaction};
 use tokio::sync::mpsc::channel;
 use tokio::sync::Mutex;
 use tower::ServiceBuilder;
@@ -70,6 +72,10 @@ use sui_core::checkpoints::{
 use sui_core::consensus_adapter::{ConsensusAdapter, ConsensusAdapterMetrics};
 use sui_core::consensus_handler::ConsensusHandler;
 use sui_core::consensus_validator::SuiTxValidator;
+use sui_core::db_backup::{
+    reindex_restored_transactions, AUTHORITY_DB_DIR, CHECKPOINTS_DB_DIR, COMMITTEE_DB_DIR,
+    INDEXES_DB_DIR,
+};
 use sui_core::epoch::reconfiguration::ReconfigurationInitiator;
 use sui_core::narwhal_manager::{
     run_narwhal_manager, NarwhalConfiguration, NarwhalManager, NarwhalStartMessage,
@@ -93,7 +99,7 @@ pub struct SuiNode {
     transaction_orchestrator: Option<Arc<TransactiondOrchestrator<NetworkAuthorityClient>>>,
     registry_service: RegistryService,
 
-    _p2p_network: Network,
+    p2p_network: Network,
     _discovery: discovery::Handle,
     state_sync: state_sync::Handle,
     checkpoint_store: Arc<CheckpointStore>,
@@ -128,22 +134,24 @@ impl SuiNode {
         let secret = Arc::pin(config.protocol_key_pair().copy());
         let genesis_committee = genesis.committee()?;
         let committee_store = Arc::new(CommitteeStore::new(
-            config.db_path().join("epochs"),
+            config.db_path().join(COMMITTEE_DB_DIR),
             &genesis_committee,
             None,
         ));
         let store = Arc::new(
             AuthorityStore::open(
-                &config.db_path().join("store"),
+                &config.db_path().join(AUTHORITY_DB_DIR),
                 None,
                 genesis,
                 &committee_store,
                 &config.authority_store_pruning_config,
+                &config.authority_store_cache_config,
+                &prometheus_registry,
             )
             .await?,
         );
 
-        let checkpoint_store = CheckpointStore::new(&config.db_path().join("checkpoints"));
+        let checkpoint_store = CheckpointStore::new(&config.db_path().join(CHECKPOINTS_DB_DIR));
         let state_sync_store = RocksDbStore::new(
             store.clone(),
             committee_store.clone(),
@@ -153,7 +161,9 @@ impl SuiNode {
         let index_store = if is_validator {
             None
         } else {
-            Some(Arc::new(IndexStore::new(config.db_path().join("indexes"))))
+            Some(Arc::new(IndexStore::new(
+                config.db_path().join(INDEXES_DB_DIR),
+            )))
         };
 
         let event_store = if config.enable_event_processing {
@@ -199,10 +209,14 @@ impl SuiNode {
         )
         .await;
 
+        // Before the checkpoint executor starts, it skips transactions that are executed.
+        reindex_restored_transactions(&state, config.db_path()).await?;
+
         let (checkpoint_executor_handle, reconfig_channel) = CheckpointExecutor::new(
             state_sync_handle.subscribe_to_synced_checkpoints(),
             checkpoint_store.clone(),
             state.clone(),
+            config.checkpoint_executor_config.clone(),
             &prometheus_registry,
         )
         .start()?;
@@ -224,6 +238,7 @@ impl SuiNode {
 
         let json_rpc_service = build_server(
             state.clone(),
+            checkpoint_store.clone(),
             &transaction_orchestrator.clone(),
             config,
             &prometheus_registry,
@@ -254,7 +269,7 @@ impl SuiNode {
             transaction_orchestrator,
             registry_service,
 
-            _p2p_network: p2p_network,
+            p2p_network,
             _discovery: discovery_handle,
             state_sync: state_sync_handle,
             checkpoint_store,
@@ -414,6 +429,8 @@ impl SuiNode {
         )
         .await?;
 
+        Self::submit_capabilities(config, &state, &consensus_adapter)?;
+
         Ok(Arc::new(ValidatorComponents {
             _validator_server_handle: validator_server_handle,
             narwhal_manager,
@@ -422,6 +439,27 @@ impl SuiNode {
         }))
     }
 
+    /// Advertise the protocol versions supported by this binary to the rest of the committee,
+    /// so that the next epoch can upgrade once a quorum of stake supports it.
+    fn submit_capabilities(
+        config: &NodeConfig,
+        state: &AuthorityState,
+        consensus_adapter: &Arc<ConsensusAdapter>,
+    ) -> SuiResult {
+        let supported_protocol_versions = config
+            .supported_protocol_versions
+            .unwrap_or(SupportedProtocolVersions::SYSTEM_DEFAULT);
+        info!(
+            ?supported_protocol_versions,
+            "Sending capability notification"
+        );
+        let transaction = ConsensusTransaction::new_capability_notification(
+            AuthorityCapabilities::new(state.name, supported_protocol_versions),
+        );
+        consensus_adapter.submit(transaction, None)?;
+        Ok(())
+    }
+
     fn start_checkpoint_service(
         config: &NodeConfig,
         consensus_adapter: Arc<ConsensusAdapter>,
@@ -551,10 +589,22 @@ impl SuiNode {
         Ok(grpc_server)
     }
 
+    pub fn config(&self) -> &NodeConfig {
+        &self.config
+    }
+
     pub fn state(&self) -> Arc<AuthorityState> {
         self.state.clone()
     }
 
+    pub fn p2p_network(&self) -> &Network {
+        &self.p2p_network
+    }
+
+    pub fn checkpoint_store(&self) -> Arc<CheckpointStore> {
+        self.checkpoint_store.clone()
+    }
+
     pub fn active(&self) -> &Arc<ActiveAuthority<NetworkAuthorityClient>> {
         &self.active
     }
@@ -647,6 +697,12 @@ impl SuiNode {
                         .await?;
                     // TODO: (Laura) wait for start complete signal
                     info!("Starting Narwhal");
+
+                    Self::submit_capabilities(
+                        &self.config,
+                        &self.state,
+                        &validator_components.consensus_adapter,
+                    )?;
                 } else {
                     info!("This node is no longer a validator after reconfiguration");
                 }
@@ -670,6 +726,7 @@ impl SuiNode {
 
 pub async fn build_server(
     state: Arc<AuthorityState>,
+    checkpoint_store: Arc<CheckpointStore>,
     transaction_orchestrator: &Option<Arc<TransactiondOrchestrator<NetworkAuthorityClient>>>,
     config: &NodeConfig,
     prometheus_registry: &Registry,
@@ -683,7 +740,7 @@ pub async fn build_server(
 
     server.register_module(ReadApi::new(state.clone()))?;
     server.register_module(CoinReadApi::new(state.clone()))?;
-    server.register_module(FullNodeApi::new(state.clone()))?;
+    server.register_module(FullNodeApi::new(state.clone(), checkpoint_store))?;
     server.register_module(BcsApiImpl::new(state.clone()))?;
     server.register_module(FullNodeTransactionBuilderApi::new(state.clone()))?;
 
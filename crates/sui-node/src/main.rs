@@ -66,9 +66,14 @@ async fn main() -> Result<()> {
         }
     });
 
-    sui_node::admin::start_admin_server(config.admin_interface_port, filter_handle);
+    let admin_node =
+        sui_node::admin::start_admin_server(config.admin_interface_port, filter_handle);
 
     let node = sui_node::SuiNode::start(&config, registry_service).await?;
+    if admin_node.set(node.clone()).is_err() {
+        unreachable!("the node is only started once");
+    }
+
     node.monitor_reconfiguration().await?;
 
     Ok(())
@@ -16,7 +16,7 @@ use sui::client_commands::EXAMPLE_NFT_NAME;
 use sui::client_commands::EXAMPLE_NFT_URL;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    EventPage, MoveCallParams, OwnedObjectRef, RPCTransactionRequestParams,
+    EventPage, MoveCallParams, ObjectsPage, OwnedObjectRef, RPCTransactionRequestParams,
     SuiCertifiedTransaction, SuiData, SuiEvent, SuiEventEnvelope, SuiExecutionStatus,
     SuiGasCostSummary, SuiObject, SuiObjectInfo, SuiObjectRead, SuiObjectRef, SuiParsedData,
     SuiPastObjectRead, SuiRawData, SuiRawMoveObject, SuiTransactionAuthSignersResponse,
@@ -38,8 +38,8 @@ use sui_types::messages::{
     TransactionKind, TransferObject,
 };
 use sui_types::object::{Owner, PACKAGE_VERSION};
-use sui_types::query::EventQuery;
 use sui_types::query::TransactionQuery;
+use sui_types::query::{EventQuery, ObjectTypeFilter};
 use sui_types::utils::to_sender_signed_transaction;
 use sui_types::SUI_FRAMEWORK_OBJECT_ID;
 
@@ -282,7 +282,7 @@ impl RpcExampleProvider {
 
     fn get_objects_owned_by_address(&mut self) -> Examples {
         let owner = SuiAddress::from(ObjectID::new(self.rng.gen()));
-        let result = (0..4)
+        let mut data = (0..4)
             .map(|_| SuiObjectInfo {
                 object_id: ObjectID::new(self.rng.gen()),
                 version: Default::default(),
@@ -292,19 +292,35 @@ impl RpcExampleProvider {
                 previous_transaction: TransactionDigest::new(self.rng.gen()),
             })
             .collect::<Vec<_>>();
+        // Objects are returned in object ID order, the last one is the cursor for the next page.
+        data.sort_by_key(|info| info.object_id);
+        let next_cursor = data.pop().map(|info| info.object_id);
+        let result = ObjectsPage { data, next_cursor };
 
         Examples::new(
             "sui_getObjectsOwnedByAddress",
             vec![ExamplePairing::new(
                 "Get objects owned by an address",
-                vec![("address", json!(owner))],
+                vec![
+                    ("address", json!(owner)),
+                    (
+                        "object_type",
+                        json!(ObjectTypeFilter::StructType {
+                            package: SUI_FRAMEWORK_OBJECT_ID,
+                            module: "coin".to_string(),
+                            name: "Coin".to_string(),
+                        }),
+                    ),
+                    ("cursor", json!(None::<ObjectID>)),
+                    ("limit", json!(3)),
+                ],
                 json!(result),
             )],
         )
     }
     fn get_objects_owned_by_object(&mut self) -> Examples {
         let owner = ObjectID::new(self.rng.gen());
-        let result = (0..4)
+        let data = (0..4)
             .map(|_| SuiObjectInfo {
                 object_id: ObjectID::new(self.rng.gen()),
                 version: Default::default(),
@@ -314,12 +330,21 @@ impl RpcExampleProvider {
                 previous_transaction: TransactionDigest::new(self.rng.gen()),
             })
             .collect::<Vec<_>>();
+        let result = ObjectsPage {
+            data,
+            next_cursor: None,
+        };
 
         Examples::new(
             "sui_getObjectsOwnedByObject",
             vec![ExamplePairing::new(
                 "Get objects owned by an object",
-                vec![("object_id", json!(owner))],
+                vec![
+                    ("object_id", json!(owner)),
+                    ("object_type", json!(None::<ObjectTypeFilter>)),
+                    ("cursor", json!(None::<ObjectID>)),
+                    ("limit", json!(None::<usize>)),
+                ],
                 json!(result),
             )],
         )
@@ -3,6 +3,8 @@
 
 // Constants that change the behavior of the protocol
 
+use serde::{Deserialize, Serialize};
+
 // ==== Move VM, Move bytecode verifier, and execution limits ===
 
 /// Maximum Move bytecode version the VM understands. All older versions are accepted.
@@ -100,3 +102,398 @@ pub const OBJ_METADATA_COST_NON_REFUNDABLE: u64 = 50;
 pub const STORAGE_REBATE_RATE: f64 = 1.0;
 
 pub const STORAGE_FUND_REINVEST_RATE: u64 = 0;
+
+// === Protocol versioning ===
+
+/// The minimum and maximum protocol versions supported by this build.
+const MIN_PROTOCOL_VERSION: u64 = 1;
+const MAX_PROTOCOL_VERSION: u64 = 2;
+
+/// Version of the protocol rules in effect for an epoch. The version is recorded in the
+/// SuiSystemState object and only changes at epoch boundaries.
+#[derive(Copy, Clone, Debug, Hash, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ProtocolVersion(u64);
+
+impl ProtocolVersion {
+    /// The oldest version this build can execute. A new network starts at this version.
+    pub const MIN: Self = Self(MIN_PROTOCOL_VERSION);
+
+    /// The newest version this build can execute.
+    pub const MAX: Self = Self(MAX_PROTOCOL_VERSION);
+
+    pub fn new(v: u64) -> Self {
+        Self(v)
+    }
+
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for ProtocolVersion {
+    fn from(v: u64) -> Self {
+        Self::new(v)
+    }
+}
+
+impl std::ops::Add<u64> for ProtocolVersion {
+    type Output = Self;
+    fn add(self, rhs: u64) -> Self::Output {
+        Self::new(self.0 + rhs)
+    }
+}
+
+impl std::fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The range of protocol versions a validator is able to run. Validators advertise this range to
+/// each other so that the network only moves to a new version once enough stake supports it.
+#[derive(Copy, Clone, Debug, Hash, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SupportedProtocolVersions {
+    pub min: ProtocolVersion,
+    pub max: ProtocolVersion,
+}
+
+impl SupportedProtocolVersions {
+    pub const SYSTEM_DEFAULT: Self = Self {
+        min: ProtocolVersion::MIN,
+        max: ProtocolVersion::MAX,
+    };
+
+    pub fn new_for_testing(min: u64, max: u64) -> Self {
+        let min = min.into();
+        let max = max.into();
+        assert!(min <= max, "invalid protocol version range {min}..={max}");
+        Self { min, max }
+    }
+
+    pub fn is_version_supported(&self, v: ProtocolVersion) -> bool {
+        self.min <= v && v <= self.max
+    }
+}
+
+impl Default for SupportedProtocolVersions {
+    fn default() -> Self {
+        Self::SYSTEM_DEFAULT
+    }
+}
+
+/// Limits and costs that are part of the protocol and must agree across all validators.
+/// The active config is determined by the protocol version of the current epoch, so any of these
+/// values can be changed in a new version without forking the network.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct ProtocolConfig {
+    version: ProtocolVersion,
+
+    // Move VM, Move bytecode verifier, and execution limits
+    move_binary_format_version: u32,
+    max_move_object_size: u64,
+    max_move_package_size: u64,
+    max_tx_gas: u64,
+    max_loop_depth: usize,
+    max_generic_instantiation_length: usize,
+    max_function_parameters: usize,
+    max_basic_blocks: usize,
+    max_value_stack_size: usize,
+    max_type_nodes: usize,
+    max_push_size: usize,
+    max_dependency_depth: u64,
+    max_num_event_emit: u64,
+
+    // Execution gas costs
+    base_tx_cost_fixed: u64,
+    package_publish_cost_fixed: u64,
+    base_tx_cost_per_byte: u64,
+    package_publish_cost_per_byte: u64,
+    obj_access_cost_read_per_byte: u64,
+    obj_access_cost_mutate_per_byte: u64,
+    obj_access_cost_delete_per_byte: u64,
+    obj_access_cost_verify_per_byte: u64,
+
+    // Storage gas costs
+    obj_data_cost_refundable: u64,
+    obj_metadata_cost_non_refundable: u64,
+
+    // Tokenomics
+    /// Stored in parts per million so that the config can be compared for equality.
+    storage_rebate_rate_ppm: u64,
+    storage_fund_reinvest_rate: u64,
+
+    // Consensus
+    /// Whether the user certificates of a consensus commit are ordered by descending gas price,
+    /// rather than scheduled in consensus order.
+    order_by_gas_price: bool,
+    /// Maximum number of certificates touching the same shared object that are scheduled in a
+    /// single consensus commit. Excess certificates are deferred to later commits.
+    max_transactions_per_shared_object_per_commit: u64,
+
+    // Randomness
+    /// Whether validators sign checkpoints with their randomness beacon shares and aggregate the
+    /// partial signatures sequenced by consensus. The beacon keys are still derived from a public
+    /// seed, so anyone can predict its output: this must stay disabled until a DKG replaces the
+    /// mocked key generation.
+    randomness_beacon: bool,
+}
+
+impl ProtocolConfig {
+    /// Returns the config for `version`.
+    /// Panics if the version is not supported by this build, since executing under the wrong
+    /// rules would fork the node off the network.
+    pub fn get_for_version(version: ProtocolVersion) -> Self {
+        assert!(
+            version >= ProtocolVersion::MIN,
+            "Network protocol version is {:?}, but the minimum supported version by the binary is {:?}. Please upgrade the binary.",
+            version,
+            ProtocolVersion::MIN,
+        );
+        assert!(
+            version <= ProtocolVersion::MAX,
+            "Network protocol version is {:?}, but the maximum supported version by the binary is {:?}. Please upgrade the binary.",
+            version,
+            ProtocolVersion::MAX,
+        );
+        Self::get_for_version_impl(version)
+    }
+
+    pub fn get_for_min_version() -> Self {
+        Self::get_for_version(ProtocolVersion::MIN)
+    }
+
+    pub fn get_for_max_version() -> Self {
+        Self::get_for_version(ProtocolVersion::MAX)
+    }
+
+    fn get_for_version_impl(version: ProtocolVersion) -> Self {
+        match version.as_u64() {
+            1 => Self {
+                version,
+                move_binary_format_version: MOVE_BINARY_FORMAT_VERSION,
+                max_move_object_size: MAX_MOVE_OBJECT_SIZE,
+                max_move_package_size: MAX_MOVE_PACKAGE_SIZE,
+                max_tx_gas: MAX_TX_GAS,
+                max_loop_depth: MAX_LOOP_DEPTH,
+                max_generic_instantiation_length: MAX_GENERIC_INSTANTIATION_LENGTH,
+                max_function_parameters: MAX_FUNCTION_PARAMETERS,
+                max_basic_blocks: MAX_BASIC_BLOCKS,
+                max_value_stack_size: MAX_VALUE_STACK_SIZE,
+                max_type_nodes: MAX_TYPE_NODES,
+                max_push_size: MAX_PUSH_SIZE,
+                max_dependency_depth: MAX_DEPENDENCY_DEPTH,
+                max_num_event_emit: MAX_NUM_EVENT_EMIT,
+                base_tx_cost_fixed: BASE_TX_COST_FIXED,
+                package_publish_cost_fixed: PACKAGE_PUBLISH_COST_FIXED,
+                base_tx_cost_per_byte: BASE_TX_COST_PER_BYTE,
+                package_publish_cost_per_byte: PACKAGE_PUBLISH_COST_PER_BYTE,
+                obj_access_cost_read_per_byte: OBJ_ACCESS_COST_READ_PER_BYTE,
+                obj_access_cost_mutate_per_byte: OBJ_ACCESS_COST_MUTATE_PER_BYTE,
+                obj_access_cost_delete_per_byte: OBJ_ACCESS_COST_DELETE_PER_BYTE,
+                obj_access_cost_verify_per_byte: OBJ_ACCESS_COST_VERIFY_PER_BYTE,
+                obj_data_cost_refundable: OBJ_DATA_COST_REFUNDABLE,
+                obj_metadata_cost_non_refundable: OBJ_METADATA_COST_NON_REFUNDABLE,
+                storage_rebate_rate_ppm: (STORAGE_REBATE_RATE * 1_000_000.0) as u64,
+                storage_fund_reinvest_rate: STORAGE_FUND_REINVEST_RATE,
+                order_by_gas_price: false,
+                max_transactions_per_shared_object_per_commit: u64::MAX,
+                randomness_beacon: false,
+            },
+            2 => Self {
+                version,
+                order_by_gas_price: true,
+                max_transactions_per_shared_object_per_commit: 100,
+                ..Self::get_for_version_impl(ProtocolVersion::new(1))
+            },
+            // Changes to the config in future versions go here, e.g.
+            // 3 => Self {
+            //     version,
+            //     max_tx_gas: 2_000_000_000,
+            //     ..Self::get_for_version_impl(ProtocolVersion::new(2))
+            // },
+            _ => panic!("unsupported protocol version {:?}", version),
+        }
+    }
+
+    pub fn version(&self) -> ProtocolVersion {
+        self.version
+    }
+
+    pub fn move_binary_format_version(&self) -> u32 {
+        self.move_binary_format_version
+    }
+
+    pub fn max_move_object_size(&self) -> u64 {
+        self.max_move_object_size
+    }
+
+    pub fn max_move_package_size(&self) -> u64 {
+        self.max_move_package_size
+    }
+
+    pub fn max_tx_gas(&self) -> u64 {
+        self.max_tx_gas
+    }
+
+    pub fn max_loop_depth(&self) -> usize {
+        self.max_loop_depth
+    }
+
+    pub fn max_generic_instantiation_length(&self) -> usize {
+        self.max_generic_instantiation_length
+    }
+
+    pub fn max_function_parameters(&self) -> usize {
+        self.max_function_parameters
+    }
+
+    pub fn max_basic_blocks(&self) -> usize {
+        self.max_basic_blocks
+    }
+
+    pub fn max_value_stack_size(&self) -> usize {
+        self.max_value_stack_size
+    }
+
+    pub fn max_type_nodes(&self) -> usize {
+        self.max_type_nodes
+    }
+
+    pub fn max_push_size(&self) -> usize {
+        self.max_push_size
+    }
+
+    pub fn max_dependency_depth(&self) -> u64 {
+        self.max_dependency_depth
+    }
+
+    pub fn max_num_event_emit(&self) -> u64 {
+        self.max_num_event_emit
+    }
+
+    pub fn base_tx_cost_fixed(&self) -> u64 {
+        self.base_tx_cost_fixed
+    }
+
+    pub fn package_publish_cost_fixed(&self) -> u64 {
+        self.package_publish_cost_fixed
+    }
+
+    pub fn base_tx_cost_per_byte(&self) -> u64 {
+        self.base_tx_cost_per_byte
+    }
+
+    pub fn package_publish_cost_per_byte(&self) -> u64 {
+        self.package_publish_cost_per_byte
+    }
+
+    pub fn obj_access_cost_read_per_byte(&self) -> u64 {
+        self.obj_access_cost_read_per_byte
+    }
+
+    pub fn obj_access_cost_mutate_per_byte(&self) -> u64 {
+        self.obj_access_cost_mutate_per_byte
+    }
+
+    pub fn obj_access_cost_delete_per_byte(&self) -> u64 {
+        self.obj_access_cost_delete_per_byte
+    }
+
+    pub fn obj_access_cost_verify_per_byte(&self) -> u64 {
+        self.obj_access_cost_verify_per_byte
+    }
+
+    pub fn obj_data_cost_refundable(&self) -> u64 {
+        self.obj_data_cost_refundable
+    }
+
+    pub fn obj_metadata_cost_non_refundable(&self) -> u64 {
+        self.obj_metadata_cost_non_refundable
+    }
+
+    pub fn storage_rebate_rate(&self) -> f64 {
+        self.storage_rebate_rate_ppm as f64 / 1_000_000.0
+    }
+
+    pub fn storage_fund_reinvest_rate(&self) -> u64 {
+        self.storage_fund_reinvest_rate
+    }
+
+    pub fn order_by_gas_price(&self) -> bool {
+        self.order_by_gas_price
+    }
+
+    pub fn max_transactions_per_shared_object_per_commit(&self) -> u64 {
+        self.max_transactions_per_shared_object_per_commit
+    }
+
+    pub fn randomness_beacon(&self) -> bool {
+        self.randomness_beacon
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn all_supported_versions_have_a_config() {
+        for v in MIN_PROTOCOL_VERSION..=MAX_PROTOCOL_VERSION {
+            let config = ProtocolConfig::get_for_version(ProtocolVersion::new(v));
+            assert_eq!(config.version().as_u64(), v);
+        }
+    }
+
+    #[test]
+    fn version_1_matches_constants() {
+        let config = ProtocolConfig::get_for_version(ProtocolVersion::new(1));
+        assert_eq!(config.max_tx_gas(), MAX_TX_GAS);
+        assert_eq!(config.max_move_object_size(), MAX_MOVE_OBJECT_SIZE);
+        assert_eq!(config.base_tx_cost_fixed(), BASE_TX_COST_FIXED);
+        assert_eq!(config.storage_rebate_rate(), STORAGE_REBATE_RATE);
+        assert_eq!(
+            config.storage_fund_reinvest_rate(),
+            STORAGE_FUND_REINVEST_RATE
+        );
+    }
+
+    #[test]
+    fn version_2_orders_certificates_by_gas_price() {
+        let v1 = ProtocolConfig::get_for_version(ProtocolVersion::new(1));
+        let v2 = ProtocolConfig::get_for_version(ProtocolVersion::new(2));
+        assert!(!v1.order_by_gas_price());
+        assert!(v2.order_by_gas_price());
+    }
+
+    #[test]
+    fn version_2_limits_shared_object_transactions_per_commit() {
+        let v1 = ProtocolConfig::get_for_version(ProtocolVersion::new(1));
+        let v2 = ProtocolConfig::get_for_version(ProtocolVersion::new(2));
+        assert_eq!(v1.max_transactions_per_shared_object_per_commit(), u64::MAX);
+        assert_eq!(v2.max_transactions_per_shared_object_per_commit(), 100);
+        assert_eq!(v2.max_tx_gas(), v1.max_tx_gas());
+    }
+
+    #[test]
+    fn randomness_beacon_is_disabled_in_all_versions() {
+        for v in MIN_PROTOCOL_VERSION..=MAX_PROTOCOL_VERSION {
+            let config = ProtocolConfig::get_for_version(ProtocolVersion::new(v));
+            assert!(!config.randomness_beacon());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Please upgrade the binary")]
+    fn unsupported_version_panics() {
+        ProtocolConfig::get_for_version(ProtocolVersion::MAX + 1);
+    }
+
+    #[test]
+    fn supported_versions_range() {
+        let supported = SupportedProtocolVersions::new_for_testing(1, 3);
+        assert!(!supported.is_version_supported(ProtocolVersion::new(0)));
+        assert!(supported.is_version_supported(ProtocolVersion::new(1)));
+        assert!(supported.is_version_supported(ProtocolVersion::new(3)));
+        assert!(!supported.is_version_supported(ProtocolVersion::new(4)));
+    }
+}
@@ -7,8 +7,6 @@ use std::sync::Arc;
 use axum::{Extension, Json};
 use futures::StreamExt;
 
-use sui_sdk::SUI_COIN_TYPE;
-
 use crate::errors::Error;
 use crate::types::{
     AccountBalanceRequest, AccountBalanceResponse, AccountCoinsRequest, AccountCoinsResponse,
@@ -35,29 +33,71 @@ pub async fn balance(
     }
     .map(|b| b.block.block_identifier);
 
-    if let Some(block_identifier) = block_id {
-        context
+    let currencies = if request.currencies.is_empty() {
+        context.currencies.clone()
+    } else {
+        for currency in &request.currencies {
+            if !context.currencies.contains(currency) {
+                return Err(Error::InvalidInput(format!(
+                    "Unsupported currency: {currency:?}"
+                )));
+            }
+        }
+        request.currencies
+    };
+
+    // Staked SUI is only known from the indexed delegations, it is reported at the
+    // current block if no block is requested.
+    if request.account_identifier.sub_account.is_some() {
+        let block_identifier = match block_id {
+            Some(block_identifier) => block_identifier,
+            None => context.blocks().current_block_identifier().await?,
+        };
+        let balance = context
             .blocks()
-            .get_balance_at_block(request.account_identifier.address, block_identifier.index)
-            .await
-            .map(|balance| AccountBalanceResponse {
-                block_identifier,
-                balances: vec![Amount::new(balance.into())],
-            })
+            .get_stake_balance_at_block(request.account_identifier.address, block_identifier.index)
+            .await?;
+        return Ok(AccountBalanceResponse {
+            block_identifier,
+            balances: vec![Amount::new(balance.into())],
+        });
+    }
+
+    if let Some(block_identifier) = block_id {
+        let mut balances = vec![];
+        for currency in currencies {
+            let balance = context
+                .blocks()
+                .get_balance_at_block(
+                    request.account_identifier.address,
+                    &currency,
+                    block_identifier.index,
+                )
+                .await?;
+            balances.push(Amount::new_with_currency(balance.into(), currency));
+        }
+        Ok(AccountBalanceResponse {
+            block_identifier,
+            balances,
+        })
     } else {
-        let amount = context
-            .client
-            .coin_read_api()
-            .get_coins_stream(
-                request.account_identifier.address,
-                Some(SUI_COIN_TYPE.to_string()),
-            )
-            .fold(0u128, |acc, coin| async move { acc + coin.balance as u128 })
-            .await;
+        let mut balances = vec![];
+        for currency in currencies {
+            let amount = context
+                .client
+                .coin_read_api()
+                .get_coins_stream(
+                    request.account_identifier.address,
+                    Some(currency.metadata.coin_type.clone()),
+                )
+                .fold(0u128, |acc, coin| async move { acc + coin.balance as u128 })
+                .await;
+            balances.push(Amount::new_with_currency(amount.into(), currency));
+        }
 
         Ok(AccountBalanceResponse {
             block_identifier: context.blocks().current_block_identifier().await?,
-            balances: vec![Amount::new(amount.into())],
+            balances,
         })
     }
 }
@@ -70,16 +110,20 @@ pub async fn coins(
     Extension(env): Extension<SuiEnv>,
 ) -> Result<AccountCoinsResponse, Error> {
     env.check_network_identifier(&request.network_identifier)?;
-    let coins = context
-        .client
-        .coin_read_api()
-        .get_coins_stream(
-            request.account_identifier.address,
-            Some(SUI_COIN_TYPE.to_string()),
-        )
-        .map(Coin::from)
-        .collect()
-        .await;
+    let mut coins = vec![];
+    for currency in &context.currencies {
+        let currency_coins = context
+            .client
+            .coin_read_api()
+            .get_coins_stream(
+                request.account_identifier.address,
+                Some(currency.metadata.coin_type.clone()),
+            )
+            .map(|coin| Coin::new(coin, currency.clone()))
+            .collect::<Vec<_>>()
+            .await;
+        coins.extend(currency_coins);
+    }
 
     Ok(AccountCoinsResponse {
         block_identifier: context.blocks().current_block_identifier().await?,
@@ -48,7 +48,12 @@ pub async fn transaction(
     let data = &response.certificate.data;
     let effects = response.effects;
 
-    let operations = Operation::from_data_and_events(data, &effects.status, &effects.events)?;
+    let operations = Operation::from_data_and_events(
+        data,
+        &effects.status,
+        &effects.events,
+        &context.currencies,
+    )?;
 
     let transaction = Transaction {
         transaction_identifier: TransactionIdentifier { hash },
@@ -7,23 +7,28 @@ use std::sync::Arc;
 use axum::{Extension, Json};
 use fastcrypto::encoding::{Encoding, Hex};
 use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use sui_sdk::rpc_types::SuiRawData;
 use sui_sdk::SUI_COIN_TYPE;
 
-use sui_types::base_types::SuiAddress;
+use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress};
 use sui_types::crypto;
 use sui_types::crypto::{SignatureScheme, ToFromBytes};
+use sui_types::governance::{Delegation, DELEGATION_STRUCT_NAME, STAKING_POOL_MODULE_NAME};
 use sui_types::messages::{ExecuteTransactionRequestType, Transaction, TransactionData};
+use sui_types::query::ObjectTypeFilter;
+use sui_types::SUI_FRAMEWORK_OBJECT_ID;
 
 use crate::errors::Error;
 use crate::operations::Operation;
 use crate::types::{
-    AccountIdentifier, ConstructionCombineRequest, ConstructionCombineResponse,
-    ConstructionDeriveRequest, ConstructionDeriveResponse, ConstructionHashRequest,
-    ConstructionMetadata, ConstructionMetadataRequest, ConstructionMetadataResponse,
-    ConstructionParseRequest, ConstructionParseResponse, ConstructionPayloadsRequest,
-    ConstructionPayloadsResponse, ConstructionPreprocessRequest, ConstructionPreprocessResponse,
-    ConstructionSubmitRequest, MetadataOptions, SignatureType, SigningPayload,
-    TransactionIdentifier, TransactionIdentifierResponse,
+    ConstructionCombineRequest, ConstructionCombineResponse, ConstructionDeriveRequest,
+    ConstructionDeriveResponse, ConstructionHashRequest, ConstructionMetadata,
+    ConstructionMetadataRequest, ConstructionMetadataResponse, ConstructionParseRequest,
+    ConstructionParseResponse, ConstructionPayloadsRequest, ConstructionPayloadsResponse,
+    ConstructionPreprocessRequest, ConstructionPreprocessResponse, ConstructionSubmitRequest,
+    Currency, MetadataOptions, OperationType, SignatureType, SigningPayload, TransactionIdentifier,
+    TransactionIdentifierResponse,
 };
 use crate::{OnlineServerContext, SuiEnv};
 use anyhow::anyhow;
@@ -31,6 +36,21 @@ use sui_types::intent::{Intent, IntentMessage};
 
 /// This module implements the [Rosetta Construction API](https://www.rosetta-api.org/docs/ConstructionApi.html)
 
+/// The unsigned transaction blob exchanged with the Rosetta client, the currency of a `PayCoin`
+/// transfer is kept alongside the transaction because it cannot be recovered offline.
+#[derive(Serialize, Deserialize)]
+struct UnsignedTransaction {
+    intent_message: IntentMessage<TransactionData>,
+    currency: Option<Currency>,
+}
+
+/// The signed counterpart of [UnsignedTransaction].
+#[derive(Serialize, Deserialize)]
+struct SignedTransaction {
+    transaction: Transaction,
+    currency: Option<Currency>,
+}
+
 /// Derive returns the AccountIdentifier associated with a public key.
 ///
 /// [Rosetta API Spec](https://www.rosetta-api.org/docs/ConstructionApi.html#constructionderive)
@@ -41,7 +61,7 @@ pub async fn derive(
     env.check_network_identifier(&request.network_identifier)?;
     let address: SuiAddress = request.public_key.try_into()?;
     Ok(ConstructionDeriveResponse {
-        account_identifier: AccountIdentifier { address },
+        account_identifier: address.into(),
     })
 }
 
@@ -56,17 +76,25 @@ pub async fn payloads(
 ) -> Result<ConstructionPayloadsResponse, Error> {
     env.check_network_identifier(&request.network_identifier)?;
     let metadata = request.metadata.ok_or(Error::MissingMetadata)?;
+    let currency = request.operations.iter().find_map(|op| match op.type_ {
+        OperationType::PayCoin => op.amount.as_ref().map(|amount| amount.currency.clone()),
+        _ => None,
+    });
 
     let data = Operation::create_data(request.operations, metadata).await?;
     let address = data.signer();
     let intent_msg = IntentMessage::new(Intent::default(), data);
     let intent_msg_bytes = bcs::to_bytes(&intent_msg)?;
+    let unsigned_tx = UnsignedTransaction {
+        intent_message: intent_msg,
+        currency,
+    };
 
     Ok(ConstructionPayloadsResponse {
-        unsigned_transaction: Hex::from_bytes(&intent_msg_bytes),
+        unsigned_transaction: Hex::from_bytes(&bcs::to_bytes(&unsigned_tx)?),
         payloads: vec![SigningPayload {
-            account_identifier: AccountIdentifier { address },
-            hex_bytes: Hex::encode(bcs::to_bytes(&intent_msg)?),
+            account_identifier: address.into(),
+            hex_bytes: Hex::encode(intent_msg_bytes),
             signature_type: Some(SignatureType::Ed25519),
         }],
     })
@@ -85,7 +113,7 @@ pub async fn combine(
         .unsigned_transaction
         .to_vec()
         .map_err(|e| anyhow!(e))?;
-    let intent_msg: IntentMessage<TransactionData> = bcs::from_bytes(&unsigned_tx)?;
+    let unsigned_tx: UnsignedTransaction = bcs::from_bytes(&unsigned_tx)?;
     let sig = request
         .signatures
         .first()
@@ -99,12 +127,15 @@ pub async fn combine(
     .flag()];
 
     let signed_tx = Transaction::from_data(
-        intent_msg.value,
+        unsigned_tx.intent_message.value,
         Intent::default(),
         crypto::Signature::from_bytes(&[&*flag, &*sig_bytes, &*pub_key].concat())?,
     );
     signed_tx.verify_signature()?;
-    let signed_tx_bytes = bcs::to_bytes(&signed_tx)?;
+    let signed_tx_bytes = bcs::to_bytes(&SignedTransaction {
+        transaction: signed_tx,
+        currency: unsigned_tx.currency,
+    })?;
 
     Ok(ConstructionCombineResponse {
         signed_transaction: Hex::from_bytes(&signed_tx_bytes),
@@ -120,8 +151,8 @@ pub async fn submit(
     Extension(env): Extension<SuiEnv>,
 ) -> Result<TransactionIdentifierResponse, Error> {
     env.check_network_identifier(&request.network_identifier)?;
-    let signed_tx: Transaction = bcs::from_bytes(&request.signed_transaction.to_vec()?)?;
-    let signed_tx = signed_tx.verify()?;
+    let signed_tx: SignedTransaction = bcs::from_bytes(&request.signed_transaction.to_vec()?)?;
+    let signed_tx = signed_tx.transaction.verify()?;
 
     let response = context
         .client
@@ -150,28 +181,61 @@ pub async fn preprocess(
 ) -> Result<ConstructionPreprocessResponse, Error> {
     env.check_network_identifier(&request.network_identifier)?;
 
-    let (sender, amount) = request
+    let operation = request
         .operations
         .iter()
-        .find_map(|op| match (&op.account, &op.amount) {
-            (Some(acc), Some(amount)) => {
-                if amount.value.is_negative() {
-                    Some((acc.address, amount.value.abs()))
-                } else {
-                    None
+        .find(|op| op.is_staking())
+        .map(|op| op.type_);
+    // Nothing but gas is paid by the sender of a withdrawal.
+    let withdrawal = request.operations.iter().find(|op| {
+        op.type_ == OperationType::WithdrawDelegation
+            && op
+                .account
+                .as_ref()
+                .map_or(false, |acc| acc.sub_account.is_none())
+    });
+    let staked_sui = withdrawal.map(|op| op.staked_sui()).transpose()?;
+
+    let (sender, amount, currency) = match withdrawal.and_then(|op| op.account.as_ref()) {
+        Some(acc) => (acc.address, 0, None),
+        None => request
+            .operations
+            .iter()
+            .find_map(|op| match (&op.account, &op.amount) {
+                (Some(acc), Some(amount)) => {
+                    if amount.value.is_negative() {
+                        let currency =
+                            (op.type_ == OperationType::PayCoin).then(|| amount.currency.clone());
+                        Some((acc.address, amount.value.abs(), currency))
+                    } else {
+                        None
+                    }
                 }
-            }
-            _ => None,
-        })
-        .ok_or_else(|| {
-            Error::MalformedOperationError(
-                "Cannot extract sender's address from operations.".to_string(),
-            )
-        })?;
+                _ => None,
+            })
+            .ok_or_else(|| {
+                Error::MalformedOperationError(
+                    "Cannot extract sender's address from operations.".to_string(),
+                )
+            })?,
+    };
+    let budget = request
+        .operations
+        .iter()
+        .find(|op| op.type_ == OperationType::GasBudget)
+        .map(|op| op.budget())
+        .transpose()?;
 
     Ok(ConstructionPreprocessResponse {
-        options: Some(MetadataOptions { sender, amount }),
-        required_public_keys: vec![AccountIdentifier { address: sender }],
+        options: Some(MetadataOptions {
+            sender,
+            amount,
+            currency,
+            budget,
+            operation,
+            staked_sui,
+        }),
+        required_public_keys: vec![sender.into()],
     })
 }
 
@@ -187,17 +251,20 @@ pub async fn hash(
         .signed_transaction
         .to_vec()
         .map_err(|e| anyhow::anyhow!(e))?;
-    let tx: Transaction = bcs::from_bytes(&tx_bytes)?;
+    let tx: SignedTransaction = bcs::from_bytes(&tx_bytes)?;
 
     Ok(TransactionIdentifierResponse {
-        transaction_identifier: TransactionIdentifier { hash: *tx.digest() },
+        transaction_identifier: TransactionIdentifier {
+            hash: *tx.transaction.digest(),
+        },
         metadata: None,
     })
 }
 
 /// Get any information required to construct a transaction for a specific network.
 /// For Sui, we are returning the latest object refs for all the input objects,
-/// which will be used in transaction construction.
+/// which will be used in transaction construction. Transfers of coins other than SUI
+/// and staking transactions also get a separate SUI coin covering the gas budget.
 ///
 /// [Rosetta API Spec](https://www.rosetta-api.org/docs/ConstructionApi.html#constructionmetadata)
 pub async fn metadata(
@@ -207,38 +274,179 @@ pub async fn metadata(
 ) -> Result<ConstructionMetadataResponse, Error> {
     env.check_network_identifier(&request.network_identifier)?;
 
-    let sender_coins = if let Some(option) = request.options {
-        let mut total = 0u128;
-        let coins = context
-            .client
-            .coin_read_api()
-            .get_coins_stream(option.sender, Some(SUI_COIN_TYPE.to_string()))
-            .take_while(|coin| {
-                let ready = future::ready(total < option.amount);
-                total += coin.balance as u128;
-                ready
-            })
-            .map(|c| c.object_ref())
-            .collect::<Vec<_>>()
-            .await;
-
-        if total < option.amount {
-            return Err(Error::InsufficientFund {
-                address: option.sender,
-                amount: option.amount,
-            });
-        }
-        coins
-    } else {
-        Default::default()
+    let metadata = match request.options {
+        Some(option) => match option.operation {
+            Some(OperationType::Delegation) => {
+                let sender_coins = select_coins(
+                    &context,
+                    option.sender,
+                    SUI_COIN_TYPE.to_string(),
+                    option.amount,
+                )
+                .await?;
+                let gas = select_gas(&context, option.sender, option.budget, &sender_coins).await?;
+                ConstructionMetadata {
+                    sender_coins,
+                    gas: Some(gas),
+                    framework: Some(get_framework(&context).await?),
+                    delegation: None,
+                }
+            }
+            Some(OperationType::WithdrawDelegation) => {
+                let staked_sui = option
+                    .staked_sui
+                    .ok_or_else(|| Error::MissingInput("StakedSui object id".to_string()))?;
+                let delegation = get_delegation(&context, option.sender, staked_sui).await?;
+                let gas = select_gas(&context, option.sender, option.budget, &[]).await?;
+                ConstructionMetadata {
+                    sender_coins: vec![],
+                    gas: Some(gas),
+                    framework: Some(get_framework(&context).await?),
+                    delegation: Some(delegation),
+                }
+            }
+            _ => {
+                let coin_type = option
+                    .currency
+                    .as_ref()
+                    .map(|currency| currency.metadata.coin_type.clone())
+                    .unwrap_or_else(|| SUI_COIN_TYPE.to_string());
+                let sender_coins =
+                    select_coins(&context, option.sender, coin_type.clone(), option.amount).await?;
+                let gas = if coin_type != SUI_COIN_TYPE {
+                    Some(select_gas(&context, option.sender, option.budget, &[]).await?)
+                } else {
+                    None
+                };
+                ConstructionMetadata {
+                    sender_coins,
+                    gas,
+                    ..Default::default()
+                }
+            }
+        },
+        None => Default::default(),
     };
 
     Ok(ConstructionMetadataResponse {
-        metadata: ConstructionMetadata { sender_coins },
+        metadata,
         suggested_fee: vec![],
     })
 }
 
+/// Select a SUI coin owned by `sender` covering the gas budget, other than the `excluded` coins.
+async fn select_gas(
+    context: &OnlineServerContext,
+    sender: SuiAddress,
+    budget: Option<u64>,
+    excluded: &[ObjectRef],
+) -> Result<ObjectRef, Error> {
+    let budget = budget.ok_or_else(|| Error::MissingInput("gas budget".to_string()))?;
+    let gas = context
+        .client
+        .coin_read_api()
+        .get_coins_stream(sender, Some(SUI_COIN_TYPE.to_string()))
+        .filter(|coin| {
+            future::ready(
+                coin.balance >= budget && !excluded.iter().any(|c| c.0 == coin.coin_object_id),
+            )
+        })
+        .take(1)
+        .collect::<Vec<_>>()
+        .await
+        .pop()
+        .ok_or(Error::InsufficientFund {
+            address: sender,
+            amount: budget as u128,
+        })?;
+    Ok(gas.object_ref())
+}
+
+/// The latest object ref of the Sui framework package, which the staking functions are called on.
+async fn get_framework(context: &OnlineServerContext) -> Result<ObjectRef, Error> {
+    let framework = context
+        .client
+        .read_api()
+        .get_object(SUI_FRAMEWORK_OBJECT_ID)
+        .await?;
+    Ok(framework.object()?.reference.to_object_ref())
+}
+
+/// Find the `Delegation` object of `sender` created for the `staked_sui` object,
+/// returns the object refs of both.
+async fn get_delegation(
+    context: &OnlineServerContext,
+    sender: SuiAddress,
+    staked_sui: ObjectID,
+) -> Result<(ObjectRef, ObjectRef), Error> {
+    let read_api = context.client.read_api();
+    let staked_sui_ref = read_api
+        .get_object(staked_sui)
+        .await?
+        .object()?
+        .reference
+        .to_object_ref();
+
+    let filter = ObjectTypeFilter::StructType {
+        package: SUI_FRAMEWORK_OBJECT_ID,
+        module: STAKING_POOL_MODULE_NAME.to_string(),
+        name: DELEGATION_STRUCT_NAME.to_string(),
+    };
+    let mut cursor = None;
+    loop {
+        let page = read_api
+            .get_objects_owned_by_address(sender, Some(filter.clone()), cursor, None)
+            .await?;
+        for info in page.data {
+            let object = read_api.get_object(info.object_id).await?;
+            if let SuiRawData::MoveObject(o) = &object.object()?.data {
+                let delegation: Delegation = bcs::from_bytes(&o.bcs_bytes)?;
+                if delegation.staked_sui_id() == staked_sui {
+                    return Ok((info.to_object_ref(), staked_sui_ref));
+                }
+            }
+        }
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    // The delegation only exists once the epoch in which it was requested has ended.
+    Err(Error::DataError(format!(
+        "Cannot find the delegation of StakedSui [{staked_sui}], it may not be active yet"
+    )))
+}
+
+/// Select coins of the given type owned by `sender` until their total balance covers `amount`.
+async fn select_coins(
+    context: &OnlineServerContext,
+    sender: SuiAddress,
+    coin_type: String,
+    amount: u128,
+) -> Result<Vec<ObjectRef>, Error> {
+    let mut total = 0u128;
+    let coins = context
+        .client
+        .coin_read_api()
+        .get_coins_stream(sender, Some(coin_type))
+        .take_while(|coin| {
+            let ready = future::ready(total < amount);
+            total += coin.balance as u128;
+            ready
+        })
+        .map(|c| c.object_ref())
+        .collect::<Vec<_>>()
+        .await;
+
+    if total < amount {
+        return Err(Error::InsufficientFund {
+            address: sender,
+            amount,
+        });
+    }
+    Ok(coins)
+}
+
 ///  This is run as a sanity check before signing (after /construction/payloads)
 /// and before broadcast (after /construction/combine).
 ///
@@ -249,27 +457,25 @@ pub async fn parse(
 ) -> Result<ConstructionParseResponse, Error> {
     env.check_network_identifier(&request.network_identifier)?;
 
-    let data = if request.signed {
-        let tx: Transaction = bcs::from_bytes(
+    let (data, currency) = if request.signed {
+        let tx: SignedTransaction = bcs::from_bytes(
             &request
                 .transaction
                 .to_vec()
                 .map_err(|e| anyhow::anyhow!(e))?,
         )?;
-        tx.into_data().intent_message.value
+        (tx.transaction.into_data().intent_message.value, tx.currency)
     } else {
-        let intent: IntentMessage<TransactionData> =
+        let tx: UnsignedTransaction =
             bcs::from_bytes(&request.transaction.to_vec().map_err(|e| anyhow!(e))?)?;
-        intent.value
+        (tx.intent_message.value, tx.currency)
     };
     let account_identifier_signers = if request.signed {
-        vec![AccountIdentifier {
-            address: data.signer(),
-        }]
+        vec![data.signer().into()]
     } else {
         vec![]
     };
-    let operations = Operation::from_data(&data.try_into()?)?;
+    let operations = Operation::from_data(&data, currency.as_ref())?;
 
     Ok(ConstructionParseResponse {
         operations,
@@ -13,25 +13,30 @@ use tracing::info;
 
 use mysten_metrics::spawn_monitored_task;
 use sui_config::genesis::Genesis;
-use sui_sdk::SuiClient;
+use sui_sdk::{SuiClient, SUI_COIN_TYPE};
 
 use crate::errors::Error;
 use crate::state::{OnlineServerContext, PseudoBlockProvider};
-use crate::types::{Currency, SuiEnv};
+use crate::types::{Currency, CurrencyMetadata, SuiEnv};
 
 /// This lib implements the Rosetta online and offline server defined by the [Rosetta API Spec](https://www.rosetta-api.org/docs/Reference.html)
 mod account;
 mod block;
 mod construction;
 mod errors;
+mod mempool;
 mod network;
 mod operations;
+mod search;
 mod state;
 pub mod types;
 
 pub static SUI: Lazy<Currency> = Lazy::new(|| Currency {
     symbol: "SUI".to_string(),
     decimals: 9,
+    metadata: CurrencyMetadata {
+        coin_type: SUI_COIN_TYPE.to_string(),
+    },
 });
 
 pub struct RosettaOnlineServer {
@@ -40,15 +45,23 @@ pub struct RosettaOnlineServer {
 }
 
 impl RosettaOnlineServer {
-    pub fn new(env: SuiEnv, client: SuiClient, genesis: Genesis, data_path: &Path) -> Self {
+    /// `currencies` are the currencies tracked by the server, SUI must be the first one.
+    pub fn new(
+        env: SuiEnv,
+        client: SuiClient,
+        genesis: Genesis,
+        currencies: Vec<Currency>,
+        data_path: &Path,
+    ) -> Self {
         let blocks = Arc::new(PseudoBlockProvider::spawn(
             client.clone(),
             genesis,
+            currencies.clone(),
             data_path,
         ));
         Self {
             env,
-            context: OnlineServerContext::new(client, blocks),
+            context: OnlineServerContext::new(client, blocks, currencies),
         }
     }
 
@@ -61,9 +74,12 @@ impl RosettaOnlineServer {
             .route("/block/transaction", post(block::transaction))
             .route("/construction/submit", post(construction::submit))
             .route("/construction/metadata", post(construction::metadata))
+            .route("/mempool", post(mempool::mempool))
+            .route("/mempool/transaction", post(mempool::transaction))
             .route("/network/status", post(network::status))
             .route("/network/list", post(network::list))
             .route("/network/options", post(network::options))
+            .route("/search/transactions", post(search::transactions))
             .layer(Extension(self.env))
             .layer(Extension(Arc::new(self.context)));
         let server = axum::Server::bind(&addr).serve(app.into_make_service());
@@ -19,11 +19,12 @@ use tracing::log::warn;
 use sui_config::genesis::Genesis;
 use sui_config::{sui_config_dir, Config, NodeConfig, SUI_FULLNODE_CONFIG, SUI_KEYSTORE_FILENAME};
 use sui_node::{metrics, SuiNode};
-use sui_rosetta::types::{AccountIdentifier, CurveType, PrefundedAccount, SuiEnv};
+use sui_rosetta::types::{Currency, CurrencyMetadata, CurveType, PrefundedAccount, SuiEnv};
 use sui_rosetta::{RosettaOfflineServer, RosettaOnlineServer, SUI};
 use sui_sdk::SuiClient;
 use sui_types::base_types::SuiAddress;
 use sui_types::crypto::{EncodeDecodeBase64, KeypairTraits, SuiKeyPair, ToFromBytes};
+use sui_types::parse_sui_struct_tag;
 
 #[derive(Parser)]
 #[clap(name = "sui-rosetta", rename_all = "kebab-case", author, version)]
@@ -49,6 +50,10 @@ pub enum RosettaServerCommand {
         genesis_path: PathBuf,
         #[clap(long, default_value = "data")]
         data_path: PathBuf,
+        /// Additional coin types to track besides SUI, e.g. `0x<package>::usdc::USDC`.
+        /// Historic balances are only available from the block the coin type started being tracked.
+        #[clap(long = "coin-type")]
+        coin_types: Vec<String>,
     },
     StartOnlineServer {
         #[clap(long, default_value = "localnet")]
@@ -59,6 +64,10 @@ pub enum RosettaServerCommand {
         node_config: Option<PathBuf>,
         #[clap(long, default_value = "data")]
         data_path: PathBuf,
+        /// Additional coin types to track besides SUI, e.g. `0x<package>::usdc::USDC`.
+        /// Historic balances are only available from the block the coin type started being tracked.
+        #[clap(long = "coin-type")]
+        coin_types: Vec<String>,
     },
     StartOfflineServer {
         #[clap(long, default_value = "localnet")]
@@ -140,15 +149,18 @@ impl RosettaServerCommand {
                 full_node_url,
                 genesis_path,
                 data_path,
+                coin_types,
             } => {
                 info!(
                     "Starting Rosetta Online Server with remove Sui full node [{full_node_url}]."
                 );
                 let sui_client = wait_for_sui_client(full_node_url).await;
+                let currencies = resolve_currencies(&sui_client, coin_types).await?;
                 let rosetta = RosettaOnlineServer::new(
                     env,
                     sui_client,
                     Genesis::load(&genesis_path)?,
+                    currencies,
                     &data_path,
                 );
                 rosetta.serve(addr).await??;
@@ -159,6 +171,7 @@ impl RosettaServerCommand {
                 addr,
                 node_config,
                 data_path,
+                coin_types,
             } => {
                 info!("Starting Rosetta Online Server with embedded Sui full node.");
 
@@ -175,7 +188,9 @@ impl RosettaServerCommand {
                 let _node = SuiNode::start(&config, registry_service).await?;
 
                 let sui_client = wait_for_sui_client(rpc_address).await;
-                let rosetta = RosettaOnlineServer::new(env, sui_client, genesis, &data_path);
+                let currencies = resolve_currencies(&sui_client, coin_types).await?;
+                let rosetta =
+                    RosettaOnlineServer::new(env, sui_client, genesis, currencies, &data_path);
                 rosetta.serve(addr).await??;
             }
         };
@@ -195,6 +210,40 @@ async fn wait_for_sui_client(rpc_address: String) -> SuiClient {
     }
 }
 
+/// Build the currencies tracked by the server from the configured coin types, using the
+/// on-chain CoinMetadata for symbol and decimals. SUI is always tracked and comes first.
+async fn resolve_currencies(
+    client: &SuiClient,
+    coin_types: Vec<String>,
+) -> Result<Vec<Currency>, anyhow::Error> {
+    let mut currencies = vec![SUI.clone()];
+    for coin_type in coin_types {
+        // Normalise the type so that it matches the coin types reported in events.
+        let coin_type = parse_sui_struct_tag(&coin_type)?.to_string();
+        if currencies
+            .iter()
+            .any(|currency| currency.metadata.coin_type == coin_type)
+        {
+            continue;
+        }
+        let metadata = client
+            .coin_read_api()
+            .get_coin_metadata(coin_type.clone())
+            .await
+            .map_err(|e| anyhow!("Cannot read coin metadata of [{coin_type}]: {e}"))?;
+        info!(
+            "Tracking coin type [{coin_type}] as currency {}",
+            metadata.symbol
+        );
+        currencies.push(Currency {
+            symbol: metadata.symbol,
+            decimals: metadata.decimals as u64,
+            metadata: CurrencyMetadata { coin_type },
+        });
+    }
+    Ok(currencies)
+}
+
 /// This method reads the keypairs from the Sui keystore to create the PrefundedAccount objects,
 /// PrefundedAccount will be written to the rosetta-cli config file for testing.
 ///
@@ -226,7 +275,7 @@ fn read_prefunded_account(path: &Path) -> Result<Vec<PrefundedAccount>, anyhow::
             };
             PrefundedAccount {
                 privkey,
-                account_identifier: AccountIdentifier { address },
+                account_identifier: address.into(),
                 curve_type,
                 currency: SUI.clone(),
             }
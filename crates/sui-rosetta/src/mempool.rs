@@ -0,0 +1,64 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use axum::{Extension, Json};
+
+use crate::operations::Operation;
+use crate::types::{
+    MempoolResponse, MempoolTransactionRequest, MempoolTransactionResponse, NetworkRequest,
+    Transaction, TransactionIdentifier,
+};
+use crate::{Error, OnlineServerContext, SuiEnv};
+
+/// This module implements the [Rosetta Mempool API](https://www.rosetta-api.org/docs/MempoolApi.html)
+
+/// Get the transactions submitted to the fullnode which are not finalized yet.
+/// [Rosetta API Spec](https://www.rosetta-api.org/docs/MempoolApi.html#mempool)
+pub async fn mempool(
+    Json(request): Json<NetworkRequest>,
+    Extension(context): Extension<Arc<OnlineServerContext>>,
+    Extension(env): Extension<SuiEnv>,
+) -> Result<MempoolResponse, Error> {
+    env.check_network_identifier(&request.network_identifier)?;
+    let digests = context
+        .client
+        .quorum_driver()
+        .get_pending_transactions()
+        .await?;
+    Ok(MempoolResponse {
+        transaction_identifiers: digests
+            .into_iter()
+            .map(|hash| TransactionIdentifier { hash })
+            .collect(),
+    })
+}
+
+/// Get a transaction which is not finalized yet, its operations are the intent of the
+/// transaction, balance changes are only known once it is executed.
+/// [Rosetta API Spec](https://www.rosetta-api.org/docs/MempoolApi.html#mempooltransaction)
+pub async fn transaction(
+    Json(request): Json<MempoolTransactionRequest>,
+    Extension(context): Extension<Arc<OnlineServerContext>>,
+    Extension(env): Extension<SuiEnv>,
+) -> Result<MempoolTransactionResponse, Error> {
+    env.check_network_identifier(&request.network_identifier)?;
+    let hash = request.transaction_identifier.hash;
+    let data = context
+        .client
+        .quorum_driver()
+        .get_pending_transaction(hash)
+        .await?;
+    let operations = Operation::from_sui_data(&data, None)?;
+
+    Ok(MempoolTransactionResponse {
+        transaction: Transaction {
+            transaction_identifier: TransactionIdentifier { hash },
+            operations,
+            related_transactions: vec![],
+            metadata: None,
+        },
+        metadata: None,
+    })
+}
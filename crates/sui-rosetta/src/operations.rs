@@ -10,17 +10,23 @@ use serde::Serialize;
 use serde_json::{json, Value};
 use sui_sdk::rpc_types::{SuiEvent, SuiExecutionStatus, SuiTransactionData, SuiTransactionKind};
 
-use sui_types::base_types::{ObjectRef, SuiAddress};
+use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress};
 use sui_types::event::BalanceChangeType;
-use sui_types::gas_coin::GAS;
-use sui_types::messages::TransactionData;
+use sui_types::governance::{
+    ADD_DELEGATION_FUN_NAME, ADD_DELEGATION_MUL_COIN_FUN_NAME, WITHDRAW_DELEGATION_FUN_NAME,
+};
+use sui_types::messages::{CallArg, ObjectArg, SingleTransactionKind, TransactionData};
 use sui_types::object::Owner;
+use sui_types::sui_system_state::SUI_SYSTEM_MODULE_NAME;
+use sui_types::{
+    SUI_FRAMEWORK_OBJECT_ID, SUI_SYSTEM_STATE_OBJECT_ID, SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION,
+};
 
 use crate::types::{
     AccountIdentifier, Amount, CoinAction, CoinChange, CoinIdentifier, ConstructionMetadata,
-    IndexCounter, OperationIdentifier, OperationStatus, OperationType, SignedValue,
+    Currency, IndexCounter, OperationIdentifier, OperationStatus, OperationType, SignedValue,
 };
-use crate::Error;
+use crate::{Error, SUI};
 
 #[cfg(test)]
 #[path = "unit_tests/operations_tests.rs"]
@@ -46,13 +52,44 @@ pub struct Operation {
 }
 
 impl Operation {
-    pub fn from_data(data: &SuiTransactionData) -> Result<Vec<Operation>, anyhow::Error> {
+    /// `pay_currency` is the currency of the coins spent by `Pay` transactions, which cannot
+    /// be derived from the transaction data alone. `Pay` transactions are reported as
+    /// generic operations if it is not provided.
+    pub fn from_data(
+        data: &TransactionData,
+        pay_currency: Option<&Currency>,
+    ) -> Result<Vec<Operation>, anyhow::Error> {
+        let mut ops = Self::from_sui_data(&data.clone().try_into()?, pay_currency)?;
+
+        // Pure call arguments are not decoded in SuiTransactionData, the staked and withdrawn
+        // amounts are read from the raw transaction instead.
+        let staking_details = data
+            .kind
+            .single_transactions()
+            .filter_map(staking_call_details);
+        for (op, (amount, metadata)) in ops
+            .iter_mut()
+            .filter(|op| op.is_staking())
+            .zip(staking_details)
+        {
+            op.amount = amount;
+            op.metadata = Some(metadata);
+        }
+        Ok(ops)
+    }
+
+    /// Same as `from_data`, but the amounts of staking operations are not known
+    /// as the call arguments are not decoded in `SuiTransactionData`.
+    pub fn from_sui_data(
+        data: &SuiTransactionData,
+        pay_currency: Option<&Currency>,
+    ) -> Result<Vec<Operation>, anyhow::Error> {
         let sender = data.sender;
         let mut counter = IndexCounter::default();
         let mut ops = data
             .transactions
             .iter()
-            .flat_map(|tx| parse_operations(tx, sender, &mut counter, None))
+            .flat_map(|tx| parse_operations(tx, sender, &mut counter, None, pay_currency))
             .flatten()
             .collect::<Vec<_>>();
         let gas = Operation::gas_budget(
@@ -70,14 +107,16 @@ impl Operation {
         data: &SuiTransactionData,
         status: &SuiExecutionStatus,
         events: &[SuiEvent],
+        currencies: &[Currency],
     ) -> Result<Vec<Operation>, anyhow::Error> {
         let sender = data.sender;
         let mut counter = IndexCounter::default();
         let status = Some((status).into());
+        let pay_currency = Self::get_pay_currency_from_events(events, currencies);
         let mut ops = data
             .transactions
             .iter()
-            .flat_map(|tx| parse_operations(tx, sender, &mut counter, status))
+            .flat_map(|tx| parse_operations(tx, sender, &mut counter, status, pay_currency))
             .flatten()
             .collect::<Vec<_>>();
         let gas = Operation::gas_budget(
@@ -89,37 +128,94 @@ impl Operation {
         );
         ops.push(gas);
 
-        // We will need to subtract the PaySui operation amounts from the actual balance
-        // change amount extracted from event to prevent double counting.
-        let mut pay_sui_balance_to_subtract = HashMap::new();
+        // The staked or withdrawn amount is the sender's SUI balance change outside of gas,
+        // it is moved between the sender's account and its stake sub-account.
+        let staked_amount = events
+            .iter()
+            .flat_map(|event| Self::get_balance_change_from_event(event, currencies))
+            .filter(|(type_, address, currency, _)| {
+                type_ != &OperationType::GasSpent && address == &sender && currency.is_sui()
+            })
+            .map(|(_, _, _, amount)| amount)
+            .sum::<i128>();
+        let stake_op = match ops.iter_mut().find(|op| op.is_staking()) {
+            Some(op) if staked_amount != 0 => {
+                op.amount = Some(Amount::new(staked_amount.into()));
+                Some(Operation {
+                    operation_identifier: counter.next_idx().into(),
+                    related_operations: vec![op.operation_identifier.clone()],
+                    type_: op.type_,
+                    status,
+                    account: Some(AccountIdentifier::stake(sender)),
+                    amount: Some(Amount::new(staked_amount.neg().into())),
+                    coin_change: None,
+                    metadata: None,
+                })
+            }
+            _ => None,
+        };
+        ops.extend(stake_op);
+
+        // We will need to subtract the PaySui, PayCoin and staking operation amounts from the
+        // actual balance change amount extracted from event to prevent double counting.
+        let mut pay_balance_to_subtract = HashMap::new();
 
-        let pay_sui_ops = ops
+        let pay_ops = ops
             .iter()
+            .filter(|op| {
+                op.account
+                    .as_ref()
+                    .map_or(false, |acc| acc.sub_account.is_none())
+            })
             .filter_map(|op| match (op.type_, &op.account, &op.amount) {
-                (OperationType::PaySui, Some(acc), Some(amount)) => {
-                    let amount = if amount.value.is_negative() {
+                (
+                    OperationType::PaySui
+                    | OperationType::PayCoin
+                    | OperationType::Delegation
+                    | OperationType::WithdrawDelegation,
+                    Some(acc),
+                    Some(amount),
+                ) => {
+                    let value = if amount.value.is_negative() {
                         // Safe to downcast, total supply of SUI is way less then i128::MAX
                         amount.value.abs() as i128
                     } else {
                         (amount.value.abs() as i128).neg()
                     };
-                    Some((acc.address, amount))
+                    Some(((acc.address, amount.currency.clone()), value))
                 }
                 _ => None,
             });
 
-        for (addr, amount) in pay_sui_ops {
-            *pay_sui_balance_to_subtract.entry(addr).or_default() += amount
+        for (key, amount) in pay_ops {
+            *pay_balance_to_subtract.entry(key).or_default() += amount
         }
 
+        // Delegation rewards are paid out to the delegators at the end of each epoch, they are
+        // reported apart from the other balance changes of the epoch change transaction.
+        let is_epoch_change = data
+            .transactions
+            .iter()
+            .any(|tx| matches!(tx, SuiTransactionKind::ChangeEpoch(_)));
+        let (reward_events, events): (Vec<_>, Vec<_>) = events
+            .iter()
+            .cloned()
+            .partition(|event| is_epoch_change && Self::is_delegation_reward(event));
+
         // Extract coin change operations from events
         let coin_change_operations = Operation::get_coin_operation_from_events(
-            events,
+            &events,
             status,
-            pay_sui_balance_to_subtract,
+            pay_balance_to_subtract,
+            currencies,
             &mut counter,
         );
         ops.extend(coin_change_operations);
+        ops.extend(Operation::get_delegation_reward_operations(
+            &reward_events,
+            status,
+            &mut counter,
+        ));
 
         Ok(ops)
     }
@@ -127,34 +223,42 @@ impl Operation {
     pub fn get_coin_operation_from_events(
         events: &[SuiEvent],
         status: Option<OperationStatus>,
-        balance_to_subtract: HashMap<SuiAddress, i128>,
+        balance_to_subtract: HashMap<(SuiAddress, Currency), i128>,
+        currencies: &[Currency],
         counter: &mut IndexCounter,
     ) -> Vec<Operation> {
-        // Aggregate balance changes by address, rosetta don't care about coins.
+        // Aggregate balance changes by address and currency, rosetta don't care about coins.
         let mut balance_change = balance_to_subtract;
         let mut gas: HashMap<SuiAddress, i128> = HashMap::new();
-        for (type_, address, amount) in events.iter().flat_map(Self::get_balance_change_from_event)
+        for (type_, address, currency, amount) in events
+            .iter()
+            .flat_map(|event| Self::get_balance_change_from_event(event, currencies))
         {
-            if type_ == OperationType::SuiBalanceChange {
-                let sum = balance_change.entry(address).or_default();
-                *sum += amount;
-            } else if type_ == OperationType::GasSpent {
+            if type_ == OperationType::GasSpent {
                 let sum = gas.entry(address).or_default();
                 *sum += amount;
+            } else {
+                let sum = balance_change.entry((address, currency)).or_default();
+                *sum += amount;
             }
         }
 
         let mut ops = balance_change
             .into_iter()
-            .filter_map(|(addr, amount)| {
+            .filter_map(|((addr, currency), amount)| {
                 if amount != 0 {
+                    let type_ = if currency.is_sui() {
+                        OperationType::SuiBalanceChange
+                    } else {
+                        OperationType::CoinBalanceChange
+                    };
                     Some(Operation {
                         operation_identifier: counter.next_idx().into(),
                         related_operations: vec![],
-                        type_: OperationType::SuiBalanceChange,
+                        type_,
                         status,
                         account: Some(addr.into()),
-                        amount: Some(Amount::new(amount.into())),
+                        amount: Some(Amount::new_with_currency(amount.into(), currency)),
                         coin_change: None,
                         metadata: None,
                     })
@@ -181,9 +285,59 @@ impl Operation {
         ops
     }
 
+    /// Whether `event` pays a delegation reward out of a staking pool. At the end of an epoch,
+    /// the rewards of the withdrawn delegations are sent to their delegators in new SUI coins
+    /// (see `staking_pool::process_pending_delegation_withdraws`). The validator rewards are
+    /// staked rather than paid in coins, and the storage fund stays in the system state object.
+    fn is_delegation_reward(event: &SuiEvent) -> bool {
+        matches!(
+            event,
+            SuiEvent::CoinBalanceChange {
+                change_type: BalanceChangeType::Receive,
+                owner: Owner::AddressOwner(_),
+                coin_type,
+                amount,
+                ..
+            } if coin_type == &SUI.metadata.coin_type && *amount > 0
+        )
+    }
+
+    /// Aggregate the delegation rewards paid by an epoch change by delegator.
+    fn get_delegation_reward_operations(
+        reward_events: &[SuiEvent],
+        status: Option<OperationStatus>,
+        counter: &mut IndexCounter,
+    ) -> Vec<Operation> {
+        let mut rewards: HashMap<SuiAddress, i128> = HashMap::new();
+        for event in reward_events {
+            if let SuiEvent::CoinBalanceChange {
+                owner: Owner::AddressOwner(owner),
+                amount,
+                ..
+            } = event
+            {
+                *rewards.entry(*owner).or_default() += amount;
+            }
+        }
+        rewards
+            .into_iter()
+            .map(|(addr, amount)| Operation {
+                operation_identifier: counter.next_idx().into(),
+                related_operations: vec![],
+                type_: OperationType::DelegationReward,
+                status,
+                account: Some(addr.into()),
+                amount: Some(Amount::new(amount.into())),
+                coin_change: None,
+                metadata: None,
+            })
+            .collect()
+    }
+
     fn get_balance_change_from_event(
         event: &SuiEvent,
-    ) -> Option<(OperationType, SuiAddress, i128)> {
+        currencies: &[Currency],
+    ) -> Option<(OperationType, SuiAddress, Currency, i128)> {
         if let SuiEvent::CoinBalanceChange {
             owner: Owner::AddressOwner(owner),
             coin_type,
@@ -192,82 +346,272 @@ impl Operation {
             ..
         } = event
         {
-            // We only interested in SUI coins and account addresses
-            if coin_type == &GAS::type_().to_string() {
-                let type_ = if change_type == &BalanceChangeType::Gas {
-                    // We always charge gas
-                    OperationType::GasSpent
-                } else {
-                    OperationType::SuiBalanceChange
-                };
-                return Some((type_, *owner, *amount));
-            }
+            // We only interested in tracked currencies and account addresses
+            let currency = currencies
+                .iter()
+                .find(|currency| &currency.metadata.coin_type == coin_type)?;
+            let type_ = if change_type == &BalanceChangeType::Gas {
+                // We always charge gas
+                OperationType::GasSpent
+            } else if currency.is_sui() {
+                OperationType::SuiBalanceChange
+            } else {
+                OperationType::CoinBalanceChange
+            };
+            return Some((type_, *owner, currency.clone(), *amount));
         }
         None
     }
 
+    /// Find the currency of the coins paid by a `Pay` transaction, if it is a tracked
+    /// currency other than SUI.
+    fn get_pay_currency_from_events<'a>(
+        events: &[SuiEvent],
+        currencies: &'a [Currency],
+    ) -> Option<&'a Currency> {
+        events.iter().find_map(|event| match event {
+            SuiEvent::CoinBalanceChange {
+                coin_type,
+                change_type: BalanceChangeType::Pay,
+                ..
+            } => currencies
+                .iter()
+                .find(|currency| !currency.is_sui() && &currency.metadata.coin_type == coin_type),
+            _ => None,
+        })
+    }
+
     /// Parse operation input from rosetta to Sui transaction
     pub async fn create_data(
         operations: Vec<Operation>,
         metadata: ConstructionMetadata,
     ) -> Result<TransactionData, Error> {
+        if operations.iter().any(|op| op.is_staking()) {
+            return Self::create_staking_data(operations, metadata);
+        }
         let mut type_ = None;
         let mut recipients = vec![];
         let mut amounts = vec![];
         let mut sender = None;
         let mut budget = None;
+        let mut currency: Option<Currency> = None;
         for op in operations {
-            // Currently only PaySui is support,
-            if op.type_ != OperationType::PaySui && op.type_ != OperationType::GasBudget {
+            // Currently only PaySui and PayCoin are supported,
+            if !matches!(
+                op.type_,
+                OperationType::PaySui | OperationType::PayCoin | OperationType::GasBudget
+            ) {
                 return Err(Error::UnsupportedOperation(op.type_));
             }
             if type_.is_none() && op.type_ != OperationType::GasBudget {
                 type_ = Some(op.type_)
             }
             if op.type_ == OperationType::GasBudget {
-                let budget_value = op
-                    .metadata
-                    .clone()
-                    .and_then(|v| v.pointer("/budget").cloned())
-                    .ok_or_else(|| Error::MissingInput("gas budget".to_string()))?;
-                budget = Some(
-                    budget_value
-                        .as_u64()
-                        .or_else(|| budget_value.as_str().and_then(|s| u64::from_str(s).ok()))
-                        .ok_or_else(|| Error::InvalidInput(format!("{budget_value}")))?,
-                );
-            } else if op.type_ == OperationType::PaySui {
-                if let (Some(amount), Some(account)) = (op.amount, op.account) {
-                    if amount.value.is_negative() {
-                        sender = Some(account.address)
-                    } else {
-                        recipients.push(account.address);
-                        let amount = amount.value.abs();
-                        if amount > u64::MAX as u128 {
-                            return Err(Error::InvalidInput(
-                                "Input amount exceed u64::MAX".to_string(),
-                            ));
-                        }
-                        amounts.push(amount as u64)
+                budget = Some(op.budget()?);
+            } else if let (Some(amount), Some(account)) = (op.amount, op.account) {
+                if type_ != Some(op.type_) {
+                    return Err(Error::MalformedOperationError(
+                        "PaySui and PayCoin operations cannot be mixed".to_string(),
+                    ));
+                }
+                if op.type_ == OperationType::PayCoin {
+                    if amount.currency.is_sui() {
+                        return Err(Error::InvalidInput(
+                            "PayCoin cannot be used for SUI, use PaySui instead".to_string(),
+                        ));
+                    }
+                    if currency.get_or_insert_with(|| amount.currency.clone()) != &amount.currency {
+                        return Err(Error::MalformedOperationError(
+                            "All PayCoin operations must use the same currency".to_string(),
+                        ));
                     }
                 }
+                if amount.value.is_negative() {
+                    sender = Some(account.address)
+                } else {
+                    recipients.push(account.address);
+                    let amount = amount.value.abs();
+                    if amount > u64::MAX as u128 {
+                        return Err(Error::InvalidInput(
+                            "Input amount exceed u64::MAX".to_string(),
+                        ));
+                    }
+                    amounts.push(amount as u64)
+                }
             }
         }
 
         let address = sender.ok_or_else(|| Error::MissingInput("Sender address".to_string()))?;
-        let gas = metadata.sender_coins[0];
         let budget = budget.ok_or_else(|| Error::MissingInput("gas budget".to_string()))?;
 
-        Ok(TransactionData::new_pay_sui(
-            address,
-            metadata.sender_coins,
-            recipients,
-            amounts,
+        if type_ == Some(OperationType::PayCoin) {
+            let gas = metadata
+                .gas
+                .ok_or_else(|| Error::MissingInput("gas coin".to_string()))?;
+            Ok(TransactionData::new_pay(
+                address,
+                metadata.sender_coins,
+                recipients,
+                amounts,
+                gas,
+                budget,
+            ))
+        } else {
+            let gas = metadata.sender_coins[0];
+            Ok(TransactionData::new_pay_sui(
+                address,
+                metadata.sender_coins,
+                recipients,
+                amounts,
+                gas,
+                budget,
+            ))
+        }
+    }
+
+    /// Create a call to the staking functions of the Sui system module, the SUI is delegated
+    /// from the sender coins and withdrawn from the `Delegation` and `StakedSui` objects
+    /// in the metadata.
+    fn create_staking_data(
+        operations: Vec<Operation>,
+        metadata: ConstructionMetadata,
+    ) -> Result<TransactionData, Error> {
+        let mut budget = None;
+        let mut stake_op = None;
+        for op in operations {
+            match op.type_ {
+                OperationType::GasBudget => budget = Some(op.budget()?),
+                // Operations on the stake sub-account mirror the main account operation.
+                OperationType::Delegation | OperationType::WithdrawDelegation
+                    if op
+                        .account
+                        .as_ref()
+                        .map_or(false, |acc| acc.sub_account.is_some()) => {}
+                OperationType::Delegation | OperationType::WithdrawDelegation => {
+                    if stake_op.replace(op).is_some() {
+                        return Err(Error::MalformedOperationError(
+                            "Only one staking operation is supported per transaction".to_string(),
+                        ));
+                    }
+                }
+                _ => return Err(Error::UnsupportedOperation(op.type_)),
+            }
+        }
+
+        let op = stake_op.ok_or_else(|| Error::MissingInput("staking operation".to_string()))?;
+        let sender = op
+            .account
+            .as_ref()
+            .ok_or_else(|| Error::MissingInput("Sender address".to_string()))?
+            .address;
+        let amount = op
+            .amount
+            .as_ref()
+            .ok_or_else(|| Error::MissingInput("staking amount".to_string()))?;
+        if amount.value.is_negative() != (op.type_ == OperationType::Delegation) {
+            return Err(Error::InvalidInput(
+                "Delegation amount must be negative and withdrawal amount positive".to_string(),
+            ));
+        }
+        let amount = u64::try_from(amount.value.abs())
+            .map_err(|_| Error::InvalidInput("Input amount exceed u64::MAX".to_string()))?;
+        let budget = budget.ok_or_else(|| Error::MissingInput("gas budget".to_string()))?;
+        let gas = metadata
+            .gas
+            .ok_or_else(|| Error::MissingInput("gas coin".to_string()))?;
+        let framework = metadata
+            .framework
+            .ok_or_else(|| Error::MissingInput("Sui framework package".to_string()))?;
+
+        let system_state = CallArg::Object(ObjectArg::SharedObject {
+            id: SUI_SYSTEM_STATE_OBJECT_ID,
+            initial_shared_version: SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION,
+        });
+        let (function, arguments) = if op.type_ == OperationType::Delegation {
+            let coins = metadata
+                .sender_coins
+                .into_iter()
+                .map(ObjectArg::ImmOrOwnedObject)
+                .collect();
+            (
+                ADD_DELEGATION_MUL_COIN_FUN_NAME,
+                vec![
+                    system_state,
+                    CallArg::ObjVec(coins),
+                    CallArg::Pure(bcs::to_bytes(&Some(amount))?),
+                    CallArg::Pure(bcs::to_bytes(&op.validator()?)?),
+                ],
+            )
+        } else {
+            let (delegation, staked_sui) = metadata
+                .delegation
+                .ok_or_else(|| Error::MissingInput("delegation objects".to_string()))?;
+            (
+                WITHDRAW_DELEGATION_FUN_NAME,
+                vec![
+                    system_state,
+                    CallArg::Object(ObjectArg::ImmOrOwnedObject(delegation)),
+                    CallArg::Object(ObjectArg::ImmOrOwnedObject(staked_sui)),
+                    CallArg::Pure(bcs::to_bytes(&amount)?),
+                ],
+            )
+        };
+
+        Ok(TransactionData::new_move_call(
+            sender,
+            framework,
+            SUI_SYSTEM_MODULE_NAME.to_owned(),
+            function.to_owned(),
+            vec![],
             gas,
+            arguments,
             budget,
         ))
     }
 
+    /// Read the validator address from the metadata of a Delegation operation.
+    pub fn validator(&self) -> Result<SuiAddress, Error> {
+        let validator = self
+            .metadata
+            .as_ref()
+            .and_then(|v| v.pointer("/validator"))
+            .ok_or_else(|| Error::MissingInput("validator address".to_string()))?;
+        serde_json::from_value(validator.clone())
+            .map_err(|_| Error::InvalidInput(format!("{validator}")))
+    }
+
+    /// Read the `StakedSui` object id from the metadata of a WithdrawDelegation operation.
+    pub fn staked_sui(&self) -> Result<ObjectID, Error> {
+        let staked_sui = self
+            .metadata
+            .as_ref()
+            .and_then(|v| v.pointer("/staked_sui"))
+            .ok_or_else(|| Error::MissingInput("StakedSui object id".to_string()))?;
+        serde_json::from_value(staked_sui.clone())
+            .map_err(|_| Error::InvalidInput(format!("{staked_sui}")))
+    }
+
+    /// Whether this is a Delegation or WithdrawDelegation operation.
+    pub fn is_staking(&self) -> bool {
+        matches!(
+            self.type_,
+            OperationType::Delegation | OperationType::WithdrawDelegation
+        )
+    }
+
+    /// Read the gas budget from the metadata of a GasBudget operation.
+    pub fn budget(&self) -> Result<u64, Error> {
+        let budget_value = self
+            .metadata
+            .as_ref()
+            .and_then(|v| v.pointer("/budget"))
+            .ok_or_else(|| Error::MissingInput("gas budget".to_string()))?;
+        budget_value
+            .as_u64()
+            .or_else(|| budget_value.as_str().and_then(|s| u64::from_str(s).ok()))
+            .ok_or_else(|| Error::InvalidInput(format!("{budget_value}")))
+    }
+
     pub fn gas_budget(
         counter: &mut IndexCounter,
         status: Option<OperationStatus>,
@@ -280,7 +624,7 @@ impl Operation {
             related_operations: vec![],
             type_: OperationType::GasBudget,
             status,
-            account: Some(AccountIdentifier { address: sender }),
+            account: Some(sender.into()),
             amount: None,
             coin_change: Some(CoinChange {
                 coin_identifier: CoinIdentifier {
@@ -298,54 +642,152 @@ fn parse_operations(
     sender: SuiAddress,
     counter: &mut IndexCounter,
     status: Option<OperationStatus>,
+    pay_currency: Option<&Currency>,
 ) -> Result<Vec<Operation>, anyhow::Error> {
-    let operations = if let SuiTransactionKind::PaySui(tx) = tx {
-        let recipients = tx.recipients.iter().zip(&tx.amounts);
-        let mut aggregated_recipients: HashMap<SuiAddress, u64> = HashMap::new();
-
-        for (recipient, amount) in recipients {
-            *aggregated_recipients.entry(*recipient).or_default() += *amount
+    let operations = match (tx, pay_currency) {
+        (SuiTransactionKind::PaySui(tx), _) => pay_operations(
+            counter,
+            OperationType::PaySui,
+            status,
+            sender,
+            &tx.recipients,
+            &tx.amounts,
+            &SUI,
+        ),
+        (SuiTransactionKind::Pay(tx), Some(currency)) => pay_operations(
+            counter,
+            OperationType::PayCoin,
+            status,
+            sender,
+            &tx.recipients,
+            &tx.amounts,
+            currency,
+        ),
+        _ => {
+            let (type_, metadata) = match tx {
+                SuiTransactionKind::TransferObject(tx) => {
+                    (OperationType::TransferObject, json!(tx))
+                }
+                SuiTransactionKind::Publish(tx) => (OperationType::Publish, json!(tx.disassembled)),
+                SuiTransactionKind::Call(tx) => (
+                    staking_operation_type(tx.package.object_id, &tx.module, &tx.function)
+                        .unwrap_or(OperationType::MoveCall),
+                    json!(tx),
+                ),
+                SuiTransactionKind::TransferSui(tx) => (OperationType::TransferSUI, json!(tx)),
+                SuiTransactionKind::Pay(tx) => (OperationType::Pay, json!(tx)),
+                SuiTransactionKind::PayAllSui(tx) => (OperationType::PayAllSui, json!(tx)),
+                SuiTransactionKind::ChangeEpoch(tx) => (OperationType::EpochChange, json!(tx)),
+                SuiTransactionKind::PaySui(_) => unreachable!(),
+            };
+            generic_operation(counter, type_, status, sender, metadata)
         }
+    };
+    Ok(operations)
+}
 
-        let mut pay_operations = aggregated_recipients
-            .into_iter()
-            .map(|(recipient, amount)| Operation {
-                operation_identifier: counter.next_idx().into(),
-                related_operations: vec![],
-                type_: OperationType::PaySui,
-                status,
-                account: Some(recipient.into()),
-                amount: Some(Amount::new(amount.into())),
-                coin_change: None,
-                metadata: None,
-            })
-            .collect::<Vec<_>>();
-        let total_paid = tx.amounts.iter().sum::<u64>();
-        pay_operations.push(Operation {
+/// The operation type of a call to one of the staking functions of the Sui system module.
+fn staking_operation_type(
+    package: ObjectID,
+    module: &str,
+    function: &str,
+) -> Option<OperationType> {
+    if package != SUI_FRAMEWORK_OBJECT_ID || module != SUI_SYSTEM_MODULE_NAME.as_str() {
+        return None;
+    }
+    if function == ADD_DELEGATION_FUN_NAME.as_str()
+        || function == ADD_DELEGATION_MUL_COIN_FUN_NAME.as_str()
+    {
+        Some(OperationType::Delegation)
+    } else if function == WITHDRAW_DELEGATION_FUN_NAME.as_str() {
+        Some(OperationType::WithdrawDelegation)
+    } else {
+        None
+    }
+}
+
+/// Returns `Some` for every staking call, holding the sender's balance change if it is
+/// specified by the call arguments, and the operation metadata read back by `create_data`.
+fn staking_call_details(tx: &SingleTransactionKind) -> Option<(Option<Amount>, Value)> {
+    let SingleTransactionKind::Call(call) = tx else {
+        return None;
+    };
+    let type_ =
+        staking_operation_type(call.package.0, call.module.as_str(), call.function.as_str())?;
+    let pure_arg = |index: usize| match call.arguments.get(index) {
+        Some(CallArg::Pure(bytes)) => Some(bytes.as_slice()),
+        _ => None,
+    };
+    let (amount, metadata) = if type_ == OperationType::WithdrawDelegation {
+        let amount = pure_arg(3).and_then(|bytes| bcs::from_bytes::<u64>(bytes).ok());
+        let staked_sui = match call.arguments.get(2) {
+            Some(CallArg::Object(ObjectArg::ImmOrOwnedObject((id, _, _)))) => Some(*id),
+            _ => None,
+        };
+        (
+            amount.map(SignedValue::from),
+            json!({ "staked_sui": staked_sui }),
+        )
+    } else if call.function.as_str() == ADD_DELEGATION_MUL_COIN_FUN_NAME.as_str() {
+        let amount = pure_arg(2).and_then(|bytes| bcs::from_bytes::<Option<u64>>(bytes).ok());
+        let validator = pure_arg(3).and_then(|bytes| bcs::from_bytes::<SuiAddress>(bytes).ok());
+        (
+            amount
+                .flatten()
+                .map(|amount| SignedValue::neg(amount as u128)),
+            json!({ "validator": validator }),
+        )
+    } else {
+        // The whole coin is delegated by `request_add_delegation`, its balance is unknown.
+        let validator = pure_arg(2).and_then(|bytes| bcs::from_bytes::<SuiAddress>(bytes).ok());
+        (None, json!({ "validator": validator }))
+    };
+    Some((amount.map(Amount::new), metadata))
+}
+
+fn pay_operations(
+    counter: &mut IndexCounter,
+    type_: OperationType,
+    status: Option<OperationStatus>,
+    sender: SuiAddress,
+    recipients: &[SuiAddress],
+    amounts: &[u64],
+    currency: &Currency,
+) -> Vec<Operation> {
+    let mut aggregated_recipients: HashMap<SuiAddress, u64> = HashMap::new();
+
+    for (recipient, amount) in recipients.iter().zip(amounts) {
+        *aggregated_recipients.entry(*recipient).or_default() += *amount
+    }
+
+    let mut pay_operations = aggregated_recipients
+        .into_iter()
+        .map(|(recipient, amount)| Operation {
             operation_identifier: counter.next_idx().into(),
             related_operations: vec![],
-            type_: OperationType::PaySui,
+            type_,
             status,
-            account: Some(sender.into()),
-            amount: Some(Amount::new(SignedValue::neg(total_paid as u128))),
+            account: Some(recipient.into()),
+            amount: Some(Amount::new_with_currency(amount.into(), currency.clone())),
             coin_change: None,
             metadata: None,
-        });
-        pay_operations
-    } else {
-        let (type_, metadata) = match tx {
-            SuiTransactionKind::TransferObject(tx) => (OperationType::TransferObject, json!(tx)),
-            SuiTransactionKind::Publish(tx) => (OperationType::Publish, json!(tx.disassembled)),
-            SuiTransactionKind::Call(tx) => (OperationType::MoveCall, json!(tx)),
-            SuiTransactionKind::TransferSui(tx) => (OperationType::TransferSUI, json!(tx)),
-            SuiTransactionKind::Pay(tx) => (OperationType::Pay, json!(tx)),
-            SuiTransactionKind::PayAllSui(tx) => (OperationType::PayAllSui, json!(tx)),
-            SuiTransactionKind::ChangeEpoch(tx) => (OperationType::EpochChange, json!(tx)),
-            SuiTransactionKind::PaySui(_) => unreachable!(),
-        };
-        generic_operation(counter, type_, status, sender, metadata)
-    };
-    Ok(operations)
+        })
+        .collect::<Vec<_>>();
+    let total_paid = amounts.iter().sum::<u64>();
+    pay_operations.push(Operation {
+        operation_identifier: counter.next_idx().into(),
+        related_operations: vec![],
+        type_,
+        status,
+        account: Some(sender.into()),
+        amount: Some(Amount::new_with_currency(
+            SignedValue::neg(total_paid as u128),
+            currency.clone(),
+        )),
+        coin_change: None,
+        metadata: None,
+    });
+    pay_operations
 }
 
 fn generic_operation(
@@ -360,7 +802,7 @@ fn generic_operation(
         related_operations: vec![],
         type_,
         status,
-        account: Some(AccountIdentifier { address: sender }),
+        account: Some(sender.into()),
         amount: None,
         coin_change: None,
         metadata: Some(metadata),
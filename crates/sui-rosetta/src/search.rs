@@ -0,0 +1,22 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use axum::{Extension, Json};
+
+use crate::types::{SearchTransactionsRequest, SearchTransactionsResponse};
+use crate::{Error, OnlineServerContext, SuiEnv};
+
+/// This module implements the [Rosetta Search API](https://www.rosetta-api.org/docs/SearchApi.html)
+
+/// Search for transactions matching a set of conditions in the blocks indexed by the server.
+/// [Rosetta API Spec](https://www.rosetta-api.org/docs/SearchApi.html#searchtransactions)
+pub async fn transactions(
+    Json(request): Json<SearchTransactionsRequest>,
+    Extension(context): Extension<Arc<OnlineServerContext>>,
+    Extension(env): Extension<SuiEnv>,
+) -> Result<SearchTransactionsResponse, Error> {
+    env.check_network_identifier(&request.network_identifier)?;
+    context.blocks().search_transactions(request).await
+}
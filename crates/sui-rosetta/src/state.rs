@@ -3,9 +3,10 @@
 
 use crate::operations::Operation;
 use crate::types::{
-    AccountIdentifier, Amount, Block, BlockHash, BlockHeight, BlockIdentifier, BlockResponse,
-    CoinAction, CoinChange, CoinID, CoinIdentifier, OperationStatus, OperationType, SignedValue,
-    Transaction, TransactionIdentifier,
+    Amount, Block, BlockHash, BlockHeight, BlockIdentifier, BlockResponse, BlockTransaction,
+    CoinAction, CoinChange, CoinID, CoinIdentifier, Currency, OperationStatus, OperationType,
+    Operator, SearchTransactionsRequest, SearchTransactionsResponse, SignedValue, Transaction,
+    TransactionIdentifier,
 };
 use crate::{Error, SUI};
 use anyhow::anyhow;
@@ -28,7 +29,7 @@ use sui_types::base_types::{
 use sui_types::gas_coin::GasCoin;
 use sui_types::query::TransactionQuery;
 use tracing::{debug, error, info};
-use typed_store::rocks::{DBMap, DBOptions};
+use typed_store::rocks::{DBBatch, DBMap, DBOptions};
 use typed_store::traits::TableSummary;
 use typed_store::traits::TypedStoreDebug;
 use typed_store::Map;
@@ -37,17 +38,30 @@ use typed_store_derive::DBMapUtils;
 #[cfg(test)]
 #[path = "unit_tests/balance_changing_tx_tests.rs"]
 mod balance_changing_tx_tests;
+#[cfg(test)]
+#[path = "unit_tests/search_transactions_tests.rs"]
+mod search_transactions_tests;
+
+/// The maximum number of transactions returned by a `/search/transactions` request.
+const MAX_SEARCH_LIMIT: u64 = 100;
 
 #[derive(Clone)]
 pub struct OnlineServerContext {
     pub client: SuiClient,
+    /// Currencies tracked by the server, SUI first.
+    pub currencies: Vec<Currency>,
     block_provider: Arc<dyn BlockProvider + Send + Sync>,
 }
 
 impl OnlineServerContext {
-    pub fn new(client: SuiClient, block_provider: Arc<dyn BlockProvider + Send + Sync>) -> Self {
+    pub fn new(
+        client: SuiClient,
+        block_provider: Arc<dyn BlockProvider + Send + Sync>,
+        currencies: Vec<Currency>,
+    ) -> Self {
         Self {
             client,
+            currencies,
             block_provider,
         }
     }
@@ -66,10 +80,21 @@ pub trait BlockProvider {
     async fn oldest_block_identifier(&self) -> Result<BlockIdentifier, Error>;
     async fn current_block_identifier(&self) -> Result<BlockIdentifier, Error>;
     async fn get_balance_at_block(
+        &self,
+        addr: SuiAddress,
+        currency: &Currency,
+        block_height: u64,
+    ) -> Result<u128, Error>;
+    /// The SUI delegated to validators by `addr`, as of `block_height`.
+    async fn get_stake_balance_at_block(
         &self,
         addr: SuiAddress,
         block_height: u64,
     ) -> Result<u128, Error>;
+    async fn search_transactions(
+        &self,
+        request: SearchTransactionsRequest,
+    ) -> Result<SearchTransactionsResponse, Error>;
 }
 
 #[derive(Clone)]
@@ -77,6 +102,7 @@ pub struct PseudoBlockProvider {
     database: Arc<BlockProviderTables>,
     client: SuiClient,
     genesis: Genesis,
+    currencies: Vec<Currency>,
 }
 
 #[async_trait]
@@ -151,31 +177,102 @@ impl BlockProvider for PseudoBlockProvider {
     async fn get_balance_at_block(
         &self,
         addr: SuiAddress,
+        currency: &Currency,
         block_height: u64,
     ) -> Result<u128, Error> {
-        Ok(self
+        // SUI balances are kept in their own table, other coins are keyed by coin type.
+        let balance = if currency.is_sui() {
+            self.database
+                .balances
+                .iter()
+                .skip_prior_to(&(addr, block_height))?
+                .next()
+                .and_then(|((address, _), balance)| (address == addr).then_some(balance))
+        } else {
+            let coin_type = &currency.metadata.coin_type;
+            self.database
+                .coin_balances
+                .iter()
+                .skip_prior_to(&(addr, coin_type.clone(), block_height))?
+                .next()
+                .and_then(|((address, type_, _), balance)| {
+                    (address == addr && &type_ == coin_type).then_some(balance)
+                })
+        };
+        Ok(balance.map(|b| b.balance).unwrap_or_default())
+    }
+
+    async fn get_stake_balance_at_block(
+        &self,
+        addr: SuiAddress,
+        block_height: u64,
+    ) -> Result<u128, Error> {
+        let balance = self
             .database
-            .balances
+            .stake_balances
             .iter()
             .skip_prior_to(&(addr, block_height))?
             .next()
-            .and_then(|((address, _), balance)| {
-                if address == addr {
-                    Some(balance.balance)
-                } else {
-                    None
+            .and_then(|((address, _), balance)| (address == addr).then_some(balance));
+        Ok(balance.map(|b| b.balance).unwrap_or_default())
+    }
+
+    async fn search_transactions(
+        &self,
+        request: SearchTransactionsRequest,
+    ) -> Result<SearchTransactionsResponse, Error> {
+        let max_block = match request.max_block {
+            Some(max_block) => max_block,
+            None => self.current_block_identifier().await?.index,
+        };
+        let offset = request.offset.unwrap_or_default();
+        let limit = request
+            .limit
+            .unwrap_or(MAX_SEARCH_LIMIT)
+            .min(MAX_SEARCH_LIMIT);
+        let filter = TransactionFilter::from(request);
+
+        // One more match than requested tells whether there is a next page.
+        let matches =
+            self.database
+                .search_transactions(&filter, max_block, (offset + limit + 1) as usize)?;
+
+        let mut transactions = vec![];
+        for block_height in matches.iter().skip(offset as usize).take(limit as usize) {
+            let block = self.get_block_by_index(*block_height).await?.block;
+            let block_identifier = block.block_identifier;
+            transactions.extend(block.transactions.into_iter().map(|transaction| {
+                BlockTransaction {
+                    block_identifier,
+                    transaction,
                 }
-            })
-            .unwrap_or_default())
+            }));
+        }
+        // The search stops after the requested page, so the count only covers the matches
+        // up to one past the page.
+        let total_count = matches.len() as u64;
+        let next_offset = offset + limit;
+
+        Ok(SearchTransactionsResponse {
+            transactions,
+            total_count,
+            next_offset: (next_offset < total_count).then_some(next_offset),
+        })
     }
 }
 
 impl PseudoBlockProvider {
-    pub fn spawn(client: SuiClient, genesis: Genesis, db_path: &Path) -> Self {
+    pub fn spawn(
+        client: SuiClient,
+        genesis: Genesis,
+        currencies: Vec<Currency>,
+        db_path: &Path,
+    ) -> Self {
         let blocks = Self {
             database: Arc::new(BlockProviderTables::open(db_path, None)),
             client: client.clone(),
             genesis,
+            currencies,
         };
 
         let block_interval = option_env!("SUI_BLOCK_INTERVAL")
@@ -189,24 +286,32 @@ impl PseudoBlockProvider {
             if f.database.is_empty() {
                 info!("Database is empty, indexing genesis block...");
                 let genesis = genesis_block(&f.genesis);
-                let genesis_txs = genesis
+                let genesis_txs: Vec<Operation> = genesis
                     .block
                     .transactions
                     .iter()
                     .flat_map(|tx| tx.operations.clone())
                     .collect();
 
-                f.add_block_index(
-                    &genesis.block.block_identifier,
-                    &genesis.block.parent_block_identifier,
-                )
-                .unwrap();
-                if let Err(e) = f.update_balance(0, genesis_txs).await {
-                    error!("Error updating balance, cause: {e:?}")
+                if let Err(e) = f
+                    .add_block(
+                        &genesis.block.block_identifier,
+                        &genesis.block.parent_block_identifier,
+                        genesis_txs,
+                    )
+                    .await
+                {
+                    error!("Error indexing genesis block, cause: {e:?}")
                 }
             } else {
                 let current_block = f.current_block_identifier().await.unwrap();
                 info!("Resuming from block {}", current_block.index);
+                // Blocks created before transactions were indexed are not searchable until
+                // they are indexed.
+                while let Err(e) = f.backfill_transaction_index().await {
+                    error!("Error indexing transactions of past blocks, cause: {e:?}");
+                    tokio::time::sleep(block_interval).await;
+                }
             };
             loop {
                 if let Err(e) = f.create_next_block(&client).await {
@@ -264,16 +369,18 @@ impl PseudoBlockProvider {
                     &response.certificate.data,
                     &response.effects.status,
                     &response.effects.events,
+                    &self.currencies,
                 )?;
 
-                self.add_block_index(&block_identifier, &parent_block_identifier)?;
-                self.update_balance(index, operations).await.map_err(|e| {
-                    anyhow!(
-                        "Failed to update balance, tx: {}, effect:{}, cause : {e}",
-                        response.certificate,
-                        response.effects
-                    )
-                })?;
+                self.add_block(&block_identifier, &parent_block_identifier, operations)
+                    .await
+                    .map_err(|e| {
+                        anyhow!(
+                            "Failed to add block, tx: {}, effect:{}, cause : {e}",
+                            response.certificate,
+                            response.effects
+                        )
+                    })?;
                 parent_block_identifier = block_identifier
             }
         } else {
@@ -283,55 +390,123 @@ impl PseudoBlockProvider {
         Ok(())
     }
 
+    /// Write a block, together with the index of its transaction and the balance changes of
+    /// its operations, in one batch, so that a block is never visible without them.
+    async fn add_block(
+        &self,
+        block_id: &BlockIdentifier,
+        parent: &BlockIdentifier,
+        ops: Vec<Operation>,
+    ) -> Result<(), anyhow::Error> {
+        let batch = self.database.blocks.batch();
+        let batch = self.add_block_index(batch, block_id, parent)?;
+        let batch = self
+            .database
+            .index_transaction(batch, block_id.index, block_id.hash, &ops)?;
+        let batch = self.update_balance(batch, block_id.index, ops).await?;
+        batch.write()?;
+        Ok(())
+    }
+
     fn add_block_index(
         &self,
+        batch: DBBatch,
         block_id: &BlockIdentifier,
         parent: &BlockIdentifier,
-    ) -> Result<(), Error> {
+    ) -> Result<DBBatch, Error> {
         let index = &block_id.index;
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_millis() as u64;
-        self.database.block_heights.insert(&block_id.hash, index)?;
-        self.database
-            .blocks
-            .insert(index, &(*block_id, *parent, timestamp))?;
+        Ok(batch
+            .insert_batch(&self.database.block_heights, [(&block_id.hash, index)])?
+            .insert_batch(
+                &self.database.blocks,
+                [(index, &(*block_id, *parent, timestamp))],
+            )?)
+    }
+
+    /// Index the transactions of the blocks that were created before transactions were
+    /// indexed, from the first block after the last indexed one.
+    async fn backfill_transaction_index(&self) -> Result<(), Error> {
+        let first_unindexed = self
+            .database
+            .transaction_index
+            .iter()
+            .skip_prior_to(&BlockHeight::MAX)?
+            .next()
+            .map_or(0, |(block_height, _)| block_height + 1);
+        let current_block = self.current_block_identifier().await?.index;
+        if first_unindexed <= current_block {
+            info!("Indexing transactions of blocks {first_unindexed} to {current_block}...");
+        }
+        for block_height in first_unindexed..=current_block {
+            let block = self.get_block_by_index(block_height).await?.block;
+            let mut batch = self.database.transaction_index.batch();
+            for tx in &block.transactions {
+                batch = self.database.index_transaction(
+                    batch,
+                    block_height,
+                    tx.transaction_identifier.hash,
+                    &tx.operations,
+                )?;
+            }
+            batch.write()?;
+        }
         Ok(())
     }
 
+    /// Add the balances after the operations of the block at `block_height` to `batch`.
     async fn update_balance(
         &self,
+        mut batch: DBBatch,
         block_height: u64,
         ops: Vec<Operation>,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<DBBatch, anyhow::Error> {
+        let stake_changes = extract_stake_changes_from_ops(&ops)?;
         let balance_changes = extract_balance_changes_from_ops(ops)?;
-        for (addr, value) in balance_changes {
-            let current_balance = self.get_balance_at_block(addr, block_height).await?;
-            let new_balance = if value.is_negative() {
-                if current_balance < value.abs() {
-                    // This can happen due to missing transactions data due to unstable validators, causing balance to
-                    // fall below zero temporarily. The problem should go away when we start using checkpoints for event and indexing
-                    return Err(anyhow!(
-                        "Account gas value fall below 0 at block {}, address: [{}]",
-                        block_height,
-                        addr
-                    ));
-                }
-                current_balance - value.abs()
+        for ((addr, currency), value) in balance_changes {
+            let current_balance = self
+                .get_balance_at_block(addr, &currency, block_height)
+                .await?;
+            let balance = HistoricBalance {
+                block_height,
+                balance: apply_balance_change(
+                    current_balance,
+                    &value,
+                    addr,
+                    &currency.symbol,
+                    block_height,
+                )?,
+            };
+            batch = if currency.is_sui() {
+                batch.insert_batch(&self.database.balances, [(&(addr, block_height), &balance)])?
             } else {
-                current_balance + value.abs()
+                batch.insert_batch(
+                    &self.database.coin_balances,
+                    [(&(addr, currency.metadata.coin_type, block_height), &balance)],
+                )?
             };
-
-            self.database.balances.insert(
-                &(addr, block_height),
-                &HistoricBalance {
+        }
+        for (addr, value) in stake_changes {
+            let current_balance = self.get_stake_balance_at_block(addr, block_height).await?;
+            let balance = HistoricBalance {
+                block_height,
+                balance: apply_balance_change(
+                    current_balance,
+                    &value,
+                    addr,
+                    "staked SUI",
                     block_height,
-                    balance: new_balance,
-                },
+                )?,
+            };
+            batch = batch.insert_batch(
+                &self.database.stake_balances,
+                [(&(addr, block_height), &balance)],
             )?;
         }
-        Ok(())
+        Ok(batch)
     }
 
     async fn create_block_response(
@@ -355,6 +530,7 @@ impl PseudoBlockProvider {
             &tx.certificate.data,
             &tx.effects.status,
             &tx.effects.events,
+            &self.currencies,
         )?;
 
         let transaction = Transaction {
@@ -383,16 +559,149 @@ pub struct HistoricBalance {
     balance: u128,
 }
 
+/// The searchable fields of the transaction in a block.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct IndexedTransaction {
+    digest: TransactionDigest,
+    success: bool,
+    addresses: Vec<SuiAddress>,
+    operation_types: Vec<OperationType>,
+    coin_types: Vec<String>,
+}
+
+impl IndexedTransaction {
+    fn new(digest: TransactionDigest, ops: &[Operation]) -> Self {
+        let success = ops
+            .iter()
+            .all(|op| !matches!(op.status, Some(OperationStatus::Failure)));
+        let mut addresses = ops
+            .iter()
+            .filter_map(|op| op.account.as_ref().map(|account| account.address))
+            .collect::<Vec<_>>();
+        addresses.sort();
+        addresses.dedup();
+        let mut operation_types: Vec<OperationType> = vec![];
+        for op in ops {
+            if !operation_types.contains(&op.type_) {
+                operation_types.push(op.type_);
+            }
+        }
+        let mut coin_types = ops
+            .iter()
+            .filter_map(|op| {
+                op.amount
+                    .as_ref()
+                    .map(|amount| amount.currency.metadata.coin_type.clone())
+            })
+            .collect::<Vec<_>>();
+        coin_types.sort();
+        coin_types.dedup();
+        Self {
+            digest,
+            success,
+            addresses,
+            operation_types,
+            coin_types,
+        }
+    }
+}
+
+/// The conditions of a `/search/transactions` request, combined with `operator`.
+struct TransactionFilter {
+    operator: Operator,
+    digest: Option<TransactionDigest>,
+    address: Option<SuiAddress>,
+    coin_type: Option<String>,
+    success: Option<bool>,
+    type_: Option<OperationType>,
+}
+
+impl From<SearchTransactionsRequest> for TransactionFilter {
+    fn from(request: SearchTransactionsRequest) -> Self {
+        Self {
+            operator: request.operator.unwrap_or_default(),
+            digest: request.transaction_identifier.map(|tx| tx.hash),
+            address: request
+                .address
+                .or_else(|| request.account_identifier.map(|account| account.address)),
+            coin_type: request.currency.map(|currency| currency.metadata.coin_type),
+            success: request.success.or_else(|| {
+                request
+                    .status
+                    .map(|status| matches!(status, OperationStatus::Success))
+            }),
+            type_: request.type_,
+        }
+    }
+}
+
+impl TransactionFilter {
+    fn matches(&self, tx: &IndexedTransaction) -> bool {
+        let mut conditions = [
+            self.digest.map(|digest| tx.digest == digest),
+            self.address.map(|address| tx.addresses.contains(&address)),
+            self.coin_type
+                .as_ref()
+                .map(|coin_type| tx.coin_types.contains(coin_type)),
+            self.success.map(|success| tx.success == success),
+            self.type_.map(|type_| tx.operation_types.contains(&type_)),
+        ]
+        .into_iter()
+        .flatten();
+        match self.operator {
+            Operator::And => conditions.all(|matched| matched),
+            Operator::Or => conditions.any(|matched| matched),
+        }
+    }
+}
+
+fn apply_balance_change(
+    current_balance: u128,
+    value: &SignedValue,
+    addr: SuiAddress,
+    symbol: &str,
+    block_height: u64,
+) -> Result<u128, anyhow::Error> {
+    if value.is_negative() {
+        if current_balance < value.abs() {
+            // This can happen due to missing transactions data due to unstable validators, causing balance to
+            // fall below zero temporarily. The problem should go away when we start using checkpoints for event and indexing
+            return Err(anyhow!(
+                "Account {} balance fall below 0 at block {}, address: [{}]",
+                symbol,
+                block_height,
+                addr
+            ));
+        }
+        Ok(current_balance - value.abs())
+    } else {
+        Ok(current_balance + value.abs())
+    }
+}
+
 fn extract_balance_changes_from_ops(
     ops: Vec<Operation>,
-) -> Result<BTreeMap<SuiAddress, SignedValue>, anyhow::Error> {
-    let mut changes: BTreeMap<SuiAddress, SignedValue> = BTreeMap::new();
+) -> Result<BTreeMap<(SuiAddress, Currency), SignedValue>, anyhow::Error> {
+    let mut changes: BTreeMap<(SuiAddress, Currency), SignedValue> = BTreeMap::new();
     for op in ops {
+        // Stake sub-account balances are tracked separately.
+        if op
+            .account
+            .as_ref()
+            .map_or(false, |acc| acc.sub_account.is_some())
+        {
+            continue;
+        }
         match op.type_ {
             OperationType::SuiBalanceChange
+            | OperationType::CoinBalanceChange
+            | OperationType::DelegationReward
             | OperationType::GasSpent
             | OperationType::Genesis
-            | OperationType::PaySui => {
+            | OperationType::PaySui
+            | OperationType::PayCoin
+            | OperationType::Delegation
+            | OperationType::WithdrawDelegation => {
                 let addr = op
                     .account
                     .ok_or_else(|| anyhow!("Account address cannot be null for {:?}", op.type_))?
@@ -400,7 +709,10 @@ fn extract_balance_changes_from_ops(
                 let amount = op
                     .amount
                     .ok_or_else(|| anyhow!("Amount cannot be null for {:?}", op.type_))?;
-                changes.entry(addr).or_default().add(&amount.value)
+                changes
+                    .entry((addr, amount.currency))
+                    .or_default()
+                    .add(&amount.value)
             }
             _ => {}
         }
@@ -408,6 +720,25 @@ fn extract_balance_changes_from_ops(
     Ok(changes)
 }
 
+fn extract_stake_changes_from_ops(
+    ops: &[Operation],
+) -> Result<BTreeMap<SuiAddress, SignedValue>, anyhow::Error> {
+    let mut changes: BTreeMap<SuiAddress, SignedValue> = BTreeMap::new();
+    for op in ops.iter().filter(|op| op.is_staking()) {
+        if let Some(account) = op.account.as_ref().filter(|acc| acc.sub_account.is_some()) {
+            let amount = op
+                .amount
+                .as_ref()
+                .ok_or_else(|| anyhow!("Amount cannot be null for {:?}", op.type_))?;
+            changes
+                .entry(account.address)
+                .or_default()
+                .add(&amount.value)
+        }
+    }
+    Ok(changes)
+}
+
 fn genesis_block(genesis: &Genesis) -> BlockResponse {
     let id = BlockIdentifier {
         index: 0,
@@ -428,7 +759,7 @@ fn genesis_block(genesis: &Genesis) -> BlockResponse {
             related_operations: vec![],
             type_: OperationType::Genesis,
             status: Some(OperationStatus::Success),
-            account: Some(AccountIdentifier { address }),
+            account: Some(address.into()),
             amount: Some(Amount {
                 value: SignedValue::from(coin.value()),
                 currency: SUI.clone(),
@@ -478,6 +809,14 @@ pub struct BlockProviderTables {
     block_heights: DBMap<BlockHash, BlockHeight>,
     #[default_options_override_fn = "default_config"]
     balances: DBMap<(SuiAddress, u64), HistoricBalance>,
+    #[default_options_override_fn = "default_config"]
+    coin_balances: DBMap<(SuiAddress, String, u64), HistoricBalance>,
+    #[default_options_override_fn = "default_config"]
+    stake_balances: DBMap<(SuiAddress, u64), HistoricBalance>,
+    #[default_options_override_fn = "default_config"]
+    transaction_index: DBMap<BlockHeight, IndexedTransaction>,
+    #[default_options_override_fn = "default_config"]
+    account_transactions: DBMap<(SuiAddress, BlockHeight), ()>,
 }
 
 impl BlockProviderTables {
@@ -488,6 +827,76 @@ impl BlockProviderTables {
     pub fn is_empty(&self) -> bool {
         self.blocks.is_empty()
     }
+
+    /// Add the index of the transaction in a block for `/search/transactions` to `batch`.
+    fn index_transaction(
+        &self,
+        batch: DBBatch,
+        block_height: BlockHeight,
+        digest: TransactionDigest,
+        ops: &[Operation],
+    ) -> Result<DBBatch, Error> {
+        let tx = IndexedTransaction::new(digest, ops);
+        Ok(batch
+            .insert_batch(
+                &self.account_transactions,
+                tx.addresses
+                    .iter()
+                    .map(|address| ((*address, block_height), ())),
+            )?
+            .insert_batch(&self.transaction_index, [(block_height, tx)])?)
+    }
+
+    /// The heights of the blocks up to `max_block` with a transaction matching `filter`, most
+    /// recent first. The search stops after `max_matches` matches.
+    fn search_transactions(
+        &self,
+        filter: &TransactionFilter,
+        max_block: BlockHeight,
+        max_matches: usize,
+    ) -> Result<Vec<BlockHeight>, Error> {
+        let mut matches = vec![];
+        match (filter.operator, filter.digest, filter.address) {
+            // Block hashes are the transaction digests.
+            (Operator::And, Some(digest), _) => {
+                if let Some(block_height) = self.block_heights.get(&digest)? {
+                    let tx = self.transaction_index.get(&block_height)?;
+                    if block_height <= max_block && tx.map_or(false, |tx| filter.matches(&tx)) {
+                        matches.push(block_height);
+                    }
+                }
+            }
+            (Operator::And, None, Some(address)) => {
+                for ((_, block_height), _) in self
+                    .account_transactions
+                    .iter()
+                    .skip_prior_to(&(address, max_block))?
+                    .reverse()
+                    .take_while(|((addr, _), _)| addr == &address)
+                {
+                    if matches.len() == max_matches {
+                        break;
+                    }
+                    let tx = self.transaction_index.get(&block_height)?;
+                    if tx.map_or(false, |tx| filter.matches(&tx)) {
+                        matches.push(block_height);
+                    }
+                }
+            }
+            _ => {
+                matches = self
+                    .transaction_index
+                    .iter()
+                    .skip_prior_to(&max_block)?
+                    .reverse()
+                    .filter(|(_, tx)| filter.matches(tx))
+                    .map(|(block_height, _)| block_height)
+                    .take(max_matches)
+                    .collect();
+            }
+        }
+        Ok(matches)
+    }
 }
 
 fn default_config() -> DBOptions {
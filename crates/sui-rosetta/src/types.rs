@@ -14,6 +14,7 @@ use serde_json::Value;
 use strum_macros::EnumIter;
 use strum_macros::EnumString;
 use sui_sdk::rpc_types::SuiExecutionStatus;
+use sui_sdk::SUI_COIN_TYPE;
 use sui_types::base_types::{ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest};
 use sui_types::crypto::PublicKey as SuiPublicKey;
 use sui_types::crypto::SignatureScheme;
@@ -62,19 +63,69 @@ impl SuiEnv {
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AccountIdentifier {
     pub address: SuiAddress,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_account: Option<SubAccount>,
 }
 
 impl From<SuiAddress> for AccountIdentifier {
     fn from(address: SuiAddress) -> Self {
-        AccountIdentifier { address }
+        AccountIdentifier {
+            address,
+            sub_account: None,
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+impl AccountIdentifier {
+    /// The sub-account holding the SUI delegated to validators by `address`.
+    pub fn stake(address: SuiAddress) -> Self {
+        AccountIdentifier {
+            address,
+            sub_account: Some(SubAccount {
+                account_type: SubAccountType::Stake,
+            }),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SubAccount {
+    #[serde(rename = "address")]
+    pub account_type: SubAccountType,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SubAccountType {
+    Stake,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Currency {
     pub symbol: String,
     pub decimals: u64,
+    #[serde(default)]
+    pub metadata: CurrencyMetadata,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct CurrencyMetadata {
+    pub coin_type: String,
+}
+
+impl Default for CurrencyMetadata {
+    fn default() -> Self {
+        Self {
+            coin_type: SUI_COIN_TYPE.to_string(),
+        }
+    }
+}
+
+impl Currency {
+    pub fn is_sui(&self) -> bool {
+        self.metadata.coin_type == SUI_COIN_TYPE
+    }
+}
+
 #[derive(Deserialize)]
 pub struct AccountBalanceRequest {
     pub network_identifier: NetworkIdentifier,
@@ -226,6 +277,10 @@ impl Amount {
             currency: SUI.clone(),
         }
     }
+
+    pub fn new_with_currency(value: SignedValue, currency: Currency) -> Self {
+        Self { value, currency }
+    }
 }
 
 #[derive(Deserialize)]
@@ -250,8 +305,8 @@ pub struct Coin {
     pub amount: Amount,
 }
 
-impl From<sui_sdk::rpc_types::Coin> for Coin {
-    fn from(coin: sui_sdk::rpc_types::Coin) -> Self {
+impl Coin {
+    pub fn new(coin: sui_sdk::rpc_types::Coin, currency: Currency) -> Self {
         Self {
             coin_identifier: CoinIdentifier {
                 identifier: CoinID {
@@ -259,10 +314,7 @@ impl From<sui_sdk::rpc_types::Coin> for Coin {
                     version: coin.version,
                 },
             },
-            amount: Amount {
-                value: SignedValue::from(coin.balance),
-                currency: SUI.clone(),
-            },
+            amount: Amount::new_with_currency(SignedValue::from(coin.balance), currency),
         }
     }
 }
@@ -412,8 +464,13 @@ pub enum OperationType {
     // Balance changing operations from TransactionEffect
     GasSpent,
     SuiBalanceChange,
+    CoinBalanceChange,
+    DelegationReward,
     // sui-rosetta supported operation type
     PaySui,
+    PayCoin,
+    Delegation,
+    WithdrawDelegation,
     GasBudget,
     // All other Sui transaction types, readonly
     TransferSUI,
@@ -556,6 +613,17 @@ pub struct ConstructionPreprocessResponse {
 pub struct MetadataOptions {
     pub sender: SuiAddress,
     pub amount: u128,
+    /// Currency of the transferred coins, SUI if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget: Option<u64>,
+    /// The operation to construct, PaySui or PayCoin if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation: Option<OperationType>,
+    /// The `StakedSui` object to withdraw from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub staked_sui: Option<ObjectID>,
 }
 
 impl IntoResponse for ConstructionPreprocessResponse {
@@ -585,9 +653,19 @@ pub struct ConstructionMetadataResponse {
     pub suggested_fee: Vec<Amount>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default)]
 pub struct ConstructionMetadata {
     pub sender_coins: Vec<ObjectRef>,
+    /// Separate SUI gas coin, only needed when the sender coins are not SUI
+    /// or are passed to a staking call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas: Option<ObjectRef>,
+    /// The Sui framework package, called by staking transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub framework: Option<ObjectRef>,
+    /// The `Delegation` and `StakedSui` objects to withdraw from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delegation: Option<(ObjectRef, ObjectRef)>,
 }
 
 impl IntoResponse for ConstructionMetadataResponse {
@@ -823,6 +901,91 @@ impl IntoResponse for BlockTransactionResponse {
     }
 }
 
+#[derive(Deserialize)]
+pub struct SearchTransactionsRequest {
+    pub network_identifier: NetworkIdentifier,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator: Option<Operator>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_block: Option<BlockHeight>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_identifier: Option<TransactionIdentifier>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_identifier: Option<AccountIdentifier>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OperationStatus>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub type_: Option<OperationType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<SuiAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Operator {
+    #[default]
+    And,
+    Or,
+}
+
+#[derive(Serialize)]
+pub struct SearchTransactionsResponse {
+    pub transactions: Vec<BlockTransaction>,
+    pub total_count: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u64>,
+}
+
+impl IntoResponse for SearchTransactionsResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+#[derive(Serialize)]
+pub struct BlockTransaction {
+    pub block_identifier: BlockIdentifier,
+    pub transaction: Transaction,
+}
+
+#[derive(Serialize)]
+pub struct MempoolResponse {
+    pub transaction_identifiers: Vec<TransactionIdentifier>,
+}
+
+impl IntoResponse for MempoolResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MempoolTransactionRequest {
+    pub network_identifier: NetworkIdentifier,
+    pub transaction_identifier: TransactionIdentifier,
+}
+
+#[derive(Serialize)]
+pub struct MempoolTransactionResponse {
+    pub transaction: Transaction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+}
+
+impl IntoResponse for MempoolTransactionResponse {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
 #[derive(Default)]
 pub struct IndexCounter {
     index: u64,
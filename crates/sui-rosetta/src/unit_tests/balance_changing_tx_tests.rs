@@ -31,6 +31,7 @@ use test_utils::network::TestClusterBuilder;
 use crate::operations::Operation;
 use crate::state::extract_balance_changes_from_ops;
 use crate::types::SignedValue;
+use crate::SUI;
 
 #[tokio::test]
 async fn test_transfer_sui() {
@@ -392,9 +393,14 @@ async fn test_transaction(
         &data.try_into().unwrap(),
         &SuiExecutionStatus::Success,
         &effect.events,
+        &[SUI.clone()],
     )
     .unwrap();
-    let balances_from_ops = extract_balance_changes_from_ops(ops).unwrap();
+    let balances_from_ops = extract_balance_changes_from_ops(ops)
+        .unwrap()
+        .into_iter()
+        .map(|((addr, _), balance)| (addr, balance))
+        .collect::<BTreeMap<_, _>>();
 
     // get actual balance changed after transaction
     let mut actual_balance_change = BTreeMap::new();
@@ -418,9 +424,10 @@ async fn get_random_sui(
 ) -> ObjectRef {
     let coins = client
         .read_api()
-        .get_objects_owned_by_address(sender)
+        .get_objects_owned_by_address(sender, None, None, None)
         .await
-        .unwrap();
+        .unwrap()
+        .data;
     let coin = coins
         .iter()
         .filter(|object| {
@@ -442,9 +449,10 @@ fn get_random_address(addresses: &[SuiAddress], except: Vec<SuiAddress>) -> SuiA
 async fn get_balance(client: &SuiClient, address: SuiAddress) -> u64 {
     let coins = client
         .read_api()
-        .get_objects_owned_by_address(address)
+        .get_objects_owned_by_address(address, None, None, None)
         .await
-        .unwrap();
+        .unwrap()
+        .data;
     let mut balance = 0u64;
     for coin in coins {
         if coin.type_ == GasCoin::type_().to_string() {
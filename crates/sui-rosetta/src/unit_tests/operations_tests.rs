@@ -1,11 +1,19 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use sui_types::base_types::{ObjectDigest, ObjectID, SequenceNumber, SuiAddress};
-use sui_types::messages::TransactionData;
+use sui_protocol_constants::ProtocolVersion;
+use sui_sdk::rpc_types::{SuiEvent, SuiExecutionStatus, SuiTransactionData};
+use sui_types::base_types::{ObjectDigest, ObjectID, ObjectRef, SequenceNumber, SuiAddress};
+use sui_types::event::BalanceChangeType;
+use sui_types::governance::{ADD_DELEGATION_MUL_COIN_FUN_NAME, WITHDRAW_DELEGATION_FUN_NAME};
+use sui_types::messages::{CallArg, ObjectArg, TransactionData, VerifiedTransaction};
+use sui_types::object::Owner;
+use sui_types::sui_system_state::SUI_SYSTEM_MODULE_NAME;
+use sui_types::{SUI_SYSTEM_STATE_OBJECT_ID, SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION};
 
 use crate::operations::Operation;
-use crate::types::ConstructionMetadata;
+use crate::types::{ConstructionMetadata, Currency, CurrencyMetadata, OperationType};
+use crate::SUI;
 
 #[tokio::test]
 async fn test_operation_data_parsing() -> Result<(), anyhow::Error> {
@@ -26,9 +34,10 @@ async fn test_operation_data_parsing() -> Result<(), anyhow::Error> {
         1000,
     );
 
-    let ops = Operation::from_data(&data.clone().try_into()?)?;
+    let ops = Operation::from_data(&data, None)?;
     let metadata = ConstructionMetadata {
         sender_coins: vec![gas],
+        ..Default::default()
     };
 
     let parsed_data = Operation::create_data(ops, metadata).await?;
@@ -36,3 +45,207 @@ async fn test_operation_data_parsing() -> Result<(), anyhow::Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_pay_coin_operation_data_parsing() -> Result<(), anyhow::Error> {
+    let coin = (
+        ObjectID::random(),
+        SequenceNumber::new(),
+        ObjectDigest::random(),
+    );
+    let gas = (
+        ObjectID::random(),
+        SequenceNumber::new(),
+        ObjectDigest::random(),
+    );
+    let currency = Currency {
+        symbol: "USDC".to_string(),
+        decimals: 6,
+        metadata: CurrencyMetadata {
+            coin_type: format!("{}::usdc::USDC", ObjectID::random()),
+        },
+    };
+
+    let sender = SuiAddress::random_for_testing_only();
+
+    let data = TransactionData::new_pay(
+        sender,
+        vec![coin],
+        vec![SuiAddress::random_for_testing_only()],
+        vec![10000],
+        gas,
+        1000,
+    );
+
+    let ops = Operation::from_data(&data, Some(&currency))?;
+    assert!(ops.iter().any(|op| op.type_ == OperationType::PayCoin
+        && op.amount.as_ref().map(|amount| &amount.currency) == Some(&currency)));
+
+    let metadata = ConstructionMetadata {
+        sender_coins: vec![coin],
+        gas: Some(gas),
+        ..Default::default()
+    };
+
+    let parsed_data = Operation::create_data(ops, metadata).await?;
+    assert_eq!(data, parsed_data);
+
+    Ok(())
+}
+
+fn random_object_ref() -> ObjectRef {
+    (
+        ObjectID::random(),
+        SequenceNumber::new(),
+        ObjectDigest::random(),
+    )
+}
+
+fn system_state_arg() -> CallArg {
+    CallArg::Object(ObjectArg::SharedObject {
+        id: SUI_SYSTEM_STATE_OBJECT_ID,
+        initial_shared_version: SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION,
+    })
+}
+
+#[tokio::test]
+async fn test_delegation_operation_data_parsing() -> Result<(), anyhow::Error> {
+    let coin = random_object_ref();
+    let gas = random_object_ref();
+    let framework = random_object_ref();
+    let sender = SuiAddress::random_for_testing_only();
+    let validator = SuiAddress::random_for_testing_only();
+
+    let data = TransactionData::new_move_call(
+        sender,
+        framework,
+        SUI_SYSTEM_MODULE_NAME.to_owned(),
+        ADD_DELEGATION_MUL_COIN_FUN_NAME.to_owned(),
+        vec![],
+        gas,
+        vec![
+            system_state_arg(),
+            CallArg::ObjVec(vec![ObjectArg::ImmOrOwnedObject(coin)]),
+            CallArg::Pure(bcs::to_bytes(&Some(10000u64))?),
+            CallArg::Pure(bcs::to_bytes(&validator)?),
+        ],
+        1000,
+    );
+
+    let ops = Operation::from_data(&data, None)?;
+    let delegation = ops
+        .iter()
+        .find(|op| op.type_ == OperationType::Delegation)
+        .unwrap();
+    assert_eq!(validator, delegation.validator()?);
+    assert_eq!(
+        "-10000",
+        delegation.amount.as_ref().unwrap().value.to_string()
+    );
+
+    let metadata = ConstructionMetadata {
+        sender_coins: vec![coin],
+        gas: Some(gas),
+        framework: Some(framework),
+        ..Default::default()
+    };
+
+    let parsed_data = Operation::create_data(ops, metadata).await?;
+    assert_eq!(data, parsed_data);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_withdraw_delegation_operation_data_parsing() -> Result<(), anyhow::Error> {
+    let delegation = random_object_ref();
+    let staked_sui = random_object_ref();
+    let gas = random_object_ref();
+    let framework = random_object_ref();
+    let sender = SuiAddress::random_for_testing_only();
+
+    let data = TransactionData::new_move_call(
+        sender,
+        framework,
+        SUI_SYSTEM_MODULE_NAME.to_owned(),
+        WITHDRAW_DELEGATION_FUN_NAME.to_owned(),
+        vec![],
+        gas,
+        vec![
+            system_state_arg(),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(delegation)),
+            CallArg::Object(ObjectArg::ImmOrOwnedObject(staked_sui)),
+            CallArg::Pure(bcs::to_bytes(&10000u64)?),
+        ],
+        1000,
+    );
+
+    let ops = Operation::from_data(&data, None)?;
+    let withdrawal = ops
+        .iter()
+        .find(|op| op.type_ == OperationType::WithdrawDelegation)
+        .unwrap();
+    assert_eq!(staked_sui.0, withdrawal.staked_sui()?);
+
+    let metadata = ConstructionMetadata {
+        gas: Some(gas),
+        framework: Some(framework),
+        delegation: Some((delegation, staked_sui)),
+        ..Default::default()
+    };
+
+    let parsed_data = Operation::create_data(ops, metadata).await?;
+    assert_eq!(data, parsed_data);
+
+    Ok(())
+}
+
+fn sui_balance_change(change_type: BalanceChangeType, owner: SuiAddress, amount: i128) -> SuiEvent {
+    SuiEvent::CoinBalanceChange {
+        package_id: ObjectID::random(),
+        transaction_module: SUI_SYSTEM_MODULE_NAME.to_string(),
+        sender: SuiAddress::default(),
+        change_type,
+        owner: Owner::AddressOwner(owner),
+        coin_type: SUI.metadata.coin_type.clone(),
+        coin_object_id: ObjectID::random(),
+        version: SequenceNumber::new(),
+        amount,
+    }
+}
+
+#[tokio::test]
+async fn test_epoch_change_delegation_rewards() -> Result<(), anyhow::Error> {
+    let tx = VerifiedTransaction::new_change_epoch(1, ProtocolVersion::MIN, 0, 0, 0);
+    let data: SuiTransactionData = tx.data().intent_message.value.clone().try_into()?;
+    let delegator = SuiAddress::random_for_testing_only();
+    let other = SuiAddress::random_for_testing_only();
+    let events = vec![
+        sui_balance_change(BalanceChangeType::Receive, delegator, 100),
+        sui_balance_change(BalanceChangeType::Receive, delegator, 20),
+        sui_balance_change(BalanceChangeType::Pay, other, -50),
+    ];
+
+    let ops = Operation::from_data_and_events(
+        &data,
+        &SuiExecutionStatus::Success,
+        &events,
+        &[SUI.clone()],
+    )?;
+    let balance_ops = ops
+        .iter()
+        .filter_map(|op| {
+            let amount = op.amount.as_ref()?.value.to_string();
+            Some((op.type_, op.account.as_ref()?.address, amount))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(2, balance_ops.len());
+    assert!(balance_ops.contains(&(
+        OperationType::DelegationReward,
+        delegator,
+        "120".to_string()
+    )));
+    assert!(balance_ops.contains(&(OperationType::SuiBalanceChange, other, "-50".to_string())));
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use sui_types::base_types::{SuiAddress, TransactionDigest};
+
+use crate::state::{BlockProviderTables, IndexedTransaction, TransactionFilter};
+use crate::types::{BlockHeight, OperationType, Operator};
+
+fn digest(block_height: BlockHeight) -> TransactionDigest {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&block_height.to_be_bytes());
+    TransactionDigest::new(bytes)
+}
+
+fn filter(operator: Operator) -> TransactionFilter {
+    TransactionFilter {
+        operator,
+        digest: None,
+        address: None,
+        coin_type: None,
+        success: None,
+        type_: None,
+    }
+}
+
+/// Blocks 0 to 9, transactions of even blocks are sent by `even`, of odd blocks by `odd`, and
+/// the ones of blocks 5 and 6 failed.
+fn index_blocks(tables: &BlockProviderTables, even: SuiAddress, odd: SuiAddress) {
+    for block_height in 0..10 {
+        let tx = IndexedTransaction {
+            digest: digest(block_height),
+            success: !(5..=6).contains(&block_height),
+            addresses: vec![if block_height % 2 == 0 { even } else { odd }],
+            operation_types: vec![OperationType::PaySui],
+            coin_types: vec![],
+        };
+        tables
+            .blocks
+            .batch()
+            .insert_batch(
+                &tables.block_heights,
+                [(digest(block_height), block_height)],
+            )
+            .unwrap()
+            .insert_batch(
+                &tables.account_transactions,
+                tx.addresses
+                    .iter()
+                    .map(|address| ((*address, block_height), ())),
+            )
+            .unwrap()
+            .insert_batch(&tables.transaction_index, [(block_height, tx)])
+            .unwrap()
+            .write()
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_search_stops_after_max_matches() {
+    let dir = tempfile::tempdir().unwrap();
+    let tables = BlockProviderTables::open(dir.path(), None);
+    let (even, odd) = (
+        SuiAddress::random_for_testing_only(),
+        SuiAddress::random_for_testing_only(),
+    );
+    index_blocks(&tables, even, odd);
+
+    // Most recent first, up to max_block.
+    let all = filter(Operator::And);
+    assert_eq!(
+        vec![8, 7, 6],
+        tables.search_transactions(&all, 8, 3).unwrap()
+    );
+    assert_eq!(
+        (0..10).rev().collect::<Vec<_>>(),
+        tables.search_transactions(&all, 9, 100).unwrap()
+    );
+
+    let mut by_address = filter(Operator::And);
+    by_address.address = Some(odd);
+    assert_eq!(
+        vec![7, 5],
+        tables.search_transactions(&by_address, 8, 2).unwrap()
+    );
+    by_address.success = Some(true);
+    assert_eq!(
+        vec![9, 7, 3, 1],
+        tables.search_transactions(&by_address, 9, 100).unwrap()
+    );
+
+    let mut failed_or_even = filter(Operator::Or);
+    failed_or_even.address = Some(even);
+    failed_or_even.success = Some(false);
+    assert_eq!(
+        vec![8, 6, 5],
+        tables.search_transactions(&failed_or_even, 9, 3).unwrap()
+    );
+}
+
+#[test]
+fn test_search_by_digest() {
+    let dir = tempfile::tempdir().unwrap();
+    let tables = BlockProviderTables::open(dir.path(), None);
+    let (even, odd) = (
+        SuiAddress::random_for_testing_only(),
+        SuiAddress::random_for_testing_only(),
+    );
+    index_blocks(&tables, even, odd);
+
+    let mut by_digest = filter(Operator::And);
+    by_digest.digest = Some(digest(4));
+    assert_eq!(
+        vec![4],
+        tables.search_transactions(&by_digest, 9, 100).unwrap()
+    );
+    // Blocks after max_block are not searched.
+    assert!(tables
+        .search_transactions(&by_digest, 3, 100)
+        .unwrap()
+        .is_empty());
+    // All the conditions must hold.
+    by_digest.address = Some(odd);
+    assert!(tables
+        .search_transactions(&by_digest, 9, 100)
+        .unwrap()
+        .is_empty());
+
+    by_digest.digest = Some(digest(42));
+    by_digest.address = None;
+    assert!(tables
+        .search_transactions(&by_digest, 9, 100)
+        .unwrap()
+        .is_empty());
+}
@@ -10,7 +10,7 @@ async fn main() -> Result<(), anyhow::Error> {
     let sui = SuiClient::new("http://127.0.0.1:5001", Some("ws://127.0.0.1:9001"), None).await?;
     let mut subscribe_all = sui
         .event_api()
-        .subscribe_event(SuiEventFilter::All(vec![]))
+        .subscribe_event(SuiEventFilter::All(vec![]), None)
         .await?;
     loop {
         println!("{:?}", subscribe_all.next().await);
@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use futures::StreamExt;
 use std::str::FromStr;
 use sui_sdk::types::base_types::SuiAddress;
 use sui_sdk::SuiClient;
@@ -9,7 +10,19 @@ use sui_sdk::SuiClient;
 async fn main() -> Result<(), anyhow::Error> {
     let sui = SuiClient::new("https://fullnode.devnet.sui.io:443", None, None).await?;
     let address = SuiAddress::from_str("0xec11cad080d0496a53bafcea629fcbcfff2a9866")?;
-    let objects = sui.read_api().get_objects_owned_by_address(address).await?;
+    let objects = sui
+        .read_api()
+        .get_objects_owned_by_address(address, None, None, Some(10))
+        .await?;
     println!("{:?}", objects);
+
+    // Or walk through every page of owned objects.
+    let mut objects = sui
+        .read_api()
+        .get_objects_owned_by_address_stream(address, None)
+        .boxed();
+    while let Some(object) = objects.next().await {
+        println!("{:?}", object);
+    }
     Ok(())
 }
@@ -11,9 +11,11 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 use sui_json_rpc_types::{
     Balance, Coin, CoinPage, DynamicFieldPage, EventPage, GetObjectDataResponse,
-    GetPastObjectDataResponse, GetRawObjectDataResponse, SuiCoinMetadata, SuiEventEnvelope,
-    SuiEventFilter, SuiExecuteTransactionResponse, SuiMoveNormalizedModule, SuiObjectInfo,
-    SuiTransactionResponse, TransactionsPage,
+    GetPastObjectDataResponse, GetRawObjectDataResponse, ObjectsPage, SuiCheckpointContents,
+    SuiCheckpointSummary, SuiCoinMetadata, SuiEventEnvelope, SuiEventFilter,
+    SuiExecuteTransactionResponse, SuiGetPastObjectRequest, SuiMoveNormalizedModule, SuiObjectInfo,
+    SuiTransactionData, SuiTransactionFilter, SuiTransactionRead, SuiTransactionResponse,
+    TransactionsPage,
 };
 use sui_types::balance::Supply;
 use sui_types::base_types::{
@@ -25,13 +27,15 @@ use sui_types::event::EventID;
 use sui_types::messages::{
     CommitteeInfoResponse, ExecuteTransactionRequestType, VerifiedTransaction,
 };
-use sui_types::query::{EventQuery, TransactionQuery};
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::query::{EventQuery, ObjectTypeFilter, TransactionQuery};
 use sui_types::sui_system_state::SuiSystemState;
 
 use futures::StreamExt;
 use sui_json_rpc::api::{
     CoinReadApiClient, EventReadApiClient, EventStreamingApiClient, RpcBcsApiClient,
     RpcFullNodeReadApiClient, RpcReadApiClient, TransactionExecutionApiClient,
+    TransactionStreamingApiClient,
 };
 #[derive(Debug)]
 pub struct ReadApi {
@@ -46,15 +50,91 @@ impl ReadApi {
     pub async fn get_objects_owned_by_address(
         &self,
         address: SuiAddress,
-    ) -> SuiRpcResult<Vec<SuiObjectInfo>> {
-        Ok(self.api.http.get_objects_owned_by_address(address).await?)
+        object_type: Option<ObjectTypeFilter>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> SuiRpcResult<ObjectsPage> {
+        Ok(self
+            .api
+            .http
+            .get_objects_owned_by_address(address, object_type, cursor, limit)
+            .await?)
+    }
+
+    pub fn get_objects_owned_by_address_stream(
+        &self,
+        address: SuiAddress,
+        object_type: Option<ObjectTypeFilter>,
+    ) -> impl Stream<Item = SuiObjectInfo> + '_ {
+        stream::unfold(
+            (vec![], None, true, object_type),
+            move |(mut data, cursor, first, object_type)| async move {
+                if let Some(item) = data.pop() {
+                    Some((item, (data, cursor, false, object_type)))
+                } else if (cursor.is_none() && first) || cursor.is_some() {
+                    let page = self
+                        .get_objects_owned_by_address(
+                            address,
+                            object_type.clone(),
+                            cursor,
+                            Some(100),
+                        )
+                        .await
+                        .ok()?;
+                    let mut data = page.data;
+                    data.reverse();
+                    data.pop()
+                        .map(|item| (item, (data, page.next_cursor, false, object_type)))
+                } else {
+                    None
+                }
+            },
+        )
     }
 
     pub async fn get_objects_owned_by_object(
         &self,
         object_id: ObjectID,
-    ) -> SuiRpcResult<Vec<SuiObjectInfo>> {
-        Ok(self.api.http.get_objects_owned_by_object(object_id).await?)
+        object_type: Option<ObjectTypeFilter>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> SuiRpcResult<ObjectsPage> {
+        Ok(self
+            .api
+            .http
+            .get_objects_owned_by_object(object_id, object_type, cursor, limit)
+            .await?)
+    }
+
+    pub fn get_objects_owned_by_object_stream(
+        &self,
+        object_id: ObjectID,
+        object_type: Option<ObjectTypeFilter>,
+    ) -> impl Stream<Item = SuiObjectInfo> + '_ {
+        stream::unfold(
+            (vec![], None, true, object_type),
+            move |(mut data, cursor, first, object_type)| async move {
+                if let Some(item) = data.pop() {
+                    Some((item, (data, cursor, false, object_type)))
+                } else if (cursor.is_none() && first) || cursor.is_some() {
+                    let page = self
+                        .get_objects_owned_by_object(
+                            object_id,
+                            object_type.clone(),
+                            cursor,
+                            Some(100),
+                        )
+                        .await
+                        .ok()?;
+                    let mut data = page.data;
+                    data.reverse();
+                    data.pop()
+                        .map(|item| (item, (data, page.next_cursor, false, object_type)))
+                } else {
+                    None
+                }
+            },
+        )
     }
 
     pub async fn get_dynamic_fields(
@@ -77,6 +157,13 @@ impl ReadApi {
         Ok(self.api.http.get_object(object_id).await?)
     }
 
+    pub async fn multi_get_parsed_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> SuiRpcResult<Vec<GetObjectDataResponse>> {
+        Ok(self.api.http.multi_get_objects(object_ids).await?)
+    }
+
     pub async fn try_get_parsed_past_object(
         &self,
         object_id: ObjectID,
@@ -89,6 +176,13 @@ impl ReadApi {
             .await?)
     }
 
+    pub async fn try_multi_get_parsed_past_objects(
+        &self,
+        past_objects: Vec<SuiGetPastObjectRequest>,
+    ) -> SuiRpcResult<Vec<GetPastObjectDataResponse>> {
+        Ok(self.api.http.multi_get_past_objects(past_objects).await?)
+    }
+
     pub async fn get_object(&self, object_id: ObjectID) -> SuiRpcResult<GetRawObjectDataResponse> {
         Ok(self.api.http.get_raw_object(object_id).await?)
     }
@@ -112,6 +206,13 @@ impl ReadApi {
         Ok(self.api.http.get_transaction(digest).await?)
     }
 
+    pub async fn multi_get_transactions(
+        &self,
+        digests: Vec<TransactionDigest>,
+    ) -> SuiRpcResult<Vec<SuiTransactionRead>> {
+        Ok(self.api.http.multi_get_transactions(digests).await?)
+    }
+
     pub async fn get_committee_info(
         &self,
         epoch: Option<EpochId>,
@@ -133,6 +234,23 @@ impl ReadApi {
             .await?)
     }
 
+    /// Subscribe to transactions executed by the node that match `filter`.
+    pub async fn subscribe_transaction(
+        &self,
+        filter: SuiTransactionFilter,
+    ) -> SuiRpcResult<impl Stream<Item = SuiRpcResult<SuiTransactionResponse>>> {
+        match &self.api.ws {
+            Some(c) => {
+                let subscription: Subscription<SuiTransactionResponse> =
+                    c.subscribe_transaction(filter).await?;
+                Ok(subscription.map(|item| Ok(item?)))
+            }
+            _ => Err(RpcError::Subscription(
+                "Subscription only supported by WebSocket client.".to_string(),
+            )),
+        }
+    }
+
     pub fn get_transactions_stream(
         &self,
         query: TransactionQuery,
@@ -174,6 +292,38 @@ impl ReadApi {
     pub async fn get_sui_system_state(&self) -> SuiRpcResult<SuiSystemState> {
         Ok(self.api.http.get_sui_system_state().await?)
     }
+
+    pub async fn get_latest_checkpoint_sequence_number(
+        &self,
+    ) -> SuiRpcResult<CheckpointSequenceNumber> {
+        Ok(self
+            .api
+            .http
+            .get_latest_checkpoint_sequence_number()
+            .await?)
+    }
+
+    pub async fn get_checkpoint_summary(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> SuiRpcResult<SuiCheckpointSummary> {
+        Ok(self
+            .api
+            .http
+            .get_checkpoint_summary(sequence_number)
+            .await?)
+    }
+
+    pub async fn get_checkpoint_contents(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+    ) -> SuiRpcResult<SuiCheckpointContents> {
+        Ok(self
+            .api
+            .http
+            .get_checkpoint_contents(sequence_number)
+            .await?)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -266,14 +416,17 @@ impl EventApi {
         Self { api }
     }
 
+    /// Subscribe to events matching `filter`. When `cursor` is set, matching events emitted
+    /// after that event are replayed from the node's event store before live events.
     pub async fn subscribe_event(
         &self,
         filter: SuiEventFilter,
+        cursor: Option<EventID>,
     ) -> SuiRpcResult<impl Stream<Item = SuiRpcResult<SuiEventEnvelope>>> {
         match &self.api.ws {
             Some(c) => {
                 let subscription: Subscription<SuiEventEnvelope> =
-                    c.subscribe_event(filter).await?;
+                    c.subscribe_event(filter, cursor).await?;
                 Ok(subscription.map(|item| Ok(item?)))
             }
             _ => Err(RpcError::Subscription(
@@ -399,6 +552,20 @@ impl QuorumDriver {
         })
     }
 
+    /// Return the digests of the transactions submitted to the FullNode which are not
+    /// finalized yet.
+    pub async fn get_pending_transactions(&self) -> SuiRpcResult<Vec<TransactionDigest>> {
+        Ok(TransactionExecutionApiClient::get_pending_transactions(&self.api.http).await?)
+    }
+
+    /// Return a transaction submitted to the FullNode which is not finalized yet.
+    pub async fn get_pending_transaction(
+        &self,
+        digest: TransactionDigest,
+    ) -> SuiRpcResult<SuiTransactionData> {
+        Ok(TransactionExecutionApiClient::get_pending_transaction(&self.api.http, digest).await?)
+    }
+
     async fn wait_until_fullnode_sees_tx(
         c: &RpcClient,
         tx_digest: TransactionDigest,
@@ -216,7 +216,19 @@ impl DataReader for ReadApi {
         &self,
         address: SuiAddress,
     ) -> Result<Vec<SuiObjectInfo>, anyhow::Error> {
-        Ok(self.get_objects_owned_by_address(address).await?)
+        let mut objects = vec![];
+        let mut cursor = None;
+        loop {
+            let page = self
+                .get_objects_owned_by_address(address, None, cursor, None)
+                .await?;
+            objects.extend(page.data);
+            if page.next_cursor.is_none() {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+        Ok(objects)
     }
 
     async fn get_object(
@@ -37,6 +37,7 @@ use sui_types::error::SuiError::{StorageCorruptedFieldError, StorageMissingField
 use sui_types::event::{BalanceChangeType, Event, EventID};
 use sui_types::event::{EventEnvelope, EventType};
 use sui_types::object::Owner;
+use sui_types::query::EventQuery;
 
 pub mod sql;
 pub mod test_utils;
@@ -516,6 +517,16 @@ pub trait EventStore {
         limit: usize,
         descending: bool,
     ) -> Result<Vec<StoredEvent>, SuiError>;
+
+    /// Returns at most `limit` events matching the query, which may combine several criteria
+    /// with `And` / `Or`, sorted in time order defined by the descending parameter.
+    async fn events_by_query(
+        &self,
+        query: &EventQuery,
+        cursor: EventID,
+        limit: usize,
+        descending: bool,
+    ) -> Result<Vec<StoredEvent>, SuiError>;
 }
 
 /// EventStoreType contains different implementations of EventStores, but implements the EventStore trait.
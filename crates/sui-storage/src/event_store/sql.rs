@@ -621,6 +621,43 @@ impl EventStore for SqlEventStore {
             .map_err(convert_sqlx_err)?;
         Ok(rows)
     }
+
+    #[instrument(level = "debug", skip_all, err)]
+    async fn events_by_query(
+        &self,
+        query: &EventQuery,
+        cursor: EventID,
+        limit: usize,
+        descending: bool,
+    ) -> Result<Vec<StoredEvent>, SuiError> {
+        let (seq_cmp, order) = if descending {
+            (Comparator::LessThanOrEq, "DESC")
+        } else {
+            (Comparator::MoreThanOrEq, "ASC")
+        };
+        let mut query_builder = QueryBuilder::new(format!(
+            "SELECT * FROM events WHERE (seq_num, event_num) {seq_cmp} ("
+        ));
+        query_builder
+            .push_bind(cursor.tx_seq)
+            .push(", ")
+            .push_bind(cursor.event_seq)
+            .push(") AND ");
+        push_event_query_clause(&mut query_builder, query)?;
+        query_builder
+            .push(format!(
+                " ORDER BY seq_num {order}, event_num {order} LIMIT "
+            ))
+            .push_bind(limit as i64);
+
+        let rows = query_builder
+            .build()
+            .map(StoredEvent::from)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(convert_sqlx_err)?;
+        Ok(rows)
+    }
 }
 
 fn convert_sqlx_err(err: sqlx::Error) -> SuiError {
@@ -633,8 +670,9 @@ fn get_event_query(causes: Vec<(&str, Comparator)>, descending: bool) -> String
     } else {
         (Comparator::MoreThanOrEq, "ASC")
     };
-    let mut query =
-        format!("SELECT * FROM events WHERE seq_num {seq_cmp} ? AND event_num {seq_cmp} ?");
+    // Compare (seq_num, event_num) as a row value so that the cursor is ordered the same way
+    // as the results, i.e. lexicographically.
+    let mut query = format!("SELECT * FROM events WHERE (seq_num, event_num) {seq_cmp} (?, ?)");
     if !causes.is_empty() {
         query.push_str(" AND ");
     }
@@ -650,6 +688,81 @@ fn get_event_query(causes: Vec<(&str, Comparator)>, descending: bool) -> String
     query
 }
 
+/// Appends the WHERE clause matching `query` to the builder. Every criterion maps onto an indexed
+/// column, so combined queries are evaluated by SQLite rather than filtered in memory.
+fn push_event_query_clause(
+    query_builder: &mut QueryBuilder<Sqlite>,
+    query: &EventQuery,
+) -> Result<(), SuiError> {
+    match query {
+        EventQuery::All => {
+            query_builder.push("1");
+        }
+        EventQuery::Transaction(digest) => {
+            query_builder
+                .push("tx_digest = ")
+                .push_bind(digest.to_bytes());
+        }
+        EventQuery::MoveModule { package, module } => {
+            query_builder
+                .push("(package_id = ")
+                .push_bind(package.to_vec())
+                .push(" AND module_name = ")
+                .push_bind(module.clone())
+                .push(")");
+        }
+        EventQuery::MoveEvent(move_event_struct_name) => {
+            query_builder
+                .push("move_event_name = ")
+                .push_bind(move_event_struct_name.clone());
+        }
+        EventQuery::EventType(event_type) => {
+            query_builder
+                .push("event_type = ")
+                .push_bind(*event_type as u16);
+        }
+        EventQuery::Sender(sender) => {
+            query_builder.push("sender = ").push_bind(sender.to_vec());
+        }
+        EventQuery::Recipient(recipient) => {
+            let recipient_str =
+                serde_json::to_string(recipient).map_err(|e| SuiError::OwnerFailedToSerialize {
+                    error: (e.to_string()),
+                })?;
+            query_builder.push("recipient = ").push_bind(recipient_str);
+        }
+        EventQuery::Object(object) => {
+            query_builder
+                .push("object_id = ")
+                .push_bind(object.to_vec());
+        }
+        EventQuery::TimeRange {
+            start_time,
+            end_time,
+        } => {
+            query_builder
+                .push("(timestamp >= ")
+                .push_bind(*start_time as i64)
+                .push(" AND timestamp < ")
+                .push_bind(*end_time as i64)
+                .push(")");
+        }
+        EventQuery::And(left, right) | EventQuery::Or(left, right) => {
+            let op = if matches!(query, EventQuery::And(..)) {
+                " AND "
+            } else {
+                " OR "
+            };
+            query_builder.push("(");
+            push_event_query_clause(query_builder, left)?;
+            query_builder.push(op);
+            push_event_query_clause(query_builder, right)?;
+            query_builder.push(")");
+        }
+    }
+    Ok(())
+}
+
 enum Comparator {
     Equal,
     LessThanOrEq,
@@ -791,6 +904,125 @@ mod tests {
             test_queried_event_vs_test_envelope(&queried_events[i], &to_insert[i]);
         }
 
+        // Cursor pointing in the middle of a transaction should include the rest of
+        // that transaction's events and everything after it.
+        let queried_events = db.all_events((3, 1).into(), 20, false).await?;
+        assert_eq!(queried_events.len(), 5);
+        for (queried, orig) in queried_events.iter().zip(&to_insert[3..]) {
+            test_queried_event_vs_test_envelope(queried, orig);
+        }
+
+        let queried_events = db.all_events((3, 0).into(), 20, true).await?;
+        assert_eq!(queried_events.len(), 3);
+        for (queried, orig) in queried_events.iter().zip(to_insert[..3].iter().rev()) {
+            test_queried_event_vs_test_envelope(queried, orig);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_eventstore_combined_query() -> Result<(), SuiError> {
+        telemetry_subscribers::init_for_testing();
+
+        // Initialize store
+        let db = SqlEventStore::new_memory_only_not_prod().await?;
+        db.initialize().await?;
+
+        let sender = SuiAddress::random_for_testing_only();
+        let to_insert = vec![
+            test_utils::new_test_newobj_event(
+                1_000_000,
+                TransactionDigest::random(),
+                1,
+                0, // event_num
+                None,
+                Some(sender),
+                None,
+            ),
+            test_utils::new_test_publish_event(
+                1_001_000,
+                TransactionDigest::random(),
+                2,
+                0, // event_num
+                Some(sender),
+            ),
+            test_utils::new_test_transfer_event(
+                1_002_000,
+                TransactionDigest::random(),
+                3,
+                0, // event_num
+                1,
+                "0x2::test::Object",
+                None,
+                None,
+                None,
+            ),
+            test_utils::new_test_transfer_event(
+                1_004_000,
+                TransactionDigest::random(),
+                4,
+                0, // event_num
+                1,
+                "0x2::test::Object",
+                None,
+                Some(sender),
+                None,
+            ),
+            test_utils::new_test_move_event(
+                1_005_000,
+                TransactionDigest::random(),
+                5,
+                0, // event_num
+                ObjectID::from_hex_literal("0x3").unwrap(),
+                "test_module",
+                "test_foo",
+            ),
+        ];
+        assert_eq!(db.add_events(&to_insert).await?, 5);
+
+        let time_range = EventQuery::TimeRange {
+            start_time: 1_001_000,
+            end_time: 1_005_000,
+        };
+
+        // Transfers within the time range
+        let query = EventQuery::And(
+            Box::new(EventQuery::EventType(EventType::TransferObject)),
+            Box::new(time_range.clone()),
+        );
+        let queried_events = db.events_by_query(&query, (0, 0).into(), 20, false).await?;
+        assert_eq!(queried_events.len(), 2);
+        test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[2]);
+        test_queried_event_vs_test_envelope(&queried_events[1], &to_insert[3]);
+
+        // Events from the sender within the time range, newest first
+        let query = EventQuery::And(Box::new(EventQuery::Sender(sender)), Box::new(time_range));
+        let queried_events = db
+            .events_by_query(&query, (i64::MAX, i64::MAX).into(), 20, true)
+            .await?;
+        assert_eq!(queried_events.len(), 2);
+        test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[3]);
+        test_queried_event_vs_test_envelope(&queried_events[1], &to_insert[1]);
+
+        // Publish events or events from the test module
+        let query = EventQuery::Or(
+            Box::new(EventQuery::EventType(EventType::Publish)),
+            Box::new(EventQuery::MoveModule {
+                package: ObjectID::from_hex_literal("0x3").unwrap(),
+                module: "test_module".to_string(),
+            }),
+        );
+        let queried_events = db.events_by_query(&query, (0, 0).into(), 20, false).await?;
+        assert_eq!(queried_events.len(), 2);
+        test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[1]);
+        test_queried_event_vs_test_envelope(&queried_events[1], &to_insert[4]);
+
+        // Cursor and limit still apply to combined queries
+        let queried_events = db.events_by_query(&query, (3, 0).into(), 1, false).await?;
+        assert_eq!(queried_events.len(), 1);
+        test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[4]);
+
         Ok(())
     }
 
@@ -1453,23 +1685,23 @@ mod tests {
     fn event_query_test() {
         let query = get_event_query(vec![], false);
         assert_eq!(
-            "SELECT * FROM events WHERE seq_num >= ? AND event_num >= ? ORDER BY seq_num ASC, event_num ASC LIMIT ?",
+            "SELECT * FROM events WHERE (seq_num, event_num) >= (?, ?) ORDER BY seq_num ASC, event_num ASC LIMIT ?",
             query
         );
         let query = get_event_query(vec![], true);
         assert_eq!(
-            "SELECT * FROM events WHERE seq_num <= ? AND event_num <= ? ORDER BY seq_num DESC, event_num DESC LIMIT ?",
+            "SELECT * FROM events WHERE (seq_num, event_num) <= (?, ?) ORDER BY seq_num DESC, event_num DESC LIMIT ?",
             query
         );
 
         let query = get_event_query(vec![("event_type", Comparator::Equal)], false);
-        assert_eq!("SELECT * FROM events WHERE seq_num >= ? AND event_num >= ? AND event_type = ? ORDER BY seq_num ASC, event_num ASC LIMIT ?", query);
+        assert_eq!("SELECT * FROM events WHERE (seq_num, event_num) >= (?, ?) AND event_type = ? ORDER BY seq_num ASC, event_num ASC LIMIT ?", query);
 
         let query = get_event_query(vec![("event_type", Comparator::Equal)], true);
-        assert_eq!("SELECT * FROM events WHERE seq_num <= ? AND event_num <= ? AND event_type = ? ORDER BY seq_num DESC, event_num DESC LIMIT ?", query);
+        assert_eq!("SELECT * FROM events WHERE (seq_num, event_num) <= (?, ?) AND event_type = ? ORDER BY seq_num DESC, event_num DESC LIMIT ?", query);
 
         let query = get_event_query(vec![("event_type", Comparator::Equal)], true);
-        assert_eq!("SELECT * FROM events WHERE seq_num <= ? AND event_num <= ? AND event_type = ? ORDER BY seq_num DESC, event_num DESC LIMIT ?", query);
+        assert_eq!("SELECT * FROM events WHERE (seq_num, event_num) <= (?, ?) AND event_type = ? ORDER BY seq_num DESC, event_num DESC LIMIT ?", query);
 
         let query = get_event_query(
             vec![
@@ -1478,7 +1710,7 @@ mod tests {
             ],
             false,
         );
-        assert_eq!("SELECT * FROM events WHERE seq_num >= ? AND event_num >= ? AND package_id = ? AND module_name = ? ORDER BY seq_num ASC, event_num ASC LIMIT ?", query);
+        assert_eq!("SELECT * FROM events WHERE (seq_num, event_num) >= (?, ?) AND package_id = ? AND module_name = ? ORDER BY seq_num ASC, event_num ASC LIMIT ?", query);
 
         let query = get_event_query(
             vec![
@@ -1487,6 +1719,6 @@ mod tests {
             ],
             true,
         );
-        assert_eq!("SELECT * FROM events WHERE seq_num <= ? AND event_num <= ? AND package_id = ? AND module_name = ? ORDER BY seq_num DESC, event_num DESC LIMIT ?", query);
+        assert_eq!("SELECT * FROM events WHERE (seq_num, event_num) <= (?, ?) AND package_id = ? AND module_name = ? ORDER BY seq_num DESC, event_num DESC LIMIT ?", query);
     }
 }
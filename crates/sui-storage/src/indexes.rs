@@ -7,7 +7,7 @@
 use anyhow::anyhow;
 use move_core_types::identifier::Identifier;
 use serde::{de::DeserializeOwned, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 use tracing::debug;
 use typed_store::rocks::DBMap;
@@ -22,7 +22,7 @@ use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::error::{SuiError, SuiResult};
 use sui_types::fp_ensure;
 use sui_types::object::Owner;
-use sui_types::query::TransactionQuery;
+use sui_types::query::{ObjectTypeFilter, TransactionQuery};
 
 use crate::default_db_options;
 
@@ -146,6 +146,16 @@ impl IndexStore {
         }
     }
 
+    /// Take a RocksDB checkpoint of the index tables into `path`.
+    pub fn checkpoint_db(&self, path: &Path) -> SuiResult {
+        // All index tables live in the same db, checkpointing through any of them is enough.
+        self.tables
+            .transaction_order
+            .rocksdb
+            .checkpoint(path)
+            .map_err(SuiError::from)
+    }
+
     pub fn index_tx(
         &self,
         sender: SuiAddress,
@@ -347,6 +357,13 @@ impl IndexStore {
             .collect())
     }
 
+    /// Iterate over all indexed transactions, in sequence order.
+    pub fn transactions_iterator(
+        &self,
+    ) -> impl Iterator<Item = (TxSequenceNumber, TransactionDigest)> + '_ {
+        self.tables.transaction_order.iter()
+    }
+
     pub fn get_recent_transactions(
         &self,
         count: u64,
@@ -375,6 +392,14 @@ impl IndexStore {
         Ok(ts)
     }
 
+    /// Returns unix timestamps for many transactions, in the same order as the digests given
+    pub fn multi_get_timestamp_ms(
+        &self,
+        transaction_digests: &[TransactionDigest],
+    ) -> SuiResult<Vec<Option<u64>>> {
+        Ok(self.tables.timestamps.multi_get(transaction_digests)?)
+    }
+
     fn get_transactions_from_index<KeyT: Clone + Serialize + DeserializeOwned + PartialEq>(
         index: &DBMap<(KeyT, TxSequenceNumber), TransactionDigest>,
         key: KeyT,
@@ -534,17 +559,27 @@ impl IndexStore {
         limit: usize,
     ) -> SuiResult<Vec<DynamicFieldInfo>> {
         debug!(?object, "get_dynamic_fields");
+        Ok(self
+            .get_dynamic_fields_iterator(object, cursor)?
+            .take(limit)
+            .collect())
+    }
+
+    pub fn get_dynamic_fields_iterator(
+        &self,
+        object: ObjectID,
+        cursor: Option<ObjectID>,
+    ) -> SuiResult<impl Iterator<Item = DynamicFieldInfo> + '_> {
+        debug!(?object, "get_dynamic_fields_iterator");
+        // The object id 0 is the smallest possible
         let cursor = cursor.unwrap_or(ObjectID::ZERO);
         Ok(self
             .tables
             .dynamic_field_index
             .iter()
-            // The object id 0 is the smallest possible
             .skip_to(&(object, cursor))?
-            .take_while(|((object_owner, _), _)| (object_owner == &object))
-            .map(|(_, object_info)| object_info)
-            .take(limit)
-            .collect())
+            .take_while(move |((object_owner, _), _)| (object_owner == &object))
+            .map(|(_, object_info)| object_info))
     }
 
     pub fn get_dynamic_field_object_id(
@@ -563,23 +598,42 @@ impl IndexStore {
             .map(|(_, object_info)| object_info.object_id))
     }
 
-    pub fn get_owner_objects(&self, owner: SuiAddress) -> SuiResult<Vec<ObjectInfo>> {
-        debug!(?owner, "get_owner_objects");
-        Ok(self.get_owner_objects_iterator(owner)?.collect())
+    pub fn get_owner_objects(
+        &self,
+        owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        limit: usize,
+        filter: Option<ObjectTypeFilter>,
+    ) -> SuiResult<Vec<ObjectInfo>> {
+        debug!(?owner, ?cursor, "get_owner_objects");
+        Ok(self
+            .get_owner_objects_iterator(owner, cursor, filter)?
+            .take(limit)
+            .collect())
     }
 
+    /// Iterate over the objects owned by `owner`, in object ID order, starting from `cursor`
+    /// (inclusive) and skipping objects whose type does not match `filter`.
     pub fn get_owner_objects_iterator(
         &self,
         owner: SuiAddress,
+        cursor: Option<ObjectID>,
+        filter: Option<ObjectTypeFilter>,
     ) -> SuiResult<impl Iterator<Item = ObjectInfo> + '_> {
-        debug!(?owner, "get_owner_objects");
+        debug!(?owner, ?cursor, "get_owner_objects");
+        // The object id 0 is the smallest possible
+        let cursor = cursor.unwrap_or(ObjectID::ZERO);
         Ok(self
             .tables
             .owner_index
             .iter()
-            // The object id 0 is the smallest possible
-            .skip_to(&(owner, ObjectID::ZERO))?
+            .skip_to(&(owner, cursor))?
             .take_while(move |((object_owner, _), _)| (object_owner == &owner))
+            .filter(move |(_, object_info)| {
+                filter
+                    .as_ref()
+                    .map_or(true, |filter| filter.matches(&object_info.type_))
+            })
             .map(|(_, object_info)| object_info))
     }
 
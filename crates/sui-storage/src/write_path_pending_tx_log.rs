@@ -78,6 +78,17 @@ impl WritePathPendingTransactionLog {
         write_batch.write().map_err(SuiError::from)
     }
 
+    pub fn get_pending_transaction(
+        &self,
+        tx: &TransactionDigest,
+    ) -> SuiResult<Option<VerifiedTransaction>> {
+        Ok(self
+            .pending_transactions
+            .logs
+            .get(tx)?
+            .map(VerifiedTransaction::from))
+    }
+
     pub fn load_all_pending_transactions(&self) -> Vec<VerifiedTransaction> {
         self.pending_transactions
             .logs
@@ -111,11 +122,19 @@ mod tests {
             .unwrap());
 
         let loaded_txes = pending_txes.load_all_pending_transactions();
-        assert_eq!(vec![tx], loaded_txes);
+        assert_eq!(vec![tx.clone()], loaded_txes);
+        assert_eq!(
+            Some(tx),
+            pending_txes.get_pending_transaction(&tx_digest).unwrap()
+        );
 
         pending_txes.finish_transaction(&tx_digest).unwrap();
         let loaded_txes = pending_txes.load_all_pending_transactions();
         assert!(loaded_txes.is_empty());
+        assert!(pending_txes
+            .get_pending_transaction(&tx_digest)
+            .unwrap()
+            .is_none());
 
         // It's ok to finish an already finished transaction
         pending_txes.finish_transaction(&tx_digest).unwrap();
@@ -14,6 +14,7 @@ use sui_types::message_envelope::Message;
 use tokio::time::Instant;
 
 use sui_core::authority_client::{AuthorityAPI, NetworkAuthorityClient};
+use sui_core::db_backup::restore_node_dbs;
 use sui_types::{base_types::*, messages::*, object::Owner};
 
 use anyhow::anyhow;
@@ -102,6 +103,18 @@ pub enum ToolCommand {
         cmd: Option<DbToolCommand>,
     },
 
+    /// Restore the databases of a node from a backup taken through the admin interface of
+    /// sui-node, and verify the restored databases. The node must be stopped.
+    #[clap(name = "restore-db")]
+    RestoreDb {
+        /// Path of the backup to restore
+        #[clap(long = "backup-path")]
+        backup_path: PathBuf,
+        /// The db path of the node to restore into
+        #[clap(long = "db-path")]
+        db_path: PathBuf,
+    },
+
     #[clap(name = "dump-validators")]
     DumpValidators {
         #[clap(long = "genesis")]
@@ -601,6 +614,18 @@ impl ToolCommand {
                     None => print_db_all_tables(path)?,
                 }
             }
+            ToolCommand::RestoreDb {
+                backup_path,
+                db_path,
+            } => {
+                let info = restore_node_dbs(&backup_path, &db_path)?;
+                println!(
+                    "Restored backup of epoch {} into {}, highest executed checkpoint: {}",
+                    info.epoch,
+                    db_path.display(),
+                    info.highest_executed_checkpoint.opt_display("none")
+                );
+            }
             ToolCommand::DumpValidators { genesis, concise } => {
                 let genesis = Genesis::load(genesis).unwrap();
                 if !concise {
@@ -0,0 +1,805 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::encoding::{Encoding, Hex};
+use fastcrypto::hash::MultisetHash;
+use serde::Serialize;
+use std::path::Path;
+use sui_core::authority::authority_store_tables::{
+    AuthorityPerpetualTables, AuthorityPerpetualTablesReadOnly,
+};
+use sui_core::checkpoints::{CheckpointStore, CheckpointWatermark};
+use sui_core::db_backup::{AUTHORITY_DB_DIR, CHECKPOINTS_DB_DIR, INDEXES_DB_DIR};
+use sui_storage::indexes::IndexStoreTablesReadOnly;
+use sui_storage::IndexStoreTables;
+use sui_types::accumulator::Accumulator;
+use sui_types::base_types::{
+    ObjectDigest, ObjectID, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
+    TransactionEffectsDigest,
+};
+use sui_types::messages::TransactionEffects;
+use sui_types::messages_checkpoint::{CheckpointContentsDigest, CheckpointSequenceNumber};
+use sui_types::object::Owner;
+use sui_types::storage::ObjectKey;
+use typed_store::traits::Map;
+
+/// Result of `db-tool verify`, printed as JSON.
+#[derive(Serialize, Default)]
+pub struct VerifyReport {
+    pub live_objects: usize,
+    /// Accumulator over the digests of all live objects.
+    pub live_object_accumulator: String,
+    /// Accumulator obtained by applying the effects of every executed checkpoint to the genesis
+    /// objects. Not computed when some executed transactions are not part of an executed
+    /// checkpoint yet, or when the versions needed to replay the effects were pruned.
+    pub checkpoint_accumulator: Option<String>,
+    pub executed_transactions: usize,
+    pub highest_executed_checkpoint: Option<CheckpointSequenceNumber>,
+    pub verified_checkpoints: usize,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Discrepancy {
+    /// The latest entry of `parent_sync` for an object does not match its latest stored version.
+    ParentSyncMismatch {
+        object_id: ObjectID,
+        parent_sync: Option<ObjectRef>,
+        stored: ObjectRef,
+    },
+    /// An owner index entry does not point at the live version of the object.
+    IndexedObjectMismatch {
+        table: &'static str,
+        object_id: ObjectID,
+        indexed: ObjectRef,
+        live: Option<ObjectRef>,
+    },
+    IndexedOwnerMismatch {
+        table: &'static str,
+        object_id: ObjectID,
+        indexed: Owner,
+        live: Owner,
+    },
+    MissingOwnerIndexEntry {
+        object_id: ObjectID,
+        owner: Owner,
+    },
+    /// A transaction index points at a transaction that has no executed effects.
+    IndexedTransactionNotExecuted {
+        table: &'static str,
+        transaction: TransactionDigest,
+    },
+    /// An object written by executed effects is not stored with the same digest.
+    EffectsObjectMismatch {
+        transaction: TransactionDigest,
+        object_ref: ObjectRef,
+        stored_digest: Option<ObjectDigest>,
+    },
+    /// An object deleted or wrapped by executed effects has no matching `parent_sync` entry.
+    EffectsDeletionNotRecorded {
+        transaction: TransactionDigest,
+        object_ref: ObjectRef,
+    },
+    CheckpointMissing {
+        sequence_number: CheckpointSequenceNumber,
+    },
+    CheckpointChainBroken {
+        sequence_number: CheckpointSequenceNumber,
+    },
+    CheckpointContentsMissing {
+        sequence_number: CheckpointSequenceNumber,
+        content_digest: CheckpointContentsDigest,
+    },
+    CheckpointTransactionNotExecuted {
+        sequence_number: CheckpointSequenceNumber,
+        transaction: TransactionDigest,
+    },
+    CheckpointEffectsMismatch {
+        sequence_number: CheckpointSequenceNumber,
+        transaction: TransactionDigest,
+        expected: TransactionEffectsDigest,
+        executed: TransactionEffectsDigest,
+    },
+    /// The live objects do not match the state reached by executing all checkpoints.
+    StateAccumulatorMismatch {
+        highest_executed_checkpoint: Option<CheckpointSequenceNumber>,
+        live: String,
+        checkpoints: String,
+    },
+}
+
+/// Check the databases of a stopped node, `db_path` being the db path of the node.
+pub fn verify_db(db_path: &Path) -> anyhow::Result<VerifyReport> {
+    let mut report = VerifyReport::default();
+    let perpetual_tables = AuthorityPerpetualTables::open_readonly(&db_path.join(AUTHORITY_DB_DIR));
+
+    verify_live_objects(&perpetual_tables, &mut report)?;
+    verify_owner_index(&perpetual_tables, &mut report)?;
+    let index_path = db_path.join(INDEXES_DB_DIR);
+    if index_path.exists() {
+        let index_tables = IndexStoreTables::get_read_only_handle(index_path, None, None);
+        verify_index_store(&index_tables, &perpetual_tables, &mut report)?;
+    }
+    verify_effects(&perpetual_tables, &mut report)?;
+
+    let checkpoints_path = db_path.join(CHECKPOINTS_DB_DIR);
+    if checkpoints_path.exists() {
+        verify_checkpoints(&checkpoints_path, &perpetual_tables, &mut report)?;
+    }
+    Ok(report)
+}
+
+/// Walk the objects and parent_sync tables side by side, both are ordered by object id first,
+/// and check the live version of every object.
+fn verify_live_objects(
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    report: &mut VerifyReport,
+) -> anyhow::Result<()> {
+    let mut accumulator = Accumulator::default();
+    let mut objects = perpetual_tables.objects.iter().peekable();
+    let mut parent_entries = perpetual_tables.parent_sync.keys().peekable();
+    while let Some((ObjectKey(id, _), mut object)) = objects.next() {
+        while let Some((_, newer)) = objects.next_if(|(ObjectKey(next, _), _)| *next == id) {
+            object = newer;
+        }
+        // Deletions are only recorded in parent_sync, which holds an entry for every version
+        // written or deleted. The last entry of an object decides whether it is live.
+        while parent_entries
+            .next_if(|object_ref| object_ref.0 < id)
+            .is_some()
+        {}
+        let mut latest_parent_entry = None;
+        while let Some(object_ref) = parent_entries.next_if(|object_ref| object_ref.0 == id) {
+            latest_parent_entry = Some(object_ref);
+        }
+
+        let stored = object.compute_object_reference();
+        match latest_parent_entry {
+            Some(parent_ref) if !parent_ref.2.is_alive() && parent_ref.1 >= stored.1 => continue,
+            Some(parent_ref) if parent_ref == stored => {}
+            parent_sync => report.discrepancies.push(Discrepancy::ParentSyncMismatch {
+                object_id: id,
+                parent_sync,
+                stored,
+            }),
+        }
+
+        report.live_objects += 1;
+        accumulator.insert(stored.2);
+        // Shared and immutable objects are not indexed, only objects with a single owner are.
+        if object.owner.get_owner_address().is_ok()
+            && !perpetual_tables
+                .owner_index
+                .contains_key(&(object.owner, id))?
+        {
+            report
+                .discrepancies
+                .push(Discrepancy::MissingOwnerIndexEntry {
+                    object_id: id,
+                    owner: object.owner,
+                });
+        }
+    }
+    report.live_object_accumulator = Hex::encode(accumulator.digest().digest);
+    Ok(())
+}
+
+/// Latest version and owner of an object, unless it was deleted or wrapped.
+fn live_object(
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    object_id: ObjectID,
+) -> anyhow::Result<Option<(ObjectRef, Owner)>> {
+    let Some((ObjectKey(id, _), object)) = perpetual_tables
+        .objects
+        .iter()
+        .skip_prior_to(&ObjectKey::max_for_id(&object_id))?
+        .next() else {
+        return Ok(None);
+    };
+    if id != object_id {
+        return Ok(None);
+    }
+    let latest_parent_entry = perpetual_tables
+        .parent_sync
+        .keys()
+        .skip_prior_to(&(object_id, SequenceNumber::MAX, ObjectDigest::MAX))?
+        .next()
+        .filter(|object_ref| object_ref.0 == object_id);
+    Ok(match latest_parent_entry {
+        Some(parent_ref) if !parent_ref.2.is_alive() && parent_ref.1 >= object.version() => None,
+        _ => Some((object.compute_object_reference(), object.owner)),
+    })
+}
+
+/// Whether `version` of an object is older than its oldest stored version, i.e. was pruned.
+fn is_pruned(
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    object_id: ObjectID,
+    version: SequenceNumber,
+) -> anyhow::Result<bool> {
+    Ok(perpetual_tables
+        .objects
+        .keys()
+        .skip_to(&ObjectKey(object_id, SequenceNumber::MIN))?
+        .next()
+        .map_or(false, |ObjectKey(id, oldest)| {
+            id == object_id && version < oldest
+        }))
+}
+
+fn verify_owner_index(
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    report: &mut VerifyReport,
+) -> anyhow::Result<()> {
+    for ((owner, object_id), info) in perpetual_tables.owner_index.iter() {
+        check_indexed_object(
+            "owner_index",
+            object_id,
+            (info.object_id, info.version, info.digest),
+            owner,
+            perpetual_tables,
+            report,
+        )?;
+    }
+    Ok(())
+}
+
+fn verify_index_store(
+    index_tables: &IndexStoreTablesReadOnly,
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    report: &mut VerifyReport,
+) -> anyhow::Result<()> {
+    for ((address, object_id), info) in index_tables.owner_index.iter() {
+        check_indexed_object(
+            "indexes.owner_index",
+            object_id,
+            (info.object_id, info.version, info.digest),
+            Owner::AddressOwner(address),
+            perpetual_tables,
+            report,
+        )?;
+    }
+    for ((parent, object_id), info) in index_tables.dynamic_field_index.iter() {
+        check_indexed_object(
+            "indexes.dynamic_field_index",
+            object_id,
+            (info.object_id, info.version, info.digest),
+            Owner::ObjectOwner(SuiAddress::from(parent)),
+            perpetual_tables,
+            report,
+        )?;
+    }
+
+    let transaction_indexes: [(
+        &'static str,
+        Box<dyn Iterator<Item = TransactionDigest> + '_>,
+    ); 6] = [
+        (
+            "indexes.transactions_from_addr",
+            Box::new(index_tables.transactions_from_addr.values()),
+        ),
+        (
+            "indexes.transactions_to_addr",
+            Box::new(index_tables.transactions_to_addr.values()),
+        ),
+        (
+            "indexes.transactions_by_input_object_id",
+            Box::new(index_tables.transactions_by_input_object_id.values()),
+        ),
+        (
+            "indexes.transactions_by_mutated_object_id",
+            Box::new(index_tables.transactions_by_mutated_object_id.values()),
+        ),
+        (
+            "indexes.transactions_by_move_function",
+            Box::new(index_tables.transactions_by_move_function.values()),
+        ),
+        (
+            "indexes.transaction_order",
+            Box::new(index_tables.transaction_order.values()),
+        ),
+    ];
+    for (table, digests) in transaction_indexes {
+        for transaction in digests {
+            if !perpetual_tables
+                .executed_effects
+                .contains_key(&transaction)?
+            {
+                report
+                    .discrepancies
+                    .push(Discrepancy::IndexedTransactionNotExecuted { table, transaction });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_indexed_object(
+    table: &'static str,
+    object_id: ObjectID,
+    indexed: ObjectRef,
+    indexed_owner: Owner,
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    report: &mut VerifyReport,
+) -> anyhow::Result<()> {
+    match live_object(perpetual_tables, object_id)? {
+        Some((live, _)) if live != indexed => {
+            report
+                .discrepancies
+                .push(Discrepancy::IndexedObjectMismatch {
+                    table,
+                    object_id,
+                    indexed,
+                    live: Some(live),
+                })
+        }
+        Some((_, live_owner)) if live_owner != indexed_owner => {
+            report
+                .discrepancies
+                .push(Discrepancy::IndexedOwnerMismatch {
+                    table,
+                    object_id,
+                    indexed: indexed_owner,
+                    live: live_owner,
+                })
+        }
+        Some(_) => {}
+        None => report
+            .discrepancies
+            .push(Discrepancy::IndexedObjectMismatch {
+                table,
+                object_id,
+                indexed,
+                live: None,
+            }),
+    }
+    Ok(())
+}
+
+fn verify_effects(
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    report: &mut VerifyReport,
+) -> anyhow::Result<()> {
+    for (transaction, effects) in perpetual_tables.executed_effects.iter() {
+        report.executed_transactions += 1;
+        let effects = effects.data();
+        for (object_ref, _, _) in effects.all_mutated() {
+            let (id, version, digest) = *object_ref;
+            let stored_digest = perpetual_tables
+                .objects
+                .get(&ObjectKey(id, version))?
+                .map(|object| object.digest());
+            if stored_digest != Some(digest) && !is_pruned(perpetual_tables, id, version)? {
+                report
+                    .discrepancies
+                    .push(Discrepancy::EffectsObjectMismatch {
+                        transaction,
+                        object_ref: *object_ref,
+                        stored_digest,
+                    });
+            }
+        }
+
+        let deleted = effects
+            .deleted
+            .iter()
+            .map(|(id, version, _)| (*id, *version, ObjectDigest::OBJECT_DIGEST_DELETED));
+        let wrapped = effects
+            .wrapped
+            .iter()
+            .map(|(id, version, _)| (*id, *version, ObjectDigest::OBJECT_DIGEST_WRAPPED));
+        for object_ref in deleted.chain(wrapped) {
+            if !perpetual_tables.parent_sync.contains_key(&object_ref)? {
+                report
+                    .discrepancies
+                    .push(Discrepancy::EffectsDeletionNotRecorded {
+                        transaction,
+                        object_ref,
+                    });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn verify_checkpoints(
+    checkpoints_path: &Path,
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    report: &mut VerifyReport,
+) -> anyhow::Result<()> {
+    let checkpoint_store =
+        CheckpointStore::get_read_only_handle(checkpoints_path.to_path_buf(), None, None);
+    let highest_executed = checkpoint_store
+        .watermarks
+        .get(&CheckpointWatermark::HighestExecuted)?
+        .map(|(sequence_number, _)| sequence_number);
+    report.highest_executed_checkpoint = highest_executed;
+
+    // Replay the effects of the executed checkpoints on top of the genesis objects.
+    let mut accumulator = Some(genesis_accumulator(perpetual_tables));
+    let mut checkpointed_transactions = 0;
+    let mut previous_digest = None;
+    for sequence_number in highest_executed.map_or(0..0, |highest| 0..highest + 1) {
+        let Some(checkpoint) = checkpoint_store.certified_checkpoints.get(&sequence_number)? else {
+            report
+                .discrepancies
+                .push(Discrepancy::CheckpointMissing { sequence_number });
+            previous_digest = None;
+            accumulator = None;
+            continue;
+        };
+        if previous_digest.is_some() && checkpoint.summary.previous_digest != previous_digest {
+            report
+                .discrepancies
+                .push(Discrepancy::CheckpointChainBroken { sequence_number });
+        }
+        previous_digest = Some(checkpoint.digest());
+
+        let content_digest = checkpoint.content_digest();
+        let Some(contents) = checkpoint_store.checkpoint_content.get(&content_digest)? else {
+            report.discrepancies.push(Discrepancy::CheckpointContentsMissing {
+                sequence_number,
+                content_digest,
+            });
+            accumulator = None;
+            continue;
+        };
+        for digests in contents.iter() {
+            let Some(effects) = perpetual_tables
+                .executed_effects
+                .get(&digests.transaction)? else {
+                report
+                    .discrepancies
+                    .push(Discrepancy::CheckpointTransactionNotExecuted {
+                        sequence_number,
+                        transaction: digests.transaction,
+                    });
+                accumulator = None;
+                continue;
+            };
+            if *effects.digest() != digests.effects {
+                report
+                    .discrepancies
+                    .push(Discrepancy::CheckpointEffectsMismatch {
+                        sequence_number,
+                        transaction: digests.transaction,
+                        expected: digests.effects,
+                        executed: *effects.digest(),
+                    });
+            }
+            checkpointed_transactions += 1;
+            let replayed = match &mut accumulator {
+                Some(accumulator) => apply_effects(perpetual_tables, accumulator, effects.data())?,
+                None => true,
+            };
+            if !replayed {
+                accumulator = None;
+            }
+        }
+        report.verified_checkpoints += 1;
+    }
+
+    let Some(accumulator) = accumulator else {
+        return Ok(());
+    };
+    // Transactions executed beyond the last executed checkpoint are part of the live objects.
+    if checkpointed_transactions != report.executed_transactions {
+        return Ok(());
+    }
+    let checkpoints = Hex::encode(accumulator.digest().digest);
+    if checkpoints != report.live_object_accumulator {
+        report
+            .discrepancies
+            .push(Discrepancy::StateAccumulatorMismatch {
+                highest_executed_checkpoint: highest_executed,
+                live: report.live_object_accumulator.clone(),
+                checkpoints: checkpoints.clone(),
+            });
+    }
+    report.checkpoint_accumulator = Some(checkpoints);
+    Ok(())
+}
+
+/// Accumulator over the objects written at genesis, which are not part of any checkpoint.
+fn genesis_accumulator(perpetual_tables: &AuthorityPerpetualTablesReadOnly) -> Accumulator {
+    let mut accumulator = Accumulator::default();
+    for (_, object) in perpetual_tables.objects.iter() {
+        if object.previous_transaction == TransactionDigest::genesis() {
+            accumulator.insert(object.digest());
+        }
+    }
+    accumulator
+}
+
+/// Remove the versions modified by `effects` from the accumulator and insert the written ones.
+/// Returns false if a modified version was pruned and the state can no longer be replayed.
+fn apply_effects(
+    perpetual_tables: &AuthorityPerpetualTablesReadOnly,
+    accumulator: &mut Accumulator,
+    effects: &TransactionEffects,
+) -> anyhow::Result<bool> {
+    for (id, version) in &effects.modified_at_versions {
+        let Some(object) = perpetual_tables.objects.get(&ObjectKey(*id, *version))? else {
+            return Ok(false);
+        };
+        accumulator.remove(object.digest());
+    }
+    for (object_ref, _, _) in effects.all_mutated() {
+        accumulator.insert(object_ref.2);
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+    use sui_core::authority::authority_store_tables::AuthorityPerpetualTables;
+    use sui_core::authority::AuthorityState;
+    use sui_core::checkpoints::CheckpointStore;
+    use sui_core::db_backup::{AUTHORITY_DB_DIR, CHECKPOINTS_DB_DIR};
+    use sui_core::test_utils::make_transfer_object_transaction;
+    use sui_types::base_types::{
+        ObjectID, ObjectInfo, ObjectRef, SequenceNumber, SuiAddress, TransactionDigest,
+    };
+    use sui_types::committee::Committee;
+    use sui_types::crypto::{get_key_pair, AccountKeyPair, AuthorityKeyPair, KeypairTraits};
+    use sui_types::gas::GasCostSummary;
+    use sui_types::messages::{CertifiedTransaction, TransactionEffects};
+    use sui_types::messages_checkpoint::{
+        CertifiedCheckpointSummary, CheckpointContents, SignedCheckpointSummary, VerifiedCheckpoint,
+    };
+    use sui_types::object::{Object, Owner};
+    use sui_types::storage::ObjectKey;
+    use typed_store::rocks::{list_tables, open_cf, DBMap, RocksDB};
+    use typed_store::traits::Map;
+
+    use crate::db_tool::db_verify::{verify_db, Discrepancy};
+
+    #[tokio::test]
+    async fn verify_genesis_state() -> Result<(), anyhow::Error> {
+        let db_path = tempfile::tempdir()?.into_path();
+        let (_, key): (_, AuthorityKeyPair) = get_key_pair();
+        let committee = Committee::new(0, BTreeMap::from([(key.public().into(), 1)]))?;
+        let _state =
+            AuthorityState::new_for_testing(committee, &key, Some(db_path.clone()), None).await;
+        // No checkpoint was executed yet, so the checkpoints describe the genesis state.
+        let _checkpoint_store = CheckpointStore::new(&db_path.join(CHECKPOINTS_DB_DIR));
+
+        let report = verify_db(&db_path)?;
+        assert!(
+            report.discrepancies.is_empty(),
+            "{:?}",
+            report.discrepancies
+        );
+        assert!(report.live_objects > 0);
+        assert_eq!(
+            report.checkpoint_accumulator,
+            Some(report.live_object_accumulator)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn verify_executed_checkpoint() -> Result<(), anyhow::Error> {
+        let (db_path, effects) = execute_checkpoint().await?;
+
+        let report = verify_db(&db_path)?;
+        assert!(
+            report.discrepancies.is_empty(),
+            "{:?}",
+            report.discrepancies
+        );
+        assert_eq!(report.highest_executed_checkpoint, Some(0));
+        assert_eq!(report.verified_checkpoints, 1);
+        assert_eq!(report.executed_transactions, effects.len());
+        assert_eq!(
+            report.checkpoint_accumulator,
+            Some(report.live_object_accumulator)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn detect_missing_object() -> Result<(), anyhow::Error> {
+        let (db_path, effects) = execute_checkpoint().await?;
+        let (transferred, _, _) = transferred_object(&effects[0]);
+        {
+            let db = open_authority_store(&db_path)?;
+            let objects: DBMap<ObjectKey, Object> = DBMap::reopen(&db, Some("objects"))?;
+            objects.remove(&ObjectKey(transferred.0, transferred.1))?;
+        }
+
+        let report = verify_db(&db_path)?;
+        assert!(report.discrepancies.iter().any(|d| matches!(
+            d,
+            Discrepancy::EffectsObjectMismatch {
+                transaction,
+                object_ref,
+                stored_digest: None,
+            } if *transaction == effects[0].transaction_digest && *object_ref == transferred
+        )));
+        // The previous version is live again, but parent_sync still records the transfer.
+        assert!(report.discrepancies.iter().any(|d| matches!(
+            d,
+            Discrepancy::ParentSyncMismatch {
+                object_id,
+                parent_sync: Some(parent_sync),
+                ..
+            } if *object_id == transferred.0 && *parent_sync == transferred
+        )));
+        assert!(report
+            .discrepancies
+            .iter()
+            .any(|d| matches!(d, Discrepancy::StateAccumulatorMismatch { .. })));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn detect_index_entry_at_wrong_version() -> Result<(), anyhow::Error> {
+        let (db_path, effects) = execute_checkpoint().await?;
+        let (transferred, owner, previous_version) = transferred_object(&effects[0]);
+        {
+            let db = open_authority_store(&db_path)?;
+            let owner_index: DBMap<(Owner, ObjectID), ObjectInfo> =
+                DBMap::reopen(&db, Some("owner_index"))?;
+            let mut info = owner_index
+                .get(&(owner, transferred.0))?
+                .expect("transferred object is indexed");
+            info.version = previous_version;
+            owner_index.insert(&(owner, transferred.0), &info)?;
+        }
+
+        let report = verify_db(&db_path)?;
+        assert_eq!(report.discrepancies.len(), 1, "{:?}", report.discrepancies);
+        assert!(matches!(
+            &report.discrepancies[0],
+            Discrepancy::IndexedObjectMismatch {
+                table: "owner_index",
+                object_id,
+                indexed,
+                live: Some(live),
+            } if *object_id == transferred.0 && indexed.1 == previous_version && *live == transferred
+        ));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn detect_extra_live_object() -> Result<(), anyhow::Error> {
+        let (db_path, _) = execute_checkpoint().await?;
+        let live_objects = verify_db(&db_path)?.live_objects;
+
+        // An object no transaction or genesis wrote, stored and indexed like a live one.
+        let mut object = Object::with_id_owner_for_testing(
+            ObjectID::random(),
+            SuiAddress::random_for_testing_only(),
+        );
+        object.previous_transaction = TransactionDigest::random();
+        let object_ref = object.compute_object_reference();
+        {
+            let db = open_authority_store(&db_path)?;
+            let objects: DBMap<ObjectKey, Object> = DBMap::reopen(&db, Some("objects"))?;
+            objects.insert(&ObjectKey(object_ref.0, object_ref.1), &object)?;
+            let parent_sync: DBMap<ObjectRef, TransactionDigest> =
+                DBMap::reopen(&db, Some("parent_sync"))?;
+            parent_sync.insert(&object_ref, &object.previous_transaction)?;
+            let owner_index: DBMap<(Owner, ObjectID), ObjectInfo> =
+                DBMap::reopen(&db, Some("owner_index"))?;
+            owner_index.insert(
+                &(object.owner, object_ref.0),
+                &ObjectInfo::new(&object_ref, &object),
+            )?;
+        }
+
+        let report = verify_db(&db_path)?;
+        assert_eq!(report.live_objects, live_objects + 1);
+        assert_eq!(report.discrepancies.len(), 1, "{:?}", report.discrepancies);
+        assert!(matches!(
+            &report.discrepancies[0],
+            Discrepancy::StateAccumulatorMismatch {
+                highest_executed_checkpoint: Some(0),
+                ..
+            }
+        ));
+        Ok(())
+    }
+
+    /// Execute two transfers, include them in checkpoint 0 and mark it executed. Returns the db
+    /// path of a copy of the databases, which tests can corrupt, and the effects of the
+    /// transfers.
+    async fn execute_checkpoint() -> Result<(PathBuf, Vec<TransactionEffects>), anyhow::Error> {
+        let dir = tempfile::tempdir()?.into_path();
+        let (_, key): (_, AuthorityKeyPair) = get_key_pair();
+        let committee = Committee::new(0, BTreeMap::from([(key.public().into(), 1)]))?;
+        let state =
+            AuthorityState::new_for_testing(committee.clone(), &key, Some(dir.join("node")), None)
+                .await;
+
+        let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+        let recipient = SuiAddress::random_for_testing_only();
+        let mut effects = vec![];
+        for _ in 0..2 {
+            let object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+            let gas_object = Object::with_id_owner_for_testing(ObjectID::random(), sender);
+            state.insert_genesis_object(object.clone()).await;
+            state.insert_genesis_object(gas_object.clone()).await;
+            let transaction = make_transfer_object_transaction(
+                object.compute_object_reference(),
+                gas_object.compute_object_reference(),
+                sender,
+                &sender_key,
+                recipient,
+            );
+            let vote = state
+                .handle_transaction(transaction.clone())
+                .await?
+                .signed_transaction
+                .expect("transaction is signed")
+                .into_inner();
+            let certificate = CertifiedTransaction::new(
+                transaction.into_message(),
+                vec![vote.auth_sig().clone()],
+                &committee,
+            )?
+            .verify(&committee)?;
+            let transaction_effects = state
+                .try_execute_for_test(&certificate)
+                .await?
+                .signed_effects
+                .expect("certificate is executed")
+                .into_data();
+            effects.push(transaction_effects);
+        }
+
+        let db_path = dir.join("db");
+        state.db().checkpoint_db(&db_path.join(AUTHORITY_DB_DIR))?;
+        let contents = CheckpointContents::new_with_causally_ordered_transactions(
+            effects.iter().map(TransactionEffects::execution_digests),
+        );
+        let signed = SignedCheckpointSummary::new(
+            0,
+            0,
+            effects.len() as u64,
+            state.name,
+            &key,
+            &contents,
+            None,
+            GasCostSummary::default(),
+            None,
+        );
+        let checkpoint = VerifiedCheckpoint::new_unchecked(CertifiedCheckpointSummary::aggregate(
+            vec![signed],
+            &committee,
+        )?);
+        let checkpoint_store = CheckpointStore::new(&db_path.join(CHECKPOINTS_DB_DIR));
+        checkpoint_store.insert_verified_checkpoint(checkpoint.clone())?;
+        checkpoint_store.insert_checkpoint_contents(contents)?;
+        checkpoint_store.update_highest_executed_checkpoint(&checkpoint)?;
+        Ok((db_path, effects))
+    }
+
+    /// Reference, owner and previous version of the object transferred by `effects`.
+    fn transferred_object(effects: &TransactionEffects) -> (ObjectRef, Owner, SequenceNumber) {
+        let (object_ref, owner) = *effects
+            .mutated
+            .iter()
+            .find(|(object_ref, _)| object_ref.0 != effects.gas_object.0 .0)
+            .expect("transfer mutates the transferred object");
+        let (_, previous_version) = *effects
+            .modified_at_versions
+            .iter()
+            .find(|(id, _)| *id == object_ref.0)
+            .expect("transferred object was modified");
+        (object_ref, owner, previous_version)
+    }
+
+    /// Open the perpetual tables of the authority store under `db_path` for writing.
+    fn open_authority_store(db_path: &Path) -> Result<Arc<RocksDB>, anyhow::Error> {
+        let path = AuthorityPerpetualTables::path(&db_path.join(AUTHORITY_DB_DIR));
+        let tables = list_tables(path.clone()).map_err(|e| anyhow::anyhow!(e))?;
+        let tables: Vec<_> = tables.iter().map(String::as_str).collect();
+        Ok(open_cf(&path, None, &tables)?)
+    }
+}
@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use self::db_dump::{dump_table, duplicate_objects_summary, list_tables, table_summary, StoreName};
+use self::db_verify::verify_db;
+use anyhow::bail;
 use clap::Parser;
 use std::path::PathBuf;
 use sui_types::base_types::EpochId;
 
 pub mod db_dump;
+pub mod db_verify;
 
 #[derive(Parser)]
 #[clap(rename_all = "kebab-case")]
@@ -15,6 +18,9 @@ pub enum DbToolCommand {
     Dump(Dump),
     TableSummary(Dump),
     DuplicatesSummary,
+    /// Check the consistency of the databases of a stopped node and print the discrepancies
+    /// found as JSON. The db path must be the db path of the node rather than of a single store.
+    Verify,
 }
 
 #[derive(Parser)]
@@ -56,9 +62,19 @@ pub fn execute_db_tool_command(db_path: PathBuf, cmd: DbToolCommand) -> anyhow::
             print_db_table_summary(d.store_name, d.epoch, db_path, &d.table_name)
         }
         DbToolCommand::DuplicatesSummary => print_db_duplicates_summary(db_path),
+        DbToolCommand::Verify => print_db_verify_report(db_path),
     }
 }
 
+pub fn print_db_verify_report(db_path: PathBuf) -> anyhow::Result<()> {
+    let report = verify_db(&db_path)?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    if !report.discrepancies.is_empty() {
+        bail!("Found {} discrepancies", report.discrepancies.len());
+    }
+    Ok(())
+}
+
 pub fn print_db_all_tables(db_path: PathBuf) -> anyhow::Result<()> {
     list_tables(db_path)?.iter().for_each(|t| println!("{}", t));
     Ok(())
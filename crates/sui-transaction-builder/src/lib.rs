@@ -18,6 +18,7 @@ use sui_json::{resolve_move_function_args, SuiJsonCallArg, SuiJsonValue};
 use sui_json_rpc_types::GetRawObjectDataResponse;
 use sui_json_rpc_types::SuiObjectInfo;
 use sui_json_rpc_types::{RPCTransactionRequestParams, SuiData, SuiTypeTag};
+use sui_protocol_constants::ProtocolConfig;
 use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress};
 use sui_types::error::SuiError;
 use sui_types::gas_coin::GasCoin;
@@ -321,7 +322,11 @@ impl TransactionBuilder {
             .try_as_package()
             .cloned()
             .ok_or_else(|| anyhow!("Object [{}] is not a move package.", package_id))?;
-        let package: MovePackage = MovePackage::new(package.id, &package.module_map)?;
+        let package: MovePackage = MovePackage::new(
+            package.id,
+            &package.module_map,
+            ProtocolConfig::get_for_max_version().max_move_package_size(),
+        )?;
 
         let json_args = resolve_move_function_args(
             &package,
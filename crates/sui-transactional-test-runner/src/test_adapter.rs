@@ -39,6 +39,7 @@ use std::{
 use sui_adapter::{adapter::new_move_vm, execution_mode, genesis};
 use sui_core::execution_engine;
 use sui_framework::DEFAULT_FRAMEWORK_PATH;
+use sui_protocol_constants::ProtocolConfig;
 use sui_types::temporary_store::TemporaryStore;
 use sui_types::utils::to_sender_signed_transaction;
 use sui_types::{
@@ -47,7 +48,7 @@ use sui_types::{
     },
     crypto::{get_key_pair_from_rng, AccountKeyPair},
     event::Event,
-    gas,
+    gas::{self, SuiCostTable},
     messages::{
         ExecutionStatus, InputObjects, TransactionData, TransactionEffects, VerifiedTransaction,
     },
@@ -67,6 +68,7 @@ const RNG_SEED: [u8; 32] = [
 
 pub struct SuiTestAdapter<'a> {
     vm: Arc<MoveVM>,
+    protocol_config: ProtocolConfig,
     pub(crate) storage: Arc<InMemoryStorage>,
     native_functions: NativeFunctionTable,
     pub(crate) compiled_state: CompiledState<'a>,
@@ -153,8 +155,10 @@ impl<'a> MoveTestAdapter<'a> for SuiTestAdapter<'a> {
             account_objects.insert(account.clone(), obj);
         }
 
+        let protocol_config = ProtocolConfig::get_for_max_version();
         let mut test_adapter = Self {
-            vm: Arc::new(new_move_vm(native_functions.clone()).unwrap()),
+            vm: Arc::new(new_move_vm(native_functions.clone(), &protocol_config).unwrap()),
+            protocol_config,
             storage: Arc::new(InMemoryStorage::new(objects)),
             native_functions,
             compiled_state: CompiledState::new(
@@ -454,7 +458,9 @@ impl<'a> SuiTestAdapter<'a> {
         transaction: VerifiedTransaction,
         gas_budget: u64,
     ) -> anyhow::Result<TxnSummary> {
-        let gas_status = gas::start_gas_metering(gas_budget, 1, 1).unwrap();
+        let gas_status =
+            gas::start_gas_metering(gas_budget, 1, 1, SuiCostTable::new(&self.protocol_config))
+                .unwrap();
         let transaction_digest = TransactionDigest::new(self.rng.gen());
         let objects_by_kind = transaction
             .data()
@@ -501,6 +507,7 @@ impl<'a> SuiTestAdapter<'a> {
             gas_status,
             // TODO: Support different epochs in transactional tests.
             0,
+            &self.protocol_config,
         );
 
         let mut created_ids: Vec<_> = created.iter().map(|((id, _, _), _)| *id).collect();
@@ -311,6 +311,11 @@ pub enum SuiError {
     GasBudgetTooHigh { error: String },
     #[error("Insufficient gas: {error:?}.")]
     InsufficientGas { error: String },
+    #[error("Gas price {gas_price} under reference gas price (RGP) {reference_gas_price}")]
+    GasPriceUnderRGP {
+        gas_price: u64,
+        reference_gas_price: u64,
+    },
 
     // Internal state errors
     #[error("Attempt to update state of TxContext from a different instance than original.")]
@@ -25,7 +25,7 @@ use sui_cost_tables::{
     bytecode_tables::{GasStatus, INITIAL_COST_SCHEDULE},
     units_types::GasUnit,
 };
-use sui_protocol_constants::*;
+use sui_protocol_constants::ProtocolConfig;
 
 pub type GasUnits = GasQuantity<GasUnit>;
 pub enum GasPriceUnit {}
@@ -94,6 +94,7 @@ impl GasCostSummary {
 }
 
 // Fixed cost type
+#[derive(Clone)]
 pub struct FixedCost(InternalGas);
 impl FixedCost {
     pub fn new(x: u64) -> Self {
@@ -112,6 +113,7 @@ impl Deref for FixedCost {
 /// to ensure a value of this type is used specifically for computation cost.
 /// Anything that does not change the amount of bytes stored in the authority data store
 /// will charge ComputationCostPerByte.
+#[derive(Clone)]
 pub struct ComputationCostPerByte(InternalGasPerByte);
 
 impl ComputationCostPerByte {
@@ -132,6 +134,7 @@ impl Deref for ComputationCostPerByte {
 /// to ensure a value of this type is used specifically for storage cost.
 /// Anything that changes the amount of bytes stored in the authority data store
 /// will charge StorageCostPerByte.
+#[derive(Clone)]
 pub struct StorageCostPerByte(InternalGasPerByte);
 
 impl Deref for StorageCostPerByte {
@@ -149,10 +152,13 @@ impl StorageCostPerByte {
 }
 
 /// A list of constant costs of various operations in Sui.
+#[derive(Clone)]
 pub struct SuiCostTable {
     /// A flat fee charged for every transaction. This is also the mimmum amount of
     /// gas charged for a transaction.
     pub min_transaction_cost: FixedCost,
+    /// Maximum amount of gas a single transaction can use.
+    pub max_transaction_cost: FixedCost,
     /// Computation cost per byte charged for package publish. This cost is primarily
     /// determined by the cost to verify and link a package. Note that this does not
     /// include the cost of writing the package to the store.
@@ -170,22 +176,49 @@ pub struct SuiCostTable {
     /// when objects are deleted. This cost covers the cost of storing transaction metadata
     /// which will always be there even after the objects are deleted.
     pub storage_per_byte_cost: StorageCostPerByte,
+    /// Share of the storage rebate of mutated and deleted objects that is returned to the sender.
+    pub storage_rebate_rate: f64,
 }
 
-// TODO: The following numbers are arbitrary at this point.
-pub static INIT_SUI_COST_TABLE: Lazy<SuiCostTable> = Lazy::new(|| SuiCostTable {
-    min_transaction_cost: FixedCost::new(BASE_TX_COST_FIXED),
-    package_publish_per_byte_cost: ComputationCostPerByte::new(PACKAGE_PUBLISH_COST_PER_BYTE),
-    object_read_per_byte_cost: ComputationCostPerByte::new(OBJ_ACCESS_COST_READ_PER_BYTE),
-    object_mutation_per_byte_cost: ComputationCostPerByte::new(OBJ_ACCESS_COST_MUTATE_PER_BYTE),
-    storage_per_byte_cost: StorageCostPerByte::new(OBJ_DATA_COST_REFUNDABLE),
-});
+impl SuiCostTable {
+    // TODO: The following numbers are arbitrary at this point.
+    pub fn new(c: &ProtocolConfig) -> Self {
+        Self {
+            min_transaction_cost: FixedCost::new(c.base_tx_cost_fixed()),
+            max_transaction_cost: FixedCost::new(c.max_tx_gas()),
+            package_publish_per_byte_cost: ComputationCostPerByte::new(
+                c.package_publish_cost_per_byte(),
+            ),
+            object_read_per_byte_cost: ComputationCostPerByte::new(
+                c.obj_access_cost_read_per_byte(),
+            ),
+            object_mutation_per_byte_cost: ComputationCostPerByte::new(
+                c.obj_access_cost_mutate_per_byte(),
+            ),
+            storage_per_byte_cost: StorageCostPerByte::new(c.obj_data_cost_refundable()),
+            storage_rebate_rate: c.storage_rebate_rate(),
+        }
+    }
+
+    pub fn new_for_testing() -> Self {
+        Self::new(&ProtocolConfig::get_for_max_version())
+    }
+
+    /// Largest gas budget a transaction can set.
+    pub fn max_gas_budget(&self) -> u64 {
+        to_external(*self.max_transaction_cost).into()
+    }
 
-pub static MAX_GAS_BUDGET: Lazy<u64> =
-    Lazy::new(|| u64::from(to_external(InternalGas::new(MAX_TX_GAS))));
+    /// Smallest gas budget a transaction can set, enough to pay for the flat transaction fee.
+    pub fn min_gas_budget(&self) -> u64 {
+        to_external(*self.min_transaction_cost).into()
+    }
+}
 
-pub static MIN_GAS_BUDGET: Lazy<u64> =
-    Lazy::new(|| to_external(*INIT_SUI_COST_TABLE.min_transaction_cost).into());
+/// Cost table of the oldest supported protocol version. Execution uses the cost table of the
+/// current epoch's protocol config instead; this is only for callers that have no epoch at hand.
+pub static INIT_SUI_COST_TABLE: Lazy<SuiCostTable> =
+    Lazy::new(|| SuiCostTable::new(&ProtocolConfig::get_for_min_version()));
 
 fn to_external(internal_units: InternalGas) -> GasUnits {
     InternalGas::to_unit_round_down(internal_units)
@@ -209,6 +242,7 @@ pub struct SuiGasStatus<'a> {
     /// was the storage cost paid when the object was last mutated. It is not affected
     /// by the current storage gas unit price.
     storage_rebate: SuiGas,
+    cost_table: SuiCostTable,
 }
 
 impl<'a> SuiGasStatus<'a> {
@@ -216,6 +250,7 @@ impl<'a> SuiGasStatus<'a> {
         gas_budget: u64,
         computation_gas_unit_price: GasPrice,
         storage_gas_unit_price: GasPrice,
+        cost_table: SuiCostTable,
     ) -> SuiGasStatus<'a> {
         Self::new(
             GasStatus::new(&INITIAL_COST_SCHEDULE, GasUnits::new(gas_budget)),
@@ -223,11 +258,19 @@ impl<'a> SuiGasStatus<'a> {
             true,
             computation_gas_unit_price,
             storage_gas_unit_price.into(),
+            cost_table,
         )
     }
 
     pub fn new_unmetered() -> SuiGasStatus<'a> {
-        Self::new(GasStatus::new_unmetered(), 0, false, 0.into(), 0)
+        Self::new(
+            GasStatus::new_unmetered(),
+            0,
+            false,
+            0.into(),
+            0,
+            INIT_SUI_COST_TABLE.clone(),
+        )
     }
 
     pub fn is_unmetered(&self) -> bool {
@@ -245,18 +288,19 @@ impl<'a> SuiGasStatus<'a> {
     }
 
     pub fn charge_min_tx_gas(&mut self) -> Result<(), ExecutionError> {
-        self.deduct_computation_cost(INIT_SUI_COST_TABLE.min_transaction_cost.deref())
+        let cost = *self.cost_table.min_transaction_cost;
+        self.deduct_computation_cost(&cost)
     }
 
     pub fn charge_publish_package(&mut self, size: usize) -> Result<(), ExecutionError> {
         let computation_cost =
-            NumBytes::new(size as u64).mul(*INIT_SUI_COST_TABLE.package_publish_per_byte_cost);
+            NumBytes::new(size as u64).mul(*self.cost_table.package_publish_per_byte_cost);
 
         self.deduct_computation_cost(&computation_cost)
     }
 
     pub fn charge_storage_read(&mut self, size: usize) -> Result<(), ExecutionError> {
-        let cost = NumBytes::new(size as u64).mul(*INIT_SUI_COST_TABLE.object_read_per_byte_cost);
+        let cost = NumBytes::new(size as u64).mul(*self.cost_table.object_read_per_byte_cost);
         self.deduct_computation_cost(&cost)
     }
 
@@ -274,13 +318,13 @@ impl<'a> SuiGasStatus<'a> {
         // This is because to update an object in the store, we have to erase the old one and
         // write a new one.
         let cost = NumBytes::new((old_size + new_size) as u64)
-            .mul(*INIT_SUI_COST_TABLE.object_mutation_per_byte_cost);
+            .mul(*self.cost_table.object_mutation_per_byte_cost);
         self.deduct_computation_cost(&cost)?;
 
         self.storage_rebate += storage_rebate;
 
         let storage_cost =
-            NumBytes::new(new_size as u64).mul(*INIT_SUI_COST_TABLE.storage_per_byte_cost);
+            NumBytes::new(new_size as u64).mul(*self.cost_table.storage_per_byte_cost);
 
         self.deduct_storage_cost(&storage_cost).map(|q| q.into())
     }
@@ -333,6 +377,7 @@ impl<'a> SuiGasStatus<'a> {
         charge: bool,
         computation_gas_unit_price: GasPrice,
         storage_gas_unit_price: u64,
+        cost_table: SuiCostTable,
     ) -> SuiGasStatus<'a> {
         SuiGasStatus {
             gas_status: move_gas_status,
@@ -344,9 +389,15 @@ impl<'a> SuiGasStatus<'a> {
             storage_gas_unit_price: ComputeGasPricePerUnit::new(storage_gas_unit_price),
             storage_gas_units: GasUnits::new(0),
             storage_rebate: 0.into(),
+            cost_table,
         }
     }
 
+    /// Share of the storage rebate returned to the sender, as set by the protocol config.
+    pub fn storage_rebate_rate(&self) -> f64 {
+        self.cost_table.storage_rebate_rate
+    }
+
     fn deduct_computation_cost(&mut self, cost: &InternalGas) -> Result<(), ExecutionError> {
         self.gas_status.deduct_gas(*cost).map_err(|e| {
             debug_assert_eq!(e.major_status(), StatusCode::OUT_OF_GAS);
@@ -389,20 +440,23 @@ pub fn check_gas_balance(
     gas_price: u64,
     extra_amount: u64,
     extra_objs: Vec<Object>,
+    cost_table: &SuiCostTable,
 ) -> SuiResult {
     ok_or_gas_error!(
         matches!(gas_object.owner, Owner::AddressOwner(_)),
         "Gas object must be owned Move object".to_owned()
     )?;
+    let max_gas_budget = cost_table.max_gas_budget();
+    let min_gas_budget = cost_table.min_gas_budget();
     ok_or_gas_error!(
-        gas_budget <= *MAX_GAS_BUDGET,
-        format!("Gas budget set too high; maximum is {}", *MAX_GAS_BUDGET)
+        gas_budget <= max_gas_budget,
+        format!("Gas budget set too high; maximum is {}", max_gas_budget)
     )?;
     ok_or_gas_error!(
-        gas_budget >= *MIN_GAS_BUDGET,
+        gas_budget >= min_gas_budget,
         format!(
             "Gas budget is {}, smaller than minimum requirement {}",
-            gas_budget, *MIN_GAS_BUDGET
+            gas_budget, min_gas_budget
         )
     )?;
 
@@ -435,11 +489,13 @@ pub fn start_gas_metering(
     gas_budget: u64,
     computation_gas_unit_price: u64,
     storage_gas_unit_price: u64,
+    cost_table: SuiCostTable,
 ) -> SuiResult<SuiGasStatus<'static>> {
     let mut gas_status = SuiGasStatus::new_with_budget(
         gas_budget,
         computation_gas_unit_price.into(),
         storage_gas_unit_price.into(),
+        cost_table,
     );
     // Charge the flat transaction fee.
     gas_status.charge_min_tx_gas()?;
@@ -459,8 +515,7 @@ pub fn deduct_gas(gas_object: &mut Object, deduct_amount: u64, rebate_amount: u6
     // unwrap safe because GasCoin is guaranteed to serialize
     let new_contents = bcs::to_bytes(&new_gas_coin).unwrap();
     assert_eq!(move_object.contents().len(), new_contents.len());
-    // unwrap safe gas object cannot exceed max object size
-    move_object.update_contents(new_contents).unwrap();
+    move_object.update_coin_contents(new_contents);
 }
 
 pub fn refund_gas(gas_object: &mut Object, amount: u64) {
@@ -471,8 +526,7 @@ pub fn refund_gas(gas_object: &mut Object, amount: u64) {
     let move_object = gas_object.data.try_as_move_mut().unwrap();
     // unwrap safe because GasCoin is guaranteed to serialize
     let new_contents = bcs::to_bytes(&new_gas_coin).unwrap();
-    // unwrap because safe gas object cannot exceed max object size
-    move_object.update_contents(new_contents).unwrap();
+    move_object.update_coin_contents(new_contents);
 }
 
 pub fn get_gas_balance(gas_object: &Object) -> SuiResult<u64> {
@@ -0,0 +1,95 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::{ident_str, identifier::IdentStr, language_storage::StructTag};
+use serde::{Deserialize, Serialize};
+
+use crate::balance::Balance;
+use crate::base_types::{ObjectID, SuiAddress};
+use crate::id::{ID, UID};
+use crate::SUI_FRAMEWORK_ADDRESS;
+
+pub const STAKING_POOL_MODULE_NAME: &IdentStr = ident_str!("staking_pool");
+pub const STAKED_SUI_STRUCT_NAME: &IdentStr = ident_str!("StakedSui");
+pub const DELEGATION_STRUCT_NAME: &IdentStr = ident_str!("Delegation");
+
+pub const ADD_DELEGATION_FUN_NAME: &IdentStr = ident_str!("request_add_delegation");
+pub const ADD_DELEGATION_MUL_COIN_FUN_NAME: &IdentStr =
+    ident_str!("request_add_delegation_mul_coin");
+pub const WITHDRAW_DELEGATION_FUN_NAME: &IdentStr = ident_str!("request_withdraw_delegation");
+
+/// Rust version of the Move sui::epoch_time_lock::EpochTimeLock type
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct EpochTimeLock {
+    pub epoch: u64,
+}
+
+/// Rust version of the Move sui::staking_pool::StakedSui type
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct StakedSui {
+    id: UID,
+    validator_address: SuiAddress,
+    pool_starting_epoch: u64,
+    delegation_request_epoch: u64,
+    principal: Balance,
+    sui_token_lock: Option<EpochTimeLock>,
+}
+
+impl StakedSui {
+    pub fn type_() -> StructTag {
+        StructTag {
+            address: SUI_FRAMEWORK_ADDRESS,
+            module: STAKING_POOL_MODULE_NAME.to_owned(),
+            name: STAKED_SUI_STRUCT_NAME.to_owned(),
+            type_params: vec![],
+        }
+    }
+
+    pub fn id(&self) -> ObjectID {
+        *self.id.object_id()
+    }
+
+    pub fn validator_address(&self) -> SuiAddress {
+        self.validator_address
+    }
+
+    pub fn principal(&self) -> u64 {
+        self.principal.value()
+    }
+
+    pub fn sui_token_lock(&self) -> Option<u64> {
+        self.sui_token_lock.as_ref().map(|lock| lock.epoch)
+    }
+}
+
+/// Rust version of the Move sui::staking_pool::Delegation type
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct Delegation {
+    id: UID,
+    staked_sui_id: ID,
+    pool_tokens: Balance,
+    principal_sui_amount: u64,
+}
+
+impl Delegation {
+    pub fn type_() -> StructTag {
+        StructTag {
+            address: SUI_FRAMEWORK_ADDRESS,
+            module: STAKING_POOL_MODULE_NAME.to_owned(),
+            name: DELEGATION_STRUCT_NAME.to_owned(),
+            type_params: vec![],
+        }
+    }
+
+    pub fn id(&self) -> ObjectID {
+        *self.id.object_id()
+    }
+
+    pub fn staked_sui_id(&self) -> ObjectID {
+        self.staked_sui_id.bytes
+    }
+
+    pub fn principal_sui_amount(&self) -> u64 {
+        self.principal_sui_amount
+    }
+}
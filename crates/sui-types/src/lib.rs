@@ -32,6 +32,7 @@ pub mod dynamic_field;
 pub mod event;
 pub mod gas;
 pub mod gas_coin;
+pub mod governance;
 pub mod id;
 pub mod in_memory_storage;
 pub mod intent;
@@ -41,6 +42,7 @@ pub mod messages_checkpoint;
 pub mod move_package;
 pub mod object;
 pub mod query;
+pub mod randomness;
 pub mod signature_seed;
 pub mod storage;
 pub mod sui_serde;
@@ -16,6 +16,7 @@ use crate::messages_checkpoint::{
     AuthenticatedCheckpoint, CheckpointSequenceNumber, CheckpointSignatureMessage,
 };
 use crate::object::{MoveObject, Object, ObjectFormatOptions, Owner, PACKAGE_VERSION};
+use crate::randomness::RandomnessPartialSignatureMessage;
 use crate::storage::{DeleteKind, WriteKind};
 use crate::{SUI_SYSTEM_STATE_OBJECT_ID, SUI_SYSTEM_STATE_OBJECT_SHARED_VERSION};
 use byteorder::{BigEndian, ReadBytesExt};
@@ -41,6 +42,7 @@ use std::{
     iter,
 };
 use strum::IntoStaticStr;
+use sui_protocol_constants::{ProtocolVersion, SupportedProtocolVersions};
 use tracing::debug;
 
 #[cfg(test)]
@@ -161,6 +163,8 @@ pub struct ChangeEpoch {
     pub computation_charge: u64,
     /// The total amount of storage rebate refunded during the epoch.
     pub storage_rebate: u64,
+    /// The protocol version in effect in the new epoch.
+    pub protocol_version: ProtocolVersion,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
@@ -457,6 +461,7 @@ impl Display for SingleTransactionKind {
                 writeln!(writer, "Storage gas reward: {}", e.storage_charge)?;
                 writeln!(writer, "Computation gas reward: {}", e.computation_charge)?;
                 writeln!(writer, "Storage rebate: {}", e.storage_rebate)?;
+                writeln!(writer, "Protocol version: {}", e.protocol_version)?;
             }
         }
         write!(f, "{}", writer)
@@ -950,6 +955,7 @@ impl Transaction {
 impl VerifiedTransaction {
     pub fn new_change_epoch(
         next_epoch: EpochId,
+        protocol_version: ProtocolVersion,
         storage_charge: u64,
         computation_charge: u64,
         storage_rebate: u64,
@@ -959,6 +965,7 @@ impl VerifiedTransaction {
             storage_charge,
             computation_charge,
             storage_rebate,
+            protocol_version,
         }));
         // For the ChangeEpoch transaction, we do not care about the sender and the gas.
         let data = TransactionData::new(
@@ -2101,6 +2108,30 @@ pub enum ConsensusTransactionKey {
     Certificate(TransactionDigest),
     CheckpointSignature(AuthorityName, CheckpointSequenceNumber),
     EndOfPublish(AuthorityName),
+    RandomnessPartialSignature(AuthorityName, CheckpointSequenceNumber),
+    CapabilityNotification(AuthorityName),
+}
+
+/// Used to advertise capabilities of each authority via consensus. This allows validators to
+/// negotiate the protocol version of the next epoch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AuthorityCapabilities {
+    /// Originating authority - must match narwhal transaction source.
+    pub authority: AuthorityName,
+    /// Protocol versions this authority is able to run.
+    pub supported_protocol_versions: SupportedProtocolVersions,
+}
+
+impl AuthorityCapabilities {
+    pub fn new(
+        authority: AuthorityName,
+        supported_protocol_versions: SupportedProtocolVersions,
+    ) -> Self {
+        Self {
+            authority,
+            supported_protocol_versions,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -2108,6 +2139,8 @@ pub enum ConsensusTransactionKind {
     UserTransaction(Box<CertifiedTransaction>),
     CheckpointSignature(Box<CheckpointSignatureMessage>),
     EndOfPublish(AuthorityName),
+    RandomnessPartialSignature(Box<RandomnessPartialSignatureMessage>),
+    CapabilityNotification(AuthorityCapabilities),
 }
 
 impl ConsensusTransaction {
@@ -2146,6 +2179,29 @@ impl ConsensusTransaction {
         }
     }
 
+    pub fn new_capability_notification(capabilities: AuthorityCapabilities) -> Self {
+        let mut hasher = DefaultHasher::new();
+        capabilities.hash(&mut hasher);
+        let tracking_id = hasher.finish().to_le_bytes();
+        Self {
+            tracking_id,
+            kind: ConsensusTransactionKind::CapabilityNotification(capabilities),
+        }
+    }
+
+    pub fn new_randomness_partial_signature_message(
+        data: RandomnessPartialSignatureMessage,
+    ) -> Self {
+        let mut hasher = DefaultHasher::new();
+        data.authority.hash(&mut hasher);
+        data.round.hash(&mut hasher);
+        let tracking_id = hasher.finish().to_le_bytes();
+        Self {
+            tracking_id,
+            kind: ConsensusTransactionKind::RandomnessPartialSignature(Box::new(data)),
+        }
+    }
+
     pub fn get_tracking_id(&self) -> u64 {
         (&self.tracking_id[..])
             .read_u64::<BigEndian>()
@@ -2159,6 +2215,10 @@ impl ConsensusTransaction {
             }
             ConsensusTransactionKind::CheckpointSignature(data) => data.verify(committee),
             ConsensusTransactionKind::EndOfPublish(_) => Ok(()),
+            // Partial signatures are verified when they are aggregated, since verification
+            // requires the share assignment of the epoch.
+            ConsensusTransactionKind::RandomnessPartialSignature(_) => Ok(()),
+            ConsensusTransactionKind::CapabilityNotification(_) => Ok(()),
         }
     }
 
@@ -2176,6 +2236,15 @@ impl ConsensusTransaction {
             ConsensusTransactionKind::EndOfPublish(authority) => {
                 ConsensusTransactionKey::EndOfPublish(*authority)
             }
+            ConsensusTransactionKind::RandomnessPartialSignature(data) => {
+                ConsensusTransactionKey::RandomnessPartialSignature(
+                    data.authority,
+                    data.round.sequence_number,
+                )
+            }
+            ConsensusTransactionKind::CapabilityNotification(cap) => {
+                ConsensusTransactionKey::CapabilityNotification(cap.authority)
+            }
         }
     }
 
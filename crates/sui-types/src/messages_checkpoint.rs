@@ -1,7 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use fastcrypto::encoding::{Encoding, Hex};
+use fastcrypto::encoding::{Base58, Encoding, Hex};
 use std::fmt::{Debug, Display, Formatter};
 use std::slice::Iter;
 
@@ -10,14 +10,17 @@ use crate::committee::{EpochId, StakeUnit};
 use crate::crypto::{AuthoritySignInfo, AuthoritySignInfoTrait, AuthorityWeakQuorumSignInfo};
 use crate::error::SuiResult;
 use crate::gas::GasCostSummary;
+use crate::sui_serde::Readable;
 use crate::{
     base_types::AuthorityName,
     committee::Committee,
     crypto::{sha3_hash, AuthoritySignature, VerificationObligation},
     error::SuiError,
 };
+use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 
 pub type CheckpointSequenceNumber = u64;
 
@@ -96,8 +99,15 @@ impl AuthenticatedCheckpoint {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-pub struct CheckpointDigest(pub [u8; 32]);
+#[serde_as]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CheckpointDigest(
+    #[schemars(with = "Base58")]
+    #[serde_as(as = "Readable<Base58, [_; 32]>")]
+    pub [u8; 32],
+);
 
 impl AsRef<[u8]> for CheckpointDigest {
     fn as_ref(&self) -> &[u8] {
@@ -111,8 +121,15 @@ impl AsRef<[u8; 32]> for CheckpointDigest {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-pub struct CheckpointContentsDigest(pub [u8; 32]);
+#[serde_as]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CheckpointContentsDigest(
+    #[schemars(with = "Base58")]
+    #[serde_as(as = "Readable<Base58, [_; 32]>")]
+    pub [u8; 32],
+);
 
 impl AsRef<[u8]> for CheckpointContentsDigest {
     fn as_ref(&self) -> &[u8] {
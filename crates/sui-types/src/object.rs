@@ -27,7 +27,7 @@ use crate::{
     },
     gas_coin::GasCoin,
 };
-use sui_protocol_constants::*;
+use sui_protocol_constants::ProtocolConfig;
 
 pub const GAS_VALUE_FOR_TESTING: u64 = 1_000_000_u64;
 pub const OBJECT_START_VERSION: SequenceNumber = SequenceNumber::from_u64(1);
@@ -35,6 +35,10 @@ pub const OBJECT_START_VERSION: SequenceNumber = SequenceNumber::from_u64(1);
 /// Packages are immutable, version is always 1
 pub const PACKAGE_VERSION: SequenceNumber = OBJECT_START_VERSION;
 
+/// Size limit used for coin objects, which have a fixed size (an ID and a balance) well below the
+/// max object size of every protocol version.
+const COIN_SIZE_LIMIT: u64 = 256;
+
 #[serde_as]
 #[derive(Eq, PartialEq, Debug, Clone, Deserialize, Serialize, Hash)]
 pub struct MoveObject {
@@ -76,15 +80,37 @@ impl MoveObject {
         has_public_transfer: bool,
         version: SequenceNumber,
         contents: Vec<u8>,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<Self, ExecutionError> {
+        Self::new_from_execution_with_limit(
+            type_,
+            has_public_transfer,
+            version,
+            contents,
+            protocol_config.max_move_object_size(),
+        )
+    }
+
+    /// Same as `new_from_execution`, but with an explicit limit on the size of `contents`.
+    ///
+    /// # Safety
+    ///
+    /// See `new_from_execution`.
+    pub unsafe fn new_from_execution_with_limit(
+        type_: StructTag,
+        has_public_transfer: bool,
+        version: SequenceNumber,
+        contents: Vec<u8>,
+        max_move_object_size: u64,
     ) -> Result<Self, ExecutionError> {
         // coins should always have public transfer, as they always should have store.
         // Thus, type_ == GasCoin::type_() ==> has_public_transfer
         debug_assert!(type_ != GasCoin::type_() || has_public_transfer);
-        if contents.len() as u64 > MAX_MOVE_OBJECT_SIZE {
+        if contents.len() as u64 > max_move_object_size {
             return Err(ExecutionError::from_kind(
                 ExecutionErrorKind::MoveObjectTooBig {
                     object_size: contents.len() as u64,
-                    max_object_size: MAX_MOVE_OBJECT_SIZE,
+                    max_object_size: max_move_object_size,
                 },
             ));
         }
@@ -99,11 +125,12 @@ impl MoveObject {
     pub fn new_gas_coin(version: SequenceNumber, id: ObjectID, value: u64) -> Self {
         // unwrap safe because coins are always smaller than the max object size
         unsafe {
-            Self::new_from_execution(
+            Self::new_from_execution_with_limit(
                 GasCoin::type_(),
                 true,
                 version,
                 GasCoin::new(id, value).to_bcs_bytes(),
+                COIN_SIZE_LIMIT,
             )
             .unwrap()
         }
@@ -117,11 +144,12 @@ impl MoveObject {
     ) -> Self {
         // unwrap safe because coins are always smaller than the max object size
         unsafe {
-            Self::new_from_execution(
+            Self::new_from_execution_with_limit(
                 coin_type,
                 true,
                 version,
                 GasCoin::new(id, value).to_bcs_bytes(),
+                COIN_SIZE_LIMIT,
             )
             .unwrap()
         }
@@ -148,12 +176,31 @@ impl MoveObject {
     }
 
     /// Update the contents of this object but does not increment its version
-    pub fn update_contents(&mut self, new_contents: Vec<u8>) -> Result<(), ExecutionError> {
-        if new_contents.len() as u64 > MAX_MOVE_OBJECT_SIZE {
+    pub fn update_contents(
+        &mut self,
+        new_contents: Vec<u8>,
+        protocol_config: &ProtocolConfig,
+    ) -> Result<(), ExecutionError> {
+        self.update_contents_with_limit(new_contents, protocol_config.max_move_object_size())
+    }
+
+    /// Update the contents of a coin object but does not increment its version. Unlike
+    /// `update_contents`, this cannot fail, as coins are always smaller than the max object size.
+    pub fn update_coin_contents(&mut self, new_contents: Vec<u8>) {
+        self.update_contents_with_limit(new_contents, COIN_SIZE_LIMIT)
+            .expect("Coins are always smaller than the max object size")
+    }
+
+    fn update_contents_with_limit(
+        &mut self,
+        new_contents: Vec<u8>,
+        max_move_object_size: u64,
+    ) -> Result<(), ExecutionError> {
+        if new_contents.len() as u64 > max_move_object_size {
             return Err(ExecutionError::from_kind(
                 ExecutionErrorKind::MoveObjectTooBig {
                     object_size: new_contents.len() as u64,
-                    max_object_size: MAX_MOVE_OBJECT_SIZE,
+                    max_object_size: max_move_object_size,
                 },
             ));
         }
@@ -405,15 +452,30 @@ impl Object {
     pub fn new_package(
         modules: Vec<CompiledModule>,
         previous_transaction: TransactionDigest,
+        max_move_package_size: u64,
     ) -> Result<Self, ExecutionError> {
         Ok(Object {
-            data: Data::Package(MovePackage::from_module_iter(modules)?),
+            data: Data::Package(MovePackage::from_module_iter(
+                modules,
+                max_move_package_size,
+            )?),
             owner: Owner::Immutable,
             previous_transaction,
             storage_rebate: 0,
         })
     }
 
+    pub fn new_package_for_testing(
+        modules: Vec<CompiledModule>,
+        previous_transaction: TransactionDigest,
+    ) -> Result<Self, ExecutionError> {
+        Self::new_package(
+            modules,
+            previous_transaction,
+            ProtocolConfig::get_for_max_version().max_move_package_size(),
+        )
+    }
+
     pub fn is_immutable(&self) -> bool {
         self.owner.is_immutable()
     }
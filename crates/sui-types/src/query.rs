@@ -1,11 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use move_core_types::account_address::AccountAddress;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::base_types::{SuiAddress, TransactionDigest};
+use crate::base_types::{ObjectType, SuiAddress, TransactionDigest};
 use crate::event::EventType;
 use crate::object::Owner;
 use crate::ObjectID;
@@ -66,4 +67,55 @@ pub enum EventQuery {
         /// right endpoint of time interval, milliseconds since epoch, exclusive
         end_time: u64,
     },
+    /// Return events that match both queries, e.g. a Move event type emitted within a time range.
+    And(Box<EventQuery>, Box<EventQuery>),
+    /// Return events that match either query.
+    Or(Box<EventQuery>, Box<EventQuery>),
+}
+
+/// Filter for owned object queries, matching a prefix of the object's Move struct type.
+#[derive(Clone, Debug, JsonSchema, Serialize, Deserialize)]
+pub enum ObjectTypeFilter {
+    /// Objects whose type is defined in the given package.
+    Package(ObjectID),
+    /// Objects whose type is defined in the given Move module.
+    MoveModule {
+        /// the Move package ID
+        package: ObjectID,
+        /// the module name
+        module: String,
+    },
+    /// Objects of the given struct type, regardless of its type parameters.
+    StructType {
+        /// the Move package ID
+        package: ObjectID,
+        /// the module name
+        module: String,
+        /// the struct name
+        name: String,
+    },
+}
+
+impl ObjectTypeFilter {
+    pub fn matches(&self, object_type: &ObjectType) -> bool {
+        let tag = match object_type {
+            ObjectType::Struct(tag) => tag,
+            ObjectType::Package => return false,
+        };
+        match self {
+            ObjectTypeFilter::Package(package) => tag.address == AccountAddress::from(*package),
+            ObjectTypeFilter::MoveModule { package, module } => {
+                tag.address == AccountAddress::from(*package) && tag.module.as_str() == module
+            }
+            ObjectTypeFilter::StructType {
+                package,
+                module,
+                name,
+            } => {
+                tag.address == AccountAddress::from(*package)
+                    && tag.module.as_str() == module
+                    && tag.name.as_str() == name
+            }
+        }
+    }
 }
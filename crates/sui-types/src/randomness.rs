@@ -0,0 +1,162 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::base_types::AuthorityName;
+use crate::committee::EpochId;
+use crate::error::{SuiError, SuiResult};
+use crate::messages_checkpoint::CheckpointSequenceNumber;
+use fastcrypto::groups::bls12381;
+use fastcrypto::groups::{GroupElement, Scalar};
+use fastcrypto::hash::{HashFunction, Sha3_256};
+use fastcrypto_tbls::polynomial::{Poly, PrivatePoly, PublicPoly};
+use fastcrypto_tbls::tbls::{PartialSignature, Share, ThresholdBls};
+use fastcrypto_tbls::types::ThresholdBls12381MinSig;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU32;
+
+/// Index of a single tBLS share. Validators own a contiguous range of share indices, see
+/// `sui_core::tbls::tbls_ids::TBlsIds`.
+pub type RandomnessShareIndex = NonZeroU32;
+
+/// Full (aggregated) threshold signature produced by the randomness beacon.
+pub type RandomnessSignature = <ThresholdBls12381MinSig as ThresholdBls>::Signature;
+
+/// Signature produced with a single share, to be aggregated with others into a
+/// [`RandomnessSignature`].
+pub type RandomnessPartialSignature = PartialSignature<RandomnessSignature>;
+
+/// Domain separation tag prepended to the seed of the mocked DKG.
+const MOCKED_DKG_SEED_PREFIX: &[u8] = b"sui-randomness-beacon-mocked-dkg";
+
+/// The message that validators sign with their shares to produce the randomness of a checkpoint.
+///
+/// Since a threshold signature is unique for a given message and group key, the aggregated
+/// signature (and the randomness derived from it) does not depend on which subset of shares was
+/// used to produce it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RandomnessRound {
+    pub epoch: EpochId,
+    pub sequence_number: CheckpointSequenceNumber,
+}
+
+impl RandomnessRound {
+    pub fn new(epoch: EpochId, sequence_number: CheckpointSequenceNumber) -> Self {
+        Self {
+            epoch,
+            sequence_number,
+        }
+    }
+
+    /// Bytes that are signed by the beacon for this round.
+    pub fn signing_message(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Serialization of RandomnessRound should not fail")
+    }
+}
+
+/// Partial signatures sent by a validator through consensus, one for each share it owns.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RandomnessPartialSignatureMessage {
+    pub authority: AuthorityName,
+    pub round: RandomnessRound,
+    pub partial_signatures: Vec<RandomnessPartialSignature>,
+}
+
+/// The output of the beacon for a single checkpoint.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CheckpointRandomness {
+    pub round: RandomnessRound,
+    pub signature: RandomnessSignature,
+}
+
+impl CheckpointRandomness {
+    pub fn verify(&self) -> SuiResult {
+        verify_randomness_signature(&self.round, &self.signature)
+    }
+
+    /// The 32 random bytes derived from the beacon signature.
+    pub fn randomness(&self) -> [u8; 32] {
+        randomness_from_signature(&self.signature)
+    }
+}
+
+/// Output of the (mocked) distributed key generation of an epoch.
+///
+/// TODO: This is an insecure placeholder - every party can derive every share from the epoch
+/// number. Replace it with the output of a real DKG once one runs during reconfiguration. Until
+/// then the beacon only exercises the protocol plumbing and must not be relied upon for security,
+/// which is why validators only run it when `ProtocolConfig::randomness_beacon` is set.
+pub struct MockedDkgOutput {
+    private_poly: PrivatePoly<ThresholdBls12381MinSig>,
+    public_poly: PublicPoly<ThresholdBls12381MinSig>,
+    /// Coefficients of `public_poly`, lowest degree first.
+    public_coefficients: Vec<bls12381::G2Element>,
+}
+
+impl MockedDkgOutput {
+    /// Generates the key material of `epoch` for the given signing threshold. The group public
+    /// key only depends on the epoch, so it can be recomputed by verifiers that do not know the
+    /// committee (e.g., Move natives).
+    pub fn new(epoch: EpochId, threshold: u32) -> Self {
+        let mut rng = mocked_dkg_rng(epoch);
+        // The constant coefficient is sampled first so that it is independent of the threshold.
+        let coefficients = (0..threshold.max(1))
+            .map(|_| bls12381::Scalar::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let public_coefficients = coefficients
+            .iter()
+            .map(|coefficient| bls12381::G2Element::generator() * coefficient)
+            .collect();
+        let private_poly: PrivatePoly<ThresholdBls12381MinSig> = Poly::from(coefficients);
+        let public_poly = private_poly.commit();
+        Self {
+            private_poly,
+            public_poly,
+            public_coefficients,
+        }
+    }
+
+    pub fn share(&self, index: RandomnessShareIndex) -> Share<bls12381::Scalar> {
+        self.private_poly.eval(index)
+    }
+
+    pub fn public_poly(&self) -> &PublicPoly<ThresholdBls12381MinSig> {
+        &self.public_poly
+    }
+
+    pub fn public_coefficients(&self) -> &[bls12381::G2Element] {
+        &self.public_coefficients
+    }
+}
+
+/// The group public key of the beacon in `epoch`.
+pub fn randomness_public_key(epoch: EpochId) -> bls12381::G2Element {
+    *MockedDkgOutput::new(epoch, 1).public_poly().c0()
+}
+
+pub fn verify_randomness_signature(
+    round: &RandomnessRound,
+    signature: &RandomnessSignature,
+) -> SuiResult {
+    ThresholdBls12381MinSig::verify(
+        &randomness_public_key(round.epoch),
+        &round.signing_message(),
+        signature,
+    )
+    .map_err(|_| SuiError::InvalidSignature {
+        error: format!("Invalid randomness signature for round {:?}", round),
+    })
+}
+
+pub fn randomness_from_signature(signature: &RandomnessSignature) -> [u8; 32] {
+    let bytes = bcs::to_bytes(signature).expect("Serialization of signature should not fail");
+    Sha3_256::digest(bytes).digest
+}
+
+fn mocked_dkg_rng(epoch: EpochId) -> StdRng {
+    let mut hasher = Sha3_256::default();
+    hasher.update(MOCKED_DKG_SEED_PREFIX);
+    hasher.update(epoch.to_le_bytes());
+    StdRng::from_seed(hasher.finalize().digest)
+}
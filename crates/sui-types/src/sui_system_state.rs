@@ -18,6 +18,7 @@ use narwhal_config::{Committee as NarwhalCommittee, WorkerCache, WorkerIndex};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use sui_protocol_constants::ProtocolVersion;
 
 const SUI_SYSTEM_STATE_STRUCT_NAME: &IdentStr = ident_str!("SuiSystemState");
 pub const SUI_SYSTEM_MODULE_NAME: &IdentStr = ident_str!("sui_system");
@@ -146,6 +147,7 @@ pub struct SuiSystemState {
     pub info: UID,
     pub chain_id: ChainId,
     pub epoch: u64,
+    pub protocol_version: u64,
     pub validators: ValidatorSet,
     pub treasury_cap: Supply,
     pub storage_fund: Balance,
@@ -173,6 +175,10 @@ impl SuiSystemState {
         }
     }
 
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        ProtocolVersion::new(self.protocol_version)
+    }
+
     pub fn get_next_epoch_committee(&self) -> Committee {
         Committee::new(
             self.epoch + 1,
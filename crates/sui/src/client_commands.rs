@@ -15,9 +15,10 @@ use bip32::DerivationPath;
 use clap::*;
 use colored::Colorize;
 use fastcrypto::{
-    encoding::{Base64, Encoding},
+    encoding::{Base58, Base64, Encoding},
     traits::ToFromBytes,
 };
+use futures::{Stream, StreamExt};
 use move_core_types::language_storage::TypeTag;
 use move_package::BuildConfig as MoveBuildConfig;
 use serde::Serialize;
@@ -33,7 +34,9 @@ use sui_adapter::execution_mode;
 use sui_framework_build::compiled_package::BuildConfig;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    GetObjectDataResponse, SuiObjectInfo, SuiParsedObject, SuiTransactionResponse,
+    EventPage, GetObjectDataResponse, Page, SuiEvent, SuiEventEnvelope, SuiEventFilter,
+    SuiObjectInfo, SuiParsedObject, SuiTransactionFilter, SuiTransactionKind,
+    SuiTransactionResponse,
 };
 use sui_json_rpc_types::{GetRawObjectDataResponse, SuiData};
 use sui_json_rpc_types::{SuiCertifiedTransaction, SuiExecutionStatus, SuiTransactionEffects};
@@ -41,11 +44,15 @@ use sui_keys::keystore::AccountKeystore;
 use sui_sdk::TransactionExecutionResult;
 use sui_types::intent::Intent;
 use sui_types::{
-    base_types::{ObjectID, SuiAddress},
+    base_types::{ObjectID, SuiAddress, TransactionDigest},
+    coin::{COIN_MODULE_NAME, COIN_STRUCT_NAME},
+    event::EventID,
     gas_coin::GasCoin,
-    messages::{Transaction, VerifiedTransaction},
+    messages::{Transaction, TransactionData, VerifiedTransaction},
     object::Owner,
-    parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS,
+    parse_sui_type_tag,
+    query::{EventQuery, ObjectTypeFilter, TransactionQuery},
+    SUI_FRAMEWORK_ADDRESS, SUI_FRAMEWORK_OBJECT_ID,
 };
 use sui_types::{
     crypto::{Signature, SignatureScheme},
@@ -337,6 +344,107 @@ pub enum SuiClientCommands {
         address: Option<SuiAddress>,
     },
 
+    /// Query transactions known to the fullnode, most recent first. At most one of `--from`,
+    /// `--to`, `--input-object`, `--mutated-object` and `--package` can be used; all transactions
+    /// are listed if none is given.
+    #[clap(name = "tx-history")]
+    #[clap(group(ArgGroup::new("query").multiple(false).args(&["from", "to", "input-object", "mutated-object", "package"])))]
+    TxHistory {
+        /// Transactions sent by this address
+        #[clap(long)]
+        from: Option<SuiAddress>,
+        /// Transactions that created or mutated objects owned by this address
+        #[clap(long)]
+        to: Option<SuiAddress>,
+        /// Transactions taking this object as input, in 20 bytes Hex string
+        #[clap(long)]
+        input_object: Option<ObjectID>,
+        /// Transactions mutating this object, in 20 bytes Hex string
+        #[clap(long)]
+        mutated_object: Option<ObjectID>,
+        /// Transactions calling a Move function of this package, in 20 bytes Hex string
+        #[clap(long)]
+        package: Option<ObjectID>,
+        /// Restrict `--package` to a module
+        #[clap(long, requires = "package")]
+        module: Option<String>,
+        /// Restrict `--module` to a function
+        #[clap(long, requires = "module")]
+        function: Option<String>,
+        /// Only show transactions executed at or after this time, in milliseconds since epoch.
+        /// The fullnode can't filter transactions by time, so the client scans all transactions
+        /// matching the query for those in the time range.
+        #[clap(long)]
+        start_time: Option<u64>,
+        /// Only show transactions executed before this time, in milliseconds since epoch. Scans
+        /// all transactions matching the query, like `--start-time`.
+        #[clap(long)]
+        end_time: Option<u64>,
+        /// Digest of the first transaction to show, as returned by a previous query
+        #[clap(long)]
+        cursor: Option<TransactionDigest>,
+        /// Maximum number of transactions to show
+        #[clap(long, default_value = "20")]
+        limit: usize,
+        /// Show the oldest transactions first
+        #[clap(long)]
+        ascending: bool,
+        /// Keep listening for new transactions matching the query. Requires a WebSocket url
+        /// for the active env. The fullnode can't filter the transaction subscription, so it
+        /// streams all its new transactions and those not matching the query are dropped by the
+        /// client. `--input-object` is not supported, as read-only inputs are not part of the
+        /// streamed effects.
+        #[clap(long, conflicts_with_all = &["input-object", "start-time", "end-time", "cursor", "ascending"])]
+        follow: bool,
+    },
+
+    /// Query events emitted by transactions, most recent first. All the given criteria must
+    /// match; all events are listed if none is given.
+    #[clap(name = "events")]
+    Events {
+        /// Events emitted by this transaction
+        #[clap(long)]
+        tx_digest: Option<TransactionDigest>,
+        /// Events emitted by a module of this package, in 20 bytes Hex string
+        #[clap(long, requires = "module")]
+        package: Option<ObjectID>,
+        /// Module of `--package` emitting the events
+        #[clap(long, requires = "package")]
+        module: Option<String>,
+        /// Move event struct type, e.g. `0x2::devnet_nft::MintNFTEvent`
+        #[clap(long)]
+        event_type: Option<String>,
+        /// Events of transactions sent by this address
+        #[clap(long)]
+        sender: Option<SuiAddress>,
+        /// Events transferring objects to this address
+        #[clap(long)]
+        recipient: Option<SuiAddress>,
+        /// Events about this object, in 20 bytes Hex string
+        #[clap(long)]
+        object: Option<ObjectID>,
+        /// Only show events emitted at or after this time, in milliseconds since epoch
+        #[clap(long)]
+        start_time: Option<u64>,
+        /// Only show events emitted before this time, in milliseconds since epoch
+        #[clap(long)]
+        end_time: Option<u64>,
+        /// ID of the first event to show as `tx_seq:event_seq`, as returned by a previous query.
+        /// With `--follow`, matching events emitted after this one are replayed first.
+        #[clap(long, parse(try_from_str = parse_event_id))]
+        cursor: Option<EventID>,
+        /// Maximum number of events to show
+        #[clap(long, default_value = "20")]
+        limit: usize,
+        /// Show the oldest events first
+        #[clap(long)]
+        ascending: bool,
+        /// Keep listening for new events matching the criteria. Requires a WebSocket url for
+        /// the active env.
+        #[clap(long, conflicts_with_all = &["tx-digest", "recipient", "start-time", "end-time", "ascending"])]
+        follow: bool,
+    },
+
     /// Split a coin object into multiple coins.
     #[clap(group(ArgGroup::new("split").required(true).args(&["amounts", "count"])))]
     SplitCoin {
@@ -437,7 +545,457 @@ impl SuiClientCommands {
         self,
         context: &mut WalletContext,
     ) -> Result<SuiClientCommandResult, anyhow::Error> {
+        let time_start = Instant::now();
+        if let Some((signer, data)) = self.transaction_data(context).await? {
+            let signature =
+                context
+                    .config
+                    .keystore
+                    .sign_secure(&signer, &data, Intent::default())?;
+            let response = context
+                .execute_transaction(
+                    Transaction::from_data(data, Intent::default(), signature).verify()?,
+                )
+                .await?;
+            return self.transaction_result(context, response, time_start).await;
+        }
+
         let ret = Ok(match self {
+            SuiClientCommands::Object { id, bcs } => {
+                // Fetch the object ref
+                let client = context.get_client().await?;
+                let object_read = client.read_api().get_parsed_object(id).await?;
+                SuiClientCommandResult::Object(object_read, bcs)
+            }
+            SuiClientCommands::Addresses => {
+                SuiClientCommandResult::Addresses(context.config.keystore.addresses())
+            }
+
+            SuiClientCommands::Objects { address } => {
+                let address = address.unwrap_or(context.active_address()?);
+                let address_object = context.get_owned_objects(address, None).await?;
+                SuiClientCommandResult::Objects(address_object)
+            }
+
+            SuiClientCommands::NewAddress {
+                key_scheme,
+                derivation_path,
+            } => {
+                let (address, phrase, scheme) = context
+                    .config
+                    .keystore
+                    .generate_and_add_new_key(key_scheme, derivation_path)?;
+                SuiClientCommandResult::NewAddress((address, phrase, scheme))
+            }
+            SuiClientCommands::Gas { address } => {
+                let address = address.unwrap_or(context.active_address()?);
+                let coins = context
+                    .gas_objects(address)
+                    .await?
+                    .iter()
+                    // Ok to unwrap() since `get_gas_objects` guarantees gas
+                    .map(|(_val, object, _object_ref)| GasCoin::try_from(object).unwrap())
+                    .collect();
+                SuiClientCommandResult::Gas(coins)
+            }
+            SuiClientCommands::TxHistory {
+                start_time,
+                end_time,
+                cursor,
+                limit,
+                ascending,
+                ..
+            } => {
+                ensure!(limit > 0, "Limit must be greater than 0");
+                let query = self.transaction_query()?;
+                let client = context.get_client().await?;
+                let page = transaction_history(
+                    &client, query, cursor, limit, !ascending, start_time, end_time,
+                )
+                .await?;
+                SuiClientCommandResult::TxHistory(page)
+            }
+            SuiClientCommands::Events {
+                ref cursor,
+                limit,
+                ascending,
+                ..
+            } => {
+                ensure!(limit > 0, "Limit must be greater than 0");
+                let query = self.event_query()?;
+                let client = context.get_client().await?;
+                let page = client
+                    .event_api()
+                    .get_events(query, cursor.clone(), Some(limit), !ascending)
+                    .await?;
+                SuiClientCommandResult::Events(page)
+            }
+            SuiClientCommands::Switch { address, env } => {
+                match (address, &env) {
+                    (None, Some(env)) => {
+                        Self::switch_env(&mut context.config, env)?;
+                    }
+                    (Some(addr), None) => {
+                        if !context.config.keystore.addresses().contains(&addr) {
+                            return Err(anyhow!("Address {} not managed by wallet", addr));
+                        }
+                        context.config.active_address = Some(addr);
+                    }
+                    _ => return Err(anyhow!("No address or env specified. Please Specify one.")),
+                }
+                context.config.save()?;
+                SuiClientCommandResult::Switch(SwitchResponse { address, env })
+            }
+            SuiClientCommands::ActiveAddress => {
+                SuiClientCommandResult::ActiveAddress(context.active_address().ok())
+            }
+            SuiClientCommands::SerializeTransferSui {
+                to,
+                sui_coin_object_id: object_id,
+                gas_budget,
+                amount,
+            } => {
+                let from = context.get_object_owner(&object_id).await?;
+                let client = context.get_client().await?;
+                let data = client
+                    .transaction_builder()
+                    .transfer_sui(from, object_id, gas_budget, to, amount)
+                    .await?;
+                let data1 = data.clone();
+                let intent_msg = IntentMessage::new(Intent::default(), data);
+                info!(
+                    "Transaction bytes : {}",
+                    Base64::encode(&bcs::to_bytes(&data1).unwrap())
+                );
+                SuiClientCommandResult::SerializeTransferSui(Base64::encode(
+                    bcs::to_bytes(&intent_msg)?.as_slice(),
+                ))
+            }
+
+            SuiClientCommands::ExecuteSignedTx {
+                tx_bytes,
+                signature,
+            } => {
+                let data = bcs::from_bytes(
+                    &Base64::try_from(tx_bytes)
+                        .map_err(|e| anyhow!(e))?
+                        .to_vec()
+                        .map_err(|e| anyhow!(e))?,
+                )?;
+                let signature = Signature::from_bytes(
+                    &Base64::try_from(signature)
+                        .map_err(|e| anyhow!(e))?
+                        .to_vec()
+                        .map_err(|e| anyhow!(e))?,
+                )?;
+                let verified =
+                    Transaction::from_data(data, Intent::default(), signature).verify()?;
+
+                let response = context.execute_transaction(verified).await?;
+                SuiClientCommandResult::ExecuteSignedTx(response)
+            }
+            SuiClientCommands::NewEnv { alias, rpc, ws } => {
+                if context.config.envs.iter().any(|env| env.alias == alias) {
+                    return Err(anyhow!(
+                        "Environment config with name [{alias}] already exists."
+                    ));
+                }
+                let env = SuiEnv { alias, rpc, ws };
+
+                // Check urls are valid and server is reachable
+                env.create_rpc_client(None).await?;
+                context.config.envs.push(env.clone());
+                context.config.save()?;
+                SuiClientCommandResult::NewEnv(env)
+            }
+            SuiClientCommands::ActiveEnv => {
+                SuiClientCommandResult::ActiveEnv(context.config.active_env.clone())
+            }
+            SuiClientCommands::Envs => SuiClientCommandResult::Envs(
+                context.config.envs.clone(),
+                context.config.active_env.clone(),
+            ),
+            SuiClientCommands::VerifySource {
+                package_path,
+                build_config,
+                verify_deps,
+                skip_source,
+                address_override,
+            } => {
+                if skip_source && !verify_deps {
+                    return Err(anyhow!(
+                        "Source skipped and not verifying deps: Nothing to verify."
+                    ));
+                }
+
+                let compiled_package = build_move_package(
+                    &package_path,
+                    BuildConfig {
+                        config: build_config,
+                        run_bytecode_verifier: true,
+                        print_diags_to_stderr: true,
+                    },
+                )?;
+
+                let client = context.get_client().await?;
+
+                BytecodeSourceVerifier::new(client.read_api(), false)
+                    .verify_package(
+                        &compiled_package.package,
+                        verify_deps,
+                        match (skip_source, address_override) {
+                            (true, _) => SourceMode::Skip,
+                            (false, None) => SourceMode::Verify,
+                            (false, Some(addr)) => SourceMode::VerifyAt(addr.into()),
+                        },
+                    )
+                    .await?;
+
+                SuiClientCommandResult::VerifySource
+            }
+            SuiClientCommands::Publish { .. }
+            | SuiClientCommands::Call { .. }
+            | SuiClientCommands::Transfer { .. }
+            | SuiClientCommands::TransferSui { .. }
+            | SuiClientCommands::Pay { .. }
+            | SuiClientCommands::PaySui { .. }
+            | SuiClientCommands::PayAllSui { .. }
+            | SuiClientCommands::SplitCoin { .. }
+            | SuiClientCommands::MergeCoin { .. }
+            | SuiClientCommands::CreateExampleNFT { .. } => {
+                unreachable!("Transactions are built by SuiClientCommands::transaction_data")
+            }
+        });
+        ret
+    }
+
+    /// Builds the transaction of the command without signing or submitting it, and returns its
+    /// BCS serialized `TransactionData`. The bytes can be signed offline with `sui keytool
+    /// sign-tx` and submitted with `execute-signed-tx`.
+    pub async fn serialize_unsigned(
+        self,
+        context: &mut WalletContext,
+    ) -> Result<SuiClientCommandResult, anyhow::Error> {
+        let (_, data) = self
+            .transaction_data(context)
+            .await?
+            .ok_or_else(|| anyhow!("Only commands submitting a transaction can be serialized"))?;
+        Ok(SuiClientCommandResult::SerializeUnsigned(Base64::encode(
+            bcs::to_bytes(&data)?,
+        )))
+    }
+
+    /// Returns true for `tx-history` and `events` with `--follow`, which stream their results
+    /// with `follow` instead of returning them from `execute`.
+    pub fn is_follow(&self) -> bool {
+        matches!(
+            self,
+            SuiClientCommands::TxHistory { follow: true, .. }
+                | SuiClientCommands::Events { follow: true, .. }
+        )
+    }
+
+    /// Prints the transactions or events matching the command as the fullnode pushes them over
+    /// its WebSocket subscriptions, until the subscription is closed. Each result is printed as
+    /// a table row if `pretty` is set, or as a JSON object on its own line otherwise.
+    pub async fn follow(
+        self,
+        context: &mut WalletContext,
+        pretty: bool,
+    ) -> Result<(), anyhow::Error> {
+        let client = context.get_client().await?;
+        match self {
+            SuiClientCommands::TxHistory { .. } => {
+                let transactions = self.follow_transactions(&client).await?;
+                futures::pin_mut!(transactions);
+                if pretty {
+                    print_lines(&write_transactions_header()?);
+                }
+                while let Some(response) = transactions.next().await {
+                    let response = response?;
+                    print_lines(&if pretty {
+                        write_transaction_row(&response)?
+                    } else {
+                        serde_json::to_string(&response)?
+                    });
+                }
+            }
+            SuiClientCommands::Events { ref cursor, .. } => {
+                let filter = self.event_filter();
+                let mut events = client
+                    .event_api()
+                    .subscribe_event(filter, cursor.clone())
+                    .await?;
+                if pretty {
+                    print_lines(&write_events_header()?);
+                }
+                while let Some(event) = events.next().await {
+                    let event = event?;
+                    print_lines(&if pretty {
+                        write_event_row(&event)?
+                    } else {
+                        serde_json::to_string(&event)?
+                    });
+                }
+            }
+            _ => return Err(anyhow!("Only tx-history and events can follow new results")),
+        }
+        Ok(())
+    }
+
+    /// Subscribes to the new transactions matching the query of `tx-history`. The subscription
+    /// can't be filtered by the fullnode, so all its transactions are received and filtered
+    /// here. Input objects can't be matched, as read-only inputs are not part of the effects.
+    pub async fn follow_transactions(
+        &self,
+        client: &SuiClient,
+    ) -> Result<impl Stream<Item = Result<SuiTransactionResponse, anyhow::Error>>, anyhow::Error>
+    {
+        let query = self.transaction_query()?;
+        ensure!(
+            !matches!(query, TransactionQuery::InputObject(_)),
+            "Transactions can't be followed by input object"
+        );
+        let transactions = client
+            .read_api()
+            .subscribe_transaction(SuiTransactionFilter::Any)
+            .await?;
+        Ok(transactions.filter_map(move |response| {
+            futures::future::ready(match response {
+                Ok(response) if !transaction_matches(&query, &response) => None,
+                response => Some(response.map_err(anyhow::Error::from)),
+            })
+        }))
+    }
+
+    fn transaction_query(&self) -> Result<TransactionQuery, anyhow::Error> {
+        let SuiClientCommands::TxHistory {
+            from,
+            to,
+            input_object,
+            mutated_object,
+            package,
+            module,
+            function,
+            ..
+        } = self else {
+            return Err(anyhow!("Only tx-history has a transaction query"));
+        };
+        let criteria = [
+            from.is_some(),
+            to.is_some(),
+            input_object.is_some(),
+            mutated_object.is_some(),
+            package.is_some(),
+        ];
+        ensure!(
+            criteria.iter().filter(|given| **given).count() <= 1,
+            "At most one of --from, --to, --input-object, --mutated-object and --package can be used"
+        );
+        Ok(match (from, to, input_object, mutated_object, package) {
+            (Some(from), _, _, _, _) => TransactionQuery::FromAddress(*from),
+            (_, Some(to), _, _, _) => TransactionQuery::ToAddress(*to),
+            (_, _, Some(id), _, _) => TransactionQuery::InputObject(*id),
+            (_, _, _, Some(id), _) => TransactionQuery::MutatedObject(*id),
+            (_, _, _, _, Some(package)) => TransactionQuery::MoveFunction {
+                package: *package,
+                module: module.clone(),
+                function: function.clone(),
+            },
+            _ => TransactionQuery::All,
+        })
+    }
+
+    /// Combines the criteria of `events` into a single query matching all of them.
+    fn event_query(&self) -> Result<EventQuery, anyhow::Error> {
+        let SuiClientCommands::Events {
+            tx_digest,
+            package,
+            module,
+            event_type,
+            sender,
+            recipient,
+            object,
+            start_time,
+            end_time,
+            ..
+        } = self else {
+            return Err(anyhow!("Only events has an event query"));
+        };
+        let mut queries = vec![];
+        if let Some(digest) = tx_digest {
+            queries.push(EventQuery::Transaction(*digest));
+        }
+        if let (Some(package), Some(module)) = (package, module) {
+            queries.push(EventQuery::MoveModule {
+                package: *package,
+                module: module.clone(),
+            });
+        }
+        if let Some(event_type) = event_type {
+            queries.push(EventQuery::MoveEvent(event_type.clone()));
+        }
+        if let Some(sender) = sender {
+            queries.push(EventQuery::Sender(*sender));
+        }
+        if let Some(recipient) = recipient {
+            queries.push(EventQuery::Recipient(Owner::AddressOwner(*recipient)));
+        }
+        if let Some(object) = object {
+            queries.push(EventQuery::Object(*object));
+        }
+        if start_time.is_some() || end_time.is_some() {
+            queries.push(EventQuery::TimeRange {
+                start_time: start_time.unwrap_or_default(),
+                // Event stores compare timestamps as i64.
+                end_time: end_time.unwrap_or(i64::MAX as u64),
+            });
+        }
+        Ok(queries
+            .into_iter()
+            .reduce(|left, right| EventQuery::And(Box::new(left), Box::new(right)))
+            .unwrap_or(EventQuery::All))
+    }
+
+    /// Subscription filter of `events --follow`. The criteria the subscription can't filter on
+    /// conflict with `--follow`.
+    fn event_filter(&self) -> SuiEventFilter {
+        let mut filters = vec![];
+        if let SuiClientCommands::Events {
+            package,
+            module,
+            event_type,
+            sender,
+            object,
+            ..
+        } = self
+        {
+            if let Some(package) = package {
+                filters.push(SuiEventFilter::Package(*package));
+            }
+            if let Some(module) = module {
+                filters.push(SuiEventFilter::Module(module.clone()));
+            }
+            if let Some(event_type) = event_type {
+                filters.push(SuiEventFilter::MoveEventType(event_type.clone()));
+            }
+            if let Some(sender) = sender {
+                filters.push(SuiEventFilter::SenderAddress(*sender));
+            }
+            if let Some(object) = object {
+                filters.push(SuiEventFilter::ObjectId(*object));
+            }
+        }
+        SuiEventFilter::All(filters)
+    }
+
+    /// Returns the transaction of the commands that submit one, with the address that must sign
+    /// it, or `None` for the other commands.
+    async fn transaction_data(
+        &self,
+        context: &mut WalletContext,
+    ) -> Result<Option<(SuiAddress, TransactionData)>, anyhow::Error> {
+        Ok(Some(match self {
             SuiClientCommands::Publish {
                 package_path,
                 gas,
@@ -445,13 +1003,13 @@ impl SuiClientCommands {
                 gas_budget,
                 verify_dependencies,
             } => {
-                let sender = context.try_get_object_owner(&gas).await?;
+                let sender = context.try_get_object_owner(gas).await?;
                 let sender = sender.unwrap_or(context.active_address()?);
 
                 let compiled_package = build_move_package(
-                    &package_path,
+                    package_path,
                     BuildConfig {
-                        config: build_config,
+                        config: build_config.clone(),
                         run_bytecode_verifier: true,
                         print_diags_to_stderr: true,
                     },
@@ -473,7 +1031,7 @@ impl SuiClientCommands {
                 let compiled_modules = compiled_package.get_package_bytes();
 
                 let client = context.get_client().await?;
-                if verify_dependencies {
+                if *verify_dependencies {
                     BytecodeSourceVerifier::new(client.read_api(), false)
                         .verify_package_deps(&compiled_package.package)
                         .await?;
@@ -482,28 +1040,11 @@ impl SuiClientCommands {
 
                 let data = client
                     .transaction_builder()
-                    .publish(sender, compiled_modules, gas, gas_budget)
+                    .publish(sender, compiled_modules, *gas, *gas_budget)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&sender, &data, Intent::default())?;
-                let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), signature).verify()?,
-                    )
-                    .await?;
-
-                SuiClientCommandResult::Publish(response)
+                (sender, data)
             }
 
-            SuiClientCommands::Object { id, bcs } => {
-                // Fetch the object ref
-                let client = context.get_client().await?;
-                let object_read = client.read_api().get_parsed_object(id).await?;
-                SuiClientCommandResult::Object(object_read, bcs)
-            }
             SuiClientCommands::Call {
                 package,
                 module,
@@ -513,11 +1054,17 @@ impl SuiClientCommands {
                 gas_budget,
                 args,
             } => {
-                let (cert, effects) = call_move(
-                    package, &module, &function, type_args, gas, gas_budget, args, context,
+                move_call_data(
+                    *package,
+                    module,
+                    function,
+                    type_args.clone(),
+                    *gas,
+                    *gas_budget,
+                    args.clone(),
+                    context,
                 )
-                .await?;
-                SuiClientCommandResult::Call(cert, effects)
+                .await?
             }
 
             SuiClientCommands::Transfer {
@@ -526,32 +1073,13 @@ impl SuiClientCommands {
                 gas,
                 gas_budget,
             } => {
-                let from = context.get_object_owner(&object_id).await?;
-                let time_start = Instant::now();
-
+                let from = context.get_object_owner(object_id).await?;
                 let client = context.get_client().await?;
                 let data = client
                     .transaction_builder()
-                    .transfer_object(from, object_id, gas, gas_budget, to)
+                    .transfer_object(from, *object_id, *gas, *gas_budget, *to)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&from, &data, Intent::default())?;
-                let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), signature).verify()?,
-                    )
-                    .await?;
-                let cert = response.certificate;
-                let effects = response.effects;
-
-                let time_total = time_start.elapsed().as_micros();
-                if matches!(effects.status, SuiExecutionStatus::Failure { .. }) {
-                    return Err(anyhow!("Error transferring object: {:#?}", effects.status));
-                }
-                SuiClientCommandResult::Transfer(time_total, cert, effects)
+                (from, data)
             }
 
             SuiClientCommands::TransferSui {
@@ -560,30 +1088,13 @@ impl SuiClientCommands {
                 gas_budget,
                 amount,
             } => {
-                let from = context.get_object_owner(&object_id).await?;
-
+                let from = context.get_object_owner(object_id).await?;
                 let client = context.get_client().await?;
                 let data = client
                     .transaction_builder()
-                    .transfer_sui(from, object_id, gas_budget, to, amount)
+                    .transfer_sui(from, *object_id, *gas_budget, *to, *amount)
                     .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&from, &data, Intent::default())?;
-                let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), signature).verify()?,
-                    )
-                    .await?;
-                let cert = response.certificate;
-                let effects = response.effects;
-
-                if matches!(effects.status, SuiExecutionStatus::Failure { .. }) {
-                    return Err(anyhow!("Error transferring SUI: {:#?}", effects.status));
-                }
-                SuiClientCommandResult::TransferSui(cert, effects)
+                (from, data)
             }
 
             SuiClientCommands::Pay {
@@ -613,27 +1124,16 @@ impl SuiClientCommands {
                 let client = context.get_client().await?;
                 let data = client
                     .transaction_builder()
-                    .pay(from, input_coins, recipients, amounts, gas, gas_budget)
-                    .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&from, &data, Intent::default())?;
-                let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), signature).verify()?,
+                    .pay(
+                        from,
+                        input_coins.clone(),
+                        recipients.clone(),
+                        amounts.clone(),
+                        *gas,
+                        *gas_budget,
                     )
                     .await?;
-                let cert = response.certificate;
-                let effects = response.effects;
-                if matches!(effects.status, SuiExecutionStatus::Failure { .. }) {
-                    return Err(anyhow!(
-                        "Error executing Pay transaction: {:#?}",
-                        effects.status
-                    ));
-                }
-                SuiClientCommandResult::Pay(cert, effects)
+                (from, data)
             }
 
             SuiClientCommands::PaySui {
@@ -662,28 +1162,15 @@ impl SuiClientCommands {
                 let client = context.get_client().await?;
                 let data = client
                     .transaction_builder()
-                    .pay_sui(signer, input_coins, recipients, amounts, gas_budget)
-                    .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&signer, &data, Intent::default())?;
-                let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), signature).verify()?,
+                    .pay_sui(
+                        signer,
+                        input_coins.clone(),
+                        recipients.clone(),
+                        amounts.clone(),
+                        *gas_budget,
                     )
                     .await?;
-
-                let cert = response.certificate;
-                let effects = response.effects;
-                if matches!(effects.status, SuiExecutionStatus::Failure { .. }) {
-                    return Err(anyhow!(
-                        "Error executing PaySui transaction: {:#?}",
-                        effects.status
-                    ));
-                }
-                SuiClientCommandResult::PaySui(cert, effects)
+                (signer, data)
             }
 
             SuiClientCommands::PayAllSui {
@@ -693,72 +1180,17 @@ impl SuiClientCommands {
             } => {
                 ensure!(
                     !input_coins.is_empty(),
-                    "PayAllSui transaction requires a non-empty list of input coins"
-                );
-                let signer = context.get_object_owner(&input_coins[0]).await?;
-                let client = context.get_client().await?;
-                let data = client
-                    .transaction_builder()
-                    .pay_all_sui(signer, input_coins, recipient, gas_budget)
-                    .await?;
-
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&signer, &data, Intent::default())?;
-                let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), signature).verify()?,
-                    )
-                    .await?;
-
-                let cert = response.certificate;
-                let effects = response.effects;
-                if matches!(effects.status, SuiExecutionStatus::Failure { .. }) {
-                    return Err(anyhow!(
-                        "Error executing PayAllSui transaction: {:#?}",
-                        effects.status
-                    ));
-                }
-                SuiClientCommandResult::PayAllSui(cert, effects)
-            }
-
-            SuiClientCommands::Addresses => {
-                SuiClientCommandResult::Addresses(context.config.keystore.addresses())
-            }
-
-            SuiClientCommands::Objects { address } => {
-                let address = address.unwrap_or(context.active_address()?);
+                    "PayAllSui transaction requires a non-empty list of input coins"
+                );
+                let signer = context.get_object_owner(&input_coins[0]).await?;
                 let client = context.get_client().await?;
-                let address_object = client
-                    .read_api()
-                    .get_objects_owned_by_address(address)
+                let data = client
+                    .transaction_builder()
+                    .pay_all_sui(signer, input_coins.clone(), *recipient, *gas_budget)
                     .await?;
-                SuiClientCommandResult::Objects(address_object)
+                (signer, data)
             }
 
-            SuiClientCommands::NewAddress {
-                key_scheme,
-                derivation_path,
-            } => {
-                let (address, phrase, scheme) = context
-                    .config
-                    .keystore
-                    .generate_and_add_new_key(key_scheme, derivation_path)?;
-                SuiClientCommandResult::NewAddress((address, phrase, scheme))
-            }
-            SuiClientCommands::Gas { address } => {
-                let address = address.unwrap_or(context.active_address()?);
-                let coins = context
-                    .gas_objects(address)
-                    .await?
-                    .iter()
-                    // Ok to unwrap() since `get_gas_objects` guarantees gas
-                    .map(|(_val, object, _object_ref)| GasCoin::try_from(object).unwrap())
-                    .collect();
-                SuiClientCommandResult::Gas(coins)
-            }
             SuiClientCommands::SplitCoin {
                 coin_id,
                 amounts,
@@ -766,40 +1198,31 @@ impl SuiClientCommands {
                 gas,
                 gas_budget,
             } => {
-                let signer = context.get_object_owner(&coin_id).await?;
+                let signer = context.get_object_owner(coin_id).await?;
                 let client = context.get_client().await?;
                 let data = match (amounts, count) {
                     (Some(amounts), None) => {
                         client
                             .transaction_builder()
-                            .split_coin(signer, coin_id, amounts, gas, gas_budget)
+                            .split_coin(signer, *coin_id, amounts.clone(), *gas, *gas_budget)
                             .await?
                     }
                     (None, Some(count)) => {
-                        if count == 0 {
+                        if *count == 0 {
                             return Err(anyhow!("Coin split count must be greater than 0"));
                         }
                         client
                             .transaction_builder()
-                            .split_coin_equal(signer, coin_id, count, gas, gas_budget)
+                            .split_coin_equal(signer, *coin_id, *count, *gas, *gas_budget)
                             .await?
                     }
                     _ => {
                         return Err(anyhow!("Exactly one of `count` and `amounts` must be present for split-coin command."));
                     }
                 };
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&signer, &data, Intent::default())?;
-                let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), signature).verify()?,
-                    )
-                    .await?;
-                SuiClientCommandResult::SplitCoin(response)
+                (signer, data)
             }
+
             SuiClientCommands::MergeCoin {
                 primary_coin,
                 coin_to_merge,
@@ -807,43 +1230,14 @@ impl SuiClientCommands {
                 gas_budget,
             } => {
                 let client = context.get_client().await?;
-                let signer = context.get_object_owner(&primary_coin).await?;
+                let signer = context.get_object_owner(primary_coin).await?;
                 let data = client
                     .transaction_builder()
-                    .merge_coins(signer, primary_coin, coin_to_merge, gas, gas_budget)
-                    .await?;
-                let signature =
-                    context
-                        .config
-                        .keystore
-                        .sign_secure(&signer, &data, Intent::default())?;
-                let response = context
-                    .execute_transaction(
-                        Transaction::from_data(data, Intent::default(), signature).verify()?,
-                    )
+                    .merge_coins(signer, *primary_coin, *coin_to_merge, *gas, *gas_budget)
                     .await?;
-
-                SuiClientCommandResult::MergeCoin(response)
-            }
-            SuiClientCommands::Switch { address, env } => {
-                match (address, &env) {
-                    (None, Some(env)) => {
-                        Self::switch_env(&mut context.config, env)?;
-                    }
-                    (Some(addr), None) => {
-                        if !context.config.keystore.addresses().contains(&addr) {
-                            return Err(anyhow!("Address {} not managed by wallet", addr));
-                        }
-                        context.config.active_address = Some(addr);
-                    }
-                    _ => return Err(anyhow!("No address or env specified. Please Specify one.")),
-                }
-                context.config.save()?;
-                SuiClientCommandResult::Switch(SwitchResponse { address, env })
-            }
-            SuiClientCommands::ActiveAddress => {
-                SuiClientCommandResult::ActiveAddress(context.active_address().ok())
+                (signer, data)
             }
+
             SuiClientCommands::CreateExampleNFT {
                 name,
                 description,
@@ -852,142 +1246,108 @@ impl SuiClientCommands {
                 gas_budget,
             } => {
                 let args_json = json!([
-                    unwrap_or(&name, EXAMPLE_NFT_NAME),
-                    unwrap_or(&description, EXAMPLE_NFT_DESCRIPTION),
-                    unwrap_or(&url, EXAMPLE_NFT_URL)
+                    unwrap_or(name, EXAMPLE_NFT_NAME),
+                    unwrap_or(description, EXAMPLE_NFT_DESCRIPTION),
+                    unwrap_or(url, EXAMPLE_NFT_URL)
                 ]);
                 let mut args = vec![];
                 for a in args_json.as_array().unwrap() {
                     args.push(SuiJsonValue::new(a.clone()).unwrap());
                 }
-                let (_, effects) = call_move(
+                move_call_data(
                     ObjectID::from(SUI_FRAMEWORK_ADDRESS),
                     "devnet_nft",
                     "mint",
                     vec![],
-                    gas,
+                    *gas,
                     gas_budget.unwrap_or(100_000),
                     args,
                     context,
                 )
-                .await?;
-                let nft_id = effects
-                    .created
-                    .first()
-                    .ok_or_else(|| anyhow!("Failed to create NFT"))?
-                    .reference
-                    .object_id;
-                let client = context.get_client().await?;
-                let object_read = client.read_api().get_parsed_object(nft_id).await?;
-                SuiClientCommandResult::CreateExampleNFT(object_read)
-            }
-
-            SuiClientCommands::SerializeTransferSui {
-                to,
-                sui_coin_object_id: object_id,
-                gas_budget,
-                amount,
-            } => {
-                let from = context.get_object_owner(&object_id).await?;
-                let client = context.get_client().await?;
-                let data = client
-                    .transaction_builder()
-                    .transfer_sui(from, object_id, gas_budget, to, amount)
-                    .await?;
-                let data1 = data.clone();
-                let intent_msg = IntentMessage::new(Intent::default(), data);
-                info!(
-                    "Transaction bytes : {}",
-                    Base64::encode(&bcs::to_bytes(&data1).unwrap())
-                );
-                SuiClientCommandResult::SerializeTransferSui(Base64::encode(
-                    bcs::to_bytes(&intent_msg)?.as_slice(),
-                ))
+                .await?
             }
 
-            SuiClientCommands::ExecuteSignedTx {
-                tx_bytes,
-                signature,
-            } => {
-                let data = bcs::from_bytes(
-                    &Base64::try_from(tx_bytes)
-                        .map_err(|e| anyhow!(e))?
-                        .to_vec()
-                        .map_err(|e| anyhow!(e))?,
-                )?;
-                let signature = Signature::from_bytes(
-                    &Base64::try_from(signature)
-                        .map_err(|e| anyhow!(e))?
-                        .to_vec()
-                        .map_err(|e| anyhow!(e))?,
-                )?;
-                let verified =
-                    Transaction::from_data(data, Intent::default(), signature).verify()?;
+            _ => return Ok(None),
+        }))
+    }
 
-                let response = context.execute_transaction(verified).await?;
-                SuiClientCommandResult::ExecuteSignedTx(response)
+    /// Converts the response of a transaction submitted by `execute` into the result of the
+    /// command.
+    async fn transaction_result(
+        self,
+        context: &mut WalletContext,
+        response: SuiTransactionResponse,
+        time_start: Instant,
+    ) -> Result<SuiClientCommandResult, anyhow::Error> {
+        let cert = response.certificate.clone();
+        let effects = response.effects.clone();
+        let failed = matches!(effects.status, SuiExecutionStatus::Failure { .. });
+        Ok(match self {
+            SuiClientCommands::Publish { .. } => SuiClientCommandResult::Publish(response),
+            SuiClientCommands::Call { .. } => {
+                if failed {
+                    return Err(anyhow!("Error calling module: {:#?}", effects.status));
+                }
+                SuiClientCommandResult::Call(cert, effects)
             }
-            SuiClientCommands::NewEnv { alias, rpc, ws } => {
-                if context.config.envs.iter().any(|env| env.alias == alias) {
+            SuiClientCommands::Transfer { .. } => {
+                let time_total = time_start.elapsed().as_micros();
+                if failed {
+                    return Err(anyhow!("Error transferring object: {:#?}", effects.status));
+                }
+                SuiClientCommandResult::Transfer(time_total, cert, effects)
+            }
+            SuiClientCommands::TransferSui { .. } => {
+                if failed {
+                    return Err(anyhow!("Error transferring SUI: {:#?}", effects.status));
+                }
+                SuiClientCommandResult::TransferSui(cert, effects)
+            }
+            SuiClientCommands::Pay { .. } => {
+                if failed {
                     return Err(anyhow!(
-                        "Environment config with name [{alias}] already exists."
+                        "Error executing Pay transaction: {:#?}",
+                        effects.status
                     ));
                 }
-                let env = SuiEnv { alias, rpc, ws };
-
-                // Check urls are valid and server is reachable
-                env.create_rpc_client(None).await?;
-                context.config.envs.push(env.clone());
-                context.config.save()?;
-                SuiClientCommandResult::NewEnv(env)
+                SuiClientCommandResult::Pay(cert, effects)
             }
-            SuiClientCommands::ActiveEnv => {
-                SuiClientCommandResult::ActiveEnv(context.config.active_env.clone())
+            SuiClientCommands::PaySui { .. } => {
+                if failed {
+                    return Err(anyhow!(
+                        "Error executing PaySui transaction: {:#?}",
+                        effects.status
+                    ));
+                }
+                SuiClientCommandResult::PaySui(cert, effects)
             }
-            SuiClientCommands::Envs => SuiClientCommandResult::Envs(
-                context.config.envs.clone(),
-                context.config.active_env.clone(),
-            ),
-            SuiClientCommands::VerifySource {
-                package_path,
-                build_config,
-                verify_deps,
-                skip_source,
-                address_override,
-            } => {
-                if skip_source && !verify_deps {
+            SuiClientCommands::PayAllSui { .. } => {
+                if failed {
                     return Err(anyhow!(
-                        "Source skipped and not verifying deps: Nothing to verify."
+                        "Error executing PayAllSui transaction: {:#?}",
+                        effects.status
                     ));
                 }
-
-                let compiled_package = build_move_package(
-                    &package_path,
-                    BuildConfig {
-                        config: build_config,
-                        run_bytecode_verifier: true,
-                        print_diags_to_stderr: true,
-                    },
-                )?;
-
+                SuiClientCommandResult::PayAllSui(cert, effects)
+            }
+            SuiClientCommands::SplitCoin { .. } => SuiClientCommandResult::SplitCoin(response),
+            SuiClientCommands::MergeCoin { .. } => SuiClientCommandResult::MergeCoin(response),
+            SuiClientCommands::CreateExampleNFT { .. } => {
+                if failed {
+                    return Err(anyhow!("Error calling module: {:#?}", effects.status));
+                }
+                let nft_id = effects
+                    .created
+                    .first()
+                    .ok_or_else(|| anyhow!("Failed to create NFT"))?
+                    .reference
+                    .object_id;
                 let client = context.get_client().await?;
-
-                BytecodeSourceVerifier::new(client.read_api(), false)
-                    .verify_package(
-                        &compiled_package.package,
-                        verify_deps,
-                        match (skip_source, address_override) {
-                            (true, _) => SourceMode::Skip,
-                            (false, None) => SourceMode::Verify,
-                            (false, Some(addr)) => SourceMode::VerifyAt(addr.into()),
-                        },
-                    )
-                    .await?;
-
-                SuiClientCommandResult::VerifySource
+                let object_read = client.read_api().get_parsed_object(nft_id).await?;
+                SuiClientCommandResult::CreateExampleNFT(object_read)
             }
-        });
-        ret
+            _ => unreachable!("Only commands submitting a transaction have a transaction result"),
+        })
     }
 
     pub fn switch_env(config: &mut SuiClientConfig, env: &str) -> Result<(), anyhow::Error> {
@@ -1073,16 +1433,41 @@ impl WalletContext {
         Ok(client.read_api().get_object(object_id).await?)
     }
 
+    /// Get all the objects owned by the address, reading every page. Unlike the stream from
+    /// the SDK, a failure to read a page is returned instead of ending the list early.
+    pub async fn get_owned_objects(
+        &self,
+        address: SuiAddress,
+        object_type: Option<ObjectTypeFilter>,
+    ) -> Result<Vec<SuiObjectInfo>, anyhow::Error> {
+        let client = self.get_client().await?;
+        let mut objects = vec![];
+        let mut cursor = None;
+        loop {
+            let page = client
+                .read_api()
+                .get_objects_owned_by_address(address, object_type.clone(), cursor, None)
+                .await?;
+            objects.extend(page.data);
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                return Ok(objects);
+            }
+        }
+    }
+
     /// Get all the gas objects (and conveniently, gas amounts) for the address
     pub async fn gas_objects(
         &self,
         address: SuiAddress,
     ) -> Result<Vec<(u64, SuiParsedObject, SuiObjectInfo)>, anyhow::Error> {
         let client = self.get_client().await?;
-        let object_refs = client
-            .read_api()
-            .get_objects_owned_by_address(address)
-            .await?;
+        let coin_filter = ObjectTypeFilter::StructType {
+            package: SUI_FRAMEWORK_OBJECT_ID,
+            module: COIN_MODULE_NAME.to_string(),
+            name: COIN_STRUCT_NAME.to_string(),
+        };
+        let object_refs = self.get_owned_objects(address, Some(coin_filter)).await?;
 
         // TODO: We should ideally fetch the objects from local cache
         let mut values_objects = Vec::new();
@@ -1273,6 +1658,30 @@ impl Display for SuiClientCommandResult {
                     writeln!(writer, " {0: ^42} | {1: ^11}", gas.id(), gas.value())?;
                 }
             }
+            SuiClientCommandResult::TxHistory(page) => {
+                write!(writer, "{}", write_transactions_header()?)?;
+                for response in &page.data {
+                    write!(writer, "{}", write_transaction_row(response)?)?;
+                }
+                writeln!(writer, "Showing {} results.", page.data.len())?;
+                if let Some(cursor) = page.next_cursor {
+                    writeln!(writer, "Next cursor: {}", Base58::encode(cursor))?;
+                }
+            }
+            SuiClientCommandResult::Events(page) => {
+                write!(writer, "{}", write_events_header()?)?;
+                for event in &page.data {
+                    write!(writer, "{}", write_event_row(event)?)?;
+                }
+                writeln!(writer, "Showing {} results.", page.data.len())?;
+                if let Some(cursor) = &page.next_cursor {
+                    writeln!(
+                        writer,
+                        "Next cursor: {}:{}",
+                        cursor.tx_seq, cursor.event_seq
+                    )?;
+                }
+            }
             SuiClientCommandResult::SplitCoin(response) => {
                 write!(
                     writer,
@@ -1321,6 +1730,9 @@ impl Display for SuiClientCommandResult {
             SuiClientCommandResult::SerializeTransferSui(res) => {
                 write!(writer, "Data to sign: {}", res)?;
             }
+            SuiClientCommandResult::SerializeUnsigned(tx_bytes) => {
+                write!(writer, "Transaction bytes to sign: {}", tx_bytes)?;
+            }
             SuiClientCommandResult::ActiveEnv(env) => {
                 write!(writer, "{}", env.as_deref().unwrap_or("None"))?;
             }
@@ -1344,7 +1756,7 @@ impl Display for SuiClientCommandResult {
     }
 }
 
-pub async fn call_move(
+async fn move_call_data(
     package: ObjectID,
     module: &str,
     function: &str,
@@ -1353,7 +1765,7 @@ pub async fn call_move(
     gas_budget: u64,
     args: Vec<SuiJsonValue>,
     context: &mut WalletContext,
-) -> Result<(SuiCertifiedTransaction, SuiTransactionEffects), anyhow::Error> {
+) -> Result<(SuiAddress, TransactionData), anyhow::Error> {
     let gas_owner = context.try_get_object_owner(&gas).await?;
     let sender = gas_owner.unwrap_or(context.active_address()?);
 
@@ -1374,20 +1786,7 @@ pub async fn call_move(
             gas_budget,
         )
         .await?;
-    let signature = context
-        .config
-        .keystore
-        .sign_secure(&sender, &data, Intent::default())?;
-    let transaction = Transaction::from_data(data, Intent::default(), signature).verify()?;
-
-    let response = context.execute_transaction(transaction).await?;
-    let cert = response.certificate;
-    let effects = response.effects;
-
-    if matches!(effects.status, SuiExecutionStatus::Failure { .. }) {
-        return Err(anyhow!("Error calling module: {:#?}", effects.status));
-    }
-    Ok((cert, effects))
+    Ok((sender, data))
 }
 
 fn unwrap_or<'a>(val: &'a Option<String>, default: &'a str) -> &'a str {
@@ -1409,6 +1808,163 @@ fn write_cert_and_effects(
     Ok(writer)
 }
 
+/// Maximum number of transactions fetched by a single `multi_get_transactions` request.
+const MULTI_GET_TRANSACTIONS_LIMIT: usize = 50;
+
+/// Returns up to `limit` transactions matching `query`, starting at `cursor`. Transactions aren't
+/// indexed by time, so the ones executed outside of `[start_time, end_time)` are filtered out
+/// here, fetching more pages until the result is filled. This is a linear scan over all the
+/// transactions matching `query`: their `timestamp_ms` is the time of their checkpoint, which
+/// doesn't follow the execution order of the query results, so the scan can't stop at the
+/// first transaction outside of the time range.
+async fn transaction_history(
+    client: &SuiClient,
+    query: TransactionQuery,
+    mut cursor: Option<TransactionDigest>,
+    limit: usize,
+    descending: bool,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+) -> Result<Page<SuiTransactionResponse, TransactionDigest>, anyhow::Error> {
+    let mut data = vec![];
+    loop {
+        let page = client
+            .read_api()
+            .get_transactions(query.clone(), cursor, Some(limit), descending)
+            .await?;
+        for digests in page.data.chunks(MULTI_GET_TRANSACTIONS_LIMIT) {
+            let reads = client
+                .read_api()
+                .multi_get_transactions(digests.to_vec())
+                .await?;
+            for read in reads {
+                let response = read.into_transaction()?;
+                if data.len() == limit {
+                    return Ok(Page {
+                        data,
+                        next_cursor: Some(response.certificate.transaction_digest),
+                    });
+                }
+                match response.timestamp_ms {
+                    Some(timestamp)
+                        if start_time.map_or(true, |t| timestamp >= t)
+                            && end_time.map_or(true, |t| timestamp < t) =>
+                    {
+                        data.push(response)
+                    }
+                    None if start_time.is_none() && end_time.is_none() => data.push(response),
+                    _ => {}
+                }
+            }
+        }
+        if data.len() == limit || page.next_cursor.is_none() {
+            return Ok(Page {
+                data,
+                next_cursor: page.next_cursor,
+            });
+        }
+        cursor = page.next_cursor;
+    }
+}
+
+/// Returns true if the transaction matches `query`, to filter the transaction subscription.
+/// `InputObject` queries never match, as read-only inputs are not part of the effects.
+fn transaction_matches(query: &TransactionQuery, response: &SuiTransactionResponse) -> bool {
+    let effects = &response.effects;
+    match query {
+        TransactionQuery::All => true,
+        TransactionQuery::FromAddress(address) => response.certificate.data.sender == *address,
+        TransactionQuery::ToAddress(address) => effects
+            .created
+            .iter()
+            .chain(&effects.mutated)
+            .chain(&effects.unwrapped)
+            .any(|object| object.owner == Owner::AddressOwner(*address)),
+        TransactionQuery::InputObject(_) => false,
+        TransactionQuery::MutatedObject(id) => effects
+            .mutated
+            .iter()
+            .any(|object| object.reference.object_id == *id),
+        TransactionQuery::MoveFunction {
+            package,
+            module,
+            function,
+        } => response.certificate.data.transactions.iter().any(|tx| {
+            matches!(tx, SuiTransactionKind::Call(call)
+                if call.package.object_id == *package
+                    && module.as_ref().map_or(true, |m| *m == call.module)
+                    && function.as_ref().map_or(true, |f| *f == call.function))
+        }),
+    }
+}
+
+/// Parses an event ID written as `tx_seq:event_seq`.
+fn parse_event_id(s: &str) -> Result<EventID, anyhow::Error> {
+    let (tx_seq, event_seq) = s
+        .split_once(':')
+        .ok_or_else(|| anyhow!("Event ID must be written as `tx_seq:event_seq`, got {s}"))?;
+    Ok(EventID::from((tx_seq.parse()?, event_seq.parse()?)))
+}
+
+fn write_transactions_header() -> Result<String, fmt::Error> {
+    let mut writer = String::new();
+    writeln!(
+        writer,
+        " {0: ^44} | {1: ^42} | {2: ^13} | {3: ^7}",
+        "Transaction Digest", "Sender", "Timestamp", "Status"
+    )?;
+    writeln!(writer, "{}", ["-"; 116].join(""))?;
+    Ok(writer)
+}
+
+fn write_transaction_row(response: &SuiTransactionResponse) -> Result<String, fmt::Error> {
+    let mut writer = String::new();
+    let status = match response.effects.status {
+        SuiExecutionStatus::Success => "success",
+        SuiExecutionStatus::Failure { .. } => "failure",
+    };
+    writeln!(
+        writer,
+        " {0: ^44} | {1: ^42} | {2: ^13} | {3: ^7}",
+        Base58::encode(response.certificate.transaction_digest),
+        response.certificate.data.sender,
+        response
+            .timestamp_ms
+            .map(|t| t.to_string())
+            .unwrap_or_default(),
+        status
+    )?;
+    Ok(writer)
+}
+
+fn write_events_header() -> Result<String, fmt::Error> {
+    let mut writer = String::new();
+    writeln!(
+        writer,
+        " {0: ^21} | {1: ^44} | {2: ^13} | {3}",
+        "Event ID", "Transaction Digest", "Timestamp", "Event Type"
+    )?;
+    writeln!(writer, "{}", ["-"; 120].join(""))?;
+    Ok(writer)
+}
+
+fn write_event_row(envelope: &SuiEventEnvelope) -> Result<String, fmt::Error> {
+    let mut writer = String::new();
+    let event_type = match &envelope.event {
+        SuiEvent::MoveEvent { type_, .. } => type_.clone(),
+        event => event.get_event_type(),
+    };
+    writeln!(
+        writer,
+        " {0: ^21} | {1: ^44} | {2: ^13} | {3}",
+        format!("{}:{}", envelope.id.tx_seq, envelope.id.event_seq),
+        envelope.tx_digest.map(Base58::encode).unwrap_or_default(),
+        envelope.timestamp,
+        event_type
+    )?;
+    Ok(writer)
+}
+
 impl Debug for SuiClientCommandResult {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = unwrap_err_to_string(|| match self {
@@ -1440,12 +1996,16 @@ impl SuiClientCommandResult {
         } else {
             format!("{:?}", self)
         };
-        // Log line by line
-        for line in line.lines() {
-            // Logs write to a file on the side.  Print to stdout and also log to file, for tests to pass.
-            println!("{line}");
-            info!("{line}")
-        }
+        print_lines(&line);
+    }
+}
+
+fn print_lines(lines: &str) {
+    // Log line by line
+    for line in lines.lines() {
+        // Logs write to a file on the side.  Print to stdout and also log to file, for tests to pass.
+        println!("{line}");
+        info!("{line}")
     }
 }
 
@@ -1479,8 +2039,11 @@ pub enum SuiClientCommandResult {
     Envs(Vec<SuiEnv>, Option<String>),
     CreateExampleNFT(GetObjectDataResponse),
     SerializeTransferSui(String),
+    SerializeUnsigned(String),
     ExecuteSignedTx(SuiTransactionResponse),
     NewEnv(SuiEnv),
+    TxHistory(Page<SuiTransactionResponse, TransactionDigest>),
+    Events(EventPage),
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -31,6 +31,10 @@ pub struct ConsoleOpts {
     /// Returns command outputs in JSON format.
     #[clap(long, global = true)]
     pub json: bool,
+    /// Outputs the BCS serialized transaction data of the command instead of signing and
+    /// submitting it.
+    #[clap(long, global = true)]
+    pub serialize_unsigned: bool,
 }
 
 pub async fn start_console(
@@ -120,7 +124,18 @@ async fn handle_command(
     completion_cache: CompletionCache,
 ) -> Result<bool, anyhow::Error> {
     let wallet_opts = wallet_opts?;
-    let result = wallet_opts.command.execute(context).await?;
+    if wallet_opts.command.is_follow() {
+        wallet_opts
+            .command
+            .follow(context, !wallet_opts.json)
+            .await?;
+        return Ok(false);
+    }
+    let result = if wallet_opts.serialize_unsigned {
+        wallet_opts.command.serialize_unsigned(context).await?
+    } else {
+        wallet_opts.command.execute(context).await?
+    };
 
     // Update completion cache
     // TODO: Completion data are keyed by strings, are there ways to make it more error proof?
@@ -52,6 +52,23 @@ pub enum KeyToolCommand {
         #[clap(long)]
         data: String,
     },
+    /// Sign a serialized transaction with one or more keys of the keystore, and print the
+    /// signatures. The signature of the sender is submitted with `sui client execute-signed-tx`.
+    /// Only the keystore is read, so this can run on an offline device, e.g. for transactions
+    /// serialized with `--serialize-unsigned`.
+    SignTx {
+        /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string.
+        #[clap(long)]
+        tx_bytes: String,
+        /// Addresses of the keys to sign with. Defaults to the sender of the transaction.
+        #[clap(
+            long,
+            multiple_occurrences = false,
+            multiple_values = true,
+            parse(try_from_str = decode_bytes_hex)
+        )]
+        addresses: Vec<SuiAddress>,
+    },
     /// Import mnemonic phrase and generate keypair based on key scheme flag {ed25519 | secp256k1}
     /// with optional derivation path, default to m/44'/784'/0'/0'/0' for ed25519 or m/54'/784'/0'/0/0 for secp256k1 or m/74'/784'/0'/0/0 for secp256r1.
     Import {
@@ -143,6 +160,16 @@ impl KeyToolCommand {
                 info!("Signature : {}", signature);
                 info!("Serialized signature Base64: {:?}", sui_signature);
             }
+            KeyToolCommand::SignTx {
+                tx_bytes,
+                addresses,
+            } => {
+                info!("Data to sign : {}", tx_bytes);
+                for (address, signature) in sign_transaction(keystore, &tx_bytes, &addresses)? {
+                    info!("Address : {}", address);
+                    info!("Serialized signature Base64: {}", signature);
+                }
+            }
             KeyToolCommand::Import {
                 mnemonic_phrase,
                 key_scheme,
@@ -181,6 +208,32 @@ impl KeyToolCommand {
     }
 }
 
+/// Sign the serialized transaction with the keys of `addresses`, or with the key of its sender
+/// if `addresses` is empty. A transaction has a single sender, and only its signature is
+/// accepted by `execute-signed-tx`.
+/// Returns every address with its Base64 encoded signature `flag || signature || pubkey`.
+pub fn sign_transaction(
+    keystore: &Keystore,
+    tx_bytes: &str,
+    addresses: &[SuiAddress],
+) -> Result<Vec<(SuiAddress, String)>, anyhow::Error> {
+    let message = Base64::decode(tx_bytes).map_err(|e| anyhow!(e))?;
+    let tx_data: TransactionData = bcs::from_bytes(&message).map_err(|e| anyhow!(e))?;
+    let sender = [tx_data.signer()];
+    let addresses = if addresses.is_empty() {
+        &sender[..]
+    } else {
+        addresses
+    };
+    addresses
+        .iter()
+        .map(|address| -> Result<_, anyhow::Error> {
+            let signature = keystore.sign_secure(address, &tx_data, Intent::default())?;
+            Ok((*address, Base64::encode(signature.as_ref())))
+        })
+        .collect()
+}
+
 fn store_and_print_keypair(address: SuiAddress, keypair: SuiKeyPair) {
     let path_str = format!("{}.key", address).to_lowercase();
     let path = Path::new(&path_str);
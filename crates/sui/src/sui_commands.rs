@@ -97,6 +97,10 @@ pub enum SuiCommand {
         /// Return command outputs in json format.
         #[clap(long, global = true)]
         json: bool,
+        /// Output the BCS serialized transaction data of the command instead of signing and
+        /// submitting it, e.g. to sign it offline with `sui keytool sign-tx`.
+        #[clap(long, global = true)]
+        serialize_unsigned: bool,
     },
 
     /// Tool to build and test Move applications.
@@ -200,12 +204,26 @@ impl SuiCommand {
                 let context = WalletContext::new(&config, None).await?;
                 start_console(context, &mut stdout(), &mut stderr()).await
             }
-            SuiCommand::Client { config, cmd, json } => {
+            SuiCommand::Client {
+                config,
+                cmd,
+                json,
+                serialize_unsigned,
+            } => {
                 let config_path = config.unwrap_or(sui_config_dir()?.join(SUI_CLIENT_CONFIG));
                 prompt_if_no_config(&config_path).await?;
                 let mut context = WalletContext::new(&config_path, None).await?;
                 if let Some(cmd) = cmd {
-                    cmd.execute(&mut context).await?.print(!json);
+                    if cmd.is_follow() {
+                        cmd.follow(&mut context, !json).await?;
+                        return Ok(());
+                    }
+                    let result = if serialize_unsigned {
+                        cmd.serialize_unsigned(&mut context).await?
+                    } else {
+                        cmd.execute(&mut context).await?
+                    };
+                    result.print(!json);
                 } else {
                     // Print help
                     let mut app: Command = SuiCommand::command();
@@ -6,9 +6,11 @@ use std::{fmt::Write, fs::read_dir, path::PathBuf, str, thread, time::Duration};
 use anyhow::anyhow;
 use move_package::BuildConfig;
 use serde_json::json;
-use tokio::time::sleep;
+use tokio::time::{sleep, timeout};
 
+use clap::Parser;
 use sui::client_commands::SwitchResponse;
+use sui::keytool::sign_transaction;
 use sui::{
     client_commands::{SuiClientCommandResult, SuiClientCommands, WalletContext},
     config::SuiClientConfig,
@@ -141,8 +143,9 @@ async fn test_objects_command() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let _object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     Ok(())
 }
@@ -274,8 +277,9 @@ async fn test_object_info_get_command() -> Result<(), anyhow::Error> {
 
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     // Check log output contains all object ids.
     let object_id = object_refs.first().unwrap().object_id;
@@ -308,8 +312,9 @@ async fn test_gas_command() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     let object_id = object_refs.first().unwrap().object_id;
     let object_to_send = object_refs.get(1).unwrap().object_id;
@@ -357,8 +362,9 @@ async fn test_move_call_args_linter_command() -> Result<(), anyhow::Error> {
     // publish the object basics package
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address1)
-        .await?;
+        .get_objects_owned_by_address(address1, None, None, None)
+        .await?
+        .data;
     let gas_obj_id = object_refs.first().unwrap().object_id;
     let mut package_path = PathBuf::from(TEST_DATA_DIR);
     package_path.push("move_call_args_linter");
@@ -390,8 +396,9 @@ async fn test_move_call_args_linter_command() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address1)
-        .await?;
+        .get_objects_owned_by_address(address1, None, None, None)
+        .await?
+        .data;
 
     // Create an object for address1 using Move call
 
@@ -521,8 +528,9 @@ async fn test_package_publish_command() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     // Check log output contains all object ids.
     let gas_obj_id = object_refs.first().unwrap().object_id;
@@ -574,8 +582,9 @@ async fn test_native_transfer() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     // Check log output contains all object ids.
     let gas_obj_id = object_refs.first().unwrap().object_id;
@@ -654,8 +663,9 @@ async fn test_native_transfer() -> Result<(), anyhow::Error> {
 
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     // Check log output contains all object ids.
     let obj_id = object_refs.get(1).unwrap().object_id;
@@ -724,9 +734,10 @@ async fn test_switch_command() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let mut actual_objs = client
         .read_api()
-        .get_objects_owned_by_address(addr1)
+        .get_objects_owned_by_address(addr1, None, None, None)
         .await
-        .unwrap();
+        .unwrap()
+        .data;
     cmd_objs.sort();
     actual_objs.sort();
     assert_eq!(cmd_objs, actual_objs);
@@ -899,8 +910,9 @@ async fn test_merge_coin() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     // Check log output contains all object ids.
     let gas = object_refs.first().unwrap().object_id;
@@ -940,8 +952,9 @@ async fn test_merge_coin() -> Result<(), anyhow::Error> {
 
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     let primary_coin = object_refs.get(1).unwrap().object_id;
     let coin_to_merge = object_refs.get(2).unwrap().object_id;
@@ -990,8 +1003,9 @@ async fn test_split_coin() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     // Check log output contains all object ids.
     let gas = object_refs.first().unwrap().object_id;
@@ -1038,8 +1052,9 @@ async fn test_split_coin() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     // Get another coin
     for c in object_refs {
@@ -1091,8 +1106,9 @@ async fn test_split_coin() -> Result<(), anyhow::Error> {
 
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
 
     // Get another coin
     for c in object_refs {
@@ -1186,8 +1202,9 @@ async fn test_serialize_tx() -> Result<(), anyhow::Error> {
     let client = context.get_client().await?;
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(address)
-        .await?;
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
     let coin = object_refs.get(1).unwrap().object_id;
 
     SuiClientCommands::SerializeTransferSui {
@@ -1200,3 +1217,257 @@ async fn test_serialize_tx() -> Result<(), anyhow::Error> {
     .await?;
     Ok(())
 }
+
+#[sim_test]
+async fn test_serialize_unsigned_sign_and_execute() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new().build().await?;
+    let address = test_cluster.get_address_0();
+    let address1 = test_cluster.get_address_1();
+    let context = &mut test_cluster.wallet;
+    let client = context.get_client().await?;
+    let object_refs = client
+        .read_api()
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
+    let coin = object_refs.get(1).unwrap().object_id;
+
+    let SuiClientCommandResult::SerializeUnsigned(tx_bytes) = SuiClientCommands::TransferSui {
+        to: address1,
+        sui_coin_object_id: coin,
+        gas_budget: 1000,
+        amount: Some(1),
+    }
+    .serialize_unsigned(context)
+    .await? else {
+        panic!("TransferSui should serialize the unsigned transaction")
+    };
+
+    // Commands that don't submit a transaction can't be serialized.
+    assert!(SuiClientCommands::Addresses
+        .serialize_unsigned(context)
+        .await
+        .is_err());
+
+    // The transaction is signed with the key of its sender by default.
+    let mut signatures = sign_transaction(&context.config.keystore, &tx_bytes, &[])?;
+    assert_eq!(signatures.len(), 1);
+    let (signer, signature) = signatures.remove(0);
+    assert_eq!(signer, address);
+
+    SuiClientCommands::ExecuteSignedTx {
+        tx_bytes,
+        signature,
+    }
+    .execute(context)
+    .await?;
+    Ok(())
+}
+
+#[test]
+fn test_tx_history_accepts_a_single_query() {
+    let address = SuiAddress::random_for_testing_only().to_string();
+    let object = ObjectID::random().to_string();
+    let tx_history = |args: &[&str]| {
+        SuiCommand::try_parse_from(["sui", "client", "tx-history"].iter().chain(args))
+    };
+    assert!(tx_history(&[]).is_ok());
+    assert!(tx_history(&["--from", &address]).is_ok());
+    assert!(tx_history(&["--from", &address, "--to", &address]).is_err());
+    assert!(tx_history(&["--to", &address, "--mutated-object", &object]).is_err());
+    assert!(tx_history(&["--input-object", &object, "--package", &object]).is_err());
+}
+
+#[sim_test]
+async fn test_tx_history_and_events() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new()
+        .enable_fullnode_events()
+        .build()
+        .await?;
+    let address = test_cluster.get_address_0();
+    let address1 = test_cluster.get_address_1();
+    let context = &mut test_cluster.wallet;
+    let client = context.get_client().await?;
+    let object_refs = client
+        .read_api()
+        .get_objects_owned_by_address(address, None, None, None)
+        .await?
+        .data;
+
+    for object in object_refs.iter().take(2) {
+        SuiClientCommands::TransferSui {
+            to: address1,
+            sui_coin_object_id: object.object_id,
+            gas_budget: 1000,
+            amount: Some(1),
+        }
+        .execute(context)
+        .await?;
+        // Give the transfers distinct timestamps.
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    let tx_history = |cursor| SuiClientCommands::TxHistory {
+        from: Some(address),
+        to: None,
+        input_object: None,
+        mutated_object: None,
+        package: None,
+        module: None,
+        function: None,
+        start_time: None,
+        end_time: None,
+        cursor,
+        limit: 1,
+        ascending: false,
+        follow: false,
+    };
+    let SuiClientCommandResult::TxHistory(first) = tx_history(None).execute(context).await? else {
+        panic!("TxHistory should return a page of transactions")
+    };
+    assert_eq!(first.data.len(), 1);
+    let cursor = first.next_cursor.expect("Both transfers should be found");
+    let SuiClientCommandResult::TxHistory(second) = tx_history(Some(cursor)).execute(context).await? else {
+        panic!("TxHistory should return a page of transactions")
+    };
+    assert_eq!(second.data.len(), 1);
+    assert_ne!(
+        first.data[0].certificate.transaction_digest,
+        second.data[0].certificate.transaction_digest
+    );
+    assert_eq!(second.data[0].certificate.data.sender, address);
+
+    // The time range is applied by the client, which scans all the transactions of the query.
+    let (newer, older) = (&first.data[0], &second.data[0]);
+    let newer_time = newer
+        .timestamp_ms
+        .expect("Fullnode should index the timestamp");
+    let older_time = older
+        .timestamp_ms
+        .expect("Fullnode should index the timestamp");
+    assert!(older_time < newer_time);
+    let in_time_range = |start_time, end_time, ascending| SuiClientCommands::TxHistory {
+        from: Some(address),
+        to: None,
+        input_object: None,
+        mutated_object: None,
+        package: None,
+        module: None,
+        function: None,
+        start_time,
+        end_time,
+        cursor: None,
+        limit: 20,
+        ascending,
+        follow: false,
+    };
+    for (start_time, end_time, ascending, expected) in [
+        (Some(newer_time), None, false, newer),
+        (None, Some(newer_time), true, older),
+        (Some(older_time), Some(newer_time), false, older),
+    ] {
+        let SuiClientCommandResult::TxHistory(page) = in_time_range(start_time, end_time, ascending)
+            .execute(context)
+            .await? else {
+            panic!("TxHistory should return a page of transactions")
+        };
+        let digests: Vec<_> = page
+            .data
+            .iter()
+            .map(|tx| tx.certificate.transaction_digest)
+            .collect();
+        assert_eq!(digests, vec![expected.certificate.transaction_digest]);
+        assert!(page.next_cursor.is_none());
+    }
+
+    let SuiClientCommandResult::Events(events) = SuiClientCommands::Events {
+        tx_digest: Some(first.data[0].certificate.transaction_digest),
+        package: None,
+        module: None,
+        event_type: None,
+        sender: Some(address),
+        recipient: None,
+        object: None,
+        start_time: None,
+        end_time: None,
+        cursor: None,
+        limit: 20,
+        ascending: true,
+        follow: false,
+    }
+    .execute(context)
+    .await? else {
+        panic!("Events should return a page of events")
+    };
+    assert!(!events.data.is_empty());
+    assert!(events
+        .data
+        .iter()
+        .all(|event| event.tx_digest == Some(first.data[0].certificate.transaction_digest)));
+    Ok(())
+}
+
+#[sim_test]
+async fn test_tx_history_follow() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new()
+        .enable_fullnode_events()
+        .build()
+        .await?;
+    let address = test_cluster.get_address_0();
+    let address1 = test_cluster.get_address_1();
+    let context = &mut test_cluster.wallet;
+    let client = context.get_client().await?;
+
+    let follow = |from, input_object| SuiClientCommands::TxHistory {
+        from,
+        to: None,
+        input_object,
+        mutated_object: None,
+        package: None,
+        module: None,
+        function: None,
+        start_time: None,
+        end_time: None,
+        cursor: None,
+        limit: 20,
+        ascending: false,
+        follow: true,
+    };
+
+    // Read-only inputs can't be matched against the streamed effects.
+    let object_id = context.gas_objects(address).await?[0].2.object_id;
+    assert!(follow(None, Some(object_id))
+        .follow_transactions(&client)
+        .await
+        .is_err());
+
+    let follow_address1 = follow(Some(address1), None);
+    let transactions = follow_address1.follow_transactions(&client).await?;
+    futures::pin_mut!(transactions);
+
+    // Only the second transfer is sent by `address1`.
+    for (sender, recipient) in [(address, address1), (address1, address)] {
+        // The first transfer sends a coin too small to pay for gas to `address1`.
+        let (_, _, object) = context
+            .gas_objects(sender)
+            .await?
+            .into_iter()
+            .max_by_key(|(value, _, _)| *value)
+            .expect("Genesis should give gas coins to both addresses");
+        let object_id = object.object_id;
+        SuiClientCommands::TransferSui {
+            to: recipient,
+            sui_coin_object_id: object_id,
+            gas_budget: 1000,
+            amount: Some(1),
+        }
+        .execute(context)
+        .await?;
+    }
+
+    let response = timeout(Duration::from_secs(30), transactions.next())
+        .await?
+        .expect("Subscription should not be closed")?;
+    assert_eq!(response.certificate.data.sender, address1);
+    Ok(())
+}
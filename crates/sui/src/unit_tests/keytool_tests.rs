@@ -4,16 +4,21 @@
 use crate::keytool::read_authority_keypair_from_file;
 use crate::keytool::read_keypair_from_file;
 
+use super::sign_transaction;
 use super::write_keypair_to_file;
 use super::KeyToolCommand;
+use fastcrypto::encoding::Base64;
 use fastcrypto::encoding::Encoding;
 use fastcrypto::encoding::Hex;
+use fastcrypto::traits::ToFromBytes;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, InMemKeystore, Keystore};
+use sui_types::base_types::random_object_ref;
 use sui_types::base_types::SuiAddress;
 use sui_types::crypto::get_key_pair;
 use sui_types::crypto::get_key_pair_from_rng;
+use sui_types::crypto::AccountKeyPair;
 use sui_types::crypto::AuthorityKeyPair;
 use sui_types::crypto::Ed25519SuiSignature;
 use sui_types::crypto::EncodeDecodeBase64;
@@ -24,6 +29,7 @@ use sui_types::crypto::SignatureScheme;
 use sui_types::crypto::SuiKeyPair;
 use sui_types::crypto::SuiSignatureInner;
 use sui_types::intent::Intent;
+use sui_types::messages::TransactionData;
 use tempfile::TempDir;
 
 const TEST_MNEMONIC: &str = "result crisp session latin must fruit genuine question prevent start coconut brave speak student dismiss";
@@ -302,3 +308,35 @@ fn test_keytool_bls12381() -> Result<(), anyhow::Error> {
     .execute(&mut keystore)?;
     Ok(())
 }
+
+#[test]
+fn test_sign_transaction() -> Result<(), anyhow::Error> {
+    let keystore = Keystore::from(InMemKeystore::new(2));
+    let sender = keystore.addresses()[0];
+    let cosigner = keystore.addresses()[1];
+    let (other, _): (_, AccountKeyPair) = get_key_pair();
+    let gas = random_object_ref();
+
+    // The sender signs by default.
+    let tx_data = TransactionData::new_transfer_sui(other, sender, None, gas, 1000);
+    let tx_bytes = Base64::encode(bcs::to_bytes(&tx_data)?);
+    let signatures = sign_transaction(&keystore, &tx_bytes, &[])?;
+    assert_eq!(signatures.len(), 1);
+    assert_eq!(signatures[0].0, sender);
+
+    // Several keys sign when their addresses are passed.
+    let signatures = sign_transaction(&keystore, &tx_bytes, &[sender, cosigner])?;
+    assert_eq!(
+        signatures.iter().map(|(a, _)| *a).collect::<Vec<_>>(),
+        vec![sender, cosigner]
+    );
+    for (_, signature) in &signatures {
+        assert!(Signature::from_bytes(&Base64::decode(signature).unwrap()).is_ok());
+    }
+
+    // Keys missing from the keystore cannot sign.
+    assert!(sign_transaction(&keystore, &tx_bytes, &[sender, other]).is_err());
+    let tx_data = TransactionData::new_transfer_sui(sender, other, None, gas, 1000);
+    assert!(sign_transaction(&keystore, &Base64::encode(bcs::to_bytes(&tx_data)?), &[]).is_err());
+    Ok(())
+}
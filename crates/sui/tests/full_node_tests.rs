@@ -679,6 +679,95 @@ async fn test_full_node_sub_and_query_move_event_ok() -> Result<(), anyhow::Erro
     Ok(())
 }
 
+#[sim_test]
+async fn test_full_node_sub_event_from_cursor_ok() -> Result<(), anyhow::Error> {
+    let mut test_cluster = TestClusterBuilder::new()
+        .enable_fullnode_events()
+        .build()
+        .await?;
+
+    let fullnode = start_fullnode_from_config(
+        test_cluster
+            .fullnode_config_builder()
+            .with_event_store()
+            .build()
+            .unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let node = fullnode.sui_node;
+    let ws_client = fullnode.ws_client;
+
+    let context = &mut test_cluster.wallet;
+    let struct_tag_str = sui_framework_address_concat_string("::devnet_nft::MintNFTEvent");
+
+    // Emit a few events before anyone subscribes
+    let mut digests = vec![];
+    for _ in 0..3 {
+        let (_, _, digest) = create_devnet_nft(context).await?;
+        wait_for_tx(digest, node.state().clone()).await;
+        digests.push(digest);
+    }
+
+    let stored = node
+        .state()
+        .get_events(
+            EventQuery::MoveEvent(struct_tag_str.clone()),
+            None,
+            10,
+            false,
+        )
+        .await?;
+    assert_eq!(stored.len(), 3);
+
+    // Resume after the first event, the other two are replayed from the store
+    let mut sub: Subscription<SuiEventEnvelope> = ws_client
+        .subscribe(
+            "sui_subscribeEvent",
+            rpc_params![
+                SuiEventFilter::MoveEventType(struct_tag_str.clone()),
+                stored[0].0.clone()
+            ],
+            "sui_unsubscribeEvent",
+        )
+        .await
+        .unwrap();
+
+    // Then it switches over to live events
+    let (_, _, digest) = create_devnet_nft(context).await?;
+    wait_for_tx(digest, node.state().clone()).await;
+    digests.push(digest);
+
+    let mut received = vec![];
+    for _ in 0..3 {
+        match timeout(Duration::from_secs(5), sub.next()).await {
+            Ok(Some(Ok(envelope))) => received.push(envelope),
+            other => panic!("Failed to get SuiEvent, but {:?}", other),
+        }
+    }
+    assert_eq!(received[0].id, stored[1].0);
+    assert_eq!(received[1].id, stored[2].0);
+    assert_eq!(
+        received
+            .iter()
+            .map(|e| e.tx_digest.unwrap())
+            .collect::<Vec<_>>(),
+        digests[1..]
+    );
+
+    // No duplicates
+    match timeout(Duration::from_secs(5), sub.next()).await {
+        Err(_) => (),
+        other => panic!(
+            "Expect to time out because no new events are coming in. Got {:?}",
+            other
+        ),
+    }
+
+    Ok(())
+}
+
 // Test fullnode has event read jsonrpc endpoints working
 #[sim_test]
 async fn test_full_node_event_read_api_ok() {
@@ -0,0 +1,48 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::future::join_all;
+use std::time::Duration;
+use sui_macros::sim_test;
+use sui_types::object::{generate_test_gas_objects, Object};
+use test_utils::authority::{spawn_test_authorities, test_authority_configs};
+use test_utils::messages::test_shared_object_transactions;
+use test_utils::transaction::submit_shared_object_transaction;
+
+/// Sequence a transaction so that validators form a checkpoint, and check that all of them
+/// produce the same, valid randomness for it.
+#[sim_test]
+async fn checkpoint_randomness_end_to_end() {
+    let mut objects = generate_test_gas_objects();
+    objects.push(Object::shared_for_testing());
+
+    let configs = test_authority_configs();
+    let handles = spawn_test_authorities(objects, &configs).await;
+
+    // Note that this transaction is random and we do not expect it to be successfully executed
+    // by the Move execution engine, we only need it to end up in a checkpoint.
+    let transaction = test_shared_object_transactions().pop().unwrap();
+    submit_shared_object_transaction(transaction, configs.validator_set())
+        .await
+        .unwrap();
+
+    let outputs = join_all(handles.iter().map(|handle| {
+        handle.with_async(|node| async {
+            let store = node.checkpoint_store();
+            loop {
+                if let Some(randomness) = store.get_checkpoint_randomness(0).unwrap() {
+                    break randomness;
+                }
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+        })
+    }))
+    .await;
+
+    let expected = outputs[0].randomness();
+    for randomness in outputs {
+        assert_eq!(randomness.round.epoch, 0);
+        randomness.verify().unwrap();
+        assert_eq!(randomness.randomness(), expected);
+    }
+}
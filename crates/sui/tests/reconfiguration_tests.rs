@@ -11,6 +11,7 @@ use sui_core::authority_aggregator::{
 };
 use sui_core::authority_client::AuthorityAPI;
 use sui_core::test_utils::init_local_authorities;
+use sui_protocol_constants::ProtocolVersion;
 use sui_types::error::SuiError;
 use sui_types::gas::GasCostSummary;
 use sui_types::messages::VerifiedTransaction;
@@ -27,7 +28,7 @@ async fn local_advance_epoch_tx_test() {
     let (net, states, _) = init_local_authorities(4, vec![]).await;
 
     // Make sure that validators do not accept advance epoch sent externally.
-    let tx = VerifiedTransaction::new_change_epoch(1, 0, 0, 0);
+    let tx = VerifiedTransaction::new_change_epoch(1, ProtocolVersion::MIN, 0, 0, 0);
     let client0 = net.get_client(&states[0].name).unwrap().authority_client();
     assert!(matches!(
         client0.handle_transaction(tx.into_inner()).await,
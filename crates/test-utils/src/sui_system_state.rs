@@ -85,6 +85,7 @@ pub fn test_sui_system_state(epoch: EpochId, validators: Vec<Validator>) -> SuiS
         info: UID::new(SUI_SYSTEM_STATE_OBJECT_ID),
         chain_id: ChainId::TESTING,
         epoch,
+        protocol_version: 1,
         validators: validator_set,
         treasury_cap: Supply { value: 0 },
         storage_fund: Balance::new(0),
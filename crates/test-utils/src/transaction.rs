@@ -311,8 +311,9 @@ pub async fn transfer_coin(
     let client = context.get_client().await.unwrap();
     let object_refs = client
         .read_api()
-        .get_objects_owned_by_address(sender)
-        .await?;
+        .get_objects_owned_by_address(sender, None, None, None)
+        .await?
+        .data;
     let object_to_send = object_refs.get(1).unwrap().object_id;
 
     // Send an object
@@ -12,8 +12,9 @@ use crate::{
 use bincode::Options;
 use collectable::TryExtend;
 use rocksdb::{
-    properties, AsColumnFamilyRef, CStrLike, ColumnFamilyDescriptor, DBWithThreadMode, Error,
-    IteratorMode, MultiThreaded, Transaction, WriteBatch, WriteBatchWithTransaction,
+    checkpoint::Checkpoint, properties, AsColumnFamilyRef, CStrLike, ColumnFamilyDescriptor,
+    DBWithThreadMode, Error, IteratorMode, MultiThreaded, Transaction, WriteBatch,
+    WriteBatchWithTransaction,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
@@ -251,6 +252,17 @@ impl RocksDB {
     pub fn flush(&self) -> Result<(), rocksdb::Error> {
         delegate_call!(self.flush())
     }
+
+    /// Creates a consistent point-in-time copy of the database at `path`, which must not exist yet.
+    /// Table files are hard linked when `path` is on the same filesystem as the database.
+    pub fn checkpoint(&self, path: &Path) -> Result<(), TypedStoreError> {
+        let checkpoint = match self {
+            Self::DBWithThreadMode(d) => Checkpoint::new(d)?,
+            Self::OptimisticTransactionDB(d) => Checkpoint::new(d)?,
+        };
+        checkpoint.create_checkpoint(path)?;
+        Ok(())
+    }
 }
 
 pub enum RocksDBBatch {
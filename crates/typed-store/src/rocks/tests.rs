@@ -58,6 +58,31 @@ async fn test_wrong_reopen(#[values(true, false)] is_transactional: bool) {
     assert!(db.is_err());
 }
 
+#[rstest]
+#[tokio::test]
+async fn test_checkpoint(#[values(true, false)] is_transactional: bool) {
+    let db = open_map(temp_dir(), None, is_transactional);
+    db.insert(&123456789, &"123456789".to_string())
+        .expect("Failed to insert");
+
+    let checkpoint_path = temp_dir().join("checkpoint");
+    db.rocksdb
+        .checkpoint(&checkpoint_path)
+        .expect("Failed to create checkpoint");
+    // Writes after the checkpoint is taken are not part of it
+    db.insert(&987654321, &"987654321".to_string())
+        .expect("Failed to insert");
+    // Checkpointing to an existing directory fails
+    assert!(db.rocksdb.checkpoint(&checkpoint_path).is_err());
+
+    let checkpoint = open_map::<_, u32, String>(checkpoint_path, None, is_transactional);
+    assert_eq!(
+        Some("123456789".to_string()),
+        checkpoint.get(&123456789).expect("Failed to get")
+    );
+    assert_eq!(None, checkpoint.get(&987654321).expect("Failed to get"));
+}
+
 #[rstest]
 #[tokio::test]
 async fn test_contains_key(#[values(true, false)] is_transactional: bool) {
@@ -143,6 +143,9 @@ pub struct Parameters {
     pub prometheus_metrics: PrometheusMetricsParameters,
     /// Network admin server ports for primary & worker.
     pub network_admin_server: NetworkAdminServerParameters,
+    /// The compression applied to worker batches on the wire and at rest.
+    #[serde(default)]
+    pub batch_compression: BatchCompressionParameters,
 }
 
 impl Parameters {
@@ -155,6 +158,45 @@ impl Parameters {
     }
 }
 
+/// The codecs that can be used to compress worker batches.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchCompressionCodec {
+    /// Batches are sent and stored as-is.
+    #[default]
+    None,
+    /// Batches are compressed with zstd.
+    Zstd,
+}
+
+impl std::fmt::Display for BatchCompressionCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchCompressionCodec::None => write!(f, "none"),
+            BatchCompressionCodec::Zstd => write!(f, "zstd"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BatchCompressionParameters {
+    /// The codec used by workers to compress the batches they broadcast and serve, and by the
+    /// batch stores. Peers always accept every codec, so nodes can switch codecs independently.
+    pub codec: BatchCompressionCodec,
+    /// The compression level. Only meaningful for codecs that support levels (zstd: 1 to 22).
+    pub level: i32,
+}
+
+impl Default for BatchCompressionParameters {
+    fn default() -> Self {
+        Self {
+            codec: BatchCompressionCodec::None,
+            level: 3,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct NetworkAdminServerParameters {
     /// Primary network admin server port number
@@ -308,6 +350,7 @@ impl Default for Parameters {
             max_concurrent_requests: 500_000,
             prometheus_metrics: PrometheusMetricsParameters::default(),
             network_admin_server: NetworkAdminServerParameters::default(),
+            batch_compression: BatchCompressionParameters::default(),
         }
     }
 }
@@ -396,6 +439,10 @@ impl Parameters {
             self.network_admin_server
                 .worker_network_admin_server_base_port
         );
+        info!(
+            "Batch compression set to {} (level {})",
+            self.batch_compression.codec, self.batch_compression.level
+        );
     }
 }
 
@@ -232,7 +232,8 @@ pub struct Consensus<ConsensusProtocol> {
     /// if it already sent us its whole history.
     rx_new_certificates: metered_channel::Receiver<Certificate>,
     /// Outputs the sequence of ordered certificates to the primary (for cleanup and feedback).
-    tx_committed_certificates: metered_channel::Sender<(Round, Vec<Certificate>)>,
+    tx_committed_certificates:
+        metered_channel::Sender<(Round, Vec<Certificate>, Option<SequenceNumber>)>,
     /// Outputs the highest committed round in the consensus. Controls GC round downstream.
     tx_consensus_round_updates: watch::Sender<Round>,
     /// Outputs the sequence of ordered certificates to the application layer.
@@ -259,7 +260,11 @@ where
         cert_store: CertificateStore,
         rx_reconfigure: watch::Receiver<ReconfigureNotification>,
         rx_new_certificates: metered_channel::Receiver<Certificate>,
-        tx_committed_certificates: metered_channel::Sender<(Round, Vec<Certificate>)>,
+        tx_committed_certificates: metered_channel::Sender<(
+            Round,
+            Vec<Certificate>,
+            Option<SequenceNumber>,
+        )>,
         tx_consensus_round_updates: watch::Sender<Round>,
         tx_sequence: metered_channel::Sender<CommittedSubDag>,
         protocol: Protocol,
@@ -349,10 +354,10 @@ where
                             .process_certificate(&mut self.state, certificate)?;
 
 
-                    // We extract a list of headers from this specific validator that
-                    // have been agreed upon, and signal this back to the narwhal sub-system
-                    // to be used to re-send batches that have not made it to a commit.
-                    let mut commited_certificates = Vec::new();
+                    // We signal the certificates of every committed sub-dag back to the narwhal
+                    // sub-system, to be used to re-send batches that have not made it to a commit
+                    // and to report the commit of our own batches.
+                    let mut leader_commit_round = None;
 
                     // Output the sequence in the right order.
                     let mut i = 0;
@@ -381,10 +386,20 @@ where
                                     .dag_size_bytes
                                     .set((mysten_util_mem::malloc_size(&self.state.dag) + std::mem::size_of::<Dag>()) as i64);
                             }
-
-                            commited_certificates.push(certificate.clone());
                         }
 
+                        // The leader round is the commit round expected by primary.
+                        let commit_round = committed_sub_dag.leader.round();
+                        leader_commit_round = Some(commit_round);
+                        self.tx_committed_certificates
+                            .send((
+                                commit_round,
+                                committed_sub_dag.certificates.clone(),
+                                Some(committed_sub_dag.sub_dag_index),
+                            ))
+                            .await
+                            .expect("Failed to send committed round and certificates to primary");
+
                         // NOTE: The size of the sub-dag can be arbitrarily large (depending on the network condition
                         // and Byzantine leaders).
                         if let Err(e) = self.tx_sequence.send(committed_sub_dag).await {
@@ -392,14 +407,7 @@ where
                         }
                     }
 
-                    if !commited_certificates.is_empty(){
-                        // Highest committed certificate round is the leader round / commit round
-                        // expected by primary.
-                        let leader_commit_round = commited_certificates.iter().map(|c| c.round()).max().unwrap();
-                        self.tx_committed_certificates
-                        .send((leader_commit_round, commited_certificates))
-                        .await
-                        .expect("Failed to send committed round and certificates to primary");
+                    if let Some(leader_commit_round) = leader_commit_round {
                         self.tx_consensus_round_updates.send(leader_commit_round).expect("Failed to notify primary about committed round!");
                     }
 
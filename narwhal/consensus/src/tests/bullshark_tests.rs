@@ -539,7 +539,7 @@ async fn committed_round_after_restart() {
                 "Received output from consensus, committed_round={}",
                 committed.leader.round()
             );
-            let (round, _certs) = rx_primary.recv().await.unwrap();
+            let (round, _certs, _sub_dag_index) = rx_primary.recv().await.unwrap();
             info!("Received committed certificates from consensus, committed_round={round}",);
         }
 
@@ -6,6 +6,7 @@ use crate::{
     traits::{ReliableNetwork, UnreliableNetwork},
     CancelOnDropHandler, RetryConfig,
 };
+use anemo::types::response::StatusCode;
 use anemo::PeerId;
 use anyhow::format_err;
 use anyhow::Result;
@@ -16,9 +17,10 @@ use tokio::task::JoinHandle;
 use types::{
     Batch, BatchDigest, FetchCertificatesRequest, FetchCertificatesResponse,
     GetCertificatesRequest, GetCertificatesResponse, PrimaryMessage, PrimaryToPrimaryClient,
-    PrimaryToWorkerClient, RequestBatchRequest, WorkerBatchMessage, WorkerDeleteBatchesMessage,
-    WorkerOthersBatchMessage, WorkerOurBatchMessage, WorkerReconfigureMessage,
-    WorkerSynchronizeMessage, WorkerToPrimaryClient, WorkerToWorkerClient,
+    PrimaryToWorkerClient, RequestBatchRequest, WorkerBatchMessage, WorkerBatchStatusMessage,
+    WorkerCompressedBatchMessage, WorkerDeleteBatchesMessage, WorkerOthersBatchMessage,
+    WorkerOurBatchMessage, WorkerReconfigureMessage, WorkerSynchronizeMessage,
+    WorkerToPrimaryClient, WorkerToWorkerClient,
 };
 
 fn unreliable_send<F, R, Fut>(
@@ -217,6 +219,23 @@ impl UnreliableNetwork<WorkerSynchronizeMessage> for anemo::Network {
     }
 }
 
+impl UnreliableNetwork<WorkerBatchStatusMessage> for anemo::Network {
+    type Response = ();
+    fn unreliable_send(
+        &self,
+        peer: NetworkPublicKey,
+        message: &WorkerBatchStatusMessage,
+    ) -> Result<JoinHandle<Result<anemo::Response<()>>>> {
+        let message = message.to_owned();
+        let f = move |peer| async move {
+            PrimaryToWorkerClient::new(peer)
+                .report_batch_status(message)
+                .await
+        };
+        unreliable_send(self, peer, f)
+    }
+}
+
 //
 // Worker-to-Primary
 //
@@ -298,6 +317,40 @@ impl ReliableNetwork<WorkerBatchMessage> for anemo::Network {
     }
 }
 
+impl ReliableNetwork<WorkerCompressedBatchMessage> for anemo::Network {
+    type Response = ();
+    fn send(
+        &self,
+        peer: NetworkPublicKey,
+        message: &WorkerCompressedBatchMessage,
+    ) -> CancelOnDropHandler<Result<anemo::Response<()>>> {
+        let message = message.to_owned();
+        let f = move |peer: anemo::Peer| {
+            let message = message.clone();
+            async move {
+                let result = WorkerToWorkerClient::new(peer.clone())
+                    .report_compressed_batch(message.clone())
+                    .await;
+                match result {
+                    // The peer does not support compressed batches, send it the batch as-is.
+                    Err(status) if status.status() == StatusCode::NotFound => {
+                        let batch = message
+                            .batch
+                            .decode()
+                            .map_err(|e| anemo::rpc::Status::from_error(Box::new(e)))?;
+                        WorkerToWorkerClient::new(peer)
+                            .report_batch(WorkerBatchMessage { batch })
+                            .await
+                    }
+                    result => result,
+                }
+            }
+        };
+
+        send(self.clone(), peer, f)
+    }
+}
+
 #[async_trait]
 impl PrimaryToWorkerRpc for anemo::Network {
     async fn delete_batches(
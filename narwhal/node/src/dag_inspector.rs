@@ -0,0 +1,260 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Offline inspection of the DAG persisted by a primary. This is meant to help debugging
+//! consensus stalls: it lists the certificates of a range of rounds together with their parents,
+//! points out parents that are missing from the store, and marks which certificates were
+//! committed (and as leader of which sub-dag).
+use crypto::PublicKey;
+use fastcrypto::{hash::Hash, traits::EncodeDecodeBase64};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+use storage::NodeStorage;
+use store::rocks::TypedStoreError;
+use types::{Certificate, CertificateDigest, Epoch, Round, SequenceNumber};
+
+/// The commit status of a certificate, derived from the committed sub-dags.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct CommitInfo {
+    /// The index of the sub-dag that committed the certificate.
+    pub sub_dag_index: SequenceNumber,
+    /// Whether the certificate is the leader of that sub-dag.
+    pub is_leader: bool,
+}
+
+/// A certificate of the DAG along with the information relevant for debugging.
+#[derive(Clone, Debug, Serialize)]
+pub struct InspectedCertificate {
+    pub digest: String,
+    pub round: Round,
+    pub epoch: Epoch,
+    pub author: String,
+    pub num_batches: usize,
+    pub parents: Vec<String>,
+    /// The parents that are not found in the certificate store. These are expected below the
+    /// garbage collection round, anything else points to a hole in the DAG.
+    pub missing_parents: Vec<String>,
+    pub committed: Option<CommitInfo>,
+}
+
+/// Reads the certificates and consensus tables of a primary's store. Tables are iterated
+/// lazily, so that inspecting a few rounds of a long running node stays cheap.
+pub struct DagInspector<'a> {
+    store: &'a NodeStorage,
+}
+
+impl<'a> DagInspector<'a> {
+    pub fn new(store: &'a NodeStorage) -> Self {
+        Self { store }
+    }
+
+    /// The index of the last committed sub-dag, or 0 if nothing was committed.
+    pub fn last_sub_dag_index(&self) -> SequenceNumber {
+        self.store.consensus_store.get_latest_sub_dag_index()
+    }
+
+    /// The last committed round of each authority, as recorded by consensus.
+    pub fn last_committed_rounds(&self) -> BTreeMap<String, Round> {
+        self.store
+            .consensus_store
+            .read_last_committed()
+            .into_iter()
+            .map(|(name, round)| (name.encode_base64(), round))
+            .collect()
+    }
+
+    /// Returns the certificates with a round in `[from, to]`, optionally restricted to a single
+    /// author, sorted by round and author.
+    pub fn certificates(
+        &self,
+        from: Round,
+        to: Round,
+        author: Option<&PublicKey>,
+    ) -> Result<Vec<InspectedCertificate>, TypedStoreError> {
+        let certificates: Vec<Certificate> = self
+            .store
+            .certificate_store
+            .in_rounds(from, to)?
+            .filter(|certificate| {
+                certificate.as_ref().map_or(true, |certificate| {
+                    author.map_or(true, |a| &certificate.header.author == a)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let commits = self.commits(&certificates)?;
+
+        let parents: BTreeSet<CertificateDigest> = certificates
+            .iter()
+            .flat_map(|certificate| certificate.header.parents.iter().cloned())
+            .collect();
+        let found: BTreeSet<CertificateDigest> = parents
+            .iter()
+            .cloned()
+            .zip(self.store.certificate_store.read_all(parents.clone())?)
+            .filter_map(|(digest, certificate)| certificate.map(|_| digest))
+            .collect();
+
+        let mut inspected: Vec<_> = certificates
+            .into_iter()
+            .map(|certificate| {
+                let digest = certificate.digest();
+                InspectedCertificate {
+                    digest: format!("{:?}", digest),
+                    round: certificate.round(),
+                    epoch: certificate.epoch(),
+                    author: certificate.header.author.encode_base64(),
+                    num_batches: certificate.header.payload.len(),
+                    parents: certificate
+                        .header
+                        .parents
+                        .iter()
+                        .map(|parent| format!("{:?}", parent))
+                        .collect(),
+                    missing_parents: certificate
+                        .header
+                        .parents
+                        .iter()
+                        .filter(|parent| !found.contains(parent))
+                        .map(|parent| format!("{:?}", parent))
+                        .collect(),
+                    committed: commits.get(&digest).cloned(),
+                }
+            })
+            .collect();
+        inspected.sort_by(|a, b| (a.round, &a.author).cmp(&(b.round, &b.author)));
+        Ok(inspected)
+    }
+
+    /// Finds the sub-dags committing `certificates`. Leaders are committed in increasing rounds,
+    /// and commit certificates of their round or below, so the sub-dags are walked from the
+    /// latest one until every certificate is found or a leader is below the inspected rounds.
+    fn commits(
+        &self,
+        certificates: &[Certificate],
+    ) -> Result<HashMap<CertificateDigest, CommitInfo>, TypedStoreError> {
+        let mut commits = HashMap::new();
+        let Some(from) = certificates.iter().map(|certificate| certificate.round()).min() else {
+            return Ok(commits);
+        };
+        let mut pending: HashSet<CertificateDigest> = certificates
+            .iter()
+            .map(|certificate| certificate.digest())
+            .collect();
+
+        for sub_dag in self.store.consensus_store.reverse_iter_committed_sub_dags() {
+            for digest in &sub_dag.certificates {
+                if pending.remove(digest) {
+                    commits.insert(
+                        *digest,
+                        CommitInfo {
+                            sub_dag_index: sub_dag.sub_dag_index,
+                            is_leader: *digest == sub_dag.leader,
+                        },
+                    );
+                }
+            }
+            if pending.is_empty() {
+                break;
+            }
+            let leader = self.store.certificate_store.read(sub_dag.leader)?;
+            if leader.map_or(false, |leader| leader.round() < from) {
+                break;
+            }
+        }
+        Ok(commits)
+    }
+}
+
+/// Renders the certificates as a human readable table, one line per certificate.
+pub fn to_text(certificates: &[InspectedCertificate]) -> String {
+    let mut output = String::new();
+    for certificate in certificates {
+        let status = match certificate.committed {
+            Some(CommitInfo {
+                sub_dag_index,
+                is_leader: true,
+            }) => format!("leader of sub-dag {sub_dag_index}"),
+            Some(CommitInfo {
+                sub_dag_index,
+                is_leader: false,
+            }) => format!("committed in sub-dag {sub_dag_index}"),
+            None => "not committed".to_string(),
+        };
+        writeln!(
+            output,
+            "round {} author {} digest {} batches {} parents {} missing parents {} ({})",
+            certificate.round,
+            certificate.author,
+            certificate.digest,
+            certificate.num_batches,
+            certificate.parents.len(),
+            certificate.missing_parents.len(),
+            status,
+        )
+        .unwrap();
+        for parent in &certificate.missing_parents {
+            writeln!(output, "    missing parent {parent}").unwrap();
+        }
+    }
+    output
+}
+
+/// Renders the certificates as a Graphviz graph. Certificates are grouped by round, leaders are
+/// drawn as boxes, committed certificates are filled and missing parents are drawn dashed.
+pub fn to_dot(certificates: &[InspectedCertificate]) -> String {
+    let mut output = String::from("digraph dag {\n    rankdir=BT;\n");
+    let mut rounds: BTreeMap<Round, Vec<&InspectedCertificate>> = BTreeMap::new();
+    for certificate in certificates {
+        rounds
+            .entry(certificate.round)
+            .or_default()
+            .push(certificate);
+    }
+    for (round, certificates) in &rounds {
+        writeln!(output, "    subgraph round_{round} {{\n        rank=same;").unwrap();
+        for certificate in certificates {
+            let (shape, style, sub_dag) = match certificate.committed {
+                Some(info) => (
+                    if info.is_leader { "box" } else { "ellipse" },
+                    "filled",
+                    format!("\\nsub-dag {}", info.sub_dag_index),
+                ),
+                None => ("ellipse", "solid", String::new()),
+            };
+            writeln!(
+                output,
+                "        \"{}\" [label=\"r{} {}{}\", shape={}, style={}];",
+                certificate.digest,
+                certificate.round,
+                short(&certificate.author),
+                sub_dag,
+                shape,
+                style
+            )
+            .unwrap();
+        }
+        writeln!(output, "    }}").unwrap();
+    }
+    for certificate in certificates {
+        for parent in &certificate.parents {
+            let style = if certificate.missing_parents.contains(parent) {
+                " [style=dashed]"
+            } else {
+                ""
+            };
+            writeln!(
+                output,
+                "    \"{}\" -> \"{}\"{};",
+                certificate.digest, parent, style
+            )
+            .unwrap();
+        }
+    }
+    output.push_str("}\n");
+    output
+}
+
+fn short(name: &str) -> &str {
+    name.get(0..16).unwrap_or(name)
+}
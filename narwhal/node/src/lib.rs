@@ -18,9 +18,10 @@ pub use storage::NodeStorage;
 use tokio::sync::oneshot;
 use tokio::{sync::watch, task::JoinHandle};
 use tracing::{debug, info};
-use types::{metered_channel, Certificate, ReconfigureNotification, Round};
+use types::{metered_channel, Certificate, ReconfigureNotification, Round, SequenceNumber};
 use worker::{metrics::initialise_metrics, TransactionValidator, Worker};
 
+pub mod dag_inspector;
 pub mod execution_state;
 pub mod metrics;
 
@@ -154,7 +155,11 @@ impl Node {
         execution_state: State,
         tx_reconfigure: &watch::Sender<ReconfigureNotification>,
         rx_new_certificates: metered_channel::Receiver<Certificate>,
-        tx_committed_certificates: metered_channel::Sender<(Round, Vec<Certificate>)>,
+        tx_committed_certificates: metered_channel::Sender<(
+            Round,
+            Vec<Certificate>,
+            Option<SequenceNumber>,
+        )>,
         tx_consensus_round_updates: watch::Sender<Round>,
         registry: &Registry,
     ) -> SubscriberResult<Vec<JoinHandle<()>>>
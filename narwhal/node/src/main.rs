@@ -11,18 +11,23 @@
 use arc_swap::ArcSwap;
 use clap::{crate_name, crate_version, App, AppSettings, ArgMatches, SubCommand};
 use config::{Committee, Import, Parameters, WorkerCache, WorkerId};
-use crypto::{KeyPair, NetworkKeyPair};
+use crypto::{KeyPair, NetworkKeyPair, PublicKey};
 use executor::SerializedTransaction;
 use eyre::Context;
-use fastcrypto::{generate_production_keypair, traits::KeyPair as _};
+use fastcrypto::{
+    generate_production_keypair,
+    traits::{EncodeDecodeBase64, KeyPair as _},
+};
 use futures::future::join_all;
 use narwhal_node as node;
 use node::{
+    dag_inspector::{self, DagInspector},
     execution_state::SimpleExecutionState,
     metrics::{primary_metrics_registry, start_prometheus_server, worker_metrics_registry},
     Node,
 };
 use prometheus::Registry;
+use std::path::Path;
 use std::sync::Arc;
 use storage::NodeStorage;
 use telemetry_subscribers::TelemetryGuards;
@@ -32,6 +37,7 @@ use tracing::subscriber::set_global_default;
 use tracing::{info, warn};
 #[cfg(feature = "benchmark")]
 use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use types::Round;
 use worker::TrivialTransactionValidator;
 
 #[tokio::main]
@@ -71,6 +77,15 @@ async fn main() -> Result<(), eyre::Report> {
                 )
                 .setting(AppSettings::SubcommandRequiredElseHelp),
         )
+        .subcommand(
+            SubCommand::with_name("inspect_dag")
+                .about("Print the DAG persisted in the store of a primary. The node must not be running")
+                .args_from_usage("--store=<PATH> 'The path of the primary's data store'")
+                .args_from_usage("--from-round=[INT] 'The first round to print (default: 0)'")
+                .args_from_usage("--to-round=[INT] 'The last round to print (default: all rounds)'")
+                .args_from_usage("--author=[KEY] 'Only print the certificates of this authority (base64 public key)'")
+                .args_from_usage("--format=[FORMAT] 'The output format: text, json or dot (default: text)'"),
+        )
         .setting(AppSettings::SubcommandRequiredElseHelp)
         .get_matches();
 
@@ -146,6 +161,10 @@ async fn main() -> Result<(), eyre::Report> {
             )
             .await?
         }
+        ("inspect_dag", Some(sub_matches)) => {
+            let _guard = setup_telemetry(tracing_level, network_tracing_level, None);
+            inspect_dag(sub_matches)?
+        }
         _ => unreachable!(),
     }
     Ok(())
@@ -237,7 +256,8 @@ async fn run(
     };
 
     // Make the data store.
-    let store = NodeStorage::reopen(store_path);
+    let store =
+        NodeStorage::reopen_with_batch_compression(store_path, &parameters.batch_compression);
 
     // The channel returning the result for each transaction's execution.
     let (tx_transaction_confirmation, rx_transaction_confirmation) =
@@ -310,3 +330,56 @@ async fn analyze(mut rx_output: Receiver<SerializedTransaction>) {
         // NOTE: Notify the user that its transaction has been processed.
     }
 }
+
+/// Prints the certificates and commit information persisted in a primary's store.
+fn inspect_dag(matches: &ArgMatches<'_>) -> Result<(), eyre::Report> {
+    let store_path = matches.value_of("store").unwrap();
+    let from_round = matches
+        .value_of("from-round")
+        .map(|round| round.parse::<Round>())
+        .transpose()
+        .context("The first round must be a positive integer")?
+        .unwrap_or_default();
+    let to_round = matches
+        .value_of("to-round")
+        .map(|round| round.parse::<Round>())
+        .transpose()
+        .context("The last round must be a positive integer")?
+        .unwrap_or(Round::MAX);
+    let author = matches
+        .value_of("author")
+        .map(PublicKey::decode_base64)
+        .transpose()
+        .map_err(|e| eyre::eyre!("Invalid author public key: {e}"))?;
+
+    // Opening the store creates it when missing, which would silently print an empty DAG.
+    if !Path::new(store_path).is_dir() {
+        eyre::bail!("No store found at {store_path}");
+    }
+    let store = NodeStorage::reopen(store_path);
+    let inspector = DagInspector::new(&store);
+    let certificates = inspector
+        .certificates(from_round, to_round, author.as_ref())
+        .context("Failed to read the certificate store")?;
+
+    match matches.value_of("format").unwrap_or("text") {
+        "text" => {
+            println!("Last committed sub-dag: {}", inspector.last_sub_dag_index());
+            for (authority, round) in inspector.last_committed_rounds() {
+                println!("Last committed round of {authority}: {round}");
+            }
+            print!("{}", dag_inspector::to_text(&certificates));
+        }
+        "json" => {
+            let output = serde_json::json!({
+                "last_sub_dag_index": inspector.last_sub_dag_index(),
+                "last_committed_rounds": inspector.last_committed_rounds(),
+                "certificates": certificates,
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+        }
+        "dot" => print!("{}", dag_inspector::to_dot(&certificates)),
+        format => eyre::bail!("Unknown output format: {format}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,92 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::{hash::Hash, traits::EncodeDecodeBase64};
+use narwhal_node::dag_inspector::{self, DagInspector};
+use std::collections::{BTreeSet, HashMap};
+use storage::NodeStorage;
+use test_utils::{make_optimal_certificates, temp_dir, CommitteeFixture};
+use types::{Certificate, CommittedSubDag};
+
+#[test]
+fn inspect_persisted_dag() {
+    let fixture = CommitteeFixture::builder().build();
+    let committee = fixture.committee();
+    let keys: Vec<_> = fixture.authorities().map(|a| a.public_key()).collect();
+    let genesis = Certificate::genesis(&committee)
+        .iter()
+        .map(|x| x.digest())
+        .collect::<BTreeSet<_>>();
+    let (certificates, _) = make_optimal_certificates(&committee, 1..=3, &genesis, &keys);
+
+    let store = NodeStorage::reopen(temp_dir());
+    store
+        .certificate_store
+        .write_all(certificates.clone())
+        .unwrap();
+
+    // Commit the certificate of the first authority at round 2 along with its history.
+    let leader = certificates
+        .iter()
+        .find(|c| c.round() == 2 && c.header.author == keys[0])
+        .unwrap()
+        .clone();
+    let mut committed: Vec<_> = certificates
+        .iter()
+        .filter(|c| c.round() == 1)
+        .cloned()
+        .collect();
+    committed.push(leader.clone());
+    let sub_dag = CommittedSubDag {
+        certificates: committed,
+        leader: leader.clone(),
+        sub_dag_index: 1,
+    };
+    store
+        .consensus_store
+        .write_consensus_state(&HashMap::new(), &sub_dag)
+        .unwrap();
+
+    let inspector = DagInspector::new(&store);
+    assert_eq!(inspector.last_sub_dag_index(), 1);
+
+    let inspected = inspector.certificates(1, 3, None).unwrap();
+    assert_eq!(inspected.len(), 3 * keys.len());
+    for certificate in &inspected {
+        match certificate.round {
+            // Genesis certificates are never persisted.
+            1 => {
+                assert_eq!(certificate.missing_parents.len(), genesis.len());
+                let info = certificate.committed.unwrap();
+                assert_eq!(info.sub_dag_index, 1);
+                assert!(!info.is_leader);
+            }
+            2 => {
+                assert!(certificate.missing_parents.is_empty());
+                let is_leader = certificate.digest == format!("{:?}", leader.digest());
+                assert_eq!(certificate.committed.is_some(), is_leader);
+                assert!(certificate.committed.map_or(true, |info| info.is_leader));
+            }
+            3 => {
+                assert!(certificate.missing_parents.is_empty());
+                assert!(certificate.committed.is_none());
+            }
+            round => panic!("Unexpected round {round}"),
+        }
+    }
+
+    // Commit information is found when inspecting only a window of rounds.
+    let inspected = inspector.certificates(1, 1, None).unwrap();
+    assert_eq!(inspected.len(), keys.len());
+    assert!(inspected.iter().all(|c| c.committed.is_some()));
+    assert!(inspector.certificates(4, 10, None).unwrap().is_empty());
+
+    // Filter by round and author.
+    let inspected = inspector.certificates(2, 2, Some(&keys[1])).unwrap();
+    assert_eq!(inspected.len(), 1);
+    assert_eq!(inspected[0].author, keys[1].encode_base64());
+
+    let dot = dag_inspector::to_dot(&inspector.certificates(1, 3, None).unwrap());
+    assert!(dot.starts_with("digraph dag {"));
+    assert!(dot.contains(&format!("\"{:?}\"", leader.digest())));
+}
@@ -16,7 +16,7 @@ use store::{rocks::TypedStoreError, Store};
 use tracing::{debug, instrument, warn};
 use types::{
     metered_channel::Sender, BatchDigest, Certificate, CertificateDigest, Header, HeaderDigest,
-    Round,
+    Round, SequenceNumber,
 };
 
 #[cfg(test)]
@@ -51,7 +51,7 @@ pub struct BlockRemover {
     worker_network: anemo::Network,
 
     /// Outputs all the successfully deleted certificates
-    tx_committed_certificates: Sender<(Round, Vec<Certificate>)>,
+    tx_committed_certificates: Sender<(Round, Vec<Certificate>, Option<SequenceNumber>)>,
 }
 
 impl BlockRemover {
@@ -64,7 +64,7 @@ impl BlockRemover {
         payload_store: Store<(BatchDigest, WorkerId), PayloadToken>,
         dag: Option<Arc<Dag>>,
         worker_network: anemo::Network,
-        tx_committed_certificates: Sender<(Round, Vec<Certificate>)>,
+        tx_committed_certificates: Sender<(Round, Vec<Certificate>, Option<SequenceNumber>)>,
     ) -> BlockRemover {
         Self {
             name,
@@ -171,7 +171,7 @@ impl BlockRemover {
 
             // We signal that these certificates must have been committed by the external consensus
             self.tx_committed_certificates
-                .send((highest_round, all_certs))
+                .send((highest_round, all_certs, None))
                 .await
                 .expect("Couldn't forward removed certificates to channel");
         }
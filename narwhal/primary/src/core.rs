@@ -7,6 +7,7 @@ use crate::{
     metrics::PrimaryMetrics,
     primary::PrimaryMessage,
     synchronizer::Synchronizer,
+    utils,
 };
 
 use anyhow::Result;
@@ -30,7 +31,7 @@ use types::{
     ensure,
     error::{DagError, DagResult},
     metered_channel::{Receiver, Sender},
-    Certificate, CertificateDigest, Header, HeaderDigest, PrimaryToPrimaryClient,
+    BatchStatus, Certificate, CertificateDigest, Header, HeaderDigest, PrimaryToPrimaryClient,
     ReconfigureNotification, RequestVoteRequest, Round, Vote,
 };
 
@@ -446,6 +447,18 @@ impl Core {
             _ => panic!("Failed to process locally-created certificate"),
         }?;
 
+        // Let our workers know that their batches are certified.
+        utils::report_batch_status(
+            &self.name,
+            &self.worker_cache,
+            &self.network,
+            &certificate,
+            BatchStatus::Certified {
+                certificate: certificate.digest(),
+                round: certificate.round(),
+            },
+        );
+
         // Broadcast the certificate.
         let epoch = certificate.epoch();
         let round = certificate.header.round;
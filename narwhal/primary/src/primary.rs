@@ -58,8 +58,8 @@ use types::{
     FetchCertificatesResponse, GetCertificatesRequest, GetCertificatesResponse, Header,
     HeaderDigest, PayloadAvailabilityRequest, PayloadAvailabilityResponse, PrimaryToPrimary,
     PrimaryToPrimaryServer, ReconfigureNotification, RequestVoteRequest, RequestVoteResponse,
-    Round, Vote, VoteInfo, WorkerInfoResponse, WorkerOthersBatchMessage, WorkerOurBatchMessage,
-    WorkerToPrimary, WorkerToPrimaryServer,
+    Round, SequenceNumber, Vote, VoteInfo, WorkerInfoResponse, WorkerOthersBatchMessage,
+    WorkerOurBatchMessage, WorkerToPrimary, WorkerToPrimaryServer,
 };
 
 #[cfg(any(test))]
@@ -96,12 +96,12 @@ impl Primary {
         payload_store: Store<(BatchDigest, WorkerId), PayloadToken>,
         vote_digest_store: Store<PublicKey, VoteInfo>,
         tx_new_certificates: Sender<Certificate>,
-        rx_committed_certificates: Receiver<(Round, Vec<Certificate>)>,
+        rx_committed_certificates: Receiver<(Round, Vec<Certificate>, Option<SequenceNumber>)>,
         rx_consensus_round_updates: watch::Receiver<Round>,
         dag: Option<Arc<Dag>>,
         network_model: NetworkModel,
         tx_reconfigure: watch::Sender<ReconfigureNotification>,
-        tx_committed_certificates: Sender<(Round, Vec<Certificate>)>,
+        tx_committed_certificates: Sender<(Round, Vec<Certificate>, Option<SequenceNumber>)>,
         registry: &Registry,
         // See comments in Subscriber::spawn
         tx_executor_network: Option<oneshot::Sender<anemo::Network>>,
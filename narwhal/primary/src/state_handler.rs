@@ -1,8 +1,10 @@
 // Copyright (c) 2021, Facebook, Inc. and its affiliates
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
+use crate::utils;
 use config::{Committee, SharedCommittee, SharedWorkerCache, WorkerCache, WorkerIndex};
 use crypto::PublicKey;
+use fastcrypto::hash::Hash;
 use mysten_metrics::spawn_logged_monitored_task;
 use network::{CancelOnDropHandler, ReliableNetwork};
 use std::{collections::BTreeMap, sync::Arc};
@@ -11,7 +13,8 @@ use tokio::{sync::watch, task::JoinHandle};
 use tracing::{debug, error, info, warn};
 use types::{
     metered_channel::{Receiver, Sender},
-    Certificate, ReconfigureNotification, Round, WorkerReconfigureMessage,
+    BatchStatus, Certificate, ReconfigureNotification, Round, SequenceNumber,
+    WorkerReconfigureMessage,
 };
 
 /// Receives the highest round reached by consensus and update it for all tasks.
@@ -23,7 +26,7 @@ pub struct StateHandler {
     /// The worker information cache.
     worker_cache: SharedWorkerCache,
     /// Receives the ordered certificates from consensus.
-    rx_committed_certificates: Receiver<(Round, Vec<Certificate>)>,
+    rx_committed_certificates: Receiver<(Round, Vec<Certificate>, Option<SequenceNumber>)>,
     /// Receives notifications to reconfigure the system.
     rx_state_handler: Receiver<ReconfigureNotification>,
     /// Channel to signal committee changes.
@@ -40,7 +43,7 @@ impl StateHandler {
         name: PublicKey,
         committee: SharedCommittee,
         worker_cache: SharedWorkerCache,
-        rx_committed_certificates: Receiver<(Round, Vec<Certificate>)>,
+        rx_committed_certificates: Receiver<(Round, Vec<Certificate>, Option<SequenceNumber>)>,
         rx_state_handler: Receiver<ReconfigureNotification>,
         tx_reconfigure: watch::Sender<ReconfigureNotification>,
         tx_commited_own_headers: Option<Sender<(Round, Vec<Round>)>>,
@@ -65,18 +68,39 @@ impl StateHandler {
         )
     }
 
-    async fn handle_sequenced(&mut self, commit_round: Round, certificates: Vec<Certificate>) {
+    async fn handle_sequenced(
+        &mut self,
+        commit_round: Round,
+        certificates: Vec<Certificate>,
+        sub_dag_index: Option<SequenceNumber>,
+    ) {
         // Now we are going to signal which of our own batches have been committed.
-        let own_rounds_committed: Vec<_> = certificates
+        let own_certificates_committed: Vec<_> = certificates
             .iter()
-            .filter_map(|cert| {
-                if cert.header.author == self.name {
-                    Some(cert.header.round)
-                } else {
-                    None
-                }
-            })
+            .filter(|cert| cert.header.author == self.name)
+            .collect();
+        let own_rounds_committed: Vec<_> = own_certificates_committed
+            .iter()
+            .map(|cert| cert.header.round)
             .collect();
+
+        // Let our workers know that their batches are committed. Certificates removed by
+        // an external consensus do not belong to a sub-dag and are not reported.
+        if let Some(sub_dag_index) = sub_dag_index {
+            for certificate in own_certificates_committed {
+                utils::report_batch_status(
+                    &self.name,
+                    &self.worker_cache,
+                    &self.network,
+                    certificate,
+                    BatchStatus::Committed {
+                        certificate: certificate.digest(),
+                        round: certificate.round(),
+                        sub_dag_index,
+                    },
+                );
+            }
+        }
         debug!(
             "Own committed rounds {:?} at round {:?}",
             own_rounds_committed, commit_round
@@ -145,8 +169,8 @@ impl StateHandler {
         );
         loop {
             tokio::select! {
-                Some((commit_round, certificates)) = self.rx_committed_certificates.recv() => {
-                    self.handle_sequenced(commit_round, certificates).await;
+                Some((commit_round, certificates, sub_dag_index)) = self.rx_committed_certificates.recv() => {
+                    self.handle_sequenced(commit_round, certificates, sub_dag_index).await;
                 },
 
                 Some(message) = self.rx_state_handler.recv() => {
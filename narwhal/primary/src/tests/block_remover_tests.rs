@@ -165,7 +165,7 @@ async fn test_successful_blocks_delete() {
     // ensure deleted certificates have been populated to output channel
     let mut total_deleted = 0;
 
-    while let Ok(Some((_round, certs))) =
+    while let Ok(Some((_round, certs, _sub_dag_index))) =
         timeout(Duration::from_secs(1), rx_removed_certificates.recv()).await
     {
         for ci in certs {
@@ -1,8 +1,11 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
-use config::WorkerId;
+use config::{SharedWorkerCache, WorkerId};
+use crypto::PublicKey;
+use network::UnreliableNetwork;
 use std::collections::HashMap;
-use types::{BatchDigest, Certificate};
+use tracing::{debug, warn};
+use types::{BatchDigest, BatchStatus, Certificate, WorkerBatchStatusMessage};
 
 // a helper method that collects all the batches from each certificate and maps
 // them by the worker id.
@@ -21,3 +24,34 @@ pub fn map_certificate_batches_by_worker(
 
     batches_by_worker
 }
+
+// a helper method that reports the status of the batches of our own certificate to the
+// workers that sealed them. Reports are best effort, so clients following the batches
+// should not rely on receiving every intermediate status.
+pub fn report_batch_status(
+    name: &PublicKey,
+    worker_cache: &SharedWorkerCache,
+    network: &anemo::Network,
+    certificate: &Certificate,
+    status: BatchStatus,
+) {
+    for (worker_id, digests) in map_certificate_batches_by_worker(std::slice::from_ref(certificate))
+    {
+        let worker = match worker_cache.load().worker(name, &worker_id) {
+            Ok(worker) => worker,
+            Err(e) => {
+                warn!("Cannot report the status of batches of worker {worker_id}: {e}");
+                continue;
+            }
+        };
+        let message = WorkerBatchStatusMessage {
+            statuses: digests
+                .into_iter()
+                .map(|digest| (digest, status.clone()))
+                .collect(),
+        };
+        if let Err(e) = network.unreliable_send(worker.name, &message) {
+            debug!("Failed to report batch status to worker {worker_id}: {e}");
+        }
+    }
+}
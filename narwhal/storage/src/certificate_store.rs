@@ -305,6 +305,33 @@ impl CertificateStore {
             .collect()
     }
 
+    /// Iterates lazily over the certificates with a round in `[from, to]`, sorted by round and
+    /// origin. Unlike `after_round`, nothing past `to` is read.
+    pub fn in_rounds(
+        &self,
+        from: Round,
+        to: Round,
+    ) -> StoreResult<impl Iterator<Item = StoreResult<Certificate>> + '_> {
+        // Skip to a row at or before the requested round.
+        // TODO: Add a more efficient seek method to typed store.
+        let mut iter = self.certificate_id_by_round.iter();
+        if from > 0 {
+            iter = iter.skip_to(&(from - 1, PublicKey::default()))?;
+        }
+
+        Ok(iter
+            .skip_while(move |((r, _), _)| *r < from)
+            .take_while(move |((r, _), _)| *r <= to)
+            .map(|(_, digest)| {
+                self.certificates_by_id.get(&digest)?.ok_or_else(|| {
+                    RocksDBError(format!(
+                        "Certificate with digest {:?} not found, CertificateStore invariant violation",
+                        digest
+                    ))
+                })
+            }))
+    }
+
     /// Retrieves origins with certificates in each round >= the provided round.
     pub fn origins_after_round(
         &self,
@@ -2,11 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::proposer_store::ProposerKey;
 use crate::{CertificateStore, ProposerStore};
-use config::WorkerId;
+use config::{BatchCompressionCodec, BatchCompressionParameters, WorkerId};
 use crypto::PublicKey;
 use std::sync::Arc;
-use store::rocks::open_cf;
 use store::rocks::DBMap;
+use store::rocks::{default_db_options, open_cf_opts};
 use store::{reopen, Store};
 use types::{
     Batch, BatchDigest, Certificate, CertificateDigest, CommittedSubDagShell, ConsensusStore,
@@ -44,21 +44,40 @@ impl NodeStorage {
 
     /// Open or reopen all the storage of the node.
     pub fn reopen<Path: AsRef<std::path::Path>>(store_path: Path) -> Self {
-        let rocksdb = open_cf(
+        Self::reopen_with_batch_compression(store_path, &BatchCompressionParameters::default())
+    }
+
+    /// Open or reopen all the storage of the node, compressing the stored batches with the given
+    /// parameters. Changing the codec only affects newly written data, so a store can be reopened
+    /// with a different codec.
+    pub fn reopen_with_batch_compression<Path: AsRef<std::path::Path>>(
+        store_path: Path,
+        batch_compression: &BatchCompressionParameters,
+    ) -> Self {
+        let options = default_db_options().options;
+        // Without a codec, the batch tables keep the same options as the other tables.
+        let mut batch_options = options.clone();
+        if batch_compression.codec == BatchCompressionCodec::Zstd {
+            batch_options.set_compression_type(rocksdb::DBCompressionType::Zstd);
+            // Keep the default window bits and strategy, only override the level.
+            batch_options.set_compression_options(-14, batch_compression.level, 0, 0);
+        }
+
+        let rocksdb = open_cf_opts(
             store_path,
-            None,
+            Some(options.clone()),
             &[
-                Self::LAST_PROPOSED_CF,
-                Self::VOTES_CF,
-                Self::HEADERS_CF,
-                Self::CERTIFICATES_CF,
-                Self::CERTIFICATE_DIGEST_BY_ROUND_CF,
-                Self::CERTIFICATE_DIGEST_BY_ORIGIN_CF,
-                Self::PAYLOAD_CF,
-                Self::BATCHES_CF,
-                Self::LAST_COMMITTED_CF,
-                Self::SUB_DAG_INDEX_CF,
-                Self::TEMP_BATCH_CF,
+                (Self::LAST_PROPOSED_CF, &options),
+                (Self::VOTES_CF, &options),
+                (Self::HEADERS_CF, &options),
+                (Self::CERTIFICATES_CF, &options),
+                (Self::CERTIFICATE_DIGEST_BY_ROUND_CF, &options),
+                (Self::CERTIFICATE_DIGEST_BY_ORIGIN_CF, &options),
+                (Self::PAYLOAD_CF, &options),
+                (Self::BATCHES_CF, &batch_options),
+                (Self::LAST_COMMITTED_CF, &options),
+                (Self::SUB_DAG_INDEX_CF, &options),
+                (Self::TEMP_BATCH_CF, &batch_options),
             ],
         )
         .expect("Cannot open database");
@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anemo::async_trait;
+use anemo::types::response::StatusCode;
 use config::{
     utils::get_available_port, Authority, Committee, Epoch, SharedWorkerCache, Stake, WorkerCache,
     WorkerId, WorkerIndex, WorkerInfo,
@@ -33,9 +34,10 @@ use types::{
     GetCertificatesResponse, Header, HeaderBuilder, PayloadAvailabilityRequest,
     PayloadAvailabilityResponse, PrimaryMessage, PrimaryToPrimary, PrimaryToPrimaryServer,
     PrimaryToWorker, PrimaryToWorkerServer, RequestBatchRequest, RequestBatchResponse,
-    RequestVoteRequest, RequestVoteResponse, Round, SequenceNumber, Transaction, Vote,
-    WorkerBatchMessage, WorkerDeleteBatchesMessage, WorkerReconfigureMessage,
-    WorkerSynchronizeMessage, WorkerToWorker, WorkerToWorkerServer,
+    RequestCompressedBatchRequest, RequestCompressedBatchResponse, RequestVoteRequest,
+    RequestVoteResponse, Round, SequenceNumber, Transaction, Vote, WorkerBatchMessage,
+    WorkerBatchStatusMessage, WorkerCompressedBatchMessage, WorkerDeleteBatchesMessage,
+    WorkerReconfigureMessage, WorkerSynchronizeMessage, WorkerToWorker, WorkerToWorkerServer,
 };
 
 pub mod cluster;
@@ -289,20 +291,48 @@ impl PrimaryToWorker for PrimaryToWorkerMockServer {
         tracing::error!("Not implemented PrimaryToWorkerMockServer::delete_batches");
         Err(anemo::rpc::Status::internal("Unimplemented"))
     }
+
+    async fn report_batch_status(
+        &self,
+        _request: anemo::Request<WorkerBatchStatusMessage>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        Ok(anemo::Response::new(()))
+    }
 }
 
 pub struct WorkerToWorkerMockServer {
     batch_sender: Sender<WorkerBatchMessage>,
+    supports_compression: bool,
 }
 
 impl WorkerToWorkerMockServer {
+    /// Spawns a worker forwarding the batches it receives, decompressed, to the returned channel.
     pub fn spawn(
         keypair: NetworkKeyPair,
         address: Multiaddr,
+    ) -> (Receiver<WorkerBatchMessage>, anemo::Network) {
+        Self::spawn_inner(keypair, address, true)
+    }
+
+    /// Same as `spawn`, for a worker that predates compressed batches.
+    pub fn spawn_without_compression(
+        keypair: NetworkKeyPair,
+        address: Multiaddr,
+    ) -> (Receiver<WorkerBatchMessage>, anemo::Network) {
+        Self::spawn_inner(keypair, address, false)
+    }
+
+    fn spawn_inner(
+        keypair: NetworkKeyPair,
+        address: Multiaddr,
+        supports_compression: bool,
     ) -> (Receiver<WorkerBatchMessage>, anemo::Network) {
         let addr = network::multiaddr_to_address(&address).unwrap();
         let (batch_sender, batch_receiver) = channel(1);
-        let service = WorkerToWorkerServer::new(Self { batch_sender });
+        let service = WorkerToWorkerServer::new(Self {
+            batch_sender,
+            supports_compression,
+        });
 
         let routes = anemo::Router::new().add_rpc_service(service);
         let network = anemo::Network::bind(addr)
@@ -334,6 +364,30 @@ impl WorkerToWorker for WorkerToWorkerMockServer {
         tracing::error!("Not implemented WorkerToWorkerMockServer::request_batch");
         Err(anemo::rpc::Status::internal("Unimplemented"))
     }
+    async fn report_compressed_batch(
+        &self,
+        request: anemo::Request<WorkerCompressedBatchMessage>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        if !self.supports_compression {
+            // What a worker without the route answers.
+            return Err(anemo::rpc::Status::new(StatusCode::NotFound));
+        }
+        let batch = request.into_body().batch.decode().unwrap();
+
+        self.batch_sender
+            .send(WorkerBatchMessage { batch })
+            .await
+            .unwrap();
+
+        Ok(anemo::Response::new(()))
+    }
+    async fn request_compressed_batch(
+        &self,
+        _request: anemo::Request<RequestCompressedBatchRequest>,
+    ) -> Result<anemo::Response<RequestCompressedBatchResponse>, anemo::rpc::Status> {
+        tracing::error!("Not implemented WorkerToWorkerMockServer::request_compressed_batch");
+        Err(anemo::rpc::Status::internal("Unimplemented"))
+    }
 }
 
 ////////////////////////////////////////////////////////////////
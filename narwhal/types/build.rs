@@ -128,6 +128,15 @@ fn build_anemo_services(out_dir: &Path) {
                 .codec_path("anemo::rpc::codec::BincodeCodec")
                 .build(),
         )
+        .method(
+            anemo_build::manual::Method::builder()
+                .name("report_batch_status")
+                .route_name("ReportBatchStatus")
+                .request_type("crate::WorkerBatchStatusMessage")
+                .response_type("()")
+                .codec_path("anemo::rpc::codec::BincodeCodec")
+                .build(),
+        )
         .build();
 
     let worker_to_primary = anemo_build::manual::Service::builder()
@@ -185,6 +194,26 @@ fn build_anemo_services(out_dir: &Path) {
                 .codec_path("anemo::rpc::codec::BincodeCodec")
                 .build(),
         )
+        // Compressed batches have their own routes, so that workers that don't support them
+        // answer with NotFound and can be sent uncompressed batches instead.
+        .method(
+            anemo_build::manual::Method::builder()
+                .name("report_compressed_batch")
+                .route_name("ReportCompressedBatch")
+                .request_type("crate::WorkerCompressedBatchMessage")
+                .response_type("()")
+                .codec_path("anemo::rpc::codec::BincodeCodec")
+                .build(),
+        )
+        .method(
+            anemo_build::manual::Method::builder()
+                .name("request_compressed_batch")
+                .route_name("RequestCompressedBatch")
+                .request_type("crate::RequestCompressedBatchRequest")
+                .response_type("crate::RequestCompressedBatchResponse")
+                .codec_path("anemo::rpc::codec::BincodeCodec")
+                .build(),
+        )
         .build();
 
     anemo_build::manual::Builder::new()
@@ -150,6 +150,17 @@ impl ConsensusStore {
         s
     }
 
+    /// Iterates lazily over the committed sub dags, from the latest one backwards.
+    pub fn reverse_iter_committed_sub_dags(
+        &self,
+    ) -> impl Iterator<Item = CommittedSubDagShell> + '_ {
+        self.committed_sub_dags_by_index
+            .iter()
+            .skip_to_last()
+            .reverse()
+            .map(|(_, sub_dag)| sub_dag)
+    }
+
     /// Load all the sub dags committed with sequence number of at least `from`.
     pub fn read_committed_sub_dags_from(
         &self,
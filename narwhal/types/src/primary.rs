@@ -4,7 +4,7 @@
 use crate::{
     error::{DagError, DagResult},
     serde::NarwhalBitmap,
-    CertificateDigestProto,
+    CertificateDigestProto, SequenceNumber,
 };
 use bytes::Bytes;
 use config::{Committee, Epoch, SharedWorkerCache, Stake, WorkerId, WorkerInfo};
@@ -955,6 +955,30 @@ pub struct WorkerDeleteBatchesMessage {
     pub digests: Vec<BatchDigest>,
 }
 
+/// The progress of a batch sealed by one of our workers through the consensus pipeline.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum BatchStatus {
+    /// The batch was stored by a quorum of workers and reported to the primary.
+    Sealed,
+    /// A header including the batch was certified.
+    Certified {
+        certificate: CertificateDigest,
+        round: Round,
+    },
+    /// The certificate including the batch was committed as part of a sub-dag.
+    Committed {
+        certificate: CertificateDigest,
+        round: Round,
+        sub_dag_index: SequenceNumber,
+    },
+}
+
+/// Used by the primary to inform the worker about the progress of the batches it sealed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WorkerBatchStatusMessage {
+    pub statuses: Vec<(BatchDigest, BatchStatus)>,
+}
+
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
 pub struct BatchMessage {
     // TODO: revisit including the digest here [see #188]
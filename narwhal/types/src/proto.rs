@@ -12,11 +12,12 @@ mod narwhal {
 
 use std::{array::TryFromSliceError, ops::Deref};
 
-use crate::{BlockError, BlockErrorKind, CertificateDigest, Transaction};
+use crate::{BatchDigest, BatchStatus, BlockError, BlockErrorKind, CertificateDigest, Transaction};
 use bytes::Bytes;
 use crypto::PublicKey;
 
 pub use narwhal::{
+    batch_status_update::Status as BatchStatusProto,
     collection_error::CollectionErrorType,
     collection_retrieval_result::RetrievalResult,
     configuration_client::ConfigurationClient,
@@ -35,12 +36,14 @@ pub use narwhal::{
     worker_to_primary_server::{MockWorkerToPrimary, WorkerToPrimary, WorkerToPrimaryServer},
     worker_to_worker_client::WorkerToWorkerClient,
     worker_to_worker_server::{MockWorkerToWorker, WorkerToWorker, WorkerToWorkerServer},
-    CertificateDigest as CertificateDigestProto, Collection, CollectionError,
-    CollectionRetrievalResult, Empty, GetCollectionsRequest, GetCollectionsResponse,
-    GetPrimaryAddressResponse, MultiAddr as MultiAddrProto, NewEpochRequest, NewNetworkInfoRequest,
-    NodeReadCausalRequest, NodeReadCausalResponse, PublicKey as PublicKeyProto, ReadCausalRequest,
-    ReadCausalResponse, RemoveCollectionsRequest, RoundsRequest, RoundsResponse,
-    Transaction as TransactionProto, ValidatorData,
+    BatchCertified, BatchCommitted, BatchDigest as BatchDigestProto, BatchSealed,
+    BatchStatusRequest, BatchStatusUpdate, CertificateDigest as CertificateDigestProto, Collection,
+    CollectionError, CollectionRetrievalResult, Empty, GetCollectionsRequest,
+    GetCollectionsResponse, GetPrimaryAddressResponse, MultiAddr as MultiAddrProto,
+    NewEpochRequest, NewNetworkInfoRequest, NodeReadCausalRequest, NodeReadCausalResponse,
+    PublicKey as PublicKeyProto, ReadCausalRequest, ReadCausalResponse, RemoveCollectionsRequest,
+    RoundsRequest, RoundsResponse, SubmitTransactionResponse, Transaction as TransactionProto,
+    ValidatorData,
 };
 
 impl From<PublicKey> for PublicKeyProto {
@@ -91,3 +94,46 @@ impl TryFrom<CertificateDigestProto> for CertificateDigest {
         Ok(CertificateDigest::new(digest.digest.deref().try_into()?))
     }
 }
+
+impl From<BatchDigest> for BatchDigestProto {
+    fn from(digest: BatchDigest) -> Self {
+        BatchDigestProto {
+            digest: Bytes::from(digest.0.to_vec()),
+        }
+    }
+}
+
+impl TryFrom<BatchDigestProto> for BatchDigest {
+    type Error = TryFromSliceError;
+
+    fn try_from(digest: BatchDigestProto) -> Result<Self, Self::Error> {
+        Ok(BatchDigest::new(digest.digest.deref().try_into()?))
+    }
+}
+
+impl From<(BatchDigest, BatchStatus)> for BatchStatusUpdate {
+    fn from((digest, status): (BatchDigest, BatchStatus)) -> Self {
+        let status = match status {
+            BatchStatus::Sealed => BatchStatusProto::Sealed(BatchSealed {}),
+            BatchStatus::Certified { certificate, round } => {
+                BatchStatusProto::Certified(BatchCertified {
+                    certificate: Some(certificate.into()),
+                    round,
+                })
+            }
+            BatchStatus::Committed {
+                certificate,
+                round,
+                sub_dag_index,
+            } => BatchStatusProto::Committed(BatchCommitted {
+                certificate: Some(certificate.into()),
+                round,
+                sub_dag_index,
+            }),
+        };
+        BatchStatusUpdate {
+            batch_digest: Some(digest.into()),
+            status: Some(status),
+        }
+    }
+}
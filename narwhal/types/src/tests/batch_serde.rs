@@ -1,7 +1,11 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{serialized_batch_digest, Batch, Metadata, WorkerBatchMessage};
+use crate::{
+    serialized_batch_digest, Batch, BatchCodecError, EncodedBatch, Metadata, WorkerBatchMessage,
+    WorkerCompressedBatchMessage, MAX_DECOMPRESSED_BATCH_SIZE,
+};
+use config::{BatchCompressionCodec, BatchCompressionParameters};
 use fastcrypto::{
     encoding::{Encoding, Hex},
     hash::Hash,
@@ -118,6 +122,48 @@ fn test_bincode_serde_batch_message() {
     );
 }
 
+#[test]
+fn test_encoded_batch_round_trip() {
+    let batch = Batch::new((0..100).map(|_| vec![7; 512]).collect());
+    let zstd = BatchCompressionParameters {
+        codec: BatchCompressionCodec::Zstd,
+        level: 3,
+    };
+
+    let uncompressed = EncodedBatch::encode(batch.clone(), &BatchCompressionParameters::default());
+    assert_eq!(uncompressed.codec(), BatchCompressionCodec::None);
+    assert_eq!(uncompressed.decode().unwrap(), batch);
+
+    let compressed = EncodedBatch::encode(batch.clone(), &zstd);
+    assert_eq!(compressed.codec(), BatchCompressionCodec::Zstd);
+    assert!(
+        compressed.compressed_len().unwrap() < bincode::serialized_size(&batch).unwrap() as usize
+    );
+    assert_eq!(compressed.decode().unwrap(), batch);
+
+    // A compressed batch travels in its own message.
+    let message = WorkerCompressedBatchMessage { batch: compressed };
+    let bytes = bincode::serialize(&message).unwrap();
+    let received: WorkerCompressedBatchMessage = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(received.batch.decode().unwrap(), batch);
+
+    // Batches that do not compress well are sent as-is.
+    let empty = EncodedBatch::encode(Batch::new(vec![]), &zstd);
+    assert_eq!(empty.codec(), BatchCompressionCodec::None);
+}
+
+#[test]
+fn test_encoded_batch_rejects_oversized_payload() {
+    let bytes = vec![0; MAX_DECOMPRESSED_BATCH_SIZE + 1];
+    let compressed = zstd::bulk::compress(&bytes, 3).unwrap();
+
+    assert!(matches!(
+        EncodedBatch::Zstd(compressed).decode(),
+        Err(BatchCodecError::TooLarge)
+    ));
+    assert!(EncodedBatch::Zstd(vec![1, 2, 3]).decode().is_err());
+}
+
 proptest::proptest! {
 
     #[test]
@@ -3,20 +3,32 @@
 
 use crate::{Batch, BatchDigest};
 
+use config::{BatchCompressionCodec, BatchCompressionParameters};
 use fastcrypto::hash::HashFunction;
 use serde::{Deserialize, Serialize};
+use std::io::Read;
 use thiserror::Error;
 
 #[cfg(test)]
 #[path = "tests/batch_serde.rs"]
 mod batch_serde;
 
+/// The maximum size of a decompressed batch. Compressed batches that would expand beyond this
+/// size are rejected, to protect against decompression bombs.
+pub const MAX_DECOMPRESSED_BATCH_SIZE: usize = 64 * 1024 * 1024;
+
 /// Used by workers to send a new batch.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct WorkerBatchMessage {
     pub batch: Batch,
 }
 
+/// Used by workers to send a new batch, possibly compressed.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WorkerCompressedBatchMessage {
+    pub batch: EncodedBatch,
+}
+
 /// Used by primary to ask worker for the request.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RequestBatchRequest {
@@ -28,10 +40,100 @@ pub struct RequestBatchResponse {
     pub batch: Option<Batch>,
 }
 
+/// Used by workers to ask another worker for a batch, possibly compressed.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RequestCompressedBatchRequest {
+    pub batch: BatchDigest,
+    /// The codec the requester would like the batch to be compressed with. The responder may
+    /// still send the batch uncompressed.
+    pub compression: BatchCompressionCodec,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RequestCompressedBatchResponse {
+    pub batch: Option<EncodedBatch>,
+}
+
+/// A batch as sent over the network, optionally compressed. The variant tells the receiver
+/// which codec to use to decode it, so peers can use different codecs.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum EncodedBatch {
+    Uncompressed(Batch),
+    /// The zstd-compressed bincode serialization of the batch.
+    Zstd(Vec<u8>),
+}
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum BatchCodecError {
+    #[error("Failed to decompress batch: {0}")]
+    Decompression(#[from] std::io::Error),
+    #[error("Decompressed batch exceeds {MAX_DECOMPRESSED_BATCH_SIZE} bytes")]
+    TooLarge,
+    #[error("Failed to deserialize batch: {0}")]
+    Deserialization(#[from] bincode::Error),
+}
+
+impl EncodedBatch {
+    /// Encodes a batch with the given parameters. The batch is kept uncompressed if the codec
+    /// fails or does not reduce its size.
+    pub fn encode(batch: Batch, parameters: &BatchCompressionParameters) -> Self {
+        match parameters.codec {
+            BatchCompressionCodec::None => Self::Uncompressed(batch),
+            BatchCompressionCodec::Zstd => {
+                let bytes = bincode::serialize(&batch).expect("Failed to serialize batch");
+                match zstd::bulk::compress(&bytes, parameters.level) {
+                    Ok(compressed) if compressed.len() < bytes.len() => Self::Zstd(compressed),
+                    _ => Self::Uncompressed(batch),
+                }
+            }
+        }
+    }
+
+    /// Decodes the batch, decompressing it if needed.
+    pub fn decode(self) -> Result<Batch, BatchCodecError> {
+        match self {
+            Self::Uncompressed(batch) => Ok(batch),
+            Self::Zstd(compressed) => {
+                let mut bytes = Vec::new();
+                zstd::stream::read::Decoder::new(compressed.as_slice())?
+                    .take(MAX_DECOMPRESSED_BATCH_SIZE as u64 + 1)
+                    .read_to_end(&mut bytes)?;
+                if bytes.len() > MAX_DECOMPRESSED_BATCH_SIZE {
+                    return Err(BatchCodecError::TooLarge);
+                }
+                Ok(bincode::deserialize(&bytes)?)
+            }
+        }
+    }
+
+    /// The codec this batch is encoded with.
+    pub fn codec(&self) -> BatchCompressionCodec {
+        match self {
+            Self::Uncompressed(_) => BatchCompressionCodec::None,
+            Self::Zstd(_) => BatchCompressionCodec::Zstd,
+        }
+    }
+
+    /// The size in bytes of the compressed payload, if the batch is compressed.
+    pub fn compressed_len(&self) -> Option<usize> {
+        match self {
+            Self::Uncompressed(_) => None,
+            Self::Zstd(compressed) => Some(compressed.len()),
+        }
+    }
+}
+
+impl From<Batch> for EncodedBatch {
+    fn from(batch: Batch) -> Self {
+        Self::Uncompressed(batch)
+    }
+}
+
 pub type TxResponse = tokio::sync::oneshot::Sender<BatchDigest>;
 pub type PrimaryResponse = Option<tokio::sync::oneshot::Sender<()>>;
 
-/// Hashes a serialized batch message without deserializing it into a batch.
+/// Hashes a serialized batch without deserializing it.
 ///
 /// See the test `test_batch_and_serialized`, which guarantees that the output of this
 /// function remains the same as the [`fastcrypto::hash::Hash::digest`] result you would get from [`Batch`].
@@ -0,0 +1,101 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use futures::{stream, Stream};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+use tokio::sync::watch;
+use types::{BatchDigest, BatchStatus};
+
+#[cfg(test)]
+#[path = "tests/batch_status_tests.rs"]
+pub mod batch_status_tests;
+
+/// The maximum number of batches whose status is remembered. Once the limit is reached, the
+/// status of the oldest batches is dropped.
+pub const MAX_TRACKED_BATCHES: usize = 10_000;
+
+/// Keeps track of the progress of the batches sealed by this worker, so that clients can follow
+/// their transactions through the consensus pipeline. Batches are registered once sealed, and
+/// their status is then updated by the primary as the batch gets certified and committed.
+#[derive(Clone, Default)]
+pub struct BatchStatusTracker {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    batches: HashMap<BatchDigest, watch::Sender<BatchStatus>>,
+    /// The tracked batches in insertion order, used to evict the oldest ones.
+    order: VecDeque<BatchDigest>,
+}
+
+impl BatchStatusTracker {
+    /// Records the new status of a batch. Updates that do not make progress (e.g. a header
+    /// certified after the batch was already committed) are ignored.
+    pub fn update(&self, digest: BatchDigest, status: BatchStatus) {
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(sender) = inner.batches.get(&digest) {
+            sender.send_if_modified(|current| {
+                if stage(&status) > stage(current) {
+                    *current = status;
+                    true
+                } else {
+                    false
+                }
+            });
+            return;
+        }
+
+        let (sender, _) = watch::channel(status);
+        inner.batches.insert(digest, sender);
+        inner.order.push_back(digest);
+        while inner.order.len() > MAX_TRACKED_BATCHES {
+            if let Some(evicted) = inner.order.pop_front() {
+                inner.batches.remove(&evicted);
+            }
+        }
+    }
+
+    /// Returns the latest known status of a batch.
+    pub fn status(&self, digest: &BatchDigest) -> Option<BatchStatus> {
+        let inner = self.inner.lock().unwrap();
+        inner
+            .batches
+            .get(digest)
+            .map(|sender| sender.borrow().clone())
+    }
+
+    /// Returns a stream of the status updates of a batch, starting with its current status. The
+    /// stream ends once the batch is committed, or if the batch stops being tracked. Returns None
+    /// if the batch is not known to this worker.
+    pub fn subscribe(&self, digest: &BatchDigest) -> Option<impl Stream<Item = BatchStatus>> {
+        let receiver = {
+            let inner = self.inner.lock().unwrap();
+            inner.batches.get(digest)?.subscribe()
+        };
+        Some(stream::unfold(
+            (receiver, true, false),
+            |(mut receiver, first, done)| async move {
+                if done {
+                    return None;
+                }
+                if !first && receiver.changed().await.is_err() {
+                    return None;
+                }
+                let status = receiver.borrow_and_update().clone();
+                let done = matches!(status, BatchStatus::Committed { .. });
+                Some((status, (receiver, false, done)))
+            },
+        ))
+    }
+}
+
+fn stage(status: &BatchStatus) -> u8 {
+    match status {
+        BatchStatus::Sealed => 0,
+        BatchStatus::Certified { .. } => 1,
+        BatchStatus::Committed { .. } => 2,
+    }
+}
@@ -4,7 +4,10 @@
 use anemo::types::response::StatusCode;
 use anyhow::Result;
 use async_trait::async_trait;
-use config::{Committee, SharedCommittee, SharedWorkerCache, WorkerCache, WorkerId, WorkerIndex};
+use config::{
+    BatchCompressionCodec, BatchCompressionParameters, Committee, SharedCommittee,
+    SharedWorkerCache, WorkerCache, WorkerId, WorkerIndex,
+};
 use crypto::PublicKey;
 use fastcrypto::hash::Hash;
 use futures::{stream::FuturesUnordered, StreamExt};
@@ -20,15 +23,17 @@ use tap::TapOptional;
 use tokio::{sync::watch, time::sleep};
 use tracing::{debug, error, info, trace, warn};
 use types::{
-    metered_channel::Sender, Batch, BatchDigest, PrimaryToWorker, ReconfigureNotification,
-    RequestBatchRequest, RequestBatchResponse, WorkerBatchMessage, WorkerDeleteBatchesMessage,
+    metered_channel::Sender, Batch, BatchDigest, EncodedBatch, PrimaryToWorker,
+    ReconfigureNotification, RequestBatchRequest, RequestBatchResponse,
+    RequestCompressedBatchRequest, RequestCompressedBatchResponse, WorkerBatchMessage,
+    WorkerBatchStatusMessage, WorkerCompressedBatchMessage, WorkerDeleteBatchesMessage,
     WorkerOthersBatchMessage, WorkerReconfigureMessage, WorkerSynchronizeMessage, WorkerToWorker,
     WorkerToWorkerClient,
 };
 
 use mysten_metrics::monitored_future;
 
-use crate::TransactionValidator;
+use crate::{batch_status::BatchStatusTracker, TransactionValidator};
 
 #[cfg(test)]
 #[path = "tests/handlers_tests.rs"]
@@ -41,24 +46,20 @@ pub struct WorkerReceiverHandler<V> {
     pub tx_others_batch: Sender<WorkerOthersBatchMessage>,
     pub store: Store<BatchDigest, Batch>,
     pub validator: V,
+    pub batch_compression: BatchCompressionParameters,
 }
 
-#[async_trait]
-impl<V: TransactionValidator> WorkerToWorker for WorkerReceiverHandler<V> {
-    async fn report_batch(
-        &self,
-        request: anemo::Request<WorkerBatchMessage>,
-    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
-        let message = request.into_body();
-        if let Err(err) = self.validator.validate_batch(&message.batch) {
+impl<V: TransactionValidator> WorkerReceiverHandler<V> {
+    async fn process_batch(&self, batch: Batch) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        if let Err(err) = self.validator.validate_batch(&batch) {
             // The batch is invalid, we don't want to process it.
             return Err(anemo::rpc::Status::new_with_message(
                 StatusCode::BadRequest,
                 format!("Invalid batch: {err}"),
             ));
         }
-        let digest = message.batch.digest();
-        self.store.async_write(digest, message.batch).await;
+        let digest = batch.digest();
+        self.store.async_write(digest, batch).await;
         self.tx_others_batch
             .send(WorkerOthersBatchMessage {
                 digest,
@@ -69,20 +70,62 @@ impl<V: TransactionValidator> WorkerToWorker for WorkerReceiverHandler<V> {
             .map_err(|e| anemo::rpc::Status::internal(e.to_string()))
     }
 
+    async fn read_batch(&self, digest: BatchDigest) -> Result<Option<Batch>, anemo::rpc::Status> {
+        // TODO [issue #7]: Do some accounting to prevent bad actors from monopolizing our resources
+        self.store
+            .read(digest)
+            .await
+            .map_err(|e| anemo::rpc::Status::from_error(Box::new(e)))
+    }
+}
+
+#[async_trait]
+impl<V: TransactionValidator> WorkerToWorker for WorkerReceiverHandler<V> {
+    async fn report_batch(
+        &self,
+        request: anemo::Request<WorkerBatchMessage>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        self.process_batch(request.into_body().batch).await
+    }
+
     async fn request_batch(
         &self,
         request: anemo::Request<RequestBatchRequest>,
     ) -> Result<anemo::Response<RequestBatchResponse>, anemo::rpc::Status> {
-        // TODO [issue #7]: Do some accounting to prevent bad actors from monopolizing our resources
-        let batch = request.into_body().batch;
-        let batch = self
-            .store
-            .read(batch)
-            .await
-            .map_err(|e| anemo::rpc::Status::from_error(Box::new(e)))?;
-
+        let batch = self.read_batch(request.into_body().batch).await?;
         Ok(anemo::Response::new(RequestBatchResponse { batch }))
     }
+
+    async fn report_compressed_batch(
+        &self,
+        request: anemo::Request<WorkerCompressedBatchMessage>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        let batch = request.into_body().batch.decode().map_err(|err| {
+            anemo::rpc::Status::new_with_message(
+                StatusCode::BadRequest,
+                format!("Undecodable batch: {err}"),
+            )
+        })?;
+        self.process_batch(batch).await
+    }
+
+    async fn request_compressed_batch(
+        &self,
+        request: anemo::Request<RequestCompressedBatchRequest>,
+    ) -> Result<anemo::Response<RequestCompressedBatchResponse>, anemo::rpc::Status> {
+        let request = request.into_body();
+        let batch = self.read_batch(request.batch).await?;
+
+        // Compress the batch with the codec asked for by the requester.
+        let parameters = BatchCompressionParameters {
+            codec: request.compression,
+            level: self.batch_compression.level,
+        };
+        let batch = batch.map(|batch| EncodedBatch::encode(batch, &parameters));
+        Ok(anemo::Response::new(RequestCompressedBatchResponse {
+            batch,
+        }))
+    }
 }
 
 /// Defines how the network receiver handles incoming primary messages.
@@ -105,6 +148,10 @@ pub struct PrimaryReceiverHandler<V> {
     pub tx_reconfigure: watch::Sender<ReconfigureNotification>,
     // Validate incoming batches
     pub validator: V,
+    // The status of the batches sealed by this worker.
+    pub batch_status: BatchStatusTracker,
+    // The compression requested for the batches fetched from other workers.
+    pub batch_compression: BatchCompressionParameters,
 }
 
 #[async_trait]
@@ -171,7 +218,10 @@ impl<V: TransactionValidator> PrimaryToWorker for PrimaryReceiverHandler<V> {
             let batch_requests: Vec<_> = missing
                 .iter()
                 .cloned()
-                .map(|batch| RequestBatchRequest { batch })
+                .map(|batch| RequestCompressedBatchRequest {
+                    batch,
+                    compression: self.batch_compression.codec,
+                })
                 .collect();
             let network = request
                 .extensions()
@@ -182,15 +232,36 @@ impl<V: TransactionValidator> PrimaryToWorker for PrimaryReceiverHandler<V> {
                 })?;
 
             let mut handles = FuturesUnordered::new();
-            let request_batch_fn =
-                |mut client: WorkerToWorkerClient<anemo::Peer>, batch_request, timeout| {
-                    // Wrapper function enables us to move `client` into the future.
-                    monitored_future!(async move {
-                        client
-                            .request_batch(anemo::Request::new(batch_request).with_timeout(timeout))
-                            .await
-                    })
-                };
+            let request_batch_fn = |mut client: WorkerToWorkerClient<anemo::Peer>,
+                                    batch_request: RequestCompressedBatchRequest,
+                                    timeout| {
+                // Wrapper function enables us to move `client` into the future.
+                monitored_future!(async move {
+                    if batch_request.compression != BatchCompressionCodec::None {
+                        let result = client
+                            .request_compressed_batch(
+                                anemo::Request::new(batch_request.clone()).with_timeout(timeout),
+                            )
+                            .await;
+                        // Fall back to uncompressed batches if the peer doesn't support them.
+                        match result {
+                            Err(status) if status.status() == StatusCode::NotFound => {}
+                            result => return result,
+                        }
+                    }
+                    let request = RequestBatchRequest {
+                        batch: batch_request.batch,
+                    };
+                    client
+                        .request_batch(anemo::Request::new(request).with_timeout(timeout))
+                        .await
+                        .map(|response| {
+                            anemo::Response::new(RequestCompressedBatchResponse {
+                                batch: response.into_body().batch.map(EncodedBatch::from),
+                            })
+                        })
+                })
+            };
             if first_attempt {
                 // Send first sync request to a single node.
                 let worker_name = match self.worker_cache.load().worker(&message.target, &self.id) {
@@ -253,6 +324,12 @@ impl<V: TransactionValidator> PrimaryToWorker for PrimaryReceiverHandler<V> {
                 match result {
                     Ok(response) => {
                         if let Some(batch) = response.into_body().batch {
+                            let batch = batch.decode().map_err(|err| {
+                                anemo::rpc::Status::new_with_message(
+                                    StatusCode::BadRequest,
+                                    format!("Undecodable batch: {err}"),
+                                )
+                            })?;
                             if let Err(err) = self.validator.validate_batch(&batch) {
                                 // The batch is invalid, we don't want to process it.
                                 return Err(anemo::rpc::Status::new_with_message(
@@ -300,6 +377,17 @@ impl<V: TransactionValidator> PrimaryToWorker for PrimaryReceiverHandler<V> {
 
         Ok(anemo::Response::new(()))
     }
+
+    async fn report_batch_status(
+        &self,
+        request: anemo::Request<WorkerBatchStatusMessage>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        for (digest, status) in request.into_body().statuses {
+            self.batch_status.update(digest, status);
+        }
+
+        Ok(anemo::Response::new(()))
+    }
 }
 
 impl<V: TransactionValidator> PrimaryReceiverHandler<V> {
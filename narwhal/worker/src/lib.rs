@@ -9,6 +9,7 @@
 )]
 
 mod batch_maker;
+mod batch_status;
 mod handlers;
 pub mod metrics;
 mod primary_connector;
@@ -16,5 +17,6 @@ mod quorum_waiter;
 mod tx_validator;
 mod worker;
 
+pub use crate::batch_status::{BatchStatusTracker, MAX_TRACKED_BATCHES};
 pub use crate::tx_validator::{TransactionValidator, TrivialTransactionValidator};
 pub use crate::worker::Worker;
@@ -61,6 +61,10 @@ pub struct WorkerMetrics {
     pub created_batch_latency: HistogramVec,
     /// The number of parallel worker batches currently processed by the worker
     pub parallel_worker_batches: IntGauge,
+    /// Size in bytes of the batches broadcasted to the other workers, after compression
+    pub compressed_batch_size: HistogramVec,
+    /// Ratio between the uncompressed and compressed size of the broadcasted batches
+    pub batch_compression_ratio: HistogramVec,
 }
 
 impl WorkerMetrics {
@@ -102,6 +106,35 @@ impl WorkerMetrics {
                 registry
             )
             .unwrap(),
+            compressed_batch_size: register_histogram_vec_with_registry!(
+                "compressed_batch_size",
+                "Size in bytes of the broadcasted batches, after compression",
+                &["epoch", "codec"],
+                // buckets with size in bytes
+                vec![
+                    100.0,
+                    500.0,
+                    1_000.0,
+                    5_000.0,
+                    10_000.0,
+                    20_000.0,
+                    50_000.0,
+                    100_000.0,
+                    250_000.0,
+                    500_000.0,
+                    1_000_000.0
+                ],
+                registry
+            )
+            .unwrap(),
+            batch_compression_ratio: register_histogram_vec_with_registry!(
+                "batch_compression_ratio",
+                "Ratio between the uncompressed and compressed size of the broadcasted batches",
+                &["epoch", "codec"],
+                vec![1.0, 1.1, 1.25, 1.5, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0],
+                registry
+            )
+            .unwrap(),
         }
     }
 }
@@ -2,17 +2,20 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::batch_maker::MAX_PARALLEL_BATCH;
-use config::{Committee, SharedWorkerCache, Stake, WorkerId};
+use crate::{batch_maker::MAX_PARALLEL_BATCH, metrics::WorkerMetrics};
+use config::{BatchCompressionParameters, Committee, SharedWorkerCache, Stake, WorkerId};
 use crypto::PublicKey;
 use fastcrypto::hash::Hash;
 use futures::stream::{futures_unordered::FuturesUnordered, FuturesOrdered, StreamExt as _};
 use mysten_metrics::{monitored_future, spawn_logged_monitored_task};
 use network::{CancelOnDropHandler, ReliableNetwork};
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 use tokio::{sync::watch, task::JoinHandle, time::timeout};
 use tracing::{error, trace};
-use types::{metered_channel::Receiver, Batch, ReconfigureNotification, WorkerBatchMessage};
+use types::{
+    metered_channel::Receiver, Batch, EncodedBatch, ReconfigureNotification, WorkerBatchMessage,
+    WorkerCompressedBatchMessage,
+};
 
 #[cfg(test)]
 #[path = "tests/quorum_waiter_tests.rs"]
@@ -34,6 +37,10 @@ pub struct QuorumWaiter {
     rx_message: Receiver<(Batch, Option<tokio::sync::oneshot::Sender<()>>)>,
     /// A network sender to broadcast the batches to the other workers.
     network: anemo::Network,
+    /// The compression applied to the broadcasted batches.
+    batch_compression: BatchCompressionParameters,
+    /// Metrics handler
+    node_metrics: Arc<WorkerMetrics>,
 }
 
 impl QuorumWaiter {
@@ -47,6 +54,8 @@ impl QuorumWaiter {
         rx_reconfigure: watch::Receiver<ReconfigureNotification>,
         rx_message: Receiver<(Batch, Option<tokio::sync::oneshot::Sender<()>>)>,
         network: anemo::Network,
+        batch_compression: BatchCompressionParameters,
+        node_metrics: Arc<WorkerMetrics>,
     ) -> JoinHandle<()> {
         spawn_logged_monitored_task!(
             async move {
@@ -58,6 +67,8 @@ impl QuorumWaiter {
                     rx_reconfigure,
                     rx_message,
                     network,
+                    batch_compression,
+                    node_metrics,
                 }
                 .run()
                 .await;
@@ -75,6 +86,25 @@ impl QuorumWaiter {
         deliver
     }
 
+    /// Compresses a batch before broadcasting it, recording how well it compressed.
+    fn encode(&self, batch: Batch) -> EncodedBatch {
+        let size = bincode::serialized_size(&batch).expect("Failed to serialize batch");
+        let encoded = EncodedBatch::encode(batch, &self.batch_compression);
+
+        let epoch = self.committee.epoch.to_string();
+        let codec = encoded.codec().to_string();
+        let compressed_size = encoded.compressed_len().map_or(size, |len| len as u64);
+        self.node_metrics
+            .compressed_batch_size
+            .with_label_values(&[epoch.as_str(), codec.as_str()])
+            .observe(compressed_size as f64);
+        self.node_metrics
+            .batch_compression_ratio
+            .with_label_values(&[epoch.as_str(), codec.as_str()])
+            .observe(size as f64 / compressed_size.max(1) as f64);
+        encoded
+    }
+
     /// Main loop.
     async fn run(&mut self) {
         //
@@ -98,8 +128,16 @@ impl QuorumWaiter {
                         .map(|(name, info)| (name, info.name))
                         .collect();
                     let (primary_names, worker_names): (Vec<_>, _) = workers.into_iter().unzip();
-                    let message  = WorkerBatchMessage{batch: batch.clone()};
-                    let handlers = self.network.broadcast(worker_names, &message);
+                    // Uncompressed batches are sent on the original route, which every worker
+                    // supports.
+                    let handlers = match self.encode(batch.clone()) {
+                        EncodedBatch::Uncompressed(batch) => self
+                            .network
+                            .broadcast(worker_names, &WorkerBatchMessage { batch }),
+                        batch => self
+                            .network
+                            .broadcast(worker_names, &WorkerCompressedBatchMessage { batch }),
+                    };
 
                     // Collect all the handlers to receive acknowledgements.
                     let mut wait_for_quorum: FuturesUnordered<_> = primary_names
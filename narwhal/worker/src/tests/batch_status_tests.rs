@@ -0,0 +1,67 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use super::*;
+use fastcrypto::hash::Hash;
+use futures::StreamExt;
+use test_utils::batch;
+use types::CertificateDigest;
+
+#[tokio::test]
+async fn stream_batch_status_until_committed() {
+    let tracker = BatchStatusTracker::default();
+    let digest = batch().digest();
+    let certificate = CertificateDigest::new([1; 32]);
+
+    assert!(tracker.subscribe(&digest).is_none());
+    tracker.update(digest, BatchStatus::Sealed);
+
+    let mut updates = Box::pin(tracker.subscribe(&digest).unwrap());
+    assert_eq!(updates.next().await, Some(BatchStatus::Sealed));
+
+    let certified = BatchStatus::Certified {
+        certificate,
+        round: 3,
+    };
+    tracker.update(digest, certified.clone());
+    assert_eq!(updates.next().await, Some(certified));
+
+    let committed = BatchStatus::Committed {
+        certificate,
+        round: 3,
+        sub_dag_index: 7,
+    };
+    tracker.update(digest, committed.clone());
+    assert_eq!(updates.next().await, Some(committed.clone()));
+    // The stream ends once the batch is committed.
+    assert_eq!(updates.next().await, None);
+
+    // Late updates do not move the batch back.
+    tracker.update(digest, BatchStatus::Sealed);
+    assert_eq!(tracker.status(&digest), Some(committed.clone()));
+
+    // New subscribers immediately get the final status.
+    let updates = tracker.subscribe(&digest).unwrap();
+    assert_eq!(updates.collect::<Vec<_>>().await, vec![committed]);
+}
+
+#[tokio::test]
+async fn evict_oldest_batches() {
+    let tracker = BatchStatusTracker::default();
+    let digests: Vec<_> = (0..=MAX_TRACKED_BATCHES)
+        .map(|i| {
+            let mut bytes = [0; 32];
+            bytes[..8].copy_from_slice(&(i as u64).to_le_bytes());
+            BatchDigest::new(bytes)
+        })
+        .collect();
+    for digest in &digests {
+        tracker.update(*digest, BatchStatus::Sealed);
+    }
+
+    assert_eq!(tracker.status(&digests[0]), None);
+    assert_eq!(tracker.status(&digests[1]), Some(BatchStatus::Sealed));
+    assert_eq!(
+        tracker.status(&digests[MAX_TRACKED_BATCHES]),
+        Some(BatchStatus::Sealed)
+    );
+}
@@ -6,7 +6,7 @@ use super::*;
 use crate::TrivialTransactionValidator;
 use fastcrypto::hash::Hash;
 use test_utils::CommitteeFixture;
-use types::{MockWorkerToWorker, WorkerToWorkerServer};
+use types::{BatchStatus, CertificateDigest, MockWorkerToWorker, WorkerToWorkerServer};
 
 #[tokio::test]
 async fn synchronize() {
@@ -33,6 +33,100 @@ async fn synchronize() {
         request_batch_retry_nodes: 3, // Not used in this test.
         tx_reconfigure,
         validator: TrivialTransactionValidator,
+        batch_status: BatchStatusTracker::default(),
+        batch_compression: BatchCompressionParameters {
+            codec: BatchCompressionCodec::Zstd,
+            level: 3,
+        },
+    };
+
+    // Set up mock behavior for child RequestBatches RPC.
+    let target_primary = fixture.authorities().nth(1).unwrap();
+    let batch = Batch::new(vec![vec![7u8; 512], vec![7u8; 512]]);
+    let digest = batch.digest();
+    let message = WorkerSynchronizeMessage {
+        digests: vec![digest],
+        target: target_primary.public_key(),
+    };
+
+    let mut mock_server = MockWorkerToWorker::new();
+    // The target worker answers with a compressed batch.
+    let mock_batch_response = EncodedBatch::encode(
+        batch.clone(),
+        &BatchCompressionParameters {
+            codec: BatchCompressionCodec::Zstd,
+            level: 3,
+        },
+    );
+    assert_eq!(mock_batch_response.codec(), BatchCompressionCodec::Zstd);
+    mock_server
+        .expect_request_compressed_batch()
+        .withf(move |request| {
+            request.body().batch == digest
+                && request.body().compression == BatchCompressionCodec::Zstd
+        })
+        .return_once(move |_| {
+            Ok(anemo::Response::new(RequestCompressedBatchResponse {
+                batch: Some(mock_batch_response),
+            }))
+        });
+    let routes = anemo::Router::new().add_rpc_service(WorkerToWorkerServer::new(mock_server));
+    let target_worker = target_primary.worker(id);
+    let _recv_network = target_worker.new_network(routes);
+
+    // Check not in store
+    assert!(store.read(digest).await.unwrap().is_none());
+
+    // Send a sync request.
+    let mut request = anemo::Request::new(message);
+    let send_network = test_utils::random_network();
+    send_network
+        .connect_with_peer_id(
+            network::multiaddr_to_address(&target_worker.info().worker_address).unwrap(),
+            anemo::PeerId(target_worker.info().name.0.to_bytes()),
+        )
+        .await
+        .unwrap();
+    assert!(request
+        .extensions_mut()
+        .insert(send_network.downgrade())
+        .is_none());
+    handler.synchronize(request).await.unwrap();
+
+    // Check its now stored
+    assert!(store.notify_read(digest).await.unwrap().is_some())
+}
+
+#[tokio::test]
+async fn synchronize_falls_back_to_uncompressed_batches() {
+    telemetry_subscribers::init_for_testing();
+
+    let fixture = CommitteeFixture::builder().randomize_ports(true).build();
+    let committee = fixture.committee();
+    let worker_cache = fixture.shared_worker_cache();
+    let name = fixture.authorities().next().unwrap().public_key();
+    let id = 0;
+    let (tx_reconfigure, _rx_reconfigure) =
+        watch::channel(ReconfigureNotification::NewEpoch(committee.clone()));
+
+    // Create a new test store.
+    let store = test_utils::open_batch_store();
+
+    let handler = PrimaryReceiverHandler {
+        name,
+        id,
+        committee: committee.into(),
+        worker_cache,
+        store: store.clone(),
+        request_batch_timeout: Duration::from_secs(999),
+        request_batch_retry_nodes: 3, // Not used in this test.
+        tx_reconfigure,
+        validator: TrivialTransactionValidator,
+        batch_status: BatchStatusTracker::default(),
+        batch_compression: BatchCompressionParameters {
+            codec: BatchCompressionCodec::Zstd,
+            level: 3,
+        },
     };
 
     // Set up mock behavior for child RequestBatches RPC.
@@ -44,7 +138,11 @@ async fn synchronize() {
         target: target_primary.public_key(),
     };
 
+    // The target worker predates compressed batches.
     let mut mock_server = MockWorkerToWorker::new();
+    mock_server
+        .expect_request_compressed_batch()
+        .return_once(|_| Err(anemo::rpc::Status::new(StatusCode::NotFound)));
     let mock_batch_response = batch.clone();
     mock_server
         .expect_request_batch()
@@ -106,6 +204,8 @@ async fn synchronize_when_batch_exists() {
         request_batch_retry_nodes: 3, // Not used in this test.
         tx_reconfigure,
         validator: TrivialTransactionValidator,
+        batch_status: BatchStatusTracker::default(),
+        batch_compression: BatchCompressionParameters::default(),
     };
 
     // Store the batch.
@@ -158,6 +258,8 @@ async fn delete_batches() {
         request_batch_retry_nodes: 3, // Not used in this test.
         tx_reconfigure,
         validator: TrivialTransactionValidator,
+        batch_status: BatchStatusTracker::default(),
+        batch_compression: BatchCompressionParameters::default(),
     };
     let message = WorkerDeleteBatchesMessage {
         digests: vec![digest],
@@ -169,3 +271,47 @@ async fn delete_batches() {
 
     assert!(store.read(digest).await.unwrap().is_none());
 }
+
+#[tokio::test]
+async fn report_batch_status() {
+    let fixture = CommitteeFixture::builder().randomize_ports(true).build();
+    let committee = fixture.committee();
+    let worker_cache = fixture.shared_worker_cache();
+    let name = fixture.authorities().next().unwrap().public_key();
+    let id = 0;
+    let (tx_reconfigure, _rx_reconfigure) =
+        watch::channel(ReconfigureNotification::NewEpoch(committee.clone()));
+
+    let batch_status = BatchStatusTracker::default();
+    let digest = test_utils::batch().digest();
+    batch_status.update(digest, BatchStatus::Sealed);
+
+    let handler = PrimaryReceiverHandler {
+        name,
+        id,
+        committee: committee.into(),
+        worker_cache,
+        store: test_utils::open_batch_store(),
+        request_batch_timeout: Duration::from_secs(999),
+        request_batch_retry_nodes: 3, // Not used in this test.
+        tx_reconfigure,
+        validator: TrivialTransactionValidator,
+        batch_status: batch_status.clone(),
+    };
+
+    // The primary reports the batch as committed.
+    let committed = BatchStatus::Committed {
+        certificate: CertificateDigest::new([0; 32]),
+        round: 2,
+        sub_dag_index: 1,
+    };
+    let message = WorkerBatchStatusMessage {
+        statuses: vec![(digest, committed.clone())],
+    };
+    handler
+        .report_batch_status(anemo::Request::new(message))
+        .await
+        .unwrap();
+
+    assert_eq!(batch_status.status(&digest), Some(committed));
+}
@@ -2,6 +2,8 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 use super::*;
+use config::BatchCompressionCodec;
+use prometheus::Registry;
 use test_utils::{batch, test_network, CommitteeFixture, WorkerToWorkerMockServer};
 
 #[tokio::test]
@@ -27,6 +29,8 @@ async fn wait_for_quorum() {
         rx_reconfiguration,
         rx_message,
         network.clone(),
+        BatchCompressionParameters::default(),
+        Arc::new(WorkerMetrics::new(&Registry::new())),
     );
 
     // Make a batch.
@@ -85,19 +89,31 @@ async fn pipeline_for_quorum() {
         rx_reconfiguration,
         rx_message,
         network.clone(),
+        BatchCompressionParameters {
+            codec: BatchCompressionCodec::Zstd,
+            level: 3,
+        },
+        Arc::new(WorkerMetrics::new(&Registry::new())),
     );
 
-    // Make a batch.
-    let batch = batch();
-    let message = WorkerBatchMessage {
-        batch: batch.clone(),
-    };
+    // Make a batch that compresses well, so it goes out compressed.
+    let batch = Batch::new(vec![vec![7u8; 512], vec![7u8; 512]]);
 
     // Spawn enough listeners to acknowledge our batches.
     let mut listener_handles = Vec::new();
-    for worker in fixture.authorities().skip(1).map(|a| a.worker(0)) {
-        let handle =
-            WorkerToWorkerMockServer::spawn(worker.keypair(), worker.info().worker_address.clone());
+    // One of them predates compressed batches and must still receive them.
+    for (i, worker) in fixture
+        .authorities()
+        .skip(1)
+        .map(|a| a.worker(0))
+        .enumerate()
+    {
+        let address = worker.info().worker_address.clone();
+        let handle = if i == 0 {
+            WorkerToWorkerMockServer::spawn_without_compression(worker.keypair(), address)
+        } else {
+            WorkerToWorkerMockServer::spawn(worker.keypair(), address)
+        };
         listener_handles.push(handle);
 
         // ensure that the networks are connected
@@ -120,8 +136,8 @@ async fn pipeline_for_quorum() {
 
     // Ensure the other listeners correctly received the batch.
     for (mut handle, _network) in listener_handles {
-        assert_eq!(handle.recv().await.unwrap(), message);
-        assert_eq!(handle.recv().await.unwrap(), message);
+        assert_eq!(handle.recv().await.unwrap().batch, batch);
+        assert_eq!(handle.recv().await.unwrap().batch, batch);
     }
 
     r1.await.unwrap();
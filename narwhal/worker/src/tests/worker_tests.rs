@@ -18,7 +18,8 @@ use storage::NodeStorage;
 use store::rocks;
 use test_utils::{batch, temp_dir, test_network, transaction, CommitteeFixture};
 use types::{
-    MockWorkerToPrimary, MockWorkerToWorker, TransactionsClient, WorkerBatchMessage,
+    BatchStatusProto, CertificateDigest, MockWorkerToPrimary, MockWorkerToWorker,
+    PrimaryToWorkerClient, TransactionsClient, WorkerBatchMessage, WorkerBatchStatusMessage,
     WorkerToPrimaryServer, WorkerToWorkerClient,
 };
 
@@ -227,6 +228,141 @@ async fn handle_clients_transactions() {
     assert!(join_handle.await.is_ok());
 }
 
+#[tokio::test]
+async fn follow_batch_status() {
+    let fixture = CommitteeFixture::builder().randomize_ports(true).build();
+    let committee = fixture.committee();
+    let worker_cache = fixture.shared_worker_cache();
+
+    let worker_id = 0;
+    let my_primary = fixture.authorities().next().unwrap();
+    let myself = my_primary.worker(worker_id);
+    let name = my_primary.public_key();
+
+    let parameters = Parameters {
+        batch_size: 200, // Two transactions.
+        ..Parameters::default()
+    };
+
+    // Create a new test store.
+    let db = rocks::DBMap::<BatchDigest, Batch>::open(temp_dir(), None, Some("batches")).unwrap();
+    let store = Store::new(db);
+
+    let registry = Registry::new();
+    let metrics = initialise_metrics(&registry);
+
+    // Spawn a `Worker` instance.
+    Worker::spawn(
+        name.clone(),
+        myself.keypair(),
+        worker_id,
+        Arc::new(ArcSwap::from_pointee(committee.clone())),
+        worker_cache.clone(),
+        parameters,
+        TrivialTransactionValidator::default(),
+        store,
+        metrics,
+    );
+
+    // Spawn a mock primary and enough workers' listeners to acknowledge our batches.
+    let mut mock_primary_server = MockWorkerToPrimary::new();
+    mock_primary_server
+        .expect_report_our_batch()
+        .returning(|_| Ok(anemo::Response::new(())));
+    let primary_routes =
+        anemo::Router::new().add_rpc_service(WorkerToPrimaryServer::new(mock_primary_server));
+    let primary_network = my_primary.new_network(primary_routes);
+
+    let mut peer_networks = Vec::new();
+    for worker in fixture.authorities().skip(1).map(|a| a.worker(worker_id)) {
+        let mut mock_server = MockWorkerToWorker::new();
+        mock_server
+            .expect_report_batch()
+            .returning(|_| Ok(anemo::Response::new(())));
+        let routes = anemo::Router::new().add_rpc_service(WorkerToWorkerServer::new(mock_server));
+        peer_networks.push(worker.new_network(routes));
+    }
+
+    // Wait till other services have been able to start up
+    tokio::task::yield_now().await;
+    // Send enough transactions to create a batch.
+    let batch = batch();
+    let batch_digest = batch.digest();
+    let address = worker_cache
+        .load()
+        .worker(&name, &worker_id)
+        .unwrap()
+        .transactions;
+    let config = mysten_network::config::Config::new();
+    let channel = config.connect_lazy(&address).unwrap();
+    let mut client = TransactionsClient::new(channel);
+
+    let mut fut_list = FuturesOrdered::new();
+    for tx in batch.transactions {
+        let txn = TransactionProto {
+            transaction: Bytes::from(tx.clone()),
+        };
+        let mut inner_client = client.clone();
+        fut_list.push_back(async move {
+            inner_client
+                .submit_transaction_with_status(txn)
+                .await
+                .unwrap()
+                .into_inner()
+        });
+    }
+    while let Some(response) = fut_list.next().await {
+        let digest: BatchDigest = response.batch_digest.unwrap().try_into().unwrap();
+        assert_eq!(digest, batch_digest);
+    }
+
+    // Follow the batch.
+    let mut updates = client
+        .subscribe_batch_status(BatchStatusRequest {
+            batch_digest: Some(batch_digest.into()),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    let update = updates.message().await.unwrap().unwrap();
+    assert!(matches!(update.status, Some(BatchStatusProto::Sealed(_))));
+
+    // Our primary reports the batch as committed.
+    let worker_address = network::multiaddr_to_address(&myself.info().worker_address).unwrap();
+    let peer_id = primary_network
+        .connect_with_peer_id(
+            worker_address,
+            PeerId(myself.keypair().public().0.to_bytes()),
+        )
+        .await
+        .unwrap();
+    let message = WorkerBatchStatusMessage {
+        statuses: vec![(
+            batch_digest,
+            BatchStatus::Committed {
+                certificate: CertificateDigest::new([0; 32]),
+                round: 2,
+                sub_dag_index: 1,
+            },
+        )],
+    };
+    PrimaryToWorkerClient::new(primary_network.peer(peer_id).unwrap())
+        .report_batch_status(message)
+        .await
+        .unwrap();
+
+    let update = updates.message().await.unwrap().unwrap();
+    match update.status {
+        Some(BatchStatusProto::Committed(committed)) => {
+            assert_eq!(committed.round, 2);
+            assert_eq!(committed.sub_dag_index, 1);
+        }
+        status => panic!("Unexpected batch status {status:?}"),
+    }
+    // The stream ends once the batch is committed.
+    assert!(updates.message().await.unwrap().is_none());
+}
+
 #[tokio::test]
 async fn get_network_peers_from_admin_server() {
     // telemetry_subscribers::init_for_testing();
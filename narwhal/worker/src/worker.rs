@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
     batch_maker::BatchMaker,
+    batch_status::BatchStatusTracker,
     handlers::{PrimaryReceiverHandler, WorkerReceiverHandler},
     metrics::WorkerChannelMetrics,
     primary_connector::PrimaryConnector,
@@ -19,24 +20,25 @@ use anemo_tower::{
 use async_trait::async_trait;
 use config::{Parameters, SharedCommittee, SharedWorkerCache, WorkerId};
 use crypto::{traits::KeyPair as _, NetworkKeyPair, NetworkPublicKey, PublicKey};
-use futures::StreamExt;
+use futures::{Stream, StreamExt};
 use multiaddr::{Multiaddr, Protocol};
 use mysten_metrics::spawn_logged_monitored_task;
 use network::failpoints::FailpointsMakeCallbackHandler;
 use network::metrics::MetricsMakeCallbackHandler;
 use std::collections::HashMap;
-use std::{net::Ipv4Addr, sync::Arc};
+use std::{net::Ipv4Addr, pin::Pin, sync::Arc};
 use store::Store;
 use tap::TapFallible;
 use tokio::sync::watch::Receiver;
 use tokio::{sync::watch, task::JoinHandle};
-use tonic::{Request, Response, Status};
+use tonic::{Code, Request, Response, Status};
 use tower::ServiceBuilder;
 use tracing::{error, info};
 use types::{
     error::DagError,
     metered_channel::{channel_with_total, Sender},
-    Batch, BatchDigest, Empty, PrimaryToWorkerServer, ReconfigureNotification, Transaction,
+    Batch, BatchDigest, BatchStatus, BatchStatusRequest, BatchStatusUpdate, Empty,
+    PrimaryToWorkerServer, ReconfigureNotification, SubmitTransactionResponse, Transaction,
     TransactionProto, Transactions, TransactionsServer, TxResponse, WorkerOurBatchMessage,
     WorkerToWorkerServer,
 };
@@ -118,6 +120,8 @@ impl Worker {
             &channel_metrics.tx_others_batch_total,
         );
 
+        let batch_status = BatchStatusTracker::default();
+
         let initial_committee = (*(*(*committee).load()).clone()).clone();
         let (tx_reconfigure, rx_reconfigure) =
             watch::channel(ReconfigureNotification::NewEpoch(initial_committee));
@@ -127,6 +131,7 @@ impl Worker {
             tx_others_batch,
             store: worker.store.clone(),
             validator: validator.clone(),
+            batch_compression: worker.parameters.batch_compression.clone(),
         });
         let primary_service = PrimaryToWorkerServer::new(PrimaryReceiverHandler {
             name: worker.primary_name.clone(),
@@ -138,6 +143,8 @@ impl Worker {
             request_batch_retry_nodes: worker.parameters.sync_retry_nodes,
             tx_reconfigure,
             validator: validator.clone(),
+            batch_status: batch_status.clone(),
+            batch_compression: worker.parameters.batch_compression.clone(),
         });
 
         // Receive incoming messages from other workers.
@@ -299,6 +306,7 @@ impl Worker {
             channel_metrics,
             endpoint_metrics,
             validator,
+            batch_status,
             network.clone(),
         );
 
@@ -379,6 +387,7 @@ impl Worker {
         channel_metrics: Arc<WorkerChannelMetrics>,
         endpoint_metrics: WorkerEndpointMetrics,
         validator: impl TransactionValidator,
+        batch_status: BatchStatusTracker,
         network: anemo::Network,
     ) -> Vec<JoinHandle<()>> {
         let (tx_batch_maker, rx_batch_maker) = channel_with_total(
@@ -405,6 +414,7 @@ impl Worker {
         let tx_receiver_handle = TxReceiverHandler {
             tx_batch_maker,
             validator,
+            batch_status,
         }
         .spawn(address.clone(), rx_reconfigure.clone(), endpoint_metrics);
 
@@ -419,7 +429,7 @@ impl Worker {
             rx_reconfigure.clone(),
             rx_batch_maker,
             tx_quorum_waiter,
-            node_metrics,
+            node_metrics.clone(),
             self.store.clone(),
             tx_our_batch,
         );
@@ -434,6 +444,8 @@ impl Worker {
             rx_reconfigure,
             /* rx_message */ rx_quorum_waiter,
             network,
+            self.parameters.batch_compression.clone(),
+            node_metrics,
         );
 
         info!(
@@ -450,6 +462,7 @@ impl Worker {
 struct TxReceiverHandler<V> {
     tx_batch_maker: Sender<(Transaction, TxResponse)>,
     validator: V,
+    batch_status: BatchStatusTracker,
 }
 
 impl<V: TransactionValidator> TxReceiverHandler<V> {
@@ -490,13 +503,10 @@ impl<V: TransactionValidator> TxReceiverHandler<V> {
     }
 }
 
-#[async_trait]
-impl<V: TransactionValidator> Transactions for TxReceiverHandler<V> {
-    async fn submit_transaction(
-        &self,
-        request: Request<TransactionProto>,
-    ) -> Result<Response<Empty>, Status> {
-        let message = request.into_inner().transaction;
+impl<V: TransactionValidator> TxReceiverHandler<V> {
+    /// Validates the transaction and forwards it to the batch maker. Returns the digest of the
+    /// batch that includes the transaction once that batch is sealed.
+    async fn submit(&self, message: Transaction) -> Result<BatchDigest, Status> {
         if message.len() > MAX_ALLOWED_TRANSACTION_SIZE {
             return Err(Status::resource_exhausted(format!(
                 "Transaction size is too large: {} > {}",
@@ -510,16 +520,64 @@ impl<V: TransactionValidator> Transactions for TxReceiverHandler<V> {
         // Send the transaction to the batch maker.
         let (notifier, when_done) = tokio::sync::oneshot::channel();
         self.tx_batch_maker
-            .send((message.to_vec(), notifier))
+            .send((message, notifier))
             .await
             .map_err(|_| DagError::ShuttingDown)
             .map_err(|e| Status::not_found(e.to_string()))?;
 
-        // TODO: distingush between a digest being returned vs the channel closing
-        // suggesting an error.
-        let _digest = when_done.await;
+        // The batch maker drops the notifier if the batch could not be sealed.
+        let digest = when_done
+            .await
+            .map_err(|_| Status::unavailable("Failed to seal a batch with the transaction"))?;
+        self.batch_status.update(digest, BatchStatus::Sealed);
+        Ok(digest)
+    }
+}
 
-        Ok(Response::new(Empty {}))
+#[async_trait]
+impl<V: TransactionValidator> Transactions for TxReceiverHandler<V> {
+    type SubscribeBatchStatusStream =
+        Pin<Box<dyn Stream<Item = Result<BatchStatusUpdate, Status>> + Send>>;
+
+    async fn submit_transaction(
+        &self,
+        request: Request<TransactionProto>,
+    ) -> Result<Response<Empty>, Status> {
+        let message = request.into_inner().transaction;
+        // Errors sealing the batch are not reported to callers of this endpoint.
+        match self.submit(message.to_vec()).await {
+            Err(status) if status.code() != Code::Unavailable => Err(status),
+            _ => Ok(Response::new(Empty {})),
+        }
+    }
+
+    async fn submit_transaction_with_status(
+        &self,
+        request: Request<TransactionProto>,
+    ) -> Result<Response<SubmitTransactionResponse>, Status> {
+        let message = request.into_inner().transaction;
+        let digest = self.submit(message.to_vec()).await?;
+        Ok(Response::new(SubmitTransactionResponse {
+            batch_digest: Some(digest.into()),
+        }))
+    }
+
+    async fn subscribe_batch_status(
+        &self,
+        request: Request<BatchStatusRequest>,
+    ) -> Result<Response<Self::SubscribeBatchStatusStream>, Status> {
+        let digest: BatchDigest = request
+            .into_inner()
+            .batch_digest
+            .ok_or_else(|| Status::invalid_argument("Missing batch digest"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("Invalid batch digest"))?;
+        let updates = self
+            .batch_status
+            .subscribe(&digest)
+            .ok_or_else(|| Status::not_found(format!("Unknown batch {digest}")))?
+            .map(move |status| Ok((digest, status).into()));
+        Ok(Response::new(Box::pin(updates)))
     }
 
     async fn submit_transaction_stream(
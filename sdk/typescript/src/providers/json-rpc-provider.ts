@@ -5,9 +5,9 @@ import { Provider } from './provider';
 import { HttpHeaders, JsonRpcClient } from '../rpc/client';
 import {
   isGetObjectDataResponse,
-  isGetOwnedObjectsResponse,
   isGetTxnDigestsResponse,
   isPaginatedEvents,
+  isPaginatedObjects,
   isPaginatedTransactionDigests,
   isSuiExecuteTransactionResponse,
   isSuiMoveFunctionArgTypes,
@@ -29,6 +29,7 @@ import {
   getObjectReference,
   GetTxnDigestsResponse,
   ObjectId,
+  PaginatedObjects,
   PaginatedTransactionDigests,
   SubscriptionId,
   SuiAddress,
@@ -326,11 +327,13 @@ export class JsonRpcProvider extends Provider {
       if (!address || !isValidSuiAddress(normalizeSuiAddress(address))) {
         throw new Error('Invalid Sui address');
       }
-      return await this.client.requestWithType(
-        'sui_getObjectsOwnedByAddress',
-        [address],
-        isGetOwnedObjectsResponse,
-        this.options.skipDataValidation
+      return await this.getAllPages((cursor) =>
+        this.client.requestWithType(
+          'sui_getObjectsOwnedByAddress',
+          [address, null, cursor, null],
+          isPaginatedObjects,
+          this.options.skipDataValidation
+        )
       );
     } catch (err) {
       throw new Error(
@@ -393,11 +396,13 @@ export class JsonRpcProvider extends Provider {
       if (!objectId || !isValidSuiObjectId(normalizeSuiObjectId(objectId))) {
         throw new Error('Invalid Sui Object id');
       }
-      return await this.client.requestWithType(
-        'sui_getObjectsOwnedByObject',
-        [objectId],
-        isGetOwnedObjectsResponse,
-        this.options.skipDataValidation
+      return await this.getAllPages((cursor) =>
+        this.client.requestWithType(
+          'sui_getObjectsOwnedByObject',
+          [objectId, null, cursor, null],
+          isPaginatedObjects,
+          this.options.skipDataValidation
+        )
       );
     } catch (err) {
       throw new Error(
@@ -406,6 +411,19 @@ export class JsonRpcProvider extends Provider {
     }
   }
 
+  private async getAllPages(
+    fetchPage: (cursor: ObjectId | null) => Promise<PaginatedObjects>
+  ): Promise<SuiObjectInfo[]> {
+    const objects: SuiObjectInfo[] = [];
+    let cursor: ObjectId | null = null;
+    do {
+      const page: PaginatedObjects = await fetchPage(cursor);
+      objects.push(...page.data);
+      cursor = page.nextCursor;
+    } while (cursor !== null);
+    return objects;
+  }
+
   async getObject(objectId: ObjectId): Promise<GetObjectDataResponse> {
     try {
       if (!objectId || !isValidSuiObjectId(normalizeSuiObjectId(objectId))) {
@@ -687,9 +705,10 @@ export class JsonRpcProvider extends Provider {
 
   async subscribeEvent(
     filter: SuiEventFilter,
-    onMessage: (event: SuiEventEnvelope) => void
+    onMessage: (event: SuiEventEnvelope) => void,
+    cursor?: EventId
   ): Promise<SubscriptionId> {
-    return this.wsClient.subscribeEvent(filter, onMessage);
+    return this.wsClient.subscribeEvent(filter, onMessage, cursor);
   }
 
   async unsubscribeEvent(id: SubscriptionId): Promise<boolean> {
@@ -237,10 +237,12 @@ export abstract class Provider {
    * Subscribe to get notifications whenever an event matching the filter occurs
    * @param filter - filter describing the subset of events to follow
    * @param onMessage - function to run when we receive a notification of a new event matching the filter
+   * @param cursor - optional id of the last event seen, matching events emitted after it are replayed before live events
    */
   abstract subscribeEvent(
     filter: SuiEventFilter,
-    onMessage: (event: SuiEventEnvelope) => void
+    onMessage: (event: SuiEventEnvelope) => void,
+    cursor?: EventId
   ): Promise<SubscriptionId>;
 
   /**
@@ -179,7 +179,8 @@ export class VoidProvider extends Provider {
 
   async subscribeEvent(
     _filter: SuiEventFilter,
-    _onMessage: (event: SuiEventEnvelope) => void
+    _onMessage: (event: SuiEventEnvelope) => void,
+    _cursor?: EventId
   ): Promise<SubscriptionId> {
     throw this.newError('subscribeEvent');
   }
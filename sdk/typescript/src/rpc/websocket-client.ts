@@ -2,7 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import { isSubscriptionEvent } from '../types/index.guard';
-import { SuiEventFilter, SuiEventEnvelope, SubscriptionId } from '../types';
+import {
+  EventId,
+  SuiEventFilter,
+  SuiEventEnvelope,
+  SubscriptionId,
+} from '../types';
 import { Client as WsRpcClient } from 'rpc-websockets';
 
 export const getWebsocketUrl = (httpUrl: string, port?: number): string => {
@@ -28,11 +33,14 @@ type FilterSubHandler = {
   id: SubscriptionId;
   onMessage: (event: SuiEventEnvelope) => void;
   filter: SuiEventFilter;
+  lastEventId?: EventId;
 };
 
 type SubscriptionData = {
   filter: SuiEventFilter;
   onMessage: (event: SuiEventEnvelope) => void;
+  // id of the last event delivered, used to resume the subscription after a reconnect
+  lastEventId?: EventId;
 };
 
 type MinimumSubscriptionMessage = {
@@ -149,13 +157,18 @@ export class WebsocketClient {
       // even with validation off, we must ensure a few properties at minimum in a message
       if (this.skipValidation && isMinimumSubscriptionMessage(params)) {
         const sub = this.eventSubscriptions.get(params.subscription);
-        if (sub)
+        if (sub) {
+          sub.lastEventId = (params.result as any).id ?? sub.lastEventId;
           // cast to bypass type validation of 'result'
           (sub.onMessage as (m: any) => void)(params.result);
+        }
       } else if (isSubscriptionEvent(params)) {
         // call any registered handler for the message's subscription
         const sub = this.eventSubscriptions.get(params.subscription);
-        if (sub) sub.onMessage(params.result);
+        if (sub) {
+          sub.lastEventId = params.result.id;
+          sub.onMessage(params.result);
+        }
       }
     }
   }
@@ -207,20 +220,25 @@ export class WebsocketClient {
           if (!filter || !onMessage) return Promise.resolve(null);
           /**
             re-subscribe to the same filter & replace the subscription id.
+            resuming after the last event we received lets the node replay
+            anything emitted while we were disconnected.
             we skip calling sui_unsubscribeEvent for the old sub id, because:
               * we assume this is being called after a reconnection
               * the node keys subscriptions with a combo of connection id & subscription id
           */
-          const id = await this.subscribeEvent(filter, onMessage);
-          return { id, onMessage, filter };
+          const id = await this.subscribeEvent(
+            filter,
+            onMessage,
+            sub.lastEventId
+          );
+          return { id, onMessage, filter, lastEventId: sub.lastEventId };
         })
       );
 
       newSubsArr.forEach((entry) => {
         if (entry === null) return;
-        const filter = entry.filter;
-        const onMessage = entry.onMessage;
-        newSubs.set(entry.id, { filter, onMessage });
+        const { filter, onMessage, lastEventId } = entry;
+        newSubs.set(entry.id, { filter, onMessage, lastEventId });
       });
 
       this.eventSubscriptions = newSubs;
@@ -231,7 +249,8 @@ export class WebsocketClient {
 
   async subscribeEvent(
     filter: SuiEventFilter,
-    onMessage: (event: SuiEventEnvelope) => void
+    onMessage: (event: SuiEventEnvelope) => void,
+    cursor?: EventId
   ): Promise<SubscriptionId> {
     try {
       // lazily connect to websocket to avoid spamming node with connections
@@ -240,11 +259,15 @@ export class WebsocketClient {
 
       let subId = (await this.rpcClient.call(
         SUBSCRIBE_EVENT_METHOD,
-        [filter],
+        cursor ? [filter, cursor] : [filter],
         this.options.callTimeout
       )) as SubscriptionId;
 
-      this.eventSubscriptions.set(subId, { filter, onMessage });
+      this.eventSubscriptions.set(subId, {
+        filter,
+        onMessage,
+        lastEventId: cursor,
+      });
       return subId;
     } catch (err) {
       throw new Error(
@@ -94,7 +94,9 @@ export type EventQuery =
     | { "Sender": SuiAddress }
     | { "Recipient": ObjectOwner }
     | { "Object": ObjectId }
-    | { "TimeRange": { "start_time": number, "end_time": number } };
+    | { "TimeRange": { "start_time": number, "end_time": number } }
+    | { "And": [EventQuery, EventQuery] }
+    | { "Or": [EventQuery, EventQuery] };
 
 export type EventId = {
   txSeq: number,
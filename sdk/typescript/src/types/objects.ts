@@ -139,6 +139,11 @@ export type ObjectType = 'moveObject' | 'package';
 
 export type GetOwnedObjectsResponse = SuiObjectInfo[];
 
+export type PaginatedObjects = {
+  data: SuiObjectInfo[];
+  nextCursor: ObjectId | null;
+};
+
 export type GetObjectDataResponse = {
   status: ObjectStatus;
   details: SuiObject | ObjectId | SuiObjectRef;
@@ -119,6 +119,8 @@ export type DevInspectResultTupleType = [number, ExecutionResultType];
 export type ExecutionResultType = {
   mutableReferenceOutputs?: MutableReferenceOutputType[];
   returnValues?: ReturnValueType[];
+  parsedMutableReferenceOutputs?: any[];
+  parsedReturnValues?: any[];
 };
 
 export type MutableReferenceOutputType = [number, number[], string];